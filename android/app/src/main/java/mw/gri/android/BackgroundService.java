@@ -23,6 +23,8 @@ public class BackgroundService extends Service {
     private boolean mStopped = false;
 
     private static final int NOTIFICATION_ID = 1;
+    private static final String FUNDS_CHANNEL_ID = TAG + "Funds";
+    private int mFundsNotificationId = NOTIFICATION_ID + 1;
     private NotificationCompat.Builder mNotificationBuilder;
 
     private String mNotificationContentText = "";
@@ -68,6 +70,12 @@ public class BackgroundService extends Service {
                 mNotificationBuilder.setStyle(new NotificationCompat.BigTextStyle().bigText(mNotificationContentText));
             }
 
+            // Show notification about received funds.
+            String receivedText = getReceivedFundsText();
+            if (receivedText != null) {
+                showFundsNotification(receivedText);
+            }
+
             // Send broadcast to MainActivity if exit from the app is needed after node stop.
             if (exitAppAfterNodeStop()) {
                 sendBroadcast(new Intent(MainActivity.STOP_APP_ACTION));
@@ -147,6 +155,11 @@ public class BackgroundService extends Service {
 
             NotificationManager manager = getSystemService(NotificationManager.class);
             manager.createNotificationChannel(notificationChannel);
+
+            NotificationChannel fundsChannel = new NotificationChannel(
+                    FUNDS_CHANNEL_ID, FUNDS_CHANNEL_ID, NotificationManager.IMPORTANCE_DEFAULT
+            );
+            manager.createNotificationChannel(fundsChannel);
         }
 
         // Show notification with sync status.
@@ -216,11 +229,27 @@ public class BackgroundService extends Service {
         }
     }
 
+    // Show notification about received funds.
+    private void showFundsNotification(String text) {
+        Intent i = getPackageManager().getLaunchIntentForPackage(this.getPackageName());
+        PendingIntent pendingIntent = PendingIntent.getActivity(this, 0, i, PendingIntent.FLAG_IMMUTABLE);
+        Notification notification = new NotificationCompat.Builder(this, FUNDS_CHANNEL_ID)
+                .setContentTitle(getSyncTitle())
+                .setContentText(text)
+                .setSmallIcon(R.drawable.ic_stat_name)
+                .setAutoCancel(true)
+                .setContentIntent(pendingIntent)
+                .build();
+        NotificationManager manager = getSystemService(NotificationManager.class);
+        manager.notify(mFundsNotificationId++, notification);
+    }
+
     // Remove notification.
     private void clearNotification() {
         NotificationManager notificationManager = getSystemService(NotificationManager.class);
         if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.O) {
             notificationManager.deleteNotificationChannel(TAG);
+            notificationManager.deleteNotificationChannel(FUNDS_CHANNEL_ID);
         }
         notificationManager.cancel(NOTIFICATION_ID);
     }
@@ -275,4 +304,7 @@ public class BackgroundService extends Service {
 
     // Check if app from the app is needed after node stop.
     private native boolean exitAppAfterNodeStop();
+
+    // Get text about received funds for notification, null if nothing was received.
+    private native String getReceivedFundsText();
 }
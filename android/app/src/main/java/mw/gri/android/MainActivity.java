@@ -6,10 +6,15 @@ import android.app.Activity;
 import android.content.*;
 import android.content.pm.PackageManager;
 import android.content.res.Configuration;
+import android.hardware.biometrics.BiometricManager;
+import android.hardware.biometrics.BiometricPrompt;
 import android.net.Uri;
 import android.os.*;
 import android.os.Process;
 import android.provider.Settings;
+import android.security.keystore.KeyGenParameterSpec;
+import android.security.keystore.KeyPermanentlyInvalidatedException;
+import android.security.keystore.KeyProperties;
 import android.system.ErrnoException;
 import android.system.Os;
 import android.util.Size;
@@ -30,7 +35,13 @@ import androidx.core.view.WindowInsetsCompat;
 import com.google.androidgamesdk.GameActivity;
 import com.google.common.util.concurrent.ListenableFuture;
 
+import javax.crypto.Cipher;
+import javax.crypto.KeyGenerator;
+import javax.crypto.SecretKey;
+import javax.crypto.spec.GCMParameterSpec;
 import java.io.*;
+import java.security.KeyStore;
+import java.util.Arrays;
 import java.util.concurrent.ExecutorService;
 import java.util.concurrent.Executors;
 
@@ -212,6 +223,11 @@ public class MainActivity extends GameActivity {
         if (data == null) {
             return;
         }
+        // Pass payment request link into native code.
+        if ("grin".equals(data.getScheme())) {
+            onData(data.toString());
+            return;
+        }
         if (Build.VERSION.SDK_INT >= 30) {
             if (!Environment.isExternalStorageManager()) {
                 Intent i = new Intent(Settings.ACTION_MANAGE_ALL_FILES_ACCESS_PERMISSION);
@@ -310,6 +326,11 @@ public class MainActivity extends GameActivity {
         finishAndRemoveTask();
     }
 
+    // Called from native code to keep wallets syncing at background.
+    public void startBackgroundService() {
+        BackgroundService.start(this);
+    }
+
     @Override
     protected void onDestroy() {
         unregisterReceiver(mBroadcastReceiver);
@@ -479,4 +500,155 @@ public class MainActivity extends GameActivity {
 
     // Pass picked file into native code.
     public native void onFilePick(String path);
+
+    private static final String KEYSTORE_PROVIDER = "AndroidKeyStore";
+    private static final String SECRET_KEY_ALIAS = "grim_secret_key";
+    private static final String BIOMETRIC_KEY_ALIAS = "grim_biometric_key";
+    private static final int GCM_IV_LENGTH = 12;
+    // Seconds to allow usage of biometric key after successful authentication.
+    private static final int BIOMETRIC_AUTH_VALIDITY = 10;
+
+    // Get or create key at platform keystore to encrypt secrets,
+    // biometric key can be used only after biometric authentication.
+    private SecretKey getSecretKey(boolean biometric) throws Exception {
+        String alias = biometric ? BIOMETRIC_KEY_ALIAS : SECRET_KEY_ALIAS;
+        KeyStore keyStore = KeyStore.getInstance(KEYSTORE_PROVIDER);
+        keyStore.load(null);
+        if (keyStore.containsAlias(alias)) {
+            return (SecretKey) keyStore.getKey(alias, null);
+        }
+        KeyGenParameterSpec.Builder spec = new KeyGenParameterSpec.Builder(alias,
+                KeyProperties.PURPOSE_ENCRYPT | KeyProperties.PURPOSE_DECRYPT)
+                .setBlockModes(KeyProperties.BLOCK_MODE_GCM)
+                .setEncryptionPaddings(KeyProperties.ENCRYPTION_PADDING_NONE);
+        if (biometric) {
+            spec.setUserAuthenticationRequired(true)
+                    .setInvalidatedByBiometricEnrollment(true);
+            if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.R) {
+                spec.setUserAuthenticationParameters(BIOMETRIC_AUTH_VALIDITY,
+                        KeyProperties.AUTH_BIOMETRIC_STRONG);
+            } else {
+                spec.setUserAuthenticationValidityDurationSeconds(BIOMETRIC_AUTH_VALIDITY);
+            }
+        }
+        KeyGenerator generator = KeyGenerator.getInstance(KeyProperties.KEY_ALGORITHM_AES, KEYSTORE_PROVIDER);
+        generator.init(spec.build());
+        return generator.generateKey();
+    }
+
+    // Remove biometric key when it was invalidated by new biometric enrollment.
+    private void deleteBiometricKey() {
+        try {
+            KeyStore keyStore = KeyStore.getInstance(KEYSTORE_PROVIDER);
+            keyStore.load(null);
+            keyStore.deleteEntry(BIOMETRIC_KEY_ALIAS);
+        } catch (Exception ignored) {
+        }
+    }
+
+    // Encrypt data with platform key.
+    private byte[] encrypt(byte[] data, boolean biometric) {
+        try {
+            Cipher cipher = Cipher.getInstance("AES/GCM/NoPadding");
+            cipher.init(Cipher.ENCRYPT_MODE, getSecretKey(biometric));
+            byte[] iv = cipher.getIV();
+            byte[] encrypted = cipher.doFinal(data);
+            byte[] result = Arrays.copyOf(iv, iv.length + encrypted.length);
+            System.arraycopy(encrypted, 0, result, iv.length, encrypted.length);
+            return result;
+        } catch (KeyPermanentlyInvalidatedException e) {
+            deleteBiometricKey();
+            return new byte[0];
+        } catch (Exception e) {
+            return new byte[0];
+        }
+    }
+
+    // Decrypt data with platform key.
+    private byte[] decrypt(byte[] data, boolean biometric) {
+        try {
+            Cipher cipher = Cipher.getInstance("AES/GCM/NoPadding");
+            GCMParameterSpec spec = new GCMParameterSpec(128, data, 0, GCM_IV_LENGTH);
+            cipher.init(Cipher.DECRYPT_MODE, getSecretKey(biometric), spec);
+            return cipher.doFinal(data, GCM_IV_LENGTH, data.length - GCM_IV_LENGTH);
+        } catch (KeyPermanentlyInvalidatedException e) {
+            deleteBiometricKey();
+            return new byte[0];
+        } catch (Exception e) {
+            return new byte[0];
+        }
+    }
+
+    // Called from native code to encrypt data with platform key.
+    public byte[] encryptSecret(byte[] data) {
+        return encrypt(data, false);
+    }
+
+    // Called from native code to decrypt data with platform key.
+    public byte[] decryptSecret(byte[] data) {
+        return decrypt(data, false);
+    }
+
+    // Called from native code to encrypt data with biometric key after authentication.
+    public byte[] encryptBiometricSecret(byte[] data) {
+        return encrypt(data, true);
+    }
+
+    // Called from native code to decrypt data with biometric key after authentication.
+    public byte[] decryptBiometricSecret(byte[] data) {
+        return decrypt(data, true);
+    }
+
+    // Called from native code to check if biometric authentication is available.
+    public boolean biometricAvailable() {
+        if (Build.VERSION.SDK_INT < Build.VERSION_CODES.P) {
+            return false;
+        }
+        // Only strong biometric can unlock keystore keys.
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.R) {
+            BiometricManager bm = getSystemService(BiometricManager.class);
+            return bm != null && bm.canAuthenticate(BiometricManager.Authenticators.BIOMETRIC_STRONG)
+                    == BiometricManager.BIOMETRIC_SUCCESS;
+        }
+        PackageManager pm = getPackageManager();
+        if (pm.hasSystemFeature(PackageManager.FEATURE_FINGERPRINT)) {
+            return true;
+        }
+        return Build.VERSION.SDK_INT >= Build.VERSION_CODES.Q &&
+                (pm.hasSystemFeature(PackageManager.FEATURE_FACE) ||
+                        pm.hasSystemFeature(PackageManager.FEATURE_IRIS));
+    }
+
+    // Called from native code to start biometric authentication.
+    public void startBiometricAuth() {
+        if (Build.VERSION.SDK_INT < Build.VERSION_CODES.P) {
+            onBiometricResult(false);
+            return;
+        }
+        runOnUiThread(() -> {
+            BiometricPrompt.Builder builder = new BiometricPrompt.Builder(this)
+                    .setTitle("Grim")
+                    .setNegativeButton(getString(android.R.string.cancel), getMainExecutor(),
+                            (dialog, which) -> onBiometricResult(false));
+            if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.R) {
+                builder.setAllowedAuthenticators(BiometricManager.Authenticators.BIOMETRIC_STRONG);
+            }
+            BiometricPrompt prompt = builder.build();
+            prompt.authenticate(new CancellationSignal(), getMainExecutor(),
+                    new BiometricPrompt.AuthenticationCallback() {
+                @Override
+                public void onAuthenticationSucceeded(BiometricPrompt.AuthenticationResult result) {
+                    onBiometricResult(true);
+                }
+
+                @Override
+                public void onAuthenticationError(int errorCode, CharSequence errString) {
+                    onBiometricResult(false);
+                }
+            });
+        });
+    }
+
+    // Pass biometric authentication result into native code.
+    public native void onBiometricResult(boolean success);
 }
\ No newline at end of file
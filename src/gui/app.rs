@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 use lazy_static::lazy_static;
-use egui::{Align, Context, CursorIcon, Layout, Modifiers, ResizeDirection, Rounding, Stroke, UiBuilder, ViewportCommand};
+use egui::{Align, Context, CursorIcon, LayerId, Layout, Modifiers, Order, ResizeDirection, Rounding, Stroke, UiBuilder, ViewportCommand};
 use egui::epaint::{RectShape};
 use egui::os::OperatingSystem;
 
 use crate::AppConfig;
-use crate::gui::Colors;
-use crate::gui::icons::{ARROWS_IN, ARROWS_OUT, CARET_DOWN, MOON, SUN, X};
+use crate::gui::{AppRoute, Colors};
+use crate::gui::icons::{ARROWS_IN, ARROWS_OUT, CARET_DOWN, FILE_ARROW_DOWN, LIST, MOON, SUN, X};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, Modal, TitlePanel, View};
+use crate::gui::views::wallets::WalletWindow;
 use crate::wallet::ExternalConnection;
 
 lazy_static! {
@@ -39,7 +42,13 @@ pub struct App<Platform> {
     /// Last window resize direction.
     resize_direction: Option<ResizeDirection>,
     /// Flag to check if it's first draw.
-    first_draw: bool
+    first_draw: bool,
+    /// Time of last user input in seconds to lock wallets on inactivity.
+    last_input_time: i64,
+    /// Flag to check if desktop window is minimized.
+    minimized: bool,
+    /// Wallets shown at separate desktop windows.
+    wallet_windows: Vec<WalletWindow>
 }
 
 impl<Platform: PlatformCallbacks> App<Platform> {
@@ -48,7 +57,10 @@ impl<Platform: PlatformCallbacks> App<Platform> {
             platform,
             content: Content::default(),
             resize_direction: None,
-            first_draw: true
+            first_draw: true,
+            last_input_time: chrono::Utc::now().timestamp(),
+            minimized: false,
+            wallet_windows: vec![]
         }
     }
 
@@ -64,6 +76,23 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         crate::setup_visuals(ctx);
     }
 
+    /// Close opened wallets if there was no user input for configured time.
+    fn check_auto_lock(&mut self, ctx: &Context) {
+        let now = chrono::Utc::now().timestamp();
+        let has_input = ctx.input(|i| !i.events.is_empty() || i.pointer.is_moving());
+        if has_input {
+            self.last_input_time = now;
+        }
+        if let Some(minutes) = AppConfig::auto_lock_minutes() {
+            if now - self.last_input_time >= minutes as i64 * 60 {
+                self.content.wallets.lock_wallets();
+                self.last_input_time = now;
+            }
+            // Check inactivity time while there is no input.
+            ctx.request_repaint_after(Duration::from_secs(30));
+        }
+    }
+
     /// Draw application content.
     pub fn ui(&mut self, ctx: &Context) {
         if self.first_draw {
@@ -71,6 +100,21 @@ impl<Platform: PlatformCallbacks> App<Platform> {
             self.first_draw = false;
         }
 
+        // Check connections health periodically.
+        ExternalConnection::check_periodically(ctx);
+
+        // Close opened wallets on inactivity.
+        self.check_auto_lock(ctx);
+
+        // Switch theme by schedule.
+        if let Some(use_dark) = AppConfig::scheduled_dark_theme() {
+            if AppConfig::dark_theme() != Some(use_dark) {
+                AppConfig::set_dark_theme(use_dark);
+                crate::setup_visuals(ctx);
+            }
+            ctx.request_repaint_after(Duration::from_secs(60));
+        }
+
         // Handle Esc keyboard key event and platform Back button key event.
         let back_pressed = BACK_BUTTON_PRESSED.load(Ordering::Relaxed);
         if back_pressed || ctx.input_mut(|i| i.consume_key(Modifiers::NONE, egui::Key::Escape)) {
@@ -82,6 +126,29 @@ impl<Platform: PlatformCallbacks> App<Platform> {
             ctx.request_repaint();
         }
 
+        // Pause camera capture while desktop window is minimized.
+        if View::is_desktop() {
+            let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+            if minimized != self.minimized {
+                self.minimized = minimized;
+                if minimized {
+                    self.platform.pause_camera();
+                } else {
+                    self.platform.resume_camera();
+                }
+            }
+        }
+
+        // Handle application menu keyboard shortcuts on desktop.
+        if View::is_desktop() && Modal::opened().is_none() {
+            self.menu_shortcuts(ctx);
+        }
+
+        // Handle files dropped onto desktop window.
+        if View::is_desktop() {
+            Self::dropped_files_ui(ctx);
+        }
+
         // Handle Close event on desktop.
         if View::is_desktop() && ctx.input(|i| i.viewport().close_requested()) {
             if !self.content.exit_allowed {
@@ -105,13 +172,14 @@ impl<Platform: PlatformCallbacks> App<Platform> {
             })
             .show(ctx, |ui| {
                 if View::is_desktop() {
-                    let is_fullscreen = ui.ctx().input(|i| {
-                        i.viewport().fullscreen.unwrap_or(false)
+                    let (is_fullscreen, is_maximized) = ui.ctx().input(|i| {
+                        (i.viewport().fullscreen.unwrap_or(false),
+                         i.viewport().maximized.unwrap_or(false))
                     });
                     if OperatingSystem::from_target_os() != OperatingSystem::Mac {
-                        self.desktop_window_ui(ui, is_fullscreen);
+                        self.desktop_window_ui(ui, is_fullscreen, is_maximized);
                     } else {
-                        self.window_title_ui(ui, is_fullscreen);
+                        self.window_title_ui(ui, is_fullscreen, is_maximized);
                         ui.add_space(-1.0);
                         Self::title_panel_bg(ui);
                         self.content.ui(ui, &self.platform);
@@ -120,14 +188,21 @@ impl<Platform: PlatformCallbacks> App<Platform> {
                     self.mobile_window_ui(ui);
                 }
 
-                // Provide incoming data to wallets.
+                // Provide incoming data to wallets or open requested screen.
                 if let Some(data) = crate::consume_incoming_data() {
-                    if !data.is_empty() {
+                    if let Some(route) = AppRoute::from_url(&data) {
+                        self.content.on_route(ui, route, &self.platform);
+                    } else if !data.is_empty() {
                         self.content.wallets.on_data(ui, Some(data), &self.platform);
                     }
                 }
             });
 
+        // Show wallets detached into separate windows on desktop.
+        if View::is_desktop() {
+            self.wallet_windows_ui(ctx);
+        }
+
         // Check if desktop window was focused after requested attention.
         if self.platform.user_attention_required() &&
             ctx.input(|i| i.viewport().focused.unwrap_or(true)) {
@@ -135,6 +210,67 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         }
     }
 
+    /// Draw separate windows of detached wallets.
+    fn wallet_windows_ui(&mut self, ctx: &Context) {
+        if let Some(wallet) = self.content.wallets.take_detach_request() {
+            let id = wallet.get_config().id;
+            if !self.wallet_windows.iter().any(|w| w.wallet_id() == id) {
+                self.wallet_windows.push(WalletWindow::new(wallet));
+            }
+        }
+        let platform = &self.platform;
+        self.wallet_windows.retain_mut(|w| w.show(ctx, platform));
+        let ids = self.wallet_windows.iter().map(|w| w.wallet_id()).collect();
+        self.content.wallets.set_detached(ids);
+    }
+
+    /// Provide content of file dropped onto the window to wallets, showing hint while dragging.
+    fn dropped_files_ui(ctx: &Context) {
+        // Show hint while files are dragged over the window.
+        if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+            let layer_id = LayerId::new(Order::Foreground, egui::Id::new("file_drop_layer"));
+            let painter = ctx.layer_painter(layer_id);
+            let rect = ctx.screen_rect();
+            painter.rect_filled(rect, Rounding::ZERO, Colors::semi_transparent());
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("{} {}", FILE_ARROW_DOWN, t!("drop_file")),
+                egui::FontId::proportional(20.0),
+                Colors::title(false),
+            );
+        }
+
+        // Read text from first dropped file.
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        let text = dropped.iter().find_map(|f| {
+            match &f.path {
+                Some(path) => fs::read_to_string(path).ok(),
+                None => f.bytes.as_ref().and_then(|b| String::from_utf8(b.to_vec()).ok())
+            }
+        });
+        if let Some(text) = text.filter(|t| !t.trim().is_empty()) {
+            crate::on_data(text);
+            ctx.request_repaint();
+        }
+    }
+
+    /// Handle keyboard shortcuts of application menu.
+    fn menu_shortcuts(&self, ctx: &Context) {
+        let (open, settings, quit) = ctx.input_mut(|i| {
+            (i.consume_key(Modifiers::COMMAND, egui::Key::O),
+             i.consume_key(Modifiers::COMMAND, egui::Key::Comma),
+             i.consume_key(Modifiers::COMMAND, egui::Key::Q))
+        });
+        if open {
+            Content::open_slatepack_file(&self.platform);
+        } else if settings {
+            Content::show_settings_modal();
+        } else if quit {
+            Content::show_exit_modal();
+        }
+    }
+
     /// Draw mobile platform window content.
     fn mobile_window_ui(&mut self, ui: &mut egui::Ui) {
         Self::title_panel_bg(ui);
@@ -142,10 +278,12 @@ impl<Platform: PlatformCallbacks> App<Platform> {
     }
 
     /// Draw desktop platform window content.
-    fn desktop_window_ui(&mut self, ui: &mut egui::Ui, is_fullscreen: bool) {
+    fn desktop_window_ui(&mut self, ui: &mut egui::Ui, is_fullscreen: bool, is_maximized: bool) {
+        // Do not draw window frame when window is snapped to the screen.
+        let frameless = is_fullscreen || is_maximized;
         let content_bg_rect = {
             let mut r = ui.max_rect();
-            if !is_fullscreen {
+            if !frameless {
                 r = r.shrink(Content::WINDOW_FRAME_MARGIN);
             }
             r.min.y += Content::WINDOW_TITLE_HEIGHT + TitlePanel::HEIGHT;
@@ -159,13 +297,13 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         ui.painter().add(content_bg);
 
         let mut content_rect = ui.max_rect();
-        if !is_fullscreen {
+        if !frameless {
             content_rect = content_rect.shrink(Content::WINDOW_FRAME_MARGIN);
         }
         // Draw window content.
         ui.allocate_new_ui(UiBuilder::new().max_rect(content_rect), |ui| {
             // Draw window title.
-            self.window_title_ui(ui, is_fullscreen);
+            self.window_title_ui(ui, is_fullscreen, is_maximized);
             ui.add_space(-1.0);
 
             // Draw title panel background.
@@ -184,7 +322,7 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         });
 
         // Setup resize areas.
-        if !is_fullscreen {
+        if !frameless {
             self.resize_area_ui(ui, ResizeDirection::North);
             self.resize_area_ui(ui, ResizeDirection::East);
             self.resize_area_ui(ui, ResizeDirection::South);
@@ -211,7 +349,7 @@ impl<Platform: PlatformCallbacks> App<Platform> {
     }
 
     /// Draw custom window title content.
-    fn window_title_ui(&self, ui: &mut egui::Ui, is_fullscreen: bool) {
+    fn window_title_ui(&self, ui: &mut egui::Ui, is_fullscreen: bool, is_maximized: bool) {
         let title_rect = {
             let mut rect = ui.max_rect();
             rect.max.y = rect.min.y + Content::WINDOW_TITLE_HEIGHT;
@@ -224,7 +362,8 @@ impl<Platform: PlatformCallbacks> App<Platform> {
             r
         };
         let is_mac = OperatingSystem::from_target_os() == OperatingSystem::Mac;
-        let window_title_bg = RectShape::new(title_bg_rect, if is_fullscreen || is_mac {
+        let frameless = is_fullscreen || is_maximized;
+        let window_title_bg = RectShape::new(title_bg_rect, if frameless || is_mac {
             Rounding::ZERO
         } else {
             Rounding {
@@ -242,8 +381,8 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         let interact_rect = {
             let mut rect = title_rect.clone();
             rect.max.x -= 128.0;
-            rect.min.x += 85.0;
-            if !is_fullscreen {
+            rect.min.x += 128.0;
+            if !frameless {
                 rect.min.y += Content::WINDOW_FRAME_MARGIN;
             }
             rect
@@ -251,11 +390,16 @@ impl<Platform: PlatformCallbacks> App<Platform> {
         let title_resp = ui.interact(
             interact_rect,
             egui::Id::new("window_title"),
-            egui::Sense::drag(),
+            egui::Sense::click_and_drag(),
         );
-        // Interact with the window title (drag to move window):
-        if !is_fullscreen && title_resp.drag_started_by(egui::PointerButton::Primary) {
-            ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+        // Interact with the window title (drag to move window, letting the system to snap it
+        // to the screen edges, double click to maximize or restore window):
+        if !is_fullscreen {
+            if title_resp.double_clicked() {
+                ui.ctx().send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+            } else if title_resp.drag_started_by(egui::PointerButton::Primary) {
+                ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+            }
         }
 
         // Paint the title.
@@ -318,9 +462,17 @@ impl<Platform: PlatformCallbacks> App<Platform> {
                         MOON
                     };
                     View::title_button_small(ui, theme_icon, |ui| {
+                        AppConfig::set_dark_theme_schedule(None);
                         AppConfig::set_dark_theme(!use_dark);
                         crate::setup_visuals(ui.ctx());
                     });
+
+                    // Draw button to show application menu.
+                    View::title_button_small(ui, LIST, |_| {
+                        if Modal::opened().is_none() {
+                            Content::show_app_menu();
+                        }
+                    });
                 });
             });
         });
@@ -400,6 +552,11 @@ impl<Platform: PlatformCallbacks> eframe::App for App<Platform> {
         self.ui(ctx);
     }
 
+    fn raw_input_hook(&mut self, _: &Context, raw_input: &mut egui::RawInput) {
+        // Handle keyboard navigation at opened modal.
+        Modal::on_raw_input(raw_input);
+    }
+
     fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
         let is_mac = OperatingSystem::from_target_os() == OperatingSystem::Mac;
         if !View::is_desktop() || is_mac {
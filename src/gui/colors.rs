@@ -36,6 +36,12 @@ const GREEN_DARK: Color32 = Color32::from_rgb(0, (0x64 as f32 * 1.3 + 0.5) as u8
 const RED: Color32 = Color32::from_rgb(0x8B, 0, 0);
 const RED_DARK: Color32 = Color32::from_rgb((0x8B as f32 * 1.3 + 0.5) as u8, 0, 0);
 
+const GREEN_CB: Color32 = Color32::from_rgb(0, 0x72, 0xB2);
+const GREEN_CB_DARK: Color32 = Color32::from_rgb(0x56, 0xB4, 0xE9);
+
+const RED_CB: Color32 = Color32::from_rgb(0xD5, 0x5E, 0);
+const RED_CB_DARK: Color32 = Color32::from_rgb(0xE6, 0x9F, 0);
+
 const BLUE: Color32 = Color32::from_rgb(0, 0x66, 0xE4);
 const BLUE_DARK: Color32 =
     Color32::from_rgb(0, (0x66 as f32 * 1.3 + 0.5) as u8, (0xE4 as f32 * 1.3 + 0.5) as u8);
@@ -83,6 +89,11 @@ fn use_dark() -> bool {
     AppConfig::dark_theme().unwrap_or(false)
 }
 
+/// Check if color-blind friendly palette should be used.
+fn use_color_blind() -> bool {
+    AppConfig::color_blind_palette()
+}
+
 impl Colors {
     pub const TRANSPARENT: Color32 = Color32::from_rgba_premultiplied(0, 0, 0, 0);
     pub const STROKE: Color32 = Color32::from_gray(200);
@@ -128,18 +139,20 @@ impl Colors {
     }
 
     pub fn green() -> Color32 {
-        if use_dark() {
-            GREEN_DARK
-        } else {
-            GREEN
+        match (use_dark(), use_color_blind()) {
+            (true, true) => GREEN_CB_DARK,
+            (false, true) => GREEN_CB,
+            (true, false) => GREEN_DARK,
+            (false, false) => GREEN
         }
     }
 
     pub fn red() -> Color32 {
-        if use_dark() {
-            RED_DARK
-        } else {
-            RED
+        match (use_dark(), use_color_blind()) {
+            (true, true) => RED_CB_DARK,
+            (false, true) => RED_CB,
+            (true, false) => RED_DARK,
+            (false, false) => RED
         }
     }
 
@@ -19,6 +19,9 @@ pub use app::App;
 mod colors;
 pub use colors::Colors;
 
+mod route;
+pub use route::*;
+
 pub mod platform;
 pub mod views;
 pub mod icons;
\ No newline at end of file
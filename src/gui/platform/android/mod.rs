@@ -19,13 +19,14 @@ use std::io::Write;
 use std::path::PathBuf;
 use lazy_static::lazy_static;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use parking_lot::RwLock;
 
 use jni::JNIEnv;
 use jni::objects::{JByteArray, JObject, JString, JValue};
 use winit::platform::android::activity::AndroidApp;
 
-use crate::gui::platform::PlatformCallbacks;
+use crate::gui::platform::{CameraFrame, PlatformCallbacks};
 
 /// Android platform implementation.
 #[derive(Clone)]
@@ -58,6 +59,22 @@ impl Android {
         }
         None
     }
+
+    /// Call Android Activity method to encrypt or decrypt data with platform key.
+    fn call_secret_method(&self, name: &str, data: Vec<u8>) -> Option<Vec<u8>> {
+        let vm = unsafe { jni::JavaVM::from_raw(self.android_app.vm_as_ptr() as _) }.unwrap();
+        let env = vm.attach_current_thread().unwrap();
+        let arg_value = env.byte_array_from_slice(data.as_slice()).ok()?;
+        let result = self.call_java_method(name,
+                                           "([B)[B",
+                                           &[JValue::Object(&JObject::from(arg_value))])?;
+        let arr = unsafe { JByteArray::from_raw(result.l) };
+        let data = env.convert_byte_array(arr).ok()?;
+        if data.is_empty() {
+            return None;
+        }
+        Some(data)
+    }
 }
 
 impl PlatformCallbacks for Android {
@@ -104,7 +121,12 @@ impl PlatformCallbacks for Android {
         paste_data
     }
 
+    fn clipboard_changed(&self) -> Option<String> {
+        None
+    }
+
     fn start_camera(&self) {
+        CAMERA_PAUSED.store(false, Ordering::Relaxed);
         // Clear image.
         let mut w_image = LAST_CAMERA_IMAGE.write();
         *w_image = None;
@@ -120,12 +142,17 @@ impl PlatformCallbacks for Android {
         *w_image = None;
     }
 
-    fn camera_image(&self) -> Option<(Vec<u8>, u32)> {
+    fn pause_camera(&self) {
+        CAMERA_PAUSED.store(true, Ordering::Relaxed);
+    }
+
+    fn resume_camera(&self) {
+        CAMERA_PAUSED.store(false, Ordering::Relaxed);
+    }
+
+    fn camera_frame(&self) -> Option<CameraFrame> {
         let r_image = LAST_CAMERA_IMAGE.read();
-        if r_image.is_some() {
-            return Some(r_image.clone().unwrap());
-        }
-        None
+        r_image.clone().map(|(data, rotation)| CameraFrame::Encoded(data, rotation))
     }
 
     fn can_switch_camera(&self) -> bool {
@@ -140,6 +167,16 @@ impl PlatformCallbacks for Android {
         let _ = self.call_java_method("switchCamera", "()V", &[]);
     }
 
+    fn camera_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn selected_camera(&self) -> usize {
+        0
+    }
+
+    fn select_camera(&self, _: usize) {}
+
     fn share_data(&self, name: String, data: Vec<u8>) -> Result<(), std::io::Error> {
         let default_cache = OsString::from(dirs::cache_dir().unwrap());
         let mut file = PathBuf::from(env::var_os("XDG_CACHE_HOME").unwrap_or(default_cache));
@@ -196,13 +233,53 @@ impl PlatformCallbacks for Android {
     }
 
     fn clear_user_attention(&self) {}
+
+    fn start_background_sync(&self) {
+        let _ = self.call_java_method("startBackgroundService", "()V", &[]);
+    }
+
+    fn secure_storage_available(&self) -> bool {
+        true
+    }
+
+    fn encrypt_secret(&self, data: Vec<u8>, biometric: bool) -> Option<Vec<u8>> {
+        let name = if biometric { "encryptBiometricSecret" } else { "encryptSecret" };
+        self.call_secret_method(name, data)
+    }
+
+    fn decrypt_secret(&self, data: Vec<u8>, biometric: bool) -> Option<Vec<u8>> {
+        let name = if biometric { "decryptBiometricSecret" } else { "decryptSecret" };
+        self.call_secret_method(name, data)
+    }
+
+    fn biometric_available(&self) -> bool {
+        if let Some(res) = self.call_java_method("biometricAvailable", "()Z", &[]) {
+            return unsafe { res.z } != 0;
+        }
+        false
+    }
+
+    fn start_biometric_auth(&self) {
+        let mut w_res = BIOMETRIC_RESULT.write();
+        *w_res = None;
+        let _ = self.call_java_method("startBiometricAuth", "()V", &[]);
+    }
+
+    fn biometric_auth_result(&self) -> Option<bool> {
+        let mut w_res = BIOMETRIC_RESULT.write();
+        w_res.take()
+    }
 }
 
 lazy_static! {
     /// Last image data from camera.
     static ref LAST_CAMERA_IMAGE: Arc<RwLock<Option<(Vec<u8>, u32)>>> = Arc::new(RwLock::new(None));
+    /// Flag to skip images from camera while it's paused.
+    static ref CAMERA_PAUSED: AtomicBool = AtomicBool::new(false);
     /// Picked file path.
     static ref PICKED_FILE_PATH: Arc<RwLock<Option<String>>> = Arc::new(RwLock::new(None));
+    /// Biometric authentication result.
+    static ref BIOMETRIC_RESULT: Arc<RwLock<Option<bool>>> = Arc::new(RwLock::new(None));
 }
 
 /// Callback from Java code with last entered character from soft keyboard.
@@ -214,6 +291,9 @@ pub extern "C" fn Java_mw_gri_android_MainActivity_onCameraImage(
     buff: jni::sys::jbyteArray,
     rotation: jni::sys::jint,
 ) {
+    if CAMERA_PAUSED.load(Ordering::Relaxed) {
+        return;
+    }
     let arr = unsafe { JByteArray::from_raw(buff) };
     let image : Vec<u8> = env.convert_byte_array(arr).unwrap();
     let mut w_image = LAST_CAMERA_IMAGE.write();
@@ -240,4 +320,16 @@ pub extern "C" fn Java_mw_gri_android_MainActivity_onFilePick(
             Err(_) => {}
         }
     }
+}
+
+/// Callback from Java code with biometric authentication result.
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn Java_mw_gri_android_MainActivity_onBiometricResult(
+    _env: JNIEnv,
+    _class: JObject,
+    success: jni::sys::jboolean
+) {
+    let mut w_res = BIOMETRIC_RESULT.write();
+    *w_res = Some(success != 0);
 }
\ No newline at end of file
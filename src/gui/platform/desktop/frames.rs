@@ -0,0 +1,95 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use parking_lot::Mutex;
+
+use crate::gui::platform::RgbFrame;
+
+/// Triple buffer to pass camera frames from capturing thread to the ui,
+/// writer and reader are working with own slots and exchange them through the middle one.
+pub struct FrameBuffer {
+    /// Frame slots.
+    slots: [Mutex<Option<RgbFrame>>; 3],
+    /// Index of middle slot with flag to check if it contains fresh frame.
+    middle: AtomicUsize,
+    /// Index of slot owned by writer.
+    back: AtomicUsize,
+    /// Index of slot owned by reader.
+    front: AtomicUsize,
+    /// Sequence number of last written frame.
+    last_id: AtomicU64,
+}
+
+/// Flag at middle slot index to check if it contains fresh frame.
+const FRESH_FLAG: usize = 1 << 2;
+
+impl Default for FrameBuffer {
+    fn default() -> Self {
+        Self {
+            slots: [Mutex::new(None), Mutex::new(None), Mutex::new(None)],
+            middle: AtomicUsize::new(1),
+            back: AtomicUsize::new(0),
+            front: AtomicUsize::new(2),
+            last_id: AtomicU64::new(0),
+        }
+    }
+}
+
+impl FrameBuffer {
+    /// Write frame pixels into back slot reusing its memory when reader is not holding it,
+    /// then publish slot to the reader.
+    pub fn write(&self, width: u32, height: u32, fill: impl FnOnce(&mut Vec<u8>) -> bool) {
+        let back = self.back.load(Ordering::Acquire);
+        {
+            let mut slot = self.slots[back].lock();
+            let mut data = slot.take()
+                .and_then(|f| Arc::try_unwrap(f.data).ok())
+                .unwrap_or_default();
+            data.resize((width * height * 3) as usize, 0);
+            if !fill(&mut data) {
+                return;
+            }
+            *slot = Some(RgbFrame {
+                id: self.last_id.fetch_add(1, Ordering::Relaxed) + 1,
+                width,
+                height,
+                data: Arc::new(data),
+            });
+        }
+        let prev = self.middle.swap(back | FRESH_FLAG, Ordering::AcqRel);
+        self.back.store(prev & !FRESH_FLAG, Ordering::Release);
+    }
+
+    /// Get last published frame.
+    pub fn read(&self) -> Option<RgbFrame> {
+        if self.middle.load(Ordering::Acquire) & FRESH_FLAG != 0 {
+            let front = self.front.load(Ordering::Acquire);
+            let prev = self.middle.swap(front, Ordering::AcqRel);
+            self.front.store(prev & !FRESH_FLAG, Ordering::Release);
+        }
+        let front = self.front.load(Ordering::Acquire);
+        let slot = self.slots[front].lock();
+        slot.clone()
+    }
+
+    /// Remove all frames.
+    pub fn clear(&self) {
+        for slot in &self.slots {
+            let mut w_slot = slot.lock();
+            *w_slot = None;
+        }
+    }
+}
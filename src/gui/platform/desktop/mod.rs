@@ -12,17 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::thread;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use parking_lot::RwLock;
-use lazy_static::lazy_static;
 use egui::{UserAttentionType, ViewportCommand, WindowLevel};
 use rfd::FileDialog;
 
-use crate::gui::platform::PlatformCallbacks;
+use crate::gui::platform::{CameraFrame, PlatformCallbacks};
+use crate::{AppConfig, Settings};
+
+mod frames;
+use frames::FrameBuffer;
 
 /// Desktop platform related actions.
 #[derive(Clone)]
@@ -32,42 +37,70 @@ pub struct Desktop {
 
     /// Cameras amount.
     cameras_amount: Arc<AtomicUsize>,
+    /// Camera device names.
+    camera_names: Arc<RwLock<Vec<String>>>,
     /// Camera index.
     camera_index: Arc<AtomicUsize>,
     /// Flag to check if camera stop is needed.
     stop_camera: Arc<AtomicBool>,
+    /// Flag to check if camera frames capturing is paused.
+    pause_camera: Arc<AtomicBool>,
+    /// Captured camera frames.
+    camera_frames: Arc<FrameBuffer>,
 
     /// Flag to check if attention required after window focusing.
     attention_required: Arc<AtomicBool>,
+
+    /// Last known clipboard text.
+    clipboard_text: Arc<RwLock<Option<String>>>,
+    /// Time of last clipboard check.
+    clipboard_check_time: Arc<AtomicI64>,
 }
 
 impl Desktop {
     pub fn new() -> Self {
+        // Remove key file of insecure storage used before, secrets are not saved without keychain.
+        let _ = fs::remove_file(Settings::config_path(LEGACY_SECRET_KEY_FILE_NAME, None));
         Self {
             ctx: Arc::new(RwLock::new(None)),
             cameras_amount: Arc::new(AtomicUsize::new(0)),
+            camera_names: Arc::new(RwLock::new(vec![])),
             camera_index: Arc::new(AtomicUsize::new(0)),
             stop_camera: Arc::new(AtomicBool::new(false)),
+            pause_camera: Arc::new(AtomicBool::new(false)),
+            camera_frames: Arc::new(FrameBuffer::default()),
             attention_required: Arc::new(AtomicBool::new(false)),
+            clipboard_text: Arc::new(RwLock::new(None)),
+            clipboard_check_time: Arc::new(AtomicI64::new(0)),
         }
     }
 
     #[allow(dead_code)]
     #[cfg(not(target_os = "macos"))]
     fn start_camera_capture(cameras_amount: Arc<AtomicUsize>,
+                            camera_names: Arc<RwLock<Vec<String>>>,
                             camera_index: Arc<AtomicUsize>,
-                            stop_camera: Arc<AtomicBool>) {
+                            stop_camera: Arc<AtomicBool>,
+                            pause_camera: Arc<AtomicBool>,
+                            frames: Arc<FrameBuffer>) {
         use nokhwa::Camera;
         use nokhwa::pixel_format::RgbFormat;
         use nokhwa::utils::{CameraIndex, RequestedFormat, RequestedFormatType};
         use nokhwa::utils::ApiBackend;
 
-        let devices = nokhwa::query(ApiBackend::Auto).unwrap();
+        let devices = nokhwa::query(ApiBackend::Auto).unwrap_or(vec![]);
         cameras_amount.store(devices.len(), Ordering::Relaxed);
-        let index = camera_index.load(Ordering::Relaxed);
-        if devices.is_empty() || index >= devices.len() {
+        {
+            let mut w_names = camera_names.write();
+            *w_names = devices.iter().map(|d| d.human_name()).collect();
+        }
+        if devices.is_empty() {
             return;
         }
+        // Use first camera if selected device is not available.
+        if camera_index.load(Ordering::Relaxed) >= devices.len() {
+            camera_index.store(0, Ordering::Relaxed);
+        }
 
         thread::spawn(move || {
             let index = CameraIndex::Index(camera_index.load(Ordering::Relaxed) as u32);
@@ -81,24 +114,26 @@ impl Desktop {
                         // Stop if camera was stopped.
                         if stop_camera.load(Ordering::Relaxed) {
                             stop_camera.store(false, Ordering::Relaxed);
-                            // Clear image.
-                            let mut w_image = LAST_CAMERA_IMAGE.write();
-                            *w_image = None;
+                            frames.clear();
                             break;
                         }
-                        // Get a frame.
+                        // Wait while camera is paused, keeping the stream opened.
+                        if pause_camera.load(Ordering::Relaxed) {
+                            thread::sleep(Self::CAMERA_PAUSE_DELAY);
+                            continue;
+                        }
+                        // Get a frame and decode it into reused buffer.
                         if let Ok(frame) = camera.frame() {
-                            // Save image.
-                            let mut w_image = LAST_CAMERA_IMAGE.write();
-                            *w_image = Some((frame.buffer().to_vec(), 0));
+                            let res = frame.resolution();
+                            frames.write(res.width(), res.height(), |data| {
+                                frame.decode_image_to_buffer::<RgbFormat>(data).is_ok()
+                            });
                         } else {
-                            // Clear image.
-                            let mut w_image = LAST_CAMERA_IMAGE.write();
-                            *w_image = None;
+                            frames.clear();
                             break;
                         }
                     }
-                    camera.stop_stream().unwrap();
+                    let _ = camera.stop_stream();
                 };
             }
         });
@@ -107,18 +142,26 @@ impl Desktop {
     #[allow(dead_code)]
     #[cfg(target_os = "macos")]
     fn start_camera_capture(cameras_amount: Arc<AtomicUsize>,
+                            camera_names: Arc<RwLock<Vec<String>>>,
                             camera_index: Arc<AtomicUsize>,
-                            stop_camera: Arc<AtomicBool>) {
-        use image::{ExtendedColorType, ImageBuffer, ImageEncoder, Rgb};
+                            stop_camera: Arc<AtomicBool>,
+                            pause_camera: Arc<AtomicBool>,
+                            frames: Arc<FrameBuffer>) {
         use eye::hal::{traits::{Context, Device, Stream}, PlatformContext};
-        use image::codecs::jpeg::JpegEncoder;
 
-        let index = camera_index.load(Ordering::Relaxed);
         let devices = PlatformContext::default().devices().unwrap_or(vec![]);
         cameras_amount.store(devices.len(), Ordering::Relaxed);
-        if devices.is_empty() || index >= devices.len() {
+        {
+            let mut w_names = camera_names.write();
+            *w_names = devices.iter().map(|d| d.product.clone()).collect();
+        }
+        if devices.is_empty() {
             return;
         }
+        // Use first camera if selected device is not available.
+        if camera_index.load(Ordering::Relaxed) >= devices.len() {
+            camera_index.store(0, Ordering::Relaxed);
+        }
 
         // Capture images at separate thread.
         let uri = devices[camera_index.load(Ordering::Relaxed)].uri.clone();
@@ -136,25 +179,35 @@ impl Desktop {
                         // Stop if camera was stopped.
                         if stop_camera.load(Ordering::Relaxed) {
                             stop_camera.store(false, Ordering::Relaxed);
-                            let mut w_image = LAST_CAMERA_IMAGE.write();
-                            *w_image = None;
+                            frames.clear();
                             break;
                         }
-                        // Get a frame.
-                        let frame = stream.next()
-                            .expect("Stream is dead")
-                            .expect("Failed to capture a frame");
-                        let mut out = vec![];
-                        if let Some(buf) = ImageBuffer::<Rgb<u8>, &[u8]>::from_raw(w, h, &frame) {
-                            JpegEncoder::new(&mut out)
-                                .write_image(buf.as_raw(), w, h, ExtendedColorType::Rgb8)
-                                .unwrap_or_default();
-                        } else {
-                            out = frame.to_vec();
+                        // Wait while camera is paused, keeping the stream opened.
+                        if pause_camera.load(Ordering::Relaxed) {
+                            thread::sleep(Self::CAMERA_PAUSE_DELAY);
+                            continue;
+                        }
+                        // Get a frame and copy it into reused buffer.
+                        let frame = match stream.next() {
+                            Some(Ok(frame)) => frame,
+                            _ => {
+                                frames.clear();
+                                break;
+                            }
+                        };
+                        if frame.len() == (w * h * 3) as usize {
+                            frames.write(w, h, |data| {
+                                data.copy_from_slice(&frame);
+                                true
+                            });
+                        } else if let Ok(img) = image::load_from_memory(&frame) {
+                            // Decode compressed frame.
+                            let img = img.to_rgb8();
+                            frames.write(img.width(), img.height(), |data| {
+                                data.copy_from_slice(img.as_raw());
+                                true
+                            });
                         }
-                        // Save image.
-                        let mut w_image = LAST_CAMERA_IMAGE.write();
-                        *w_image = Some((out, 0));
                     }
                 }
             }
@@ -162,6 +215,13 @@ impl Desktop {
     }
 }
 
+impl Desktop {
+    /// Delay between clipboard checks in milliseconds.
+    const CLIPBOARD_CHECK_DELAY: i64 = 1000;
+    /// Delay between checks to resume paused camera.
+    const CAMERA_PAUSE_DELAY: Duration = Duration::from_millis(100);
+}
+
 impl PlatformCallbacks for Desktop {
     fn set_context(&mut self, ctx: &egui::Context) {
         let mut w_ctx = self.ctx.write();
@@ -181,6 +241,11 @@ impl PlatformCallbacks for Desktop {
     fn hide_keyboard(&self) {}
 
     fn copy_string_to_buffer(&self, data: String) {
+        // Do not detect own copied data as clipboard change.
+        {
+            let mut w_text = self.clipboard_text.write();
+            *w_text = Some(data.clone());
+        }
         let mut clipboard = arboard::Clipboard::new().unwrap();
         clipboard.set_text(data).unwrap();
     }
@@ -190,19 +255,45 @@ impl PlatformCallbacks for Desktop {
         clipboard.get_text().unwrap_or("".to_string())
     }
 
-    fn start_camera(&self) {
-        // Clear image.
-        {
-            let mut w_image = LAST_CAMERA_IMAGE.write();
-            *w_image = None;
+    fn clipboard_changed(&self) -> Option<String> {
+        // Check clipboard not often than delay.
+        let now = chrono::Utc::now().timestamp_millis();
+        let last_check = self.clipboard_check_time.load(Ordering::Relaxed);
+        if now - last_check < Self::CLIPBOARD_CHECK_DELAY {
+            return None;
         }
-        // Setup stop camera flag.
+        self.clipboard_check_time.store(now, Ordering::Relaxed);
+        let text = arboard::Clipboard::new().ok()?.get_text().ok()?;
+        let mut w_text = self.clipboard_text.write();
+        // Save initial clipboard text without detection.
+        if w_text.is_none() {
+            *w_text = Some(text);
+            return None;
+        }
+        if w_text.as_ref() == Some(&text) {
+            return None;
+        }
+        *w_text = Some(text.clone());
+        Some(text)
+    }
+
+    fn start_camera(&self) {
+        // Clear frames.
+        self.camera_frames.clear();
+        // Setup stop and pause camera flags.
         let stop_camera = self.stop_camera.clone();
         stop_camera.store(false, Ordering::Relaxed);
+        self.pause_camera.store(false, Ordering::Relaxed);
+
+        // Setup saved camera device.
+        self.camera_index.store(AppConfig::camera_index(), Ordering::Relaxed);
 
         Self::start_camera_capture(self.cameras_amount.clone(),
+                                   self.camera_names.clone(),
                                    self.camera_index.clone(),
-                                   stop_camera);
+                                   stop_camera,
+                                   self.pause_camera.clone(),
+                                   self.camera_frames.clone());
     }
 
     fn stop_camera(&self) {
@@ -210,12 +301,16 @@ impl PlatformCallbacks for Desktop {
         self.stop_camera.store(true, Ordering::Relaxed);
     }
 
-    fn camera_image(&self) -> Option<(Vec<u8>, u32)> {
-        let r_image = LAST_CAMERA_IMAGE.read();
-        if r_image.is_some() {
-            return r_image.clone();
-        }
-        None
+    fn pause_camera(&self) {
+        self.pause_camera.store(true, Ordering::Relaxed);
+    }
+
+    fn resume_camera(&self) {
+        self.pause_camera.store(false, Ordering::Relaxed);
+    }
+
+    fn camera_frame(&self) -> Option<CameraFrame> {
+        self.camera_frames.read().map(|f| CameraFrame::Rgb(f))
     }
 
     fn can_switch_camera(&self) -> bool {
@@ -224,14 +319,26 @@ impl PlatformCallbacks for Desktop {
     }
 
     fn switch_camera(&self) {
-        self.stop_camera();
         let index = self.camera_index.load(Ordering::Relaxed);
         let amount = self.cameras_amount.load(Ordering::Relaxed);
-        if index == amount - 1 {
-            self.camera_index.store(0, Ordering::Relaxed);
+        if index + 1 >= amount {
+            self.select_camera(0);
         } else {
-            self.camera_index.store(index + 1, Ordering::Relaxed);
+            self.select_camera(index + 1);
         }
+    }
+
+    fn camera_names(&self) -> Vec<String> {
+        self.camera_names.read().clone()
+    }
+
+    fn selected_camera(&self) -> usize {
+        self.camera_index.load(Ordering::Relaxed)
+    }
+
+    fn select_camera(&self, index: usize) {
+        self.stop_camera();
+        AppConfig::save_camera_index(index);
         self.start_camera();
     }
 
@@ -301,9 +408,31 @@ impl PlatformCallbacks for Desktop {
         }
         self.attention_required.store(false, Ordering::Relaxed);
     }
-}
 
-lazy_static! {
-    /// Last captured image from started camera.
-    static ref LAST_CAMERA_IMAGE: Arc<RwLock<Option<(Vec<u8>, u32)>>> = Arc::new(RwLock::new(None));
+    fn start_background_sync(&self) {}
+
+    fn secure_storage_available(&self) -> bool {
+        false
+    }
+
+    fn encrypt_secret(&self, _: Vec<u8>, _: bool) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn decrypt_secret(&self, _: Vec<u8>, _: bool) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn biometric_available(&self) -> bool {
+        false
+    }
+
+    fn start_biometric_auth(&self) {}
+
+    fn biometric_auth_result(&self) -> Option<bool> {
+        None
+    }
 }
+
+/// Secret key file name used to encrypt secrets without platform keystore before.
+const LEGACY_SECRET_KEY_FILE_NAME: &'static str = "secret.key";
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 pub use self::platform::*;
 
 #[cfg(target_os = "android")]
@@ -28,15 +30,50 @@ pub trait PlatformCallbacks {
     fn hide_keyboard(&self);
     fn copy_string_to_buffer(&self, data: String);
     fn get_string_from_buffer(&self) -> String;
+    fn clipboard_changed(&self) -> Option<String>;
     fn start_camera(&self);
     fn stop_camera(&self);
-    fn camera_image(&self) -> Option<(Vec<u8>, u32)>;
+    fn pause_camera(&self);
+    fn resume_camera(&self);
+    fn camera_frame(&self) -> Option<CameraFrame>;
     fn can_switch_camera(&self) -> bool;
     fn switch_camera(&self);
+    fn camera_names(&self) -> Vec<String>;
+    fn selected_camera(&self) -> usize;
+    fn select_camera(&self, index: usize);
     fn share_data(&self, name: String, data: Vec<u8>) -> Result<(), std::io::Error>;
     fn pick_file(&self) -> Option<String>;
     fn picked_file(&self) -> Option<String>;
     fn request_user_attention(&self);
     fn user_attention_required(&self) -> bool;
     fn clear_user_attention(&self);
+    fn start_background_sync(&self);
+    fn secure_storage_available(&self) -> bool;
+    fn encrypt_secret(&self, data: Vec<u8>, biometric: bool) -> Option<Vec<u8>>;
+    fn decrypt_secret(&self, data: Vec<u8>, biometric: bool) -> Option<Vec<u8>>;
+    fn biometric_available(&self) -> bool;
+    fn start_biometric_auth(&self);
+    fn biometric_auth_result(&self) -> Option<bool>;
+}
+
+/// Image captured from camera.
+#[derive(Clone)]
+pub enum CameraFrame {
+    /// Encoded image data with rotation angle in degrees.
+    Encoded(Vec<u8>, u32),
+    /// Raw image shared with capturing thread without copying.
+    Rgb(RgbFrame)
+}
+
+/// Raw RGB image captured from camera.
+#[derive(Clone)]
+pub struct RgbFrame {
+    /// Sequence number of captured frame.
+    pub id: u64,
+    /// Image width.
+    pub width: u32,
+    /// Image height.
+    pub height: u32,
+    /// Image pixels data.
+    pub data: Arc<Vec<u8>>,
 }
\ No newline at end of file
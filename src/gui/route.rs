@@ -0,0 +1,186 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use grin_core::global::ChainTypes;
+use url::Url;
+
+use crate::gui::views::wallets::types::WalletTabType;
+
+/// Screen to open from startup arguments or deeplink.
+#[derive(Clone, PartialEq)]
+pub enum RouteScreen {
+    Wallet,
+    Accounts,
+    Network,
+    Settings
+}
+
+/// Navigation request to open specific screen, e.g. `grim --wallet "Savings" --tab txs`,
+/// `grim accounts` or `grim://wallet?name=Savings&tab=txs`, optionally switching network
+/// with `--network testnet` or handling Slatepack message from `--receive-file PATH`
+/// or `--finalize-file PATH`.
+#[derive(Clone)]
+pub struct AppRoute {
+    /// Screen to open.
+    pub screen: RouteScreen,
+    /// Wallet name, single wallet is used if not provided.
+    pub wallet: Option<String>,
+    /// Wallet tab to show.
+    pub tab: Option<WalletTabType>,
+    /// Network to switch to.
+    pub network: Option<ChainTypes>,
+    /// Slatepack message to handle at wallet.
+    pub message: Option<String>,
+}
+
+impl AppRoute {
+    /// Deeplink scheme.
+    const SCHEME: &'static str = "grim";
+
+    /// Parse route from command line arguments without program name.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        let mut screen = None;
+        let mut wallet = None;
+        let mut tab = None;
+        let mut network = None;
+        let mut message = None;
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--wallet" | "-w" | "--open-wallet" => {
+                    wallet = Some(iter.next()?.clone());
+                    screen = screen.or(Some(RouteScreen::Wallet));
+                }
+                "--tab" | "-t" => {
+                    tab = Some(tab_from_name(iter.next()?)?);
+                    screen = screen.or(Some(RouteScreen::Wallet));
+                }
+                "--receive-file" | "--finalize-file" => {
+                    let path = iter.next()?;
+                    match fs::read_to_string(path) {
+                        Ok(text) => message = Some(text),
+                        Err(e) => {
+                            eprintln!("Failed to read {}: {}", path, e);
+                            return None;
+                        }
+                    }
+                    screen = Some(RouteScreen::Wallet);
+                }
+                "--network" | "-n" => {
+                    network = Some(chain_from_name(iter.next()?)?);
+                    screen = screen.or(Some(RouteScreen::Network));
+                }
+                name => {
+                    if let Some(s) = screen_from_name(name) {
+                        screen = Some(s);
+                    } else {
+                        // Not a route, could be file path or Slatepack message.
+                        return None;
+                    }
+                }
+            }
+        }
+        Some(Self { screen: screen?, wallet, tab, network, message })
+    }
+
+    /// Parse route from deeplink.
+    pub fn from_url(data: &str) -> Option<Self> {
+        let url = Url::parse(data.trim()).ok()?;
+        if url.scheme() != Self::SCHEME {
+            return None;
+        }
+        let screen = screen_from_name(url.host_str()?)?;
+        let mut wallet = None;
+        let mut tab = None;
+        let mut network = None;
+        let mut message = None;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "name" => wallet = Some(value.to_string()),
+                "tab" => tab = tab_from_name(value.as_ref()),
+                "network" => network = chain_from_name(value.as_ref()),
+                "message" => message = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        Some(Self { screen, wallet, tab, network, message })
+    }
+
+    /// Convert route to deeplink to pass it into running application.
+    pub fn to_url(&self) -> String {
+        let screen = match self.screen {
+            RouteScreen::Wallet => "wallet",
+            RouteScreen::Accounts => "accounts",
+            RouteScreen::Network => "network",
+            RouteScreen::Settings => "settings"
+        };
+        let mut url = Url::parse(&format!("{}://{}", Self::SCHEME, screen)).unwrap();
+        {
+            let mut query = url.query_pairs_mut();
+            if let Some(name) = &self.wallet {
+                query.append_pair("name", name);
+            }
+            if let Some(tab) = &self.tab {
+                let tab_name = match tab {
+                    WalletTabType::Txs => "txs",
+                    WalletTabType::Invoices => "invoices",
+                    WalletTabType::Messages => "messages",
+                    WalletTabType::Transport => "transport",
+                    WalletTabType::Settings => "settings"
+                };
+                query.append_pair("tab", tab_name);
+            }
+            if let Some(network) = &self.network {
+                query.append_pair("network", network.shortname().as_str());
+            }
+            if let Some(message) = &self.message {
+                query.append_pair("message", message);
+            }
+        }
+        url.to_string()
+    }
+}
+
+/// Get screen from provided name.
+fn screen_from_name(name: &str) -> Option<RouteScreen> {
+    match name {
+        "wallet" => Some(RouteScreen::Wallet),
+        "accounts" => Some(RouteScreen::Accounts),
+        "network" => Some(RouteScreen::Network),
+        "settings" => Some(RouteScreen::Settings),
+        _ => None
+    }
+}
+
+/// Get wallet tab from provided name.
+fn tab_from_name(name: &str) -> Option<WalletTabType> {
+    match name {
+        "txs" => Some(WalletTabType::Txs),
+        "invoices" => Some(WalletTabType::Invoices),
+        "messages" => Some(WalletTabType::Messages),
+        "transport" => Some(WalletTabType::Transport),
+        "settings" => Some(WalletTabType::Settings),
+        _ => None
+    }
+}
+
+/// Get network from provided name.
+fn chain_from_name(name: &str) -> Option<ChainTypes> {
+    match name {
+        "mainnet" | "main" => Some(ChainTypes::Mainnet),
+        "testnet" | "test" => Some(ChainTypes::Testnet),
+        _ => None
+    }
+}
@@ -16,15 +16,15 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use std::thread;
 use egui::load::SizedTexture;
-use egui::{Pos2, Rect, RichText, TextureOptions, UiBuilder, Widget};
-use image::{DynamicImage, EncodableLayout};
+use egui::{Layout, Pos2, Rect, RichText, TextureHandle, TextureOptions, UiBuilder, Widget};
+use image::{DynamicImage, EncodableLayout, GrayImage, ImageBuffer, Rgb};
 use grin_util::ZeroingString;
 use grin_wallet_libwallet::SlatepackAddress;
 use grin_keychain::mnemonic::WORDS;
 
 use crate::gui::Colors;
-use crate::gui::icons::CAMERA_ROTATE;
-use crate::gui::platform::PlatformCallbacks;
+use crate::gui::icons::{CAMERA, CAMERA_ROTATE, CARET_LEFT, CARET_RIGHT};
+use crate::gui::platform::{CameraFrame, PlatformCallbacks, RgbFrame};
 use crate::gui::views::types::{QrScanResult, QrScanState};
 use crate::gui::views::View;
 use crate::wallet::types::PhraseSize;
@@ -35,14 +35,20 @@ pub struct CameraContent {
     /// QR code scanning progress and result.
     qr_scan_state: Arc<RwLock<QrScanState>>,
     /// Uniform Resources URIs collected from QR code scanning.
-    ur_data: Arc<RwLock<Option<(Vec<String>, usize)>>>
+    ur_data: Arc<RwLock<Option<(Vec<String>, usize)>>>,
+    /// Camera image texture.
+    texture: Option<TextureHandle>,
+    /// Sequence number of last shown raw frame.
+    frame_id: Option<u64>,
 }
 
 impl Default for CameraContent {
     fn default() -> Self {
         Self {
             qr_scan_state: Arc::new(RwLock::new(QrScanState::default())),
-            ur_data: Arc::new(RwLock::new(None))
+            ur_data: Arc::new(RwLock::new(None)),
+            texture: None,
+            frame_id: None,
         }
     }
 }
@@ -51,27 +57,44 @@ impl CameraContent {
     /// Draw camera content.
     pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         ui.ctx().request_repaint();
-        let rect = if let Some(img_data) = cb.camera_image() {
-            if let Ok(img) =
-                image::load_from_memory(&*img_data.0) {
-                // Process image to find QR code.
-                self.scan_qr(&img);
+        let rect = match cb.camera_frame() {
+            Some(CameraFrame::Encoded(data, rotation)) => {
+                if let Ok(img) = image::load_from_memory(&data) {
+                    // Process image to find QR code.
+                    if !self.image_processing() {
+                        let img = img.clone();
+                        self.scan_qr(move || img.to_luma8());
+                    }
+
+                    // Draw image.
+                    let img_rect = self.image_ui(ui, img, rotation);
 
-                // Draw image.
-                let img_rect = self.image_ui(ui, img, img_data.1);
+                    // Show UR scan progress.
+                    self.ur_progress_ui(ui);
+                    img_rect
+                } else {
+                    self.loading_ui(ui)
+                }
+            }
+            Some(CameraFrame::Rgb(frame)) => {
+                // Draw frame.
+                let img_rect = self.frame_ui(ui, frame);
 
                 // Show UR scan progress.
                 self.ur_progress_ui(ui);
                 img_rect
-            } else {
-                self.loading_ui(ui)
             }
-        } else {
-            self.loading_ui(ui)
+            None => self.loading_ui(ui)
         };
 
+        // Show camera device selection on desktop.
+        let names = cb.camera_names();
+        if names.len() > 1 {
+            Self::camera_selection_ui(ui, &names, cb);
+        }
+
         // Show button to switch cameras.
-        if cb.can_switch_camera() {
+        if cb.can_switch_camera() && names.is_empty() {
             let r = {
                 let mut r = rect.clone();
                 r.min.y = r.max.y - 52.0;
@@ -87,6 +110,27 @@ impl CameraContent {
         }
     }
 
+    /// Draw camera device selection content.
+    fn camera_selection_ui(ui: &mut egui::Ui, names: &Vec<String>, cb: &dyn PlatformCallbacks) {
+        let index = cb.selected_camera().min(names.len() - 1);
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                let prev = if index == 0 { names.len() - 1 } else { index - 1 };
+                cb.select_camera(prev);
+            });
+            let name_width = ui.available_width() - 44.0;
+            let layout = Layout::centered_and_justified(egui::Direction::LeftToRight);
+            ui.allocate_ui_with_layout(egui::vec2(name_width, 32.0), layout, |ui| {
+                let text = format!("{} {}", CAMERA, names[index]);
+                View::ellipsize_text(ui, text, 16.0, Colors::text(false));
+            });
+            View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                cb.select_camera((index + 1) % names.len());
+            });
+        });
+    }
+
     /// Draw camera image.
     fn image_ui(&mut self, ui: &mut egui::Ui, mut img: DynamicImage, rotation: u32) -> Rect {
         // Setup image rotation.
@@ -115,19 +159,65 @@ impl CameraContent {
                 )
             },
         };
-        // Create image texture.
-        let texture = ui.ctx().load_texture("camera_image",
-                                            color_img.clone(),
-                                            TextureOptions::default());
-        let img_size = egui::emath::vec2(color_img.width() as f32,
-                                         color_img.height() as f32);
+        self.update_texture(ui.ctx(), color_img);
+        self.texture_ui(ui, false)
+    }
+
+    /// Draw raw camera frame, processing new frame to find QR code.
+    fn frame_ui(&mut self, ui: &mut egui::Ui, frame: RgbFrame) -> Rect {
+        let size = [frame.width as usize, frame.height as usize];
+        if frame.data.len() != size[0] * size[1] * 3 {
+            return self.loading_ui(ui);
+        }
+        if self.frame_id != Some(frame.id) {
+            self.frame_id = Some(frame.id);
+            // Update texture from shared pixels.
+            let color_img = egui::ColorImage::from_rgb(size, frame.data.as_slice());
+            self.update_texture(ui.ctx(), color_img);
+
+            // Convert frame to find QR code only when previous one was processed.
+            if !self.image_processing() {
+                self.scan_qr(move || {
+                    ImageBuffer::<Rgb<u8>, &[u8]>::from_raw(frame.width,
+                                                           frame.height,
+                                                           frame.data.as_slice())
+                        .map(|img| image::imageops::grayscale(&img))
+                        .unwrap_or_else(|| GrayImage::new(1, 1))
+                });
+            }
+        }
+        // Show mirrored image on desktop.
+        self.texture_ui(ui, View::is_desktop())
+    }
+
+    /// Update camera image texture, creating it when needed.
+    fn update_texture(&mut self, ctx: &egui::Context, img: egui::ColorImage) {
+        match self.texture.as_mut() {
+            Some(texture) => texture.set(img, TextureOptions::default()),
+            None => {
+                let texture = ctx.load_texture("camera_image", img, TextureOptions::default());
+                self.texture = Some(texture);
+            }
+        }
+    }
+
+    /// Draw camera image texture cropped at square, with optional horizontal mirroring.
+    fn texture_ui(&self, ui: &mut egui::Ui, mirror: bool) -> Rect {
+        let texture = match self.texture.as_ref() {
+            Some(t) => t,
+            None => return self.loading_ui(ui)
+        };
+        let img_size = texture.size_vec2();
         let sized_img = SizedTexture::new(texture.id(), img_size);
+        // Setup to crop image at square.
+        let crop = 1.0 - (img_size.y / img_size.x);
+        let uv = if mirror {
+            Rect::from_min_max(Pos2::new(1.0 - crop, 0.0), Pos2::new(0.0, 1.0))
+        } else {
+            Rect::from_min_max(Pos2::new(crop, 0.0), Pos2::new(1.0, 1.0))
+        };
         egui::Image::from_texture(sized_img)
-            // Setup to crop image at square.
-            .uv(Rect::from([
-                Pos2::new(1.0 - (img_size.y / img_size.x), 0.0),
-                Pos2::new(1.0, 1.0)
-            ]))
+            .uv(uv)
             .max_height(ui.available_width())
             .maintain_aspect_ratio(false)
             .shrink_to_fit()
@@ -182,8 +272,8 @@ impl CameraContent {
         (100 * complete / total) as i32
     }
 
-    /// Parse QR code from provided image data.
-    fn scan_qr(&self, image_data: &DynamicImage) {
+    /// Parse QR code from grayscale image provided by callback at separate thread.
+    fn scan_qr(&self, luma: impl FnOnce() -> GrayImage + Send + 'static) {
         // Do not scan when another image is processing.
         if self.image_processing() {
             return;
@@ -194,13 +284,12 @@ impl CameraContent {
             w_scan.image_processing = true;
         }
 
-        let image_data = image_data.clone();
         let qr_scan_state = self.qr_scan_state.clone();
         let ur_data = self.ur_data.clone();
 
         let on_scan = async move {
             // Prepare image data.
-            let img = image_data.to_luma8();
+            let img = luma();
             let mut img: rqrr::PreparedImage<image::GrayImage>
                 = rqrr::PreparedImage::prepare(img);
             // Scan and save results.
@@ -15,17 +15,19 @@
 use std::fs;
 use std::sync::atomic::{AtomicBool, Ordering};
 use egui::os::OperatingSystem;
-use egui::{Align, Layout, RichText};
+use egui::{Align, Layout, RichText, ViewportCommand};
+use grin_core::global::ChainTypes;
 use lazy_static::lazy_static;
 
-use crate::gui::Colors;
+use crate::gui::{AppRoute, Colors, RouteScreen};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, View};
+use crate::gui::views::{CrashReportContent, HelpContent, Modal, PortsContent, View};
 use crate::gui::views::types::{ModalContainer, ModalPosition};
 use crate::node::Node;
+use crate::wallet::{ExternalConnection, FiatCurrency, PriceFeed};
 use crate::{AppConfig, Settings};
-use crate::gui::icons::{CHECK, CHECK_FAT, FILE_X};
-use crate::gui::views::network::NetworkContent;
+use crate::gui::icons::{BUG, CARET_LEFT, CARET_RIGHT, CHECK, CHECK_FAT, CORNERS_IN, CORNERS_OUT, FOLDER_OPEN, GEAR, PLUGS_CONNECTED, POWER, QUESTION};
+use crate::gui::views::network::{NetworkContent, NodeSetup};
 use crate::gui::views::wallets::WalletsContent;
 
 lazy_static! {
@@ -39,6 +41,10 @@ pub struct Content {
     network: NetworkContent,
     /// Central panel [`WalletsContent`] content.
     pub wallets: WalletsContent,
+    /// Crash reports [`Modal`] content.
+    crash_report: CrashReportContent,
+    /// Network ports overview [`Modal`] content.
+    ports: PortsContent,
 
     /// Check if app exit is allowed on Desktop close event.
     pub exit_allowed: bool,
@@ -47,6 +53,10 @@ pub struct Content {
 
     /// Flag to check it's first draw of content.
     first_draw: bool,
+    /// Time of last check for external changes of configuration files.
+    config_check_time: i64,
+    /// Network used at last draw to handle its change.
+    chain_type: ChainTypes,
 
     /// List of allowed [`Modal`] ids for this [`ModalContainer`].
     allowed_modal_ids: Vec<&'static str>
@@ -54,8 +64,8 @@ pub struct Content {
 
 /// Identifier for integrated node warning [`Modal`] on Android.
 const ANDROID_INTEGRATED_NODE_WARNING_MODAL: &'static str = "android_node_warning_modal";
-/// Identifier for crash report [`Modal`].
-const CRASH_REPORT_MODAL: &'static str = "crash_report_modal";
+/// Identifier for external configuration changes [`Modal`].
+const CONFIG_CHANGED_MODAL: &'static str = "config_changed_modal";
 
 impl Default for Content {
     fn default() -> Self {
@@ -65,14 +75,22 @@ impl Default for Content {
         Self {
             network: NetworkContent::default(),
             wallets: WalletsContent::default(),
+            crash_report: CrashReportContent::default(),
+            ports: PortsContent::default(),
             exit_allowed,
             show_exit_progress: false,
             first_draw: true,
+            config_check_time: 0,
+            chain_type: AppConfig::chain_type(),
             allowed_modal_ids: vec![
                 Self::EXIT_CONFIRMATION_MODAL,
                 Self::SETTINGS_MODAL,
                 ANDROID_INTEGRATED_NODE_WARNING_MODAL,
-                CRASH_REPORT_MODAL
+                CrashReportContent::MODAL_ID,
+                PortsContent::MODAL_ID,
+                CONFIG_CHANGED_MODAL,
+                HelpContent::MODAL_ID,
+                Self::APP_MENU_MODAL
             ],
         }
     }
@@ -91,7 +109,13 @@ impl ModalContainer for Content {
             Self::EXIT_CONFIRMATION_MODAL => self.exit_modal_content(ui, modal, cb),
             Self::SETTINGS_MODAL => self.settings_modal_ui(ui, modal),
             ANDROID_INTEGRATED_NODE_WARNING_MODAL => self.android_warning_modal_ui(ui, modal),
-            CRASH_REPORT_MODAL => self.crash_report_modal_ui(ui, modal, cb),
+            CrashReportContent::MODAL_ID => self.crash_report.modal_ui(ui, modal, cb),
+            PortsContent::MODAL_ID => {
+                self.ports.modal_ui(ui, modal, self.wallets.wallet_list(), cb)
+            }
+            CONFIG_CHANGED_MODAL => self.config_changed_modal_ui(ui, modal),
+            HelpContent::MODAL_ID => HelpContent::modal_ui(ui, modal),
+            Self::APP_MENU_MODAL => Self::app_menu_modal_ui(ui, modal, cb),
             _ => {}
         }
     }
@@ -102,6 +126,18 @@ impl Content {
     pub const EXIT_CONFIRMATION_MODAL: &'static str = "exit_confirmation_modal";
     /// Identifier for wallet opening [`Modal`].
     pub const SETTINGS_MODAL: &'static str = "settings_modal";
+    /// Identifier for desktop application menu [`Modal`].
+    pub const APP_MENU_MODAL: &'static str = "app_menu_modal";
+
+    /// Default hours range to use dark theme by schedule.
+    const DEFAULT_DARK_THEME_SCHEDULE: (u32, u32) = (20, 7);
+    /// Default minutes of inactivity to close opened wallets.
+    const DEFAULT_AUTO_LOCK_MINUTES: u64 = 15;
+    /// Default amount of decimal places to show at wallet amounts.
+    const DEFAULT_AMOUNT_PRECISION: usize = 4;
+
+    /// Delay between checks for external changes of configuration files in seconds.
+    const CONFIG_CHECK_DELAY: i64 = 2;
 
     /// Default width of side panel at application UI.
     pub const SIDE_PANEL_WIDTH: f32 = 400.0;
@@ -111,6 +147,12 @@ impl Content {
     pub const WINDOW_FRAME_MARGIN: f32 = 6.0;
 
     pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        // Handle network change.
+        let chain_type = AppConfig::chain_type();
+        if self.chain_type != chain_type {
+            self.on_chain_change(ui, chain_type);
+        }
+
         self.current_modal_ui(ui, cb);
 
         let dual_panel = Self::is_dual_panel_mode(ui.ctx());
@@ -139,11 +181,7 @@ impl Content {
         if self.first_draw {
             // Show crash report or integrated node Android warning.
             if Settings::crash_report_path().exists() {
-                Modal::new(CRASH_REPORT_MODAL)
-                    .closeable(false)
-                    .position(ModalPosition::Center)
-                    .title(t!("crash_report"))
-                    .show();
+                self.crash_report.show_last();
             } else if OperatingSystem::from_target_os() == OperatingSystem::Android &&
                     AppConfig::android_integrated_node_warning_needed() {
                     Modal::new(ANDROID_INTEGRATED_NODE_WARNING_MODAL)
@@ -152,6 +190,33 @@ impl Content {
             }
             self.first_draw = false;
         }
+
+        // Check for external changes of configuration files.
+        let now = chrono::Utc::now().timestamp();
+        if now - self.config_check_time >= Self::CONFIG_CHECK_DELAY && Modal::opened().is_none() {
+            self.config_check_time = now;
+            if Settings::configs_changed_externally() ||
+                self.wallets.configs_changed_externally() {
+                Modal::new(CONFIG_CHANGED_MODAL)
+                    .closeable(false)
+                    .position(ModalPosition::Center)
+                    .title(t!("config_changed.title"))
+                    .show();
+            }
+        }
+    }
+
+    /// Close wallets of previous network, checking connections of the new one.
+    fn on_chain_change(&mut self, ui: &mut egui::Ui, chain_type: ChainTypes) {
+        // Close wallet modals showing content of previous network.
+        if let Some(id) = Modal::opened() {
+            if !self.allowed_modal_ids.contains(&id) {
+                Modal::on_back();
+            }
+        }
+        self.wallets.on_chain_change(self.chain_type);
+        ExternalConnection::check(None, ui.ctx());
+        self.chain_type = chain_type;
     }
 
     /// Check if ui can show [`NetworkContent`] and [`WalletsContent`] at same time.
@@ -176,6 +241,105 @@ impl Content {
         NETWORK_PANEL_OPEN.load(Ordering::Relaxed)
     }
 
+    /// Open screen from provided navigation request.
+    pub fn on_route(&mut self, ui: &mut egui::Ui, route: AppRoute, cb: &dyn PlatformCallbacks) {
+        // Switch network if requested.
+        if let Some(chain_type) = route.network {
+            if chain_type != AppConfig::chain_type() && !Node::is_restarting() {
+                AppConfig::change_chain_type(&chain_type);
+                if Node::is_running() {
+                    Node::restart();
+                }
+                self.on_chain_change(ui, chain_type);
+            }
+        }
+        match route.screen {
+            RouteScreen::Network => {
+                if !Self::is_dual_panel_mode(ui.ctx()) && !Self::is_network_panel_open() {
+                    Self::toggle_network_panel();
+                }
+            }
+            RouteScreen::Settings => Self::show_settings_modal(),
+            RouteScreen::Wallet | RouteScreen::Accounts => {
+                self.wallets.on_route(ui, route, cb);
+            }
+        }
+    }
+
+    /// Show application settings [`Modal`].
+    pub fn show_settings_modal() {
+        Modal::new(Self::SETTINGS_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("settings"))
+            .show();
+    }
+
+    /// Show desktop application menu [`Modal`].
+    pub fn show_app_menu() {
+        Modal::new(Self::APP_MENU_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("menu"))
+            .show();
+    }
+
+    /// Pick file with Slatepack message to provide it into wallets.
+    pub fn open_slatepack_file(cb: &dyn PlatformCallbacks) {
+        if let Some(path) = cb.pick_file() {
+            if let Ok(text) = fs::read_to_string(path) {
+                crate::on_data(text);
+            }
+        }
+    }
+
+    /// Draw desktop application menu [`Modal`] content.
+    fn app_menu_modal_ui(ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered_justified(|ui| {
+            // Show button to open Slatepack message from file.
+            let open_text = format!("{} {}", FOLDER_OPEN, t!("menu_open_slatepack"));
+            View::button(ui, open_text, Colors::white_or_black(false), || {
+                modal.close();
+                Self::open_slatepack_file(cb);
+            });
+            ui.add_space(8.0);
+
+            // Show button to open settings.
+            let settings_text = format!("{} {}", GEAR, t!("settings"));
+            View::button(ui, settings_text, Colors::white_or_black(false), || {
+                modal.close();
+                Self::show_settings_modal();
+            });
+            ui.add_space(8.0);
+
+            // Show button to maximize or restore window.
+            let is_maximized = ui.ctx().input(|i| i.viewport().maximized.unwrap_or(false));
+            let (window_icon, window_text) = if is_maximized {
+                (CORNERS_IN, t!("menu_restore"))
+            } else {
+                (CORNERS_OUT, t!("menu_maximize"))
+            };
+            let window_text = format!("{} {}", window_icon, window_text);
+            View::button_ui(ui, window_text, Colors::white_or_black(false), |ui| {
+                modal.close();
+                ui.ctx().send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+            });
+            ui.add_space(8.0);
+
+            // Show button to quit from the application.
+            let quit_text = format!("{} {}", POWER, t!("menu_quit"));
+            View::button(ui, quit_text, Colors::white_or_black(false), || {
+                Self::show_exit_modal();
+            });
+        });
+        ui.add_space(8.0);
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                modal.close();
+            });
+        });
+        ui.add_space(6.0);
+    }
+
     /// Show exit confirmation [`Modal`].
     pub fn show_exit_modal() {
         Modal::new(Self::EXIT_CONFIRMATION_MODAL)
@@ -186,7 +350,8 @@ impl Content {
     /// Draw exit confirmation modal content.
     fn exit_modal_content(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
         if self.show_exit_progress {
-            if !Node::is_running() {
+            let tasks_running = self.wallets.wallet_tasks_running();
+            if !Node::is_running() && !tasks_running {
                 self.exit_allowed = true;
                 cb.exit();
                 modal.close();
@@ -195,10 +360,17 @@ impl Content {
             ui.vertical_centered(|ui| {
                 View::small_loading_spinner(ui);
                 ui.add_space(12.0);
-                ui.label(RichText::new(t!("sync_status.shutdown"))
+                // Show wallet tasks waiting or node shutdown status.
+                let text = if tasks_running {
+                    t!("modal_exit.wallet_tasks")
+                } else {
+                    t!("sync_status.shutdown")
+                };
+                ui.label(RichText::new(text)
                     .size(17.0)
                     .color(Colors::text(false)));
             });
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(500));
             ui.add_space(10.0);
         } else {
             ui.add_space(8.0);
@@ -220,12 +392,15 @@ impl Content {
                 });
                 columns[1].vertical_centered_justified(|ui| {
                     View::button_ui(ui, t!("modal_exit.exit"), Colors::white_or_black(false), |_| {
-                        if !Node::is_running() {
+                        if !Node::is_running() && !self.wallets.wallet_tasks_running() {
                             self.exit_allowed = true;
                             cb.exit();
                             modal.close();
                         } else {
-                            Node::stop(true);
+                            // Wait for node to stop and wallet tasks to finish.
+                            if Node::is_running() {
+                                Node::stop(true);
+                            }
                             modal.disable_closing();
                             Modal::set_title(t!("modal_exit.exit"));
                             self.show_exit_progress = true;
@@ -237,6 +412,38 @@ impl Content {
         }
     }
 
+    /// Draw external configuration changes [`Modal`] content.
+    fn config_changed_modal_ui(&mut self, ui: &mut egui::Ui, modal: &Modal) {
+        ui.add_space(8.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("config_changed.description"))
+                .size(17.0)
+                .color(Colors::text(false)));
+        });
+        ui.add_space(10.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("config_changed.keep"), Colors::white_or_black(false), || {
+                    Settings::save_configs();
+                    self.wallets.resolve_configs_changes(false);
+                    modal.close();
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("config_changed.reload"), Colors::white_or_black(false), || {
+                    Settings::reload_configs();
+                    self.wallets.resolve_configs_changes(true);
+                    modal.close();
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
     /// Handle Back key event.
     pub fn on_back(&mut self, cb: &dyn PlatformCallbacks) {
         if Modal::on_back() {
@@ -250,6 +457,18 @@ impl Content {
     pub fn settings_modal_ui(&mut self, ui: &mut egui::Ui, modal: &Modal) {
         ui.add_space(6.0);
 
+        // Show network selection.
+        NodeSetup::chain_type_ui(ui);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network.switch_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+        });
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
         // Show theme selection.
         Self::theme_selection_ui(ui);
 
@@ -257,6 +476,71 @@ impl Content {
         View::horizontal_line(ui, Colors::item_stroke());
         ui.add_space(6.0);
 
+        // Show wallets auto-lock setup.
+        Self::auto_lock_ui(ui);
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show amount display precision setup.
+        Self::amount_precision_ui(ui);
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show fiat currency conversion setup.
+        Self::fiat_currency_ui(ui);
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show donation shortcuts setup.
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, AppConfig::show_donations(), t!("show_donations"), || {
+                AppConfig::toggle_show_donations();
+            });
+        });
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show last opened wallet opening on launch setup.
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, AppConfig::open_last_wallet(), t!("open_last_wallet"), || {
+                AppConfig::toggle_open_last_wallet();
+            });
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("open_last_wallet_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+        });
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show clipboard detection setup on desktop.
+        if View::is_desktop() {
+            ui.vertical_centered(|ui| {
+                let watch = AppConfig::clipboard_watch();
+                View::checkbox(ui, watch, t!("clipboard_watch"), || {
+                    AppConfig::toggle_clipboard_watch();
+                });
+                ui.add_space(4.0);
+                ui.label(RichText::new(t!("clipboard_watch_desc"))
+                    .size(15.0)
+                    .color(Colors::inactive_text()));
+            });
+
+            ui.add_space(8.0);
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+        }
+
         ui.vertical_centered(|ui| {
             ui.label(RichText::new(format!("{}:", t!("language")))
                 .size(16.0)
@@ -265,18 +549,47 @@ impl Content {
         });
         ui.add_space(8.0);
 
-        // Draw available list of languages to select.
+        // Draw system language item and available list of languages to select.
         let locales = rust_i18n::available_locales!();
+        let len = locales.len() + 1;
+        Self::language_item_ui(None, ui, 0, len, modal);
         for (index, locale) in locales.iter().enumerate() {
-            Self::language_item_ui(locale, ui, index, locales.len(), modal);
+            Self::language_item_ui(Some(locale), ui, index + 1, len, modal);
         }
 
         ui.add_space(8.0);
 
-        // Show button to close modal.
-        ui.vertical_centered_justified(|ui| {
-            View::button(ui, t!("close"), Colors::white_or_black(false), || {
-                modal.close();
+        // Show buttons to open help pages and close modal.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let help_text = format!("{} {}", QUESTION, t!("help.title"));
+                    View::button(ui, help_text, Colors::white_or_black(false), || {
+                        HelpContent::show(None);
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let reports_text = format!("{} {}", BUG, t!("crash_reports"));
+                    View::button(ui, reports_text, Colors::white_or_black(false), || {
+                        self.crash_report.show_history();
+                    });
+                });
+            });
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                let ports_text = format!("{} {}", PLUGS_CONNECTED, t!("network_ports"));
+                View::button(ui, ports_text, Colors::white_or_black(false), || {
+                    self.ports.show();
+                });
+            });
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                    modal.close();
+                });
             });
         });
         ui.add_space(6.0);
@@ -288,28 +601,216 @@ impl Content {
             ui.label(RichText::new(t!("theme")).size(16.0).color(Colors::gray()));
         });
 
-        let saved_use_dark = AppConfig::dark_theme().unwrap_or(false);
-        let mut selected_use_dark = saved_use_dark;
+        let schedule = AppConfig::dark_theme_schedule();
+        if schedule.is_none() {
+            let saved_use_dark = AppConfig::dark_theme().unwrap_or(false);
+            let mut selected_use_dark = saved_use_dark;
+
+            ui.add_space(8.0);
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    View::radio_value(ui, &mut selected_use_dark, false, t!("light"));
+                });
+                columns[1].vertical_centered(|ui| {
+                    View::radio_value(ui, &mut selected_use_dark, true, t!("dark"));
+                })
+            });
+
+            if saved_use_dark != selected_use_dark {
+                AppConfig::set_dark_theme(selected_use_dark);
+                crate::setup_visuals(ui.ctx());
+            }
+        }
+        ui.add_space(8.0);
 
+        // Show theme schedule setup.
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, schedule.is_some(), t!("theme_schedule"), || {
+                let value = if schedule.is_some() {
+                    None
+                } else {
+                    Some(Self::DEFAULT_DARK_THEME_SCHEDULE)
+                };
+                AppConfig::set_dark_theme_schedule(value);
+            });
+        });
+        if let Some((mut from, mut to)) = schedule {
+            ui.add_space(6.0);
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("dark_theme_from")).size(16.0).color(Colors::gray()));
+                    Self::hour_selection_ui(ui, &mut from);
+                });
+                columns[1].vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("dark_theme_to")).size(16.0).color(Colors::gray()));
+                    Self::hour_selection_ui(ui, &mut to);
+                });
+            });
+            if schedule != Some((from, to)) {
+                AppConfig::set_dark_theme_schedule(Some((from, to)));
+            }
+        }
         ui.add_space(8.0);
-        ui.columns(2, |columns| {
-            columns[0].vertical_centered(|ui| {
-                View::radio_value(ui, &mut selected_use_dark, false, t!("light"));
+
+        // Show color-blind friendly palette setup.
+        ui.vertical_centered(|ui| {
+            let use_palette = AppConfig::color_blind_palette();
+            View::checkbox(ui, use_palette, t!("color_blind_palette"), || {
+                AppConfig::toggle_color_blind_palette();
             });
-            columns[1].vertical_centered(|ui| {
-                View::radio_value(ui, &mut selected_use_dark, true, t!("dark"));
-            })
         });
         ui.add_space(8.0);
+    }
+
+    /// Draw wallets auto-lock setup content.
+    fn auto_lock_ui(ui: &mut egui::Ui) {
+        let minutes = AppConfig::auto_lock_minutes();
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, minutes.is_some(), t!("auto_lock"), || {
+                let value = if minutes.is_some() {
+                    None
+                } else {
+                    Some(Self::DEFAULT_AUTO_LOCK_MINUTES)
+                };
+                AppConfig::set_auto_lock_minutes(value);
+            });
+        });
+        if let Some(mut value) = minutes {
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                // Center content horizontally.
+                let w = ui.available_width();
+                ui.add_space((w - 124.0).max(0.0) / 2.0);
+                View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                    value = (value - 1).max(1);
+                });
+                let layout = Layout::centered_and_justified(egui::Direction::LeftToRight);
+                ui.allocate_ui_with_layout(egui::vec2(60.0, 32.0), layout, |ui| {
+                    ui.label(RichText::new(t!("auto_lock_minutes", "minutes" => value))
+                        .size(17.0)
+                        .color(Colors::white_or_black(true)));
+                });
+                View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                    value = (value + 1).min(24 * 60);
+                });
+            });
+            if minutes != Some(value) {
+                AppConfig::set_auto_lock_minutes(Some(value));
+            }
+        }
+        ui.add_space(2.0);
+    }
+
+    /// Draw wallet amounts display precision setup content.
+    fn amount_precision_ui(ui: &mut egui::Ui) {
+        let precision = AppConfig::amount_precision();
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, precision.is_some(), t!("round_amounts"), || {
+                let value = if precision.is_some() {
+                    None
+                } else {
+                    Some(Self::DEFAULT_AMOUNT_PRECISION)
+                };
+                AppConfig::set_amount_precision(value);
+            });
+        });
+        if let Some(mut value) = precision {
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                // Center content horizontally.
+                let w = ui.available_width();
+                ui.add_space((w - 124.0).max(0.0) / 2.0);
+                View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                    value = value.saturating_sub(1);
+                });
+                let layout = Layout::centered_and_justified(egui::Direction::LeftToRight);
+                ui.allocate_ui_with_layout(egui::vec2(60.0, 32.0), layout, |ui| {
+                    ui.label(RichText::new(format!("{:.*}", value, 0.0))
+                        .size(17.0)
+                        .color(Colors::white_or_black(true)));
+                });
+                View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                    value = (value + 1).min(8);
+                });
+            });
+            if precision != Some(value) {
+                AppConfig::set_amount_precision(Some(value));
+            }
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("round_amounts_desc"))
+                    .size(15.0)
+                    .color(Colors::inactive_text()));
+            });
+        }
+        ui.add_space(2.0);
+    }
 
-        if saved_use_dark != selected_use_dark {
-            AppConfig::set_dark_theme(selected_use_dark);
-            crate::setup_visuals(ui.ctx());
+    /// Draw fiat currency selection content to enter amounts with conversion.
+    fn fiat_currency_ui(ui: &mut egui::Ui) {
+        let currency = AppConfig::fiat_currency();
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, currency.is_some(), t!("fiat_amounts"), || {
+                let value = if currency.is_some() {
+                    None
+                } else {
+                    Some(FiatCurrency::Usd)
+                };
+                AppConfig::set_fiat_currency(value);
+                PriceFeed::refresh();
+            });
+        });
+        if let Some(saved) = currency {
+            let mut selected = saved;
+            ui.add_space(8.0);
+            ui.columns(FiatCurrency::ALL.len(), |columns| {
+                for (i, c) in FiatCurrency::ALL.iter().enumerate() {
+                    columns[i].vertical_centered(|ui| {
+                        View::radio_value(ui, &mut selected, *c, c.code().to_string());
+                    });
+                }
+            });
+            if saved != selected {
+                AppConfig::set_fiat_currency(Some(selected));
+                PriceFeed::refresh();
+            }
         }
+        ui.add_space(4.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("fiat_amounts_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+        });
+    }
+
+    /// Draw hour value selection content.
+    fn hour_selection_ui(ui: &mut egui::Ui, hour: &mut u32) {
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            // Center content horizontally.
+            let w = ui.available_width();
+            ui.add_space((w - 124.0).max(0.0) / 2.0);
+            View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                *hour = (*hour + 23) % 24;
+            });
+            let layout = Layout::centered_and_justified(egui::Direction::LeftToRight);
+            ui.allocate_ui_with_layout(egui::vec2(60.0, 32.0), layout, |ui| {
+                ui.label(RichText::new(format!("{:02}:00", hour))
+                    .size(17.0)
+                    .color(Colors::white_or_black(true)));
+            });
+            View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                *hour = (*hour + 1) % 24;
+            });
+        });
     }
 
     /// Draw language selection item content.
-    fn language_item_ui(locale: &str, ui: &mut egui::Ui, index: usize, len: usize, modal: &Modal) {
+    fn language_item_ui(locale: Option<&str>,
+                        ui: &mut egui::Ui,
+                        index: usize,
+                        len: usize,
+                        modal: &Modal) {
         // Setup layout size.
         let mut rect = ui.available_rect_before_wrap();
         rect.set_height(50.0);
@@ -321,15 +822,11 @@ impl Content {
 
         ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
             // Draw button to select language.
-            let is_current = if let Some(lang) = AppConfig::locale() {
-                lang == locale
-            } else {
-                rust_i18n::locale() == locale
-            };
+            let is_current = AppConfig::locale().as_deref() == locale;
             if !is_current {
+                let ctx = ui.ctx().clone();
                 View::item_button(ui, View::item_rounding(index, len, true), CHECK, None, || {
-                    rust_i18n::set_locale(locale);
-                    AppConfig::save_locale(locale);
+                    crate::change_locale(&ctx, locale);
                     modal.close();
                 });
             } else {
@@ -349,7 +846,11 @@ impl Content {
                     } else {
                         Colors::gray()
                     };
-                    ui.label(RichText::new(t!("lang_name", locale = locale))
+                    let name = match locale {
+                        Some(l) => t!("lang_name", locale = l),
+                        None => t!("system_language")
+                    };
+                    ui.label(RichText::new(name)
                         .size(17.0)
                         .color(color));
                     ui.add_space(3.0);
@@ -375,40 +876,6 @@ impl Content {
         });
         ui.add_space(6.0);
     }
-
-    /// Draw content for integrated node warning [`Modal`] on Android.
-    fn crash_report_modal_ui(&mut self,
-                             ui: &mut egui::Ui,
-                             modal: &Modal,
-                             cb: &dyn PlatformCallbacks) {
-        ui.add_space(6.0);
-        ui.vertical_centered(|ui| {
-            ui.label(RichText::new(t!("crash_report_warning"))
-                .size(16.0)
-                .color(Colors::text(false)));
-            ui.add_space(6.0);
-            // Draw button to share crash report.
-            let text = format!("{} {}", FILE_X, t!("share"));
-            View::colored_text_button(ui, text, Colors::blue(), Colors::white_or_black(false), || {
-                if let Ok(data) = fs::read_to_string(Settings::crash_report_path()) {
-                    let name = Settings::CRASH_REPORT_FILE_NAME.to_string();
-                    let _ = cb.share_data(name, data.as_bytes().to_vec());
-                }
-                Settings::delete_crash_report();
-                modal.close();
-            });
-        });
-        ui.add_space(8.0);
-        View::horizontal_line(ui, Colors::item_stroke());
-        ui.add_space(8.0);
-        ui.vertical_centered_justified(|ui| {
-            View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
-                Settings::delete_crash_report();
-                modal.close();
-            });
-        });
-        ui.add_space(6.0);
-    }
 }
 
 /// Get [`NetworkContent`] panel state and width.
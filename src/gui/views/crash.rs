@@ -0,0 +1,340 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use egui::{Align, Id, Layout, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use parking_lot::RwLock;
+
+use crate::{AppConfig, Settings};
+use crate::gui::Colors;
+use crate::gui::icons::{ARROW_LEFT, CARET_RIGHT, CLOUD_ARROW_UP, COPY, SHARE_FAT};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+
+/// Crash report viewer with history of past crashes and submission to configured endpoint.
+pub struct CrashReportContent {
+    /// Opened report file name and content.
+    report: Option<(String, String)>,
+    /// Flag to check if opened report is from last launch.
+    is_new: bool,
+
+    /// Endpoint URL value to submit reports.
+    url_edit: String,
+
+    /// Flag to check if report is submitting.
+    submitting: Arc<AtomicBool>,
+    /// Result of report submission.
+    submit_result: Arc<RwLock<Option<bool>>>,
+}
+
+impl Default for CrashReportContent {
+    fn default() -> Self {
+        Self {
+            report: None,
+            is_new: false,
+            url_edit: "".to_string(),
+            submitting: Arc::new(AtomicBool::new(false)),
+            submit_result: Arc::new(RwLock::new(None)),
+        }
+    }
+}
+
+impl CrashReportContent {
+    /// Identifier for crash report [`Modal`].
+    pub const MODAL_ID: &'static str = "crash_report_modal";
+
+    /// Show [`Modal`] with crash report from last launch.
+    pub fn show_last(&mut self) {
+        let data = fs::read_to_string(Settings::crash_report_path()).unwrap_or_default();
+        self.report = Some((Settings::CRASH_REPORT_FILE_NAME.to_string(), data));
+        self.is_new = true;
+        *self.submit_result.write() = None;
+        Modal::new(Self::MODAL_ID)
+            .closeable(false)
+            .position(ModalPosition::Center)
+            .title(t!("crash_report"))
+            .show();
+    }
+
+    /// Show [`Modal`] with history of crash reports.
+    pub fn show_history(&mut self) {
+        self.report = None;
+        self.is_new = false;
+        self.url_edit = AppConfig::crash_report_url().unwrap_or_default();
+        *self.submit_result.write() = None;
+        Modal::new(Self::MODAL_ID)
+            .position(ModalPosition::CenterTop)
+            .title(t!("crash_reports"))
+            .show();
+    }
+
+    /// Draw crash report [`Modal`] content.
+    pub fn modal_ui(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        if self.report.is_some() {
+            self.report_ui(ui, modal, cb);
+        } else {
+            self.history_ui(ui, modal, cb);
+        }
+    }
+
+    /// Draw opened crash report content.
+    fn report_ui(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        let (name, data) = self.report.clone().unwrap();
+        if self.is_new {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("crash_report_warning"))
+                    .size(16.0)
+                    .color(Colors::text(false)));
+            });
+            ui.add_space(8.0);
+        }
+
+        // Show report backtrace.
+        ScrollArea::vertical()
+            .id_salt("crash_report_scroll")
+            .max_height(View::window_size(ui.ctx()).1 * 0.4)
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                ui.label(RichText::new(&data)
+                    .monospace()
+                    .size(13.0)
+                    .color(Colors::text(false)));
+            });
+        ui.add_space(8.0);
+
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Show buttons to copy or share report.
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let copy_text = format!("{} {}", COPY, t!("copy"));
+                    View::button(ui, copy_text, Colors::white_or_black(false), || {
+                        cb.copy_string_to_buffer(data.clone());
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let share_text = format!("{} {}", SHARE_FAT, t!("share"));
+                    View::button(ui, share_text, Colors::white_or_black(false), || {
+                        let _ = cb.share_data(name.clone(), data.as_bytes().to_vec());
+                    });
+                });
+            });
+
+            // Show button to submit report to configured endpoint.
+            if let Some(url) = AppConfig::crash_report_url() {
+                ui.add_space(8.0);
+                ui.vertical_centered_justified(|ui| {
+                    if self.submitting.load(Ordering::Relaxed) {
+                        View::small_loading_spinner(ui);
+                        ui.ctx().request_repaint_after(std::time::Duration::from_millis(500));
+                    } else {
+                        let result = *self.submit_result.read();
+                        match result {
+                            Some(true) => {
+                                ui.label(RichText::new(t!("crash_report_submitted"))
+                                    .size(16.0)
+                                    .color(Colors::green()));
+                            }
+                            _ => {
+                                if result == Some(false) {
+                                    ui.label(RichText::new(t!("crash_report_submit_error"))
+                                        .size(16.0)
+                                        .color(Colors::red()));
+                                    ui.add_space(6.0);
+                                }
+                                let submit_text = format!("{} {}", CLOUD_ARROW_UP, t!("submit"));
+                                let ctx = ui.ctx().clone();
+                                View::colored_text_button(ui,
+                                                          submit_text,
+                                                          Colors::blue(),
+                                                          Colors::white_or_black(false),
+                                                          || {
+                                    self.submit(url.clone(), data.clone(), &ctx);
+                                });
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(8.0);
+
+        // Show buttons to go back to history or close modal.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            if self.is_new {
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        Settings::archive_crash_report();
+                        modal.close();
+                    });
+                });
+            } else {
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        let back_text = format!("{} {}", ARROW_LEFT, t!("back"));
+                        View::button(ui, back_text, Colors::white_or_black(false), || {
+                            self.report = None;
+                            *self.submit_result.write() = None;
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            modal.close();
+                        });
+                    });
+                });
+            }
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw crash reports history and submission endpoint setup.
+    fn history_ui(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        // Show submission endpoint input.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("crash_report_url"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let url_before = self.url_edit.clone();
+            let mut url_edit_opts = TextEditOptions::new(Id::from(modal.id).with("url"))
+                .paste()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.url_edit, &mut url_edit_opts);
+            let url = self.url_edit.trim();
+            if url_before != self.url_edit {
+                if url.is_empty() {
+                    AppConfig::set_crash_report_url(None);
+                } else if url::Url::parse(url).is_ok() {
+                    AppConfig::set_crash_report_url(Some(url.to_string()));
+                }
+            }
+            if !url.is_empty() && url::Url::parse(url).is_err() {
+                ui.add_space(6.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+        });
+        ui.add_space(8.0);
+
+        // Show list of past crash reports.
+        let reports = Settings::crash_reports();
+        if reports.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("crash_reports_empty"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            ScrollArea::vertical()
+                .id_salt("crash_reports_scroll")
+                .max_height(View::window_size(ui.ctx()).1 * 0.4)
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for (i, path) in reports.iter().enumerate() {
+                        self.report_item_ui(ui, path, i, reports.len());
+                    }
+                });
+        }
+        ui.add_space(8.0);
+
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                modal.close();
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Draw crash report history item.
+    fn report_item_ui(&mut self, ui: &mut egui::Ui, path: &Path, index: usize, len: usize) {
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(50.0);
+
+        // Draw round background.
+        let item_rounding = View::item_rounding(index, len, false);
+        ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke());
+
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            View::item_button(ui, View::item_rounding(index, len, true), CARET_RIGHT, None, || {
+                let data = fs::read_to_string(path).unwrap_or_default();
+                let name = path.file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or(Settings::CRASH_REPORT_FILE_NAME.to_string());
+                self.report = Some((name, data));
+                *self.submit_result.write() = None;
+            });
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(12.0);
+                let time = Settings::crash_report_time(path)
+                    .map(View::format_time)
+                    .unwrap_or("-".to_string());
+                ui.label(RichText::new(time).size(17.0).color(Colors::title(false)));
+            });
+        });
+    }
+
+    /// Submit report to provided endpoint URL in separate thread.
+    fn submit(&self, url: String, data: String, ctx: &egui::Context) {
+        self.submitting.store(true, Ordering::Relaxed);
+        *self.submit_result.write() = None;
+        let submitting = self.submitting.clone();
+        let submit_result = self.submit_result.clone();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let res = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    let client = hyper::Client::builder()
+                        .build::<_, hyper::Body>(hyper_tls::HttpsConnector::new());
+                    let req = match hyper::Request::builder()
+                        .method(hyper::Method::POST)
+                        .uri(url)
+                        .header(hyper::header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                        .body(hyper::Body::from(data)) {
+                        Ok(req) => req,
+                        Err(_) => return false
+                    };
+                    match client.request(req).await {
+                        Ok(res) => res.status().is_success(),
+                        Err(_) => false
+                    }
+                });
+            *submit_result.write() = Some(res);
+            submitting.store(false, Ordering::Relaxed);
+            ctx.request_repaint();
+        });
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use egui::{Align, Layout, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+use crate::gui::Colors;
+use crate::gui::icons::{ARROW_LEFT, CARET_RIGHT};
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::ModalPosition;
+
+/// Bundled help page.
+#[derive(Clone, Copy, PartialEq)]
+pub enum HelpPage {
+    Slatepack,
+    Tor,
+    Node,
+    Troubleshooting
+}
+
+impl HelpPage {
+    /// All available pages.
+    pub const ALL: [HelpPage; 4] = [
+        HelpPage::Slatepack,
+        HelpPage::Tor,
+        HelpPage::Node,
+        HelpPage::Troubleshooting
+    ];
+
+    /// Get page title.
+    pub fn title(&self) -> String {
+        match self {
+            HelpPage::Slatepack => t!("help.slatepack"),
+            HelpPage::Tor => t!("help.tor"),
+            HelpPage::Node => t!("help.node"),
+            HelpPage::Troubleshooting => t!("help.troubleshooting")
+        }.to_string()
+    }
+
+    /// Get page markdown text.
+    fn text(&self) -> &'static str {
+        match self {
+            HelpPage::Slatepack => include_str!("../../../help/slatepack.md"),
+            HelpPage::Tor => include_str!("../../../help/tor.md"),
+            HelpPage::Node => include_str!("../../../help/node.md"),
+            HelpPage::Troubleshooting => include_str!("../../../help/troubleshooting.md")
+        }
+    }
+}
+
+lazy_static! {
+    /// Currently selected help page.
+    static ref HELP_PAGE: Arc<RwLock<Option<HelpPage>>> = Arc::new(RwLock::new(None));
+}
+
+/// Offline help pages content.
+pub struct HelpContent;
+
+impl HelpContent {
+    /// Identifier for help [`Modal`].
+    pub const MODAL_ID: &'static str = "help_modal";
+
+    /// Show help [`Modal`] with provided page or list of pages.
+    pub fn show(page: Option<HelpPage>) {
+        *HELP_PAGE.write() = page;
+        Modal::new(Self::MODAL_ID)
+            .position(ModalPosition::CenterTop)
+            .title(t!("help.title"))
+            .show();
+    }
+
+    /// Draw help [`Modal`] content.
+    pub fn modal_ui(ui: &mut egui::Ui, modal: &Modal) {
+        let page = HELP_PAGE.read().clone();
+        ui.add_space(6.0);
+        ScrollArea::vertical()
+            .id_salt("help_scroll")
+            .max_height(View::window_size(ui.ctx()).1 * 0.6)
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                match page {
+                    None => {
+                        for (i, p) in HelpPage::ALL.iter().enumerate() {
+                            Self::page_item_ui(ui, p, i, HelpPage::ALL.len());
+                        }
+                    }
+                    Some(p) => markdown_ui(ui, p.text())
+                }
+            });
+        ui.add_space(8.0);
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            if page.is_some() {
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        let back_text = format!("{} {}", ARROW_LEFT, t!("back"));
+                        View::button(ui, back_text, Colors::white_or_black(false), || {
+                            *HELP_PAGE.write() = None;
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            modal.close();
+                        });
+                    });
+                });
+            } else {
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        modal.close();
+                    });
+                });
+            }
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw help page list item.
+    fn page_item_ui(ui: &mut egui::Ui, page: &HelpPage, index: usize, len: usize) {
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(50.0);
+
+        // Draw round background.
+        let item_rounding = View::item_rounding(index, len, false);
+        ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke());
+
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            View::item_button(ui, View::item_rounding(index, len, true), CARET_RIGHT, None, || {
+                *HELP_PAGE.write() = Some(*page);
+            });
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(12.0);
+                ui.label(RichText::new(page.title()).size(17.0).color(Colors::title(false)));
+            });
+        });
+    }
+}
+
+/// Draw simple markdown text with headings and list items.
+fn markdown_ui(ui: &mut egui::Ui, text: &str) {
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            ui.add_space(6.0);
+        } else if let Some(h) = line.strip_prefix("## ") {
+            ui.add_space(4.0);
+            ui.label(RichText::new(h).size(17.0).color(Colors::title(false)));
+        } else if let Some(h) = line.strip_prefix("# ") {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(h).size(19.0).color(Colors::white_or_black(true)));
+            });
+        } else if let Some(item) = line.strip_prefix("- ") {
+            ui.label(RichText::new(format!("• {}", item)).size(16.0).color(Colors::text(false)));
+        } else {
+            ui.label(RichText::new(line).size(16.0).color(Colors::text(false)));
+        }
+    }
+}
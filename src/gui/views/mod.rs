@@ -43,4 +43,13 @@ mod pull_to_refresh;
 pub use pull_to_refresh::*;
 
 mod scan;
-pub use scan::*;
\ No newline at end of file
+pub use scan::*;
+
+mod help;
+pub use help::*;
+
+mod crash;
+pub use crash::*;
+
+mod ports;
+pub use ports::*;
\ No newline at end of file
@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use lazy_static::lazy_static;
+use std::collections::HashMap;
 use std::sync::Arc;
 use parking_lot::RwLock;
 use std::sync::atomic::{AtomicBool, Ordering};
-use egui::{Align2, RichText, Rounding, Stroke, UiBuilder, Vec2};
+use egui::{Align2, Key, RichText, Rounding, Stroke, UiBuilder, Vec2, ViewportId};
 use egui::epaint::{RectShape, Shadow};
 use egui::os::OperatingSystem;
 
@@ -27,6 +28,12 @@ use crate::gui::views::types::{ModalPosition, ModalState};
 lazy_static! {
     /// Showing [`Modal`] state to be accessible from different ui parts.
     static ref MODAL_STATE: Arc<RwLock<ModalState>> = Arc::new(RwLock::new(ModalState::default()));
+    /// Viewport of current [`Modal`] state.
+    static ref MODAL_VIEWPORT: Arc<RwLock<ViewportId>> = Arc::new(RwLock::new(ViewportId::ROOT));
+    /// Saved [`Modal`] states of other viewports.
+    static ref VIEWPORT_STATES: Arc<RwLock<HashMap<ViewportId, ModalState>>> = Arc::new(
+        RwLock::new(HashMap::new())
+    );
 }
 
 /// Modal [`egui::Window`] container.
@@ -47,6 +54,8 @@ impl Modal {
     const DEFAULT_MARGIN: f32 = 8.0;
     /// Maximum width of the content.
     const DEFAULT_WIDTH: f32 = Content::SIDE_PANEL_WIDTH - (2.0 * Self::DEFAULT_MARGIN);
+    /// Identifier of content window.
+    const WINDOW_ID: &'static str = "modal_window";
 
     /// Create closeable [`Modal`] with center position.
     pub fn new(id: &'static str) -> Self {
@@ -107,6 +116,98 @@ impl Modal {
     pub fn show(self) {
         let mut w_nav = MODAL_STATE.write();
         w_nav.modal = Some(self);
+        w_nav.focus_widgets.clear();
+        w_nav.last_focus_widgets.clear();
+        w_nav.focus_direction = None;
+        w_nav.keyboard_focus = false;
+    }
+
+    /// Switch [`Modal`] state to provided viewport before drawing its content,
+    /// returning identifier of previous viewport to switch back after drawing.
+    pub fn switch_viewport(id: ViewportId) -> ViewportId {
+        let mut w_viewport = MODAL_VIEWPORT.write();
+        let prev = *w_viewport;
+        if prev == id {
+            return prev;
+        }
+        let mut w_state = MODAL_STATE.write();
+        let mut w_states = VIEWPORT_STATES.write();
+        let state = w_states.remove(&id).unwrap_or_default();
+        let prev_state = std::mem::replace(&mut *w_state, state);
+        // Save state of previous viewport only when modal is opened.
+        if prev_state.modal.is_some() {
+            w_states.insert(prev, prev_state);
+        }
+        *w_viewport = id;
+        prev
+    }
+
+    /// Remove [`Modal`] state of closed viewport.
+    pub fn remove_viewport(id: ViewportId) {
+        let mut w_states = VIEWPORT_STATES.write();
+        w_states.remove(&id);
+    }
+
+    /// Handle keyboard input before frame to navigate between widgets of opened [`Modal`]
+    /// with Tab key instead of default focus traversal across all layers.
+    pub fn on_raw_input(raw_input: &mut egui::RawInput) {
+        let mut w_state = MODAL_STATE.write();
+        if w_state.modal.is_none() {
+            return;
+        }
+        let mut direction = None;
+        raw_input.events.retain(|e| {
+            if let egui::Event::Key { key: Key::Tab, pressed, modifiers, .. } = e {
+                if *pressed {
+                    direction = Some(!modifiers.shift);
+                }
+                return false;
+            }
+            true
+        });
+        if direction.is_some() {
+            w_state.focus_direction = direction;
+        }
+    }
+
+    /// Register widget drawn at opened [`Modal`] to navigate with keyboard.
+    pub fn add_focusable(ui: &egui::Ui, id: egui::Id, text_input: bool) {
+        if ui.layer_id().id != egui::Id::new(Self::WINDOW_ID) {
+            return;
+        }
+        let mut w_state = MODAL_STATE.write();
+        if w_state.modal.is_some() {
+            w_state.focus_widgets.push((id, text_input));
+        }
+    }
+
+    /// Check if keyboard focus was moved to another widget of opened [`Modal`],
+    /// so initial focus should not be requested.
+    pub fn focus_moved(ui: &egui::Ui, id: egui::Id) -> bool {
+        let r_state = MODAL_STATE.read();
+        if !r_state.keyboard_focus || ui.layer_id().id != egui::Id::new(Self::WINDOW_ID) {
+            return false;
+        }
+        match ui.ctx().memory(|m| m.focused()) {
+            Some(focused) => focused != id &&
+                r_state.last_focus_widgets.iter().any(|(w, _)| *w == focused),
+            None => false
+        }
+    }
+
+    /// Check if button of opened [`Modal`] has keyboard focus to activate it by Enter key
+    /// instead of default action.
+    pub fn button_focused(ctx: &egui::Context) -> bool {
+        let r_state = MODAL_STATE.read();
+        if r_state.modal.is_none() || !r_state.keyboard_focus {
+            return false;
+        }
+        match ctx.memory(|m| m.focused()) {
+            Some(focused) => r_state.last_focus_widgets
+                .iter()
+                .any(|(w, text)| *w == focused && !*text),
+            None => false
+        }
     }
 
     /// Remove [`Modal`] from [`ModalState`] if it's showing and can be closed.
@@ -216,9 +317,15 @@ impl Modal {
         let available_width = ctx.screen_rect().width() - (side_insets + Self::DEFAULT_MARGIN);
         let width = f32::min(available_width, Self::DEFAULT_WIDTH);
 
+        // Start to collect widgets for keyboard navigation.
+        {
+            let mut w_state = MODAL_STATE.write();
+            w_state.last_focus_widgets = std::mem::take(&mut w_state.focus_widgets);
+        }
+
         // Show main content window at given position.
         let (content_align, content_offset) = self.modal_position();
-        let layer_id = egui::Window::new("modal_window")
+        let layer_id = egui::Window::new(Self::WINDOW_ID)
             .title_bar(false)
             .resizable(false)
             .collapsible(false)
@@ -244,6 +351,42 @@ impl Modal {
 
         // Always show main content window above background window.
         ctx.move_to_top(layer_id);
+
+        // Keep keyboard focus inside content window.
+        Self::trap_focus(ctx);
+    }
+
+    /// Move keyboard focus between [`Modal`] widgets by requested direction,
+    /// returning it back to content when it was moved outside.
+    fn trap_focus(ctx: &egui::Context) {
+        let mut w_state = MODAL_STATE.write();
+        let direction = w_state.focus_direction.take();
+        if w_state.modal.is_none() || w_state.focus_widgets.is_empty() {
+            return;
+        }
+        let widgets = &w_state.focus_widgets;
+        let len = widgets.len();
+        let focused = ctx.memory(|m| m.focused());
+        let index = focused.and_then(|f| widgets.iter().position(|(w, _)| *w == f));
+        let next = match (direction, index) {
+            (Some(true), Some(i)) => Some((i + 1) % len),
+            (Some(false), Some(i)) => Some((i + len - 1) % len),
+            (Some(true), None) => Some(0),
+            (Some(false), None) => Some(len - 1),
+            // Return focus to the first text input or widget when it was moved outside.
+            (None, None) if focused.is_some() => {
+                Some(widgets.iter().position(|(_, text)| *text).unwrap_or(0))
+            }
+            _ => None
+        };
+        if let Some(i) = next {
+            let id = widgets[i].0;
+            ctx.memory_mut(|m| m.request_focus(id));
+            if direction.is_some() {
+                w_state.keyboard_focus = true;
+            }
+            ctx.request_repaint();
+        }
     }
 
     /// Get [`egui::Window`] position based on [`ModalPosition`].
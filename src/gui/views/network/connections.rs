@@ -16,12 +16,12 @@ use egui::{Align, Layout, RichText, Rounding};
 
 use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{CARET_RIGHT, CHECK_CIRCLE, COMPUTER_TOWER, DOTS_THREE_CIRCLE, GLOBE_SIMPLE, PENCIL, PLUS_CIRCLE, POWER, TRASH, WARNING_CIRCLE, X_CIRCLE};
+use crate::gui::icons::{CARET_RIGHT, CHECK_CIRCLE, COMPUTER_TOWER, CUBE, DOTS_THREE_CIRCLE, GLOBE_SIMPLE, LIST_PLUS, PENCIL, PLUS_CIRCLE, POWER, SCAN, SHARE_FAT, STOP, TIMER, TRASH, WARNING_CIRCLE, X_CIRCLE};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, View};
+use crate::gui::views::{CameraScanModal, FilePickButton, Modal, View};
 use crate::gui::views::network::modals::ExternalConnectionModal;
 use crate::gui::views::network::NodeSetup;
-use crate::gui::views::types::{ModalContainer, ModalPosition};
+use crate::gui::views::types::{ModalContainer, ModalPosition, QrScanResult};
 use crate::node::{Node, NodeConfig};
 use crate::wallet::{ConnectionsConfig, ExternalConnection};
 
@@ -30,6 +30,13 @@ pub struct ConnectionsContent {
     /// External connection [`Modal`] content.
     ext_conn_modal: ExternalConnectionModal,
 
+    /// QR code scan [`Modal`] content to import connections.
+    scan_modal_content: Option<CameraScanModal>,
+    /// Button to pick file to import connections.
+    file_pick_button: FilePickButton,
+    /// Amount of imported connections or `None` if import failed.
+    import_result: Option<Option<usize>>,
+
     /// [`Modal`] identifiers allowed at this ui container.
     modal_ids: Vec<&'static str>
 }
@@ -38,13 +45,23 @@ impl Default for ConnectionsContent {
     fn default() -> Self {
         Self {
             ext_conn_modal: ExternalConnectionModal::new(None),
+            scan_modal_content: None,
+            file_pick_button: FilePickButton::default(),
+            import_result: None,
             modal_ids: vec![
-                ExternalConnectionModal::NETWORK_ID
+                ExternalConnectionModal::NETWORK_ID,
+                IMPORT_SCAN_MODAL
             ],
         }
     }
 }
 
+/// Identifier for [`Modal`] to scan connections from QR code.
+const IMPORT_SCAN_MODAL: &'static str = "import_connections_scan_modal";
+
+/// Name of the file to export connections.
+const EXPORT_FILE_NAME: &'static str = "grin-nodes.json";
+
 impl ModalContainer for ConnectionsContent {
     fn modal_ids(&self) -> &Vec<&'static str> {
         &self.modal_ids
@@ -58,6 +75,19 @@ impl ModalContainer for ConnectionsContent {
             ExternalConnectionModal::NETWORK_ID => {
                 self.ext_conn_modal.ui(ui, cb, modal, |_| {});
             },
+            IMPORT_SCAN_MODAL => {
+                let mut result = None;
+                if let Some(content) = self.scan_modal_content.as_mut() {
+                    content.ui(ui, modal, cb, |res| {
+                        result = Some(res.clone());
+                        modal.close();
+                    });
+                }
+                if let Some(res) = result {
+                    self.scan_modal_content = None;
+                    self.import(res.text(), ui.ctx());
+                }
+            },
             _ => {}
         }
     }
@@ -71,15 +101,9 @@ impl ConnectionsContent {
         ui.add_space(2.0);
 
         // Show network type selection.
-        let saved_chain_type = AppConfig::chain_type();
         NodeSetup::chain_type_ui(ui);
         ui.add_space(6.0);
 
-        // Check connections availability.
-        if saved_chain_type != AppConfig::chain_type() {
-            ExternalConnection::check(None, ui.ctx());
-        }
-
         // Show integrated node info content.
         Self::integrated_node_item_ui(ui, |ui| {
             // Draw button to show integrated node info.
@@ -98,6 +122,19 @@ impl ConnectionsContent {
         View::button(ui, add_node_text, Colors::white_or_black(false), || {
             self.show_add_ext_conn_modal(None, cb);
         });
+        ui.add_space(8.0);
+
+        // Show button to add default external nodes.
+        let default_nodes_text = format!("{} {}", LIST_PLUS, t!("wallets.add_default_nodes"));
+        let ui_ctx = ui.ctx().clone();
+        View::button(ui, default_nodes_text, Colors::white_or_black(false), || {
+            ConnectionsConfig::add_default_ext_conns();
+            ExternalConnection::check(None, &ui_ctx);
+        });
+        ui.add_space(8.0);
+
+        // Show connections export and import buttons.
+        self.share_ui(ui, cb);
 
         ui.add_space(4.0);
 
@@ -141,7 +178,7 @@ impl ConnectionsContent {
                         Node::start();
                     });
                 } else if !Node::is_starting() && !Node::is_stopping() && !Node::is_restarting() {
-                    View::item_button(ui, Rounding::default(), POWER, Some(Colors::red()), || {
+                    View::item_button(ui, Rounding::default(), STOP, Some(Colors::red()), || {
                         Node::stop(false);
                     });
                 }
@@ -219,20 +256,95 @@ impl ConnectionsContent {
                     // Setup connection status text.
                     let status_text = if let Some(available) = conn.available {
                         if available {
-                            format!("{} {}", CHECK_CIRCLE, t!("network.available"))
+                            let mut text = format!("{} {}", CHECK_CIRCLE, t!("network.available"));
+                            // Show health check results.
+                            if let Some(height) = conn.height {
+                                text = format!("{}  {} {}", text, CUBE, height);
+                            }
+                            if let Some(latency) = conn.latency {
+                                text = format!("{}  {} {} ms", text, TIMER, latency);
+                            }
+                            if let Some(version) = &conn.version {
+                                text = format!("{}  v{}", text, version);
+                            }
+                            text
                         } else {
                             format!("{} {}", X_CIRCLE, t!("network.not_available"))
                         }
                     } else {
                         format!("{} {}", DOTS_THREE_CIRCLE, t!("network.availability_check"))
                     };
-                    ui.label(RichText::new(status_text).size(15.0).color(Colors::gray()));
+                    View::ellipsize_text(ui, status_text, 15.0, Colors::gray());
                     ui.add_space(3.0);
                 });
             });
         });
     }
 
+    /// Draw buttons to export and import external connections.
+    fn share_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(3, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let share_text = format!("{} {}", SHARE_FAT, t!("share"));
+                    View::button(ui, share_text, Colors::white_or_black(false), || {
+                        if let Some(data) = ConnectionsConfig::export_ext_conns() {
+                            let _ = cb.share_data(EXPORT_FILE_NAME.to_string(), data);
+                        }
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let scan_text = format!("{} {}", SCAN, t!("scan"));
+                    View::button(ui, scan_text, Colors::white_or_black(false), || {
+                        self.import_result = None;
+                        self.scan_modal_content = Some(CameraScanModal::default());
+                        // Show QR code scan modal.
+                        Modal::new(IMPORT_SCAN_MODAL)
+                            .position(ModalPosition::CenterTop)
+                            .title(t!("scan_qr"))
+                            .closeable(false)
+                            .show();
+                        cb.start_camera();
+                    });
+                });
+                columns[2].vertical_centered_justified(|ui| {
+                    let mut picked = None;
+                    self.file_pick_button.ui(ui, cb, |text| {
+                        picked = Some(text);
+                    });
+                    if let Some(text) = picked {
+                        self.import(text, ui.ctx());
+                    }
+                });
+            });
+        });
+
+        // Show import result.
+        if let Some(res) = self.import_result {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                let (text, color) = match res {
+                    Some(count) => {
+                        (t!("wallets.nodes_imported", "count" => count), Colors::green())
+                    }
+                    None => (t!("wallets.nodes_import_error"), Colors::red())
+                };
+                ui.label(RichText::new(text).size(16.0).color(color));
+            });
+        }
+    }
+
+    /// Import external connections from provided data.
+    fn import(&mut self, data: String, ui_ctx: &egui::Context) {
+        self.import_result = Some(ConnectionsConfig::import_ext_conns(&data));
+        if self.import_result.flatten().unwrap_or(0) > 0 {
+            ExternalConnection::check(None, ui_ctx);
+        }
+    }
+
     /// Show [`Modal`] to add external connection.
     pub fn show_add_ext_conn_modal(&mut self,
                                    conn: Option<ExternalConnection>,
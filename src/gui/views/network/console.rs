@@ -0,0 +1,285 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use egui::{Align, Id, Layout, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use grin_util::to_base64;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::gui::Colors;
+use crate::gui::icons::{ARROW_COUNTER_CLOCKWISE, COPY, PAPER_PLANE_TILT, TERMINAL_WINDOW};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Content, View};
+use crate::gui::views::network::types::{NodeTab, NodeTabType};
+use crate::gui::views::types::TextEditOptions;
+use crate::node::NodeConfig;
+use crate::settings::ViewState;
+
+/// Request to integrated node API.
+#[derive(Clone)]
+struct ConsoleRequest {
+    /// Flag to check if Foreign API was used.
+    foreign: bool,
+    /// JSON-RPC method name.
+    method: String,
+    /// JSON-RPC method params.
+    params: String,
+    /// Raw response or error text.
+    response: Option<String>,
+}
+
+lazy_static! {
+    /// History of requests to integrated node API, the last one is in progress when no response.
+    static ref REQUESTS_HISTORY: Arc<RwLock<Vec<ConsoleRequest>>> = Arc::new(RwLock::new(vec![]));
+}
+
+/// Maximal amount of requests to keep at history.
+const MAX_HISTORY_SIZE: usize = 20;
+
+/// Key to save last request input at [`ViewState`].
+const INPUT_STATE_KEY: &'static str = "network_console_input";
+
+/// Last request input saved at [`ViewState`].
+#[derive(Serialize, Deserialize)]
+struct ConsoleInput {
+    foreign: bool,
+    method: String,
+    params: String,
+}
+
+/// Integrated node API console tab content.
+pub struct NetworkConsole {
+    /// Flag to check if Foreign API should be used.
+    foreign: bool,
+    /// JSON-RPC method name value.
+    method_edit: String,
+    /// JSON-RPC method params value.
+    params_edit: String,
+}
+
+impl Default for NetworkConsole {
+    fn default() -> Self {
+        if let Some(input) = ViewState::get::<ConsoleInput>(INPUT_STATE_KEY) {
+            return Self {
+                foreign: input.foreign,
+                method_edit: input.method,
+                params_edit: input.params,
+            };
+        }
+        Self {
+            foreign: false,
+            method_edit: "get_status".to_string(),
+            params_edit: "[]".to_string(),
+        }
+    }
+}
+
+impl NodeTab for NetworkConsole {
+    fn get_type(&self) -> NodeTabType {
+        NodeTabType::Console
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ScrollArea::vertical()
+            .id_salt("node_console_scroll")
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                ui.add_space(1.0);
+                ui.vertical_centered(|ui| {
+                    View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                        self.request_ui(ui, cb);
+                        self.history_ui(ui, cb);
+                    });
+                });
+            });
+    }
+}
+
+impl NetworkConsole {
+    /// Draw request input content.
+    fn request_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        View::sub_title(ui, format!("{} {}", TERMINAL_WINDOW, t!("network_console.request")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+
+        // Show API type selection.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered(|ui| {
+                View::radio_value(ui, &mut self.foreign, false, "Owner API".to_string());
+            });
+            columns[1].vertical_centered(|ui| {
+                View::radio_value(ui, &mut self.foreign, true, "Foreign API".to_string());
+            });
+        });
+        ui.add_space(8.0);
+
+        // Show method input.
+        ui.label(RichText::new(t!("network_console.method")).size(16.0).color(Colors::gray()));
+        ui.add_space(6.0);
+        let mut method_opts = TextEditOptions::new(Id::from("node_console_method")).no_focus();
+        View::text_edit(ui, cb, &mut self.method_edit, &mut method_opts);
+        ui.add_space(8.0);
+
+        // Show params input.
+        ui.label(RichText::new(t!("network_console.params")).size(16.0).color(Colors::gray()));
+        ui.add_space(6.0);
+        egui::TextEdit::multiline(&mut self.params_edit)
+            .id(Id::from("node_console_params"))
+            .font(egui::TextStyle::Monospace)
+            .desired_rows(3)
+            .desired_width(f32::INFINITY)
+            .show(ui);
+        ui.add_space(8.0);
+
+        // Show button to send request.
+        let in_progress = {
+            let r_history = REQUESTS_HISTORY.read();
+            r_history.last().map(|r| r.response.is_none()).unwrap_or(false)
+        };
+        ui.vertical_centered_justified(|ui| {
+            if in_progress {
+                View::small_loading_spinner(ui);
+            } else {
+                let send_text = format!("{} {}", PAPER_PLANE_TILT, t!("network_console.send"));
+                View::button(ui, send_text, Colors::white_or_black(false), || {
+                    cb.hide_keyboard();
+                    let input = ConsoleInput {
+                        foreign: self.foreign,
+                        method: self.method_edit.trim().to_string(),
+                        params: self.params_edit.trim().to_string(),
+                    };
+                    ViewState::put(INPUT_STATE_KEY, &input);
+                    send_request(ConsoleRequest {
+                        foreign: input.foreign,
+                        method: input.method,
+                        params: input.params,
+                        response: None,
+                    }, ui.ctx());
+                });
+            }
+        });
+        ui.add_space(8.0);
+    }
+
+    /// Draw requests history content.
+    fn history_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        let history = REQUESTS_HISTORY.read().clone();
+        if history.is_empty() {
+            return;
+        }
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        for req in history.iter().rev() {
+            let api = if req.foreign { "foreign" } else { "owner" };
+            ui.horizontal(|ui| {
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    // Draw button to repeat request.
+                    View::item_button(ui, View::item_rounding(0, 1, true),
+                                      ARROW_COUNTER_CLOCKWISE, None, || {
+                        self.foreign = req.foreign;
+                        self.method_edit = req.method.clone();
+                        self.params_edit = req.params.clone();
+                    });
+                    // Draw button to copy response.
+                    if let Some(res) = &req.response {
+                        View::item_button(ui, View::item_rounding(0, 1, true), COPY, None, || {
+                            cb.copy_string_to_buffer(res.clone());
+                        });
+                    }
+                    ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                        let title = format!("{}: {}", api, req.method);
+                        ui.label(RichText::new(title).size(16.0).color(Colors::title(false)));
+                    });
+                });
+            });
+            let response = req.response.clone().unwrap_or("…".to_string());
+            ui.label(RichText::new(response)
+                .monospace()
+                .size(13.0)
+                .color(Colors::text(false)));
+            ui.add_space(6.0);
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+        }
+    }
+}
+
+/// Send request to integrated node API and save response to history.
+fn send_request(req: ConsoleRequest, ui_ctx: &egui::Context) {
+    {
+        let mut w_history = REQUESTS_HISTORY.write();
+        w_history.push(req.clone());
+        if w_history.len() > MAX_HISTORY_SIZE {
+            w_history.remove(0);
+        }
+    }
+    let ui_ctx = ui_ctx.clone();
+    std::thread::spawn(move || {
+        let response = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let params: serde_json::Value = match serde_json::from_str(&req.params) {
+                    Ok(p) => p,
+                    Err(e) => return e.to_string()
+                };
+                let body = json!({
+                    "id": 1,
+                    "jsonrpc": "2.0",
+                    "method": req.method,
+                    "params": params
+                });
+                let api = if req.foreign { "foreign" } else { "owner" };
+                let addr = format!("http://{}/v2/{}", NodeConfig::get_api_address(), api);
+                let mut req_setup = hyper::Request::builder()
+                    .method(hyper::Method::POST)
+                    .uri(addr);
+                // Setup secret key auth.
+                if let Some(secret) = NodeConfig::get_api_secret(req.foreign) {
+                    let basic_auth = format!("Basic {}", to_base64(&format!("grin:{}", secret)));
+                    req_setup = req_setup.header(hyper::header::AUTHORIZATION, basic_auth);
+                }
+                let http_req = req_setup.body(hyper::Body::from(body.to_string())).unwrap();
+                match hyper::Client::new().request(http_req).await {
+                    Ok(res) => {
+                        let status = res.status();
+                        match hyper::body::to_bytes(res.into_body()).await {
+                            Ok(bytes) => {
+                                let text = String::from_utf8_lossy(&bytes).to_string();
+                                // Format JSON response.
+                                match serde_json::from_str::<serde_json::Value>(&text) {
+                                    Ok(v) => serde_json::to_string_pretty(&v).unwrap_or(text),
+                                    Err(_) => format!("{}\n{}", status, text)
+                                }
+                            }
+                            Err(e) => e.to_string()
+                        }
+                    }
+                    Err(e) => e.to_string()
+                }
+            });
+        // Save response to the history.
+        let mut w_history = REQUESTS_HISTORY.write();
+        if let Some(last) = w_history.last_mut() {
+            last.response = Some(response);
+        }
+        ui_ctx.request_repaint();
+    });
+}
@@ -17,13 +17,14 @@ use egui::scroll_area::ScrollBarVisibility;
 
 use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{ARROWS_COUNTER_CLOCKWISE, BRIEFCASE, DATABASE, DOTS_THREE_OUTLINE_VERTICAL, FACTORY, FADERS, GAUGE, POWER};
+use crate::gui::icons::{ARROWS_COUNTER_CLOCKWISE, BRIEFCASE, DATABASE, DOTS_THREE_OUTLINE_VERTICAL, FACTORY, FADERS, GAUGE, POWER, SCROLL, TERMINAL_WINDOW};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, TitlePanel, View};
-use crate::gui::views::network::{ConnectionsContent, NetworkMetrics, NetworkMining, NetworkNode, NetworkSettings};
+use crate::gui::views::network::{ConnectionsContent, NetworkConsole, NetworkLogs, NetworkMetrics, NetworkMining, NetworkNode, NetworkSettings};
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
 use crate::gui::views::types::{LinePosition, TitleContentType, TitleType};
 use crate::node::{Node, NodeConfig, NodeError};
+use crate::settings::ViewState;
 use crate::wallet::ExternalConnection;
 
 /// Network content.
@@ -37,13 +38,36 @@ pub struct NetworkContent {
 impl Default for NetworkContent {
     fn default() -> Self {
         Self {
-            node_tab_content: Box::new(NetworkNode::default()),
+            node_tab_content: Self::tab_content(
+                ViewState::get::<NodeTabType>(Self::TAB_STATE_KEY).unwrap_or(NodeTabType::Info)
+            ),
             connections: ConnectionsContent::default(),
         }
     }
 }
 
 impl NetworkContent {
+    /// Key to save selected tab at [`ViewState`].
+    const TAB_STATE_KEY: &'static str = "network_tab";
+
+    /// Create tab content for provided type.
+    fn tab_content(tab: NodeTabType) -> Box<dyn NodeTab> {
+        match tab {
+            NodeTabType::Info => Box::new(NetworkNode::default()),
+            NodeTabType::Metrics => Box::new(NetworkMetrics::default()),
+            NodeTabType::Mining => Box::new(NetworkMining::default()),
+            NodeTabType::Console => Box::new(NetworkConsole::default()),
+            NodeTabType::Logs => Box::new(NetworkLogs::default()),
+            NodeTabType::Settings => Box::new(NetworkSettings::default())
+        }
+    }
+
+    /// Select tab and save it at [`ViewState`].
+    fn select_tab(&mut self, tab: NodeTabType) {
+        ViewState::put(Self::TAB_STATE_KEY, &tab);
+        self.node_tab_content = Self::tab_content(tab);
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         let show_connections = AppConfig::show_connections_network_panel();
         let dual_panel = Content::is_dual_panel_mode(ui.ctx());
@@ -202,25 +226,35 @@ impl NetworkContent {
 
             // Draw tab buttons.
             let current_type = self.node_tab_content.get_type();
-            ui.columns(4, |columns| {
+            ui.columns(6, |columns| {
                 columns[0].vertical_centered_justified(|ui| {
                     View::tab_button(ui, DATABASE, current_type == NodeTabType::Info, |_| {
-                        self.node_tab_content = Box::new(NetworkNode::default());
+                        self.select_tab(NodeTabType::Info);
                     });
                 });
                 columns[1].vertical_centered_justified(|ui| {
                     View::tab_button(ui, GAUGE, current_type == NodeTabType::Metrics, |_| {
-                        self.node_tab_content = Box::new(NetworkMetrics::default());
+                        self.select_tab(NodeTabType::Metrics);
                     });
                 });
                 columns[2].vertical_centered_justified(|ui| {
                     View::tab_button(ui, FACTORY, current_type == NodeTabType::Mining, |_| {
-                        self.node_tab_content = Box::new(NetworkMining::default());
+                        self.select_tab(NodeTabType::Mining);
                     });
                 });
                 columns[3].vertical_centered_justified(|ui| {
+                    View::tab_button(ui, TERMINAL_WINDOW, current_type == NodeTabType::Console, |_| {
+                        self.select_tab(NodeTabType::Console);
+                    });
+                });
+                columns[4].vertical_centered_justified(|ui| {
+                    View::tab_button(ui, SCROLL, current_type == NodeTabType::Logs, |_| {
+                        self.select_tab(NodeTabType::Logs);
+                    });
+                });
+                columns[5].vertical_centered_justified(|ui| {
                     View::tab_button(ui, FADERS, current_type == NodeTabType::Settings, |_| {
-                        self.node_tab_content = Box::new(NetworkSettings::default());
+                        self.select_tab(NodeTabType::Settings);
                     });
                 });
             });
@@ -0,0 +1,165 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use egui::{FontId, Id, Label, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use log::Level;
+
+use crate::gui::Colors;
+use crate::gui::icons::{COPY, MAGNIFYING_GLASS, SCROLL, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Content, View};
+use crate::gui::views::network::types::{NodeTab, NodeTabType};
+use crate::gui::views::types::TextEditOptions;
+use crate::node::{LogEntry, NodeLogger};
+
+/// Integrated node logs tab content.
+pub struct NetworkLogs {
+    /// Maximal level of records to show.
+    level: Level,
+    /// Text to search at records.
+    search_edit: String,
+}
+
+impl Default for NetworkLogs {
+    fn default() -> Self {
+        Self {
+            level: Level::Info,
+            search_edit: "".to_string(),
+        }
+    }
+}
+
+/// Size of log records text.
+const LOG_TEXT_SIZE: f32 = 13.0;
+
+impl NodeTab for NetworkLogs {
+    fn get_type(&self) -> NodeTabType {
+        NodeTabType::Logs
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        let entries = self.filtered_entries();
+        ui.add_space(1.0);
+        ui.vertical_centered(|ui| {
+            View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                self.filter_ui(ui, cb, &entries);
+            });
+        });
+        ui.add_space(6.0);
+
+        // Show log records.
+        if entries.is_empty() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("network_logs.empty"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            let row_height = ui.fonts(|f| f.row_height(&FontId::monospace(LOG_TEXT_SIZE)));
+            ScrollArea::both()
+                .id_salt("node_logs_scroll")
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .stick_to_bottom(true)
+                .auto_shrink([false; 2])
+                .show_rows(ui, row_height, entries.len(), |ui, row_range| {
+                    for entry in &entries[row_range] {
+                        let color = match entry.level {
+                            Level::Error => Colors::red(),
+                            Level::Warn => Colors::gold(),
+                            Level::Info => Colors::text(false),
+                            _ => Colors::inactive_text()
+                        };
+                        let text = RichText::new(entry.to_line())
+                            .monospace()
+                            .size(LOG_TEXT_SIZE)
+                            .color(color);
+                        ui.add(Label::new(text).extend());
+                    }
+                });
+        }
+
+        // Refresh logs.
+        ui.ctx().request_repaint_after(Duration::from_millis(1000));
+    }
+}
+
+impl NetworkLogs {
+    /// Get log records based on selected level and search text.
+    fn filtered_entries(&self) -> Vec<LogEntry> {
+        let search = self.search_edit.trim().to_lowercase();
+        NodeLogger::entries()
+            .into_iter()
+            .filter(|e| {
+                e.level <= self.level && (search.is_empty() ||
+                    e.message.to_lowercase().contains(&search) ||
+                    e.target.to_lowercase().contains(&search))
+            })
+            .collect()
+    }
+
+    /// Draw level filter, search input and buttons content.
+    fn filter_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks, entries: &Vec<LogEntry>) {
+        View::sub_title(ui, format!("{} {}", SCROLL, t!("network.logs")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+
+        // Show level selection.
+        let levels = [Level::Error, Level::Warn, Level::Info, Level::Debug];
+        ui.columns(levels.len(), |columns| {
+            for (i, level) in levels.into_iter().enumerate() {
+                columns[i].vertical_centered(|ui| {
+                    View::radio_value(ui, &mut self.level, level, level.to_string());
+                });
+            }
+        });
+        ui.add_space(8.0);
+
+        // Show search input.
+        ui.label(RichText::new(format!("{} {}", MAGNIFYING_GLASS, t!("network_logs.search")))
+            .size(16.0)
+            .color(Colors::gray()));
+        ui.add_space(6.0);
+        let mut search_opts = TextEditOptions::new(Id::from("node_logs_search")).no_focus();
+        View::text_edit(ui, cb, &mut self.search_edit, &mut search_opts);
+        ui.add_space(8.0);
+
+        // Show buttons to copy and clear records.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let copy_text = format!("{} {}", COPY, t!("copy"));
+                    View::button(ui, copy_text, Colors::white_or_black(false), || {
+                        let text = entries.iter()
+                            .map(|e| e.to_line())
+                            .collect::<Vec<String>>()
+                            .join("\n");
+                        cb.copy_string_to_buffer(text);
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let clear_text = format!("{} {}", TRASH, t!("network_logs.clear"));
+                    View::button(ui, clear_text, Colors::white_or_black(false), || {
+                        NodeLogger::clear();
+                    });
+                });
+            });
+        });
+    }
+}
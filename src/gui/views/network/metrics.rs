@@ -18,12 +18,12 @@ use grin_core::consensus::{DAY_HEIGHT, GRIN_BASE, HOUR_SEC, REWARD};
 use grin_servers::{DiffBlock, ServerStats};
 
 use crate::gui::Colors;
-use crate::gui::icons::{AT, COINS, CUBE_TRANSPARENT, HOURGLASS_LOW, HOURGLASS_MEDIUM, TIMER};
+use crate::gui::icons::{AT, CLOUD_ARROW_DOWN, COINS, CUBE_TRANSPARENT, DOWNLOAD_SIMPLE, GRAPH, HOURGLASS_LOW, HOURGLASS_MEDIUM, TIMER};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, View};
-use crate::gui::views::network::NetworkContent;
+use crate::gui::views::network::{format_size, NetworkContent};
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
-use crate::node::Node;
+use crate::node::{Node, NodeConfig};
 
 /// Chain metrics tab content.
 #[derive(Default)]
@@ -49,6 +49,10 @@ impl NodeTab for NetworkMetrics {
         View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
             // Show emission and difficulty info.
             info_ui(ui, stats);
+            // Show Dandelion relay info.
+            dandelion_ui(ui, stats);
+            // Show sync time and traffic info.
+            sync_stats_ui(ui);
             // Show difficulty adjustment window blocks.
             blocks_ui(ui, stats);
         });
@@ -112,6 +116,126 @@ fn info_ui(ui: &mut egui::Ui, stats: &ServerStats) {
     });
 }
 
+/// Draw Dandelion relay state and settings info.
+fn dandelion_ui(ui: &mut egui::Ui, stats: &ServerStats) {
+    ui.add_space(5.0);
+    View::sub_title(ui, format!("{} {}", GRAPH, "Dandelion"));
+    let stem_txs = stats.tx_stats.as_ref().map(|s| s.stem_pool_size).unwrap_or(0);
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            let phase = if stem_txs > 0 {
+                t!("network_metrics.stem")
+            } else {
+                t!("network_metrics.idle")
+            };
+            View::label_box(ui,
+                            phase,
+                            t!("network_metrics.relay_phase"),
+                            [true, false, false, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            stem_txs.to_string(),
+                            t!("network_settings.stem_txs"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}%", NodeConfig::get_stem_probability()),
+                            t!("network_metrics.stem_probability"),
+                            [false, true, false, false]);
+        });
+    });
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}s", NodeConfig::get_dandelion_epoch()),
+                            t!("network_metrics.epoch"),
+                            [false, false, true, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}s", NodeConfig::get_dandelion_embargo()),
+                            t!("network_metrics.embargo"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}s", NodeConfig::get_dandelion_aggregation()),
+                            t!("network_metrics.aggregation"),
+                            [false, false, false, true]);
+        });
+    });
+}
+
+/// Draw node sync time and traffic info.
+fn sync_stats_ui(ui: &mut egui::Ui) {
+    let sync_stats = Node::sync_stats();
+    ui.add_space(5.0);
+
+    // Show initial sync info.
+    View::sub_title(ui, format!("{} {}", CLOUD_ARROW_DOWN, t!("network_metrics.initial_sync")));
+    ui.columns(3, |columns| {
+        let initial = sync_stats.initial.as_ref();
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            initial.map(|s| format_duration(s.duration)).unwrap_or("-".into()),
+                            t!("network_metrics.sync_time"),
+                            [true, false, true, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            initial.map(|s| format_size(s.received)).unwrap_or("-".into()),
+                            t!("network_metrics.downloaded"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            initial.map(|s| format!("{}/s", format_size(s.speed())))
+                                .unwrap_or("-".into()),
+                            t!("network_metrics.avg_speed"),
+                            [false, true, false, true]);
+        });
+    });
+    ui.add_space(5.0);
+
+    // Show daily traffic info.
+    View::sub_title(ui, format!("{} {}", DOWNLOAD_SIMPLE, t!("network_metrics.traffic")));
+    ui.columns(3, |columns| {
+        let (received, sent) = sync_stats.today().map(|d| (d.received, d.sent)).unwrap_or((0, 0));
+        let (avg_received, _) = sync_stats.daily_average();
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format_size(received),
+                            t!("network_metrics.downloaded_today"),
+                            [true, false, true, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format_size(sent),
+                            t!("network_metrics.uploaded_today"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format_size(avg_received),
+                            t!("network_metrics.downloaded_daily"),
+                            [false, true, false, true]);
+        });
+    });
+}
+
+/// Format duration in seconds to hours and minutes.
+pub fn format_duration(secs: u64) -> String {
+    let hours = secs / HOUR_SEC;
+    let minutes = (secs % HOUR_SEC) / 60;
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m {}s", minutes, secs % 60)
+    }
+}
+
 const BLOCK_ITEM_HEIGHT: f32 = 77.0;
 
 /// Draw difficulty adjustment window blocks content.
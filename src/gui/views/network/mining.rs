@@ -15,27 +15,34 @@
 use egui::{RichText, Rounding, ScrollArea};
 use egui::scroll_area::ScrollBarVisibility;
 use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
 use grin_servers::WorkerStats;
 
 use crate::gui::Colors;
-use crate::gui::icons::{BARBELL, CLOCK_AFTERNOON, CPU, CUBE, FADERS, FOLDER_DASHED, FOLDER_SIMPLE_MINUS, FOLDER_SIMPLE_PLUS, HARD_DRIVES, PLUGS, PLUGS_CONNECTED, POLYGON};
+use crate::gui::icons::{BARBELL, CLOCK_AFTERNOON, CPU, CUBE, FADERS, FOLDER_DASHED, FOLDER_SIMPLE_MINUS, FOLDER_SIMPLE_PLUS, HARD_DRIVES, PLAY, PLUGS, PLUGS_CONNECTED, POLYGON, STOP};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, View};
 use crate::gui::views::network::NetworkContent;
-use crate::gui::views::network::setup::StratumSetup;
+use crate::gui::views::network::setup::{MinerApiSetup, MinerSetup, StratumSetup};
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
-use crate::node::{Node, NodeConfig};
+use crate::node::{BlockTemplate, ExternalMiner, Node, NodeConfig, TestMiner};
 
 /// Mining tab content.
 pub struct NetworkMining {
     /// Stratum server setup content.
     stratum_server_setup: StratumSetup,
+    /// External miner setup content.
+    miner_setup: MinerSetup,
+    /// External miner or mining pool API statistics content.
+    miner_api_setup: MinerApiSetup,
 }
 
 impl Default for NetworkMining {
     fn default() -> Self {
         Self {
             stratum_server_setup: StratumSetup::default(),
+            miner_setup: MinerSetup::default(),
+            miner_api_setup: MinerApiSetup::default(),
         }
     }
 }
@@ -130,6 +137,11 @@ impl NodeTab for NetworkMining {
         });
         ui.add_space(4.0);
 
+        // Show block template served to miners.
+        if let Some(template) = Node::get_block_template() {
+            block_template_ui(ui, &template);
+        }
+
         // Show mining info.
         View::sub_title(ui, format!("{} {}", CPU, t!("network_mining.miners")));
         ui.columns(2, |columns| {
@@ -149,6 +161,19 @@ impl NodeTab for NetworkMining {
         });
         ui.add_space(4.0);
 
+        // Show combined local and remote hashrate statistics.
+        self.miner_api_setup.ui(ui, &stratum_stats, cb);
+
+        // Show external miner launcher.
+        if ExternalMiner::is_supported() {
+            self.miner_setup.ui(ui, cb);
+        }
+
+        // Show built-in miner for testnet.
+        if TestMiner::is_available() {
+            test_miner_ui(ui);
+        }
+
         // Show workers stats or info text when possible.
         let workers_size = stratum_stats.worker_stats.len();
         if workers_size != 0 && stratum_stats.num_workers > 0 {
@@ -186,6 +211,98 @@ impl NodeTab for NetworkMining {
     }
 }
 
+/// Draw information about block template served by stratum server.
+fn block_template_ui(ui: &mut egui::Ui, template: &BlockTemplate) {
+    View::sub_title(ui, format!("{} {}", CUBE, t!("network_mining.block_template")));
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            template.height.to_string(),
+                            t!("network_node.height"),
+                            [true, false, false, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            template.kernels.to_string(),
+                            t!("network_mining.kernels"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            template.job_id.to_string(),
+                            t!("network_mining.job_id"),
+                            [false, true, false, false]);
+        });
+    });
+    ui.columns(2, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}ツ", amount_to_hr_string(template.fees, true)),
+                            t!("network_mining.fees"),
+                            [false, false, true, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            format!("{}ツ", amount_to_hr_string(template.reward, true)),
+                            t!("network_mining.reward"),
+                            [false, false, false, true]);
+        });
+    });
+    ui.add_space(4.0);
+    ui.vertical_centered(|ui| {
+        let updated = t!("network_mining.template_updated",
+            "time" => View::format_time(template.time));
+        ui.label(RichText::new(format!("{} {}", CLOCK_AFTERNOON, updated))
+            .size(15.0)
+            .color(Colors::inactive_text()));
+    });
+    // Refresh template on new jobs.
+    ui.ctx().request_repaint_after(std::time::Duration::from_millis(1000));
+    ui.add_space(4.0);
+}
+
+/// Draw built-in testnet miner controls and statistics.
+fn test_miner_ui(ui: &mut egui::Ui) {
+    View::sub_title(ui, format!("{} {}", CPU, t!("network_mining.test_miner")));
+    ui.scope(|ui| {
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                let stats = t!("network_mining.test_miner_stats",
+                    "graphs" => TestMiner::graphs(),
+                    "blocks" => TestMiner::blocks());
+                ui.add_space(6.0);
+                ui.label(RichText::new(stats).size(16.0).color(Colors::inactive_text()));
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                // Show button to start or stop miner.
+                if TestMiner::is_running() {
+                    let stop_text = format!("{} {}", STOP, t!("network_settings.disable"));
+                    View::button(ui, stop_text, Colors::white_or_black(false), || {
+                        TestMiner::stop();
+                    });
+                } else {
+                    let start_text = format!("{} {}", PLAY, t!("network_settings.enable"));
+                    View::button(ui, start_text, Colors::white_or_black(false), || {
+                        TestMiner::start();
+                    });
+                }
+            });
+        });
+    });
+    ui.add_space(6.0);
+    ui.label(RichText::new(t!("network_mining.test_miner_desc"))
+        .size(16.0)
+        .color(Colors::inactive_text()));
+    // Refresh statistics while miner is running.
+    if TestMiner::is_running() {
+        ui.ctx().request_repaint_after(std::time::Duration::from_millis(1000));
+    }
+    ui.add_space(4.0);
+}
+
 /// Height of Stratum server worker list item.
 const WORKER_ITEM_HEIGHT: f32 = 76.0;
 
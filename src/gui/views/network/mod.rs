@@ -18,6 +18,12 @@ pub use metrics::*;
 mod mining;
 pub use mining::*;
 
+mod console;
+pub use console::*;
+
+mod logs;
+pub use logs::*;
+
 mod settings;
 pub use settings::*;
 
@@ -12,27 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{RichText, Rounding, ScrollArea};
+use egui::{Align, Align2, FontId, Layout, RichText, Rounding, ScrollArea, Sense, Shape, Stroke};
 use egui::scroll_area::ScrollBarVisibility;
+use grin_chain::SyncStatus;
 use grin_servers::PeerStats;
 
 use crate::gui::Colors;
-use crate::gui::icons::{AT, CUBE, DEVICES, FLOW_ARROW, HANDSHAKE, PACKAGE, SHARE_NETWORK};
+use crate::gui::icons::{AT, CUBE, DEVICES, FILE_ARROW_UP, FILE_ZIP, FLOW_ARROW, GAUGE, HANDSHAKE, PACKAGE, SHARE_NETWORK, WARNING, X};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, View};
+use crate::gui::views::network::{format_duration, format_size};
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
-use crate::node::{Node, NodeConfig};
+use crate::node::{BootstrapError, Node, NodeConfig, NodeLogger, NodeMonitor, NodeWarning};
 
 /// Integrated node tab content.
 #[derive(Default)]
-pub struct NetworkNode;
+pub struct NetworkNode {
+    /// Flag to check if archive file is picking to bootstrap the node.
+    file_picking: bool,
+}
 
 impl NodeTab for NetworkNode {
     fn get_type(&self) -> NodeTabType {
         NodeTabType::Info
     }
 
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn PlatformCallbacks) {
+    fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         ScrollArea::vertical()
             .id_salt("integrated_node_info_scroll")
             .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
@@ -40,6 +45,10 @@ impl NodeTab for NetworkNode {
             .show(ui, |ui| {
                 ui.add_space(2.0);
                 View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                    // Show node warnings.
+                    node_warnings_ui(ui);
+                    // Show bootstrap from archive file content.
+                    self.bootstrap_ui(ui, cb);
                     // Show node stats content.
                     node_stats_ui(ui);
                 });
@@ -47,6 +56,110 @@ impl NodeTab for NetworkNode {
     }
 }
 
+impl NetworkNode {
+    /// Draw content to bootstrap chain state from txhashset archive file while syncing.
+    fn bootstrap_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        let bootstrapping = Node::is_bootstrapping();
+        let error = Node::bootstrap_error();
+        if !bootstrapping && Node::not_syncing() {
+            return;
+        }
+        View::sub_title(ui, format!("{} {}", FILE_ZIP, t!("network_node.bootstrap")));
+        ui.vertical_centered(|ui| {
+            ui.add_space(4.0);
+            if bootstrapping {
+                View::small_loading_spinner(ui);
+                ui.add_space(6.0);
+                let status = match Node::get_sync_status() {
+                    Some(SyncStatus::NoSync) | Some(SyncStatus::Initial) | None => {
+                        t!("network_node.bootstrapping")
+                    }
+                    Some(_) => Node::get_sync_status_text()
+                };
+                ui.label(RichText::new(status).size(16.0).color(Colors::inactive_text()));
+            } else if self.file_picking {
+                View::small_loading_spinner(ui);
+                // Check file pick result.
+                if let Some(path) = cb.picked_file() {
+                    self.file_picking = false;
+                    if !path.is_empty() {
+                        Node::bootstrap(path);
+                    }
+                }
+            } else {
+                ui.label(RichText::new(t!("network_node.bootstrap_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+                ui.add_space(8.0);
+                let text = format!("{} {}", FILE_ARROW_UP, t!("choose_file"));
+                View::button(ui, text, Colors::white_or_black(false), || {
+                    if let Some(path) = cb.pick_file() {
+                        // Wait for asynchronous file pick result if path is empty.
+                        if path.is_empty() {
+                            self.file_picking = true;
+                        } else {
+                            Node::bootstrap(path);
+                        }
+                    }
+                });
+                if let Some(e) = error {
+                    let text = match e {
+                        BootstrapError::InvalidFile => t!("network_node.bootstrap_invalid_file"),
+                        BootstrapError::HeaderNotFound => t!("network_node.bootstrap_no_header"),
+                        BootstrapError::Validation => t!("network_node.bootstrap_error")
+                    };
+                    ui.add_space(8.0);
+                    ui.label(RichText::new(text).size(16.0).color(Colors::red()));
+                }
+            }
+            ui.add_space(8.0);
+        });
+    }
+}
+
+/// Maximal difference in seconds between local time and network tip time.
+const MAX_TIME_DRIFT: i64 = 5 * 60;
+
+/// Draw node warnings content.
+fn node_warnings_ui(ui: &mut egui::Ui) {
+    let mut warnings = NodeLogger::warnings()
+        .into_iter()
+        .map(|(w, _)| w)
+        .collect::<Vec<NodeWarning>>();
+    // Check if network tip time is far ahead of local time.
+    if let Some(stats) = Node::get_stats() {
+        let tip_time = stats.header_stats.latest_timestamp.timestamp();
+        let drift = tip_time - chrono::Utc::now().timestamp();
+        if drift > MAX_TIME_DRIFT && !warnings.contains(&NodeWarning::TimeDrift) {
+            warnings.push(NodeWarning::TimeDrift);
+        }
+    }
+    for warning in warnings {
+        let text = match warning {
+            NodeWarning::FutureBlock => t!("network_node.future_block_warning"),
+            NodeWarning::TimeDrift => t!("network_node.time_drift_warning"),
+            NodeWarning::Fork => t!("network_node.fork_warning")
+        };
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(52.0);
+        let r = View::item_rounding(0, 1, false);
+        ui.painter().rect(rect, r, Colors::fill(), View::item_stroke());
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            // Draw button to hide warning.
+            View::item_button(ui, View::item_rounding(0, 1, true), X, None, || {
+                NodeLogger::dismiss_warning(warning);
+            });
+            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(8.0);
+                ui.label(RichText::new(WARNING).size(20.0).color(Colors::red()));
+                ui.add_space(4.0);
+                View::ellipsize_text(ui, text.clone(), 15.0, Colors::text(false));
+            });
+        }).response.on_hover_text(text);
+        ui.add_space(6.0);
+    }
+}
+
 /// Draw node statistics content.
 fn node_stats_ui(ui: &mut egui::Ui) {
     let server_stats = Node::get_stats();
@@ -162,6 +275,9 @@ fn node_stats_ui(ui: &mut egui::Ui) {
     });
     ui.add_space(5.0);
 
+    // Show resources usage.
+    resources_ui(ui);
+
     // Show peer stats when available.
     if stats.peer_count > 0 {
         View::sub_title(ui, format!("{} {}", HANDSHAKE, t!("network_node.peers")));
@@ -173,6 +289,121 @@ fn node_stats_ui(ui: &mut egui::Ui) {
     }
 }
 
+/// Draw node resources usage info with peers and sync speed history.
+fn resources_ui(ui: &mut egui::Ui) {
+    let last = NodeMonitor::last_sample();
+    View::sub_title(ui, format!("{} {}", GAUGE, t!("network_node.resources")));
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            NodeMonitor::uptime().map(format_duration).unwrap_or("-".into()),
+                            t!("network_node.uptime"),
+                            [true, false, false, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            last.and_then(|s| s.cpu)
+                                .map(|cpu| format!("{:.1}%", cpu))
+                                .unwrap_or("-".into()),
+                            t!("network_node.cpu"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            last.and_then(|s| s.memory).map(format_size).unwrap_or("-".into()),
+                            t!("network_node.memory"),
+                            [false, true, false, false]);
+        });
+    });
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::label_box(ui,
+                            Node::data_size()
+                                .map(|(total, _)| format_size(total))
+                                .unwrap_or("-".into()),
+                            t!("network_node.database"),
+                            [false, false, true, false]);
+        });
+        columns[1].vertical_centered(|ui| {
+            View::label_box(ui,
+                            NodeMonitor::sync_speed()
+                                .map(|speed| format!("{:.1}", speed))
+                                .unwrap_or("-".into()),
+                            t!("network_node.blocks_per_minute"),
+                            [false, false, false, false]);
+        });
+        columns[2].vertical_centered(|ui| {
+            View::label_box(ui,
+                            last.map(|s| format!("{}/s", format_size(s.download_speed)))
+                                .unwrap_or("-".into()),
+                            t!("network_node.download_speed"),
+                            [false, false, false, true]);
+        });
+    });
+    ui.add_space(8.0);
+
+    // Show peers and sync speed history.
+    let samples = NodeMonitor::samples();
+    let peers = samples.iter().map(|s| s.peers as f64).collect::<Vec<f64>>();
+    let peers_value = last.map(|s| s.peers.to_string()).unwrap_or("-".into());
+    history_graph_ui(ui,
+                     t!("network_node.peers"),
+                     peers_value,
+                     &peers,
+                     View::item_rounding(0, 2, false));
+    let speeds = samples.iter().map(|s| s.download_speed as f64).collect::<Vec<f64>>();
+    let speed_value = last.map(|s| format!("{}/s", format_size(s.download_speed)))
+        .unwrap_or("-".into());
+    history_graph_ui(ui,
+                     t!("network_node.sync_speed"),
+                     speed_value,
+                     &speeds,
+                     View::item_rounding(1, 2, false));
+    ui.add_space(5.0);
+}
+
+/// Height of history graph.
+const GRAPH_HEIGHT: f32 = 72.0;
+
+/// Draw history graph of values from oldest to newest with title and current value.
+fn history_graph_ui(ui: &mut egui::Ui,
+                    title: String,
+                    value: String,
+                    values: &[f64],
+                    rounding: Rounding) {
+    let size = egui::vec2(ui.available_width(), GRAPH_HEIGHT);
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter();
+
+    // Draw round background.
+    painter.rect(rect, rounding, Colors::fill_lite(), View::item_stroke());
+
+    // Draw values line with newest value at the right side.
+    let mut graph_rect = rect.shrink(8.0);
+    graph_rect.min.y += 20.0;
+    let max = values.iter().cloned().fold(0.0, f64::max);
+    if values.len() > 1 && max > 0.0 {
+        let step = graph_rect.width() / (NodeMonitor::MAX_SAMPLES - 1) as f32;
+        let offset = NodeMonitor::MAX_SAMPLES.saturating_sub(values.len());
+        let points = values.iter().enumerate().map(|(i, v)| {
+            let x = graph_rect.min.x + (offset + i) as f32 * step;
+            let y = graph_rect.max.y - (*v / max) as f32 * graph_rect.height();
+            egui::pos2(x, y)
+        }).collect::<Vec<egui::Pos2>>();
+        painter.add(Shape::line(points, Stroke::new(2.0, Colors::gold())));
+    }
+
+    // Draw title and current value.
+    let text_pos = rect.min + egui::vec2(8.0, 6.0);
+    painter.text(text_pos, Align2::LEFT_TOP, title, FontId::proportional(15.0), Colors::gray());
+    let value_pos = egui::pos2(rect.max.x - 8.0, rect.min.y + 6.0);
+    painter.text(value_pos,
+                 Align2::RIGHT_TOP,
+                 value,
+                 FontId::proportional(15.0),
+                 Colors::white_or_black(true));
+}
+
 const PEER_ITEM_HEIGHT: f32 = 77.0;
 
 /// Draw connected peer info item.
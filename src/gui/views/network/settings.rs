@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{RichText, ScrollArea};
+use egui::{ComboBox, Id, RichText, ScrollArea};
 use egui::scroll_area::ScrollBarVisibility;
 
 use crate::gui::Colors;
@@ -21,7 +21,7 @@ use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, Content, View};
 use crate::gui::views::network::setup::{DandelionSetup, NodeSetup, P2PSetup, PoolSetup, StratumSetup};
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
-use crate::gui::views::types::{ModalContainer, ModalPosition};
+use crate::gui::views::types::{ModalContainer, ModalPosition, TextEditOptions};
 use crate::node::{Node, NodeConfig};
 
 /// Integrated node settings tab content.
@@ -147,43 +147,82 @@ impl NetworkSettings {
         }
     }
 
-    /// Draw IP addresses as radio buttons.
+    /// Draw dropdown of IP addresses available at system with manual entry.
     pub fn ip_addrs_ui(ui: &mut egui::Ui,
+                       id: Id,
                        saved_ip: &String,
-                       ips: &Vec<String>,
+                       ips: &Vec<(String, String)>,
+                       manual_edit: &mut Option<String>,
+                       cb: &dyn PlatformCallbacks,
                        on_change: impl FnOnce(&String)) {
-        let mut selected_ip = saved_ip;
-
-        // Set first IP address as current if saved is not present at system.
-        if !ips.contains(saved_ip) {
-            selected_ip = ips.get(0).unwrap();
+        // Enable manual entry if saved IP address is not detected at system.
+        let detected = ips.iter().any(|(ip, _)| ip == saved_ip);
+        if !detected && manual_edit.is_none() {
+            *manual_edit = Some(saved_ip.clone());
         }
 
         ui.add_space(2.0);
 
         // Show available IP addresses on the system.
-        let _ = ips.chunks(2).map(|x| {
-            if x.len() == 2 {
-                ui.columns(2, |columns| {
-                    let ip_left = x.get(0).unwrap();
-                    columns[0].vertical_centered(|ui| {
-                        View::radio_value(ui, &mut selected_ip, ip_left, ip_left.to_string());
-                    });
-                    let ip_right = x.get(1).unwrap();
-                    columns[1].vertical_centered(|ui| {
-                        View::radio_value(ui, &mut selected_ip, ip_right, ip_right.to_string());
-                    })
-                });
+        let mut selected: Option<String> = None;
+        let mut manual_selected = false;
+        let selected_text = if manual_edit.is_some() {
+            t!("network_settings.manual_ip")
+        } else {
+            ips.iter()
+                .find(|(ip, _)| ip == saved_ip)
+                .map(|(ip, name)| format!("{} ({})", ip, name))
+                .unwrap_or(saved_ip.clone())
+        };
+        ComboBox::from_id_salt(id)
+            .width(ui.available_width().min(280.0))
+            .selected_text(RichText::new(selected_text).size(16.0).color(Colors::text_button()))
+            .show_ui(ui, |ui| {
+                for (ip, name) in ips {
+                    let is_current = manual_edit.is_none() && ip == saved_ip;
+                    let text = format!("{} ({})", ip, name);
+                    if ui.selectable_label(is_current, text).clicked() {
+                        selected = Some(ip.clone());
+                    }
+                }
+                let manual_text = t!("network_settings.manual_ip");
+                if ui.selectable_label(manual_edit.is_some(), manual_text).clicked() {
+                    manual_selected = true;
+                }
+            });
+        ui.add_space(8.0);
+
+        if manual_selected && manual_edit.is_none() {
+            *manual_edit = Some(saved_ip.clone());
+        }
+
+        // Show manual IP address entry.
+        let mut changed_ip = selected;
+        if changed_ip.is_some() {
+            *manual_edit = None;
+        } else if let Some(edit) = manual_edit.as_mut() {
+            let mut edit_opts = TextEditOptions::new(id.with("manual_ip")).no_focus();
+            View::text_edit(ui, cb, edit, &mut edit_opts);
+            let ip = edit.trim().to_string();
+            if NodeConfig::is_ip_assigned(&ip) {
+                if &ip != saved_ip {
+                    changed_ip = Some(ip);
+                }
             } else {
-                let ip = x.get(0).unwrap();
-                View::radio_value(ui, &mut selected_ip, ip, ip.to_string());
+                ui.add_space(6.0);
+                ui.label(RichText::new(t!("network_settings.ip_not_assigned"))
+                    .size(16.0)
+                    .color(Colors::red()));
             }
-            ui.add_space(12.0);
-        }).collect::<Vec<_>>();
+            ui.add_space(8.0);
+        }
 
-        if saved_ip != selected_ip {
-            (on_change)(&selected_ip.to_string());
+        if let Some(ip) = changed_ip {
+            if &ip != saved_ip {
+                (on_change)(&ip);
+            }
         }
+        ui.add_space(4.0);
     }
 
     /// Show message when IP addresses are not available at system.
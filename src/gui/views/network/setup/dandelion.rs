@@ -53,7 +53,7 @@ impl Default for DandelionSetup {
     fn default() -> Self {
         Self {
             epoch_edit: NodeConfig::get_dandelion_epoch(),
-            embargo_edit: NodeConfig::get_reorg_cache_period(),
+            embargo_edit: NodeConfig::get_dandelion_embargo(),
             aggregation_edit: NodeConfig::get_dandelion_aggregation(),
             stem_prob_edit: NodeConfig::get_stem_probability(),
             modal_ids: vec![
@@ -0,0 +1,209 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::icons::{FOLDER_OPEN, GEAR_SIX, PLAY, STOP, TERMINAL};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalContainer, ModalPosition, TextEditOptions};
+use crate::node::ExternalMiner;
+
+/// External miner launcher setup and output content.
+pub struct MinerSetup {
+    /// Miner binary path value.
+    path_edit: String,
+    /// Miner arguments template value.
+    args_edit: String,
+
+    /// [`Modal`] identifiers allowed at this ui container.
+    modal_ids: Vec<&'static str>
+}
+
+/// Identifier for miner setup [`Modal`].
+const MINER_SETUP_MODAL: &'static str = "external_miner_setup";
+
+impl Default for MinerSetup {
+    fn default() -> Self {
+        Self {
+            path_edit: "".to_string(),
+            args_edit: "".to_string(),
+            modal_ids: vec![
+                MINER_SETUP_MODAL
+            ]
+        }
+    }
+}
+
+impl ModalContainer for MinerSetup {
+    fn modal_ids(&self) -> &Vec<&'static str> {
+        &self.modal_ids
+    }
+
+    fn modal_ui(&mut self,
+                ui: &mut egui::Ui,
+                modal: &Modal,
+                cb: &dyn PlatformCallbacks) {
+        match modal.id {
+            MINER_SETUP_MODAL => self.setup_modal(ui, modal, cb),
+            _ => {}
+        }
+    }
+}
+
+impl MinerSetup {
+    pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        // Draw modal content for current ui container.
+        self.current_modal_ui(ui, cb);
+
+        View::sub_title(ui, format!("{} {}", TERMINAL, t!("network_mining.external_miner")));
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    // Show button to setup miner.
+                    let path = AppConfig::miner_path();
+                    let name = path.as_ref()
+                        .and_then(|p| {
+                            std::path::Path::new(p)
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                        })
+                        .unwrap_or(t!("network_mining.choose_miner").into());
+                    let setup_text = format!("{} {}", GEAR_SIX, name);
+                    View::button(ui, setup_text, Colors::white_or_black(false), || {
+                        self.path_edit = path.clone().unwrap_or_default();
+                        self.args_edit = AppConfig::miner_args();
+                        Modal::new(MINER_SETUP_MODAL)
+                            .position(ModalPosition::CenterTop)
+                            .title(t!("network_mining.external_miner"))
+                            .show();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Show button to start or stop miner.
+                    if ExternalMiner::is_running() {
+                        let stop_text = format!("{} {}", STOP, t!("network_settings.disable"));
+                        View::button(ui, stop_text, Colors::white_or_black(false), || {
+                            ExternalMiner::stop();
+                        });
+                    } else if AppConfig::miner_path().is_some() {
+                        let start_text = format!("{} {}", PLAY, t!("network_settings.enable"));
+                        View::button(ui, start_text, Colors::white_or_black(false), || {
+                            ExternalMiner::start();
+                        });
+                    }
+                });
+            });
+        });
+
+        // Show miner output.
+        let output = ExternalMiner::output();
+        if !output.is_empty() {
+            ui.add_space(6.0);
+            ScrollArea::vertical()
+                .id_salt("external_miner_output_scroll")
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(120.0)
+                .stick_to_bottom(true)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for line in output {
+                        ui.label(RichText::new(line)
+                            .monospace()
+                            .size(13.0)
+                            .color(Colors::text(false)));
+                    }
+                });
+            // Refresh output while miner is running.
+            if ExternalMiner::is_running() {
+                ui.ctx().request_repaint_after(std::time::Duration::from_millis(500));
+            }
+        }
+        ui.add_space(4.0);
+    }
+
+    /// Draw miner setup [`Modal`] content.
+    fn setup_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            // Show miner binary path input.
+            ui.label(RichText::new(t!("network_mining.miner_path"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                View::button(ui, FOLDER_OPEN.to_string(), Colors::white_or_black(false), || {
+                    if let Some(path) = cb.pick_file() {
+                        self.path_edit = path;
+                    }
+                });
+                ui.add_space(4.0);
+                let mut path_edit_opts = TextEditOptions::new(Id::from(modal.id)).no_focus();
+                View::text_edit(ui, cb, &mut self.path_edit, &mut path_edit_opts);
+            });
+            ui.add_space(8.0);
+
+            // Show miner arguments input.
+            ui.label(RichText::new(t!("network_mining.miner_args"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut args_edit_opts = TextEditOptions::new(Id::from(modal.id).with("args"))
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.args_edit, &mut args_edit_opts);
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("network_mining.miner_args_desc",
+                                      "values" => "{host} {port} {login}"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                        let path = self.path_edit.trim();
+                        let path = if path.is_empty() {
+                            None
+                        } else {
+                            Some(path.to_string())
+                        };
+                        AppConfig::save_miner(path, self.args_edit.trim().to_string());
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
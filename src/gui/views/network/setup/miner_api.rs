@@ -0,0 +1,264 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+use grin_servers::StratumStats;
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::icons::{ARROWS_CLOCKWISE, GAUGE, LINK};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalContainer, ModalPosition, TextEditOptions};
+use crate::node::MinerApi;
+
+/// External miner or mining pool API statistics content.
+pub struct MinerApiSetup {
+    /// API URL value.
+    url_edit: String,
+    /// Flag to check if entered URL is invalid.
+    url_error: bool,
+
+    /// [`Modal`] identifiers allowed at this ui container.
+    modal_ids: Vec<&'static str>
+}
+
+/// Identifier for API URL setup [`Modal`].
+const MINER_API_MODAL: &'static str = "miner_api_setup";
+
+impl Default for MinerApiSetup {
+    fn default() -> Self {
+        Self {
+            url_edit: "".to_string(),
+            url_error: false,
+            modal_ids: vec![
+                MINER_API_MODAL
+            ]
+        }
+    }
+}
+
+impl ModalContainer for MinerApiSetup {
+    fn modal_ids(&self) -> &Vec<&'static str> {
+        &self.modal_ids
+    }
+
+    fn modal_ui(&mut self,
+                ui: &mut egui::Ui,
+                modal: &Modal,
+                cb: &dyn PlatformCallbacks) {
+        match modal.id {
+            MINER_API_MODAL => self.url_modal(ui, modal, cb),
+            _ => {}
+        }
+    }
+}
+
+impl MinerApiSetup {
+    pub fn ui(&mut self, ui: &mut egui::Ui, stats: &StratumStats, cb: &dyn PlatformCallbacks) {
+        // Draw modal content for current ui container.
+        self.current_modal_ui(ui, cb);
+
+        // Request remote statistics.
+        MinerApi::check_periodically(ui.ctx());
+
+        View::sub_title(ui, format!("{} {}", GAUGE, t!("network_mining.hashrate_stats")));
+        let local = MinerApi::local_hashrate(stats);
+        let remote = MinerApi::remote_stats();
+        let combined = match (local, remote.as_ref()) {
+            (None, None) => None,
+            (l, r) => Some(l.unwrap_or(0.0) + r.map(|r| r.hashrate).unwrap_or(0.0))
+        };
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered(|ui| {
+                View::label_box(ui,
+                                format_hashrate(local),
+                                t!("network_mining.local_hashrate"),
+                                [true, false, false, false]);
+            });
+            columns[1].vertical_centered(|ui| {
+                View::label_box(ui,
+                                format_hashrate(remote.as_ref().map(|r| r.hashrate)),
+                                t!("network_mining.remote_hashrate"),
+                                [false, false, false, false]);
+            });
+            columns[2].vertical_centered(|ui| {
+                View::label_box(ui,
+                                format_hashrate(combined),
+                                t!("network_mining.combined_hashrate"),
+                                [false, true, false, false]);
+            });
+        });
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered(|ui| {
+                let workers = remote.as_ref()
+                    .and_then(|r| r.workers)
+                    .map(|w| w.to_string())
+                    .unwrap_or("-".into());
+                View::label_box(ui,
+                                workers,
+                                t!("network_mining.remote_workers"),
+                                [false, false, true, false]);
+            });
+            columns[1].vertical_centered(|ui| {
+                let time = combined
+                    .and_then(|h| MinerApi::time_to_block(stats.network_hashrate, h))
+                    .map(format_duration)
+                    .unwrap_or("-".into());
+                View::label_box(ui,
+                                time,
+                                t!("network_mining.time_to_block"),
+                                [false, false, false, true]);
+            });
+        });
+        ui.add_space(6.0);
+
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            let url = AppConfig::miner_api_url();
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    // Show button to setup API URL.
+                    let host = url.as_ref()
+                        .and_then(|u| url::Url::parse(u).ok())
+                        .and_then(|u| u.host_str().map(|h| h.to_string()))
+                        .unwrap_or(t!("network_mining.setup_api").into());
+                    let setup_text = format!("{} {}", LINK, host);
+                    View::button(ui, setup_text, Colors::white_or_black(false), || {
+                        self.url_edit = url.clone().unwrap_or_default();
+                        self.url_error = false;
+                        Modal::new(MINER_API_MODAL)
+                            .position(ModalPosition::CenterTop)
+                            .title(t!("network_mining.miner_api"))
+                            .show();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Show button to refresh statistics.
+                    if url.is_some() && !MinerApi::is_loading() {
+                        let refresh_text = format!("{} {}", ARROWS_CLOCKWISE, t!("network_mining.refresh_stats"));
+                        View::button(ui, refresh_text, Colors::white_or_black(false), || {
+                            MinerApi::refresh();
+                        });
+                    } else if MinerApi::is_loading() {
+                        ui.add_space(4.0);
+                        View::small_loading_spinner(ui);
+                    }
+                });
+            });
+        });
+        if MinerApi::has_error() {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("network_mining.miner_api_error"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            });
+        }
+        // Refresh local hashrate.
+        ui.ctx().request_repaint_after(std::time::Duration::from_millis(1000));
+        ui.add_space(4.0);
+    }
+
+    /// Draw API URL setup [`Modal`] content.
+    fn url_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_mining.miner_api_url"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut url_edit_opts = TextEditOptions::new(Id::from(modal.id)).paste();
+            View::text_edit(ui, cb, &mut self.url_edit, &mut url_edit_opts);
+            if self.url_error {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("network_mining.miner_api_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let mut on_save = || {
+                        let url = self.url_edit.trim();
+                        let url = if url.is_empty() {
+                            None
+                        } else if url::Url::parse(url).is_ok() {
+                            Some(url.to_string())
+                        } else {
+                            self.url_error = true;
+                            return;
+                        };
+                        AppConfig::save_miner_api_url(url);
+                        MinerApi::refresh();
+                        cb.hide_keyboard();
+                        modal.close();
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
+
+/// Format hashrate value in graphs per second.
+fn format_hashrate(hashrate: Option<f64>) -> String {
+    match hashrate {
+        Some(h) => format!("{:.2}", h),
+        None => "-".into()
+    }
+}
+
+/// Format estimated duration from seconds.
+fn format_duration(secs: u64) -> String {
+    let days = secs / 86400;
+    let hours = secs % 86400 / 3600;
+    let minutes = secs % 3600 / 60;
+    if days > 0 {
+        format!("~{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("~{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("~{}m", minutes)
+    } else {
+        format!("~{}s", secs)
+    }
+}
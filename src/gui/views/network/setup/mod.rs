@@ -13,7 +13,7 @@
 // limitations under the License.
 
 mod node;
-pub use node::NodeSetup;
+pub use node::{format_size, NodeSetup};
 
 mod p2p;
 pub use p2p::P2PSetup;
@@ -25,4 +25,10 @@ mod dandelion;
 pub use dandelion::DandelionSetup;
 
 mod stratum;
-pub use stratum::StratumSetup;
\ No newline at end of file
+pub use stratum::StratumSetup;
+
+mod miner;
+pub use miner::MinerSetup;
+
+mod miner_api;
+pub use miner_api::MinerApiSetup;
\ No newline at end of file
@@ -13,11 +13,12 @@
 // limitations under the License.
 
 use egui::{Id, RichText};
+use grin_core::global;
 use grin_core::global::ChainTypes;
 
 use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{CLOCK_CLOCKWISE, COMPUTER_TOWER, PLUG, POWER, SHIELD, SHIELD_SLASH};
+use crate::gui::icons::{ARCHIVE_BOX, CLOCK_CLOCKWISE, CLOCK_COUNTDOWN, COMPUTER_TOWER, PLUG, POWER, SHIELD, SHIELD_SLASH, USERS_THREE};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::network::NetworkContent;
@@ -27,8 +28,10 @@ use crate::node::{Node, NodeConfig};
 
 /// Integrated node general setup section content.
 pub struct NodeSetup {
-    /// IP Addresses available at system.
-    available_ips: Vec<String>,
+    /// IP Addresses with interface names available at system.
+    available_ips: Vec<(String, String)>,
+    /// Manually entered IP address.
+    manual_ip_edit: Option<String>,
 
     /// API port value.
     api_port_edit: String,
@@ -44,6 +47,9 @@ pub struct NodeSetup {
     /// Future Time Limit value.
     ftl_edit: String,
 
+    /// Wallets sync threshold value for modal.
+    wallet_sync_edit: String,
+
     /// [`Modal`] identifiers allowed at this ui container.
     modal_ids: Vec<&'static str>
 }
@@ -56,23 +62,31 @@ pub const API_SECRET_MODAL: &'static str = "api_secret";
 pub const FOREIGN_API_SECRET_MODAL: &'static str = "foreign_api_secret";
 /// Identifier for FTL value [`Modal`].
 pub const FTL_MODAL: &'static str = "ftl";
+/// Identifier for minimal peers amount to sync wallets [`Modal`].
+pub const WALLET_MIN_PEERS_MODAL: &'static str = "wallet_sync_min_peers";
+/// Identifier for maximal last block age to sync wallets [`Modal`].
+pub const WALLET_MAX_TIP_AGE_MODAL: &'static str = "wallet_sync_max_tip_age";
 
 impl Default for NodeSetup {
     fn default() -> Self {
         let (api_ip, api_port) = NodeConfig::get_api_ip_port();
         let is_api_port_available = NodeConfig::is_api_port_available(&api_ip, &api_port);
         Self {
-            available_ips: NodeConfig::get_ip_addrs(),
+            available_ips: NodeConfig::get_ip_interfaces(),
+            manual_ip_edit: None,
             api_port_edit: api_port,
             api_port_available_edit: is_api_port_available,
             is_api_port_available,
             secret_edit: "".to_string(),
             ftl_edit: NodeConfig::get_ftl(),
+            wallet_sync_edit: "".to_string(),
             modal_ids: vec![
                 API_PORT_MODAL,
                 API_SECRET_MODAL,
                 FOREIGN_API_SECRET_MODAL,
-                FTL_MODAL
+                FTL_MODAL,
+                WALLET_MIN_PEERS_MODAL,
+                WALLET_MAX_TIP_AGE_MODAL
             ]
         }
     }
@@ -92,6 +106,8 @@ impl ModalContainer for NodeSetup {
             API_SECRET_MODAL => self.secret_modal(ui, modal, cb),
             FOREIGN_API_SECRET_MODAL => self.secret_modal(ui, modal, cb),
             FTL_MODAL => self.ftl_modal(ui, modal, cb),
+            WALLET_MIN_PEERS_MODAL => self.wallet_sync_modal(ui, modal, cb),
+            WALLET_MAX_TIP_AGE_MODAL => self.wallet_sync_modal(ui, modal, cb),
             _ => {}
         }
     }
@@ -179,7 +195,14 @@ impl NodeSetup {
 
                 // Show API IP addresses to select.
                 let (api_ip, api_port) = NodeConfig::get_api_ip_port();
-                NetworkSettings::ip_addrs_ui(ui, &api_ip, &self.available_ips, |selected_ip| {
+                let ip_id = Id::from("node_api_ip");
+                NetworkSettings::ip_addrs_ui(ui,
+                                             ip_id,
+                                             &api_ip,
+                                             &self.available_ips,
+                                             &mut self.manual_ip_edit,
+                                             cb,
+                                             |selected_ip| {
                     let api_available = NodeConfig::is_api_port_available(selected_ip, &api_port);
                     self.is_api_port_available = api_available;
                     NodeConfig::save_api_address(selected_ip, &api_port);
@@ -216,6 +239,20 @@ impl NodeSetup {
 
             // Archive mode setup.
             self.archive_mode_ui(ui);
+
+            ui.add_space(6.0);
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Chain data maintenance.
+            Self::chain_data_ui(ui);
+
+            ui.add_space(6.0);
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Wallets sync threshold setup.
+            self.wallet_sync_ui(ui, cb);
         });
     }
 
@@ -550,4 +587,173 @@ impl NodeSetup {
             .color(Colors::inactive_text())
         );
     }
+
+    /// Draw chain data size and compaction content.
+    fn chain_data_ui(ui: &mut egui::Ui) {
+        ui.label(RichText::new(t!("network_settings.chain_data"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+        if let Some((total, txhashset)) = Node::data_size() {
+            let size_text = t!("network_settings.chain_data_size",
+                "total" => format_size(total),
+                "txhashset" => format_size(txhashset));
+            ui.label(RichText::new(size_text)
+                .size(16.0)
+                .color(Colors::text(false))
+            );
+            ui.add_space(6.0);
+        }
+        ui.label(RichText::new(t!("network_settings.compaction_horizon",
+                                  "blocks" => global::cut_through_horizon()))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(8.0);
+
+        if Node::is_compacting() {
+            View::small_loading_spinner(ui);
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("network_settings.compacting"))
+                .size(16.0)
+                .color(Colors::inactive_text())
+            );
+            ui.ctx().request_repaint_after(Node::STATS_UPDATE_DELAY);
+        } else if NodeConfig::is_archive_mode() {
+            ui.label(RichText::new(t!("network_settings.compaction_archive"))
+                .size(16.0)
+                .color(Colors::inactive_text())
+            );
+        } else if Node::not_syncing() && Node::is_running() {
+            let compact_text = format!("{} {}", ARCHIVE_BOX, t!("network_settings.compact"));
+            View::button(ui, compact_text, Colors::white_or_black(false), || {
+                Node::compact();
+            });
+            if Node::compaction_failed() {
+                ui.add_space(6.0);
+                ui.label(RichText::new(t!("network_settings.compaction_error"))
+                    .size(16.0)
+                    .color(Colors::red())
+                );
+            }
+        }
+        ui.add_space(6.0);
+    }
+
+    /// Draw integrated node health threshold setup to sync wallets.
+    fn wallet_sync_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.label(RichText::new(t!("network_settings.wallet_sync_min_peers"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+        let min_peers = AppConfig::wallet_sync_min_peers().to_string();
+        View::button(ui,
+                     format!("{} {}", USERS_THREE, &min_peers),
+                     Colors::white_or_black(false), || {
+            self.wallet_sync_edit = min_peers;
+            Modal::new(WALLET_MIN_PEERS_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+            cb.show_keyboard();
+        });
+        ui.add_space(12.0);
+
+        ui.label(RichText::new(t!("network_settings.wallet_sync_max_tip_age"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+        let max_age = AppConfig::wallet_sync_max_tip_age().to_string();
+        View::button(ui,
+                     format!("{} {}", CLOCK_COUNTDOWN, &max_age),
+                     Colors::white_or_black(false), || {
+            self.wallet_sync_edit = max_age;
+            Modal::new(WALLET_MAX_TIP_AGE_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+            cb.show_keyboard();
+        });
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("network_settings.wallet_sync_description"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+    }
+
+    /// Draw integrated node health threshold value [`Modal`] content.
+    fn wallet_sync_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            let label = match modal.id {
+                WALLET_MIN_PEERS_MODAL => t!("network_settings.wallet_sync_min_peers"),
+                _ => t!("network_settings.wallet_sync_max_tip_age")
+            };
+            ui.label(RichText::new(label)
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw value text edit.
+            let mut edit_opts = TextEditOptions::new(Id::from(modal.id)).h_center();
+            View::text_edit(ui, cb, &mut self.wallet_sync_edit, &mut edit_opts);
+
+            // Show error when specified value is not valid.
+            if self.wallet_sync_edit.parse::<u32>().is_err() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Save button callback.
+            let on_save = || {
+                if let Ok(value) = self.wallet_sync_edit.parse::<u32>() {
+                    match modal.id {
+                        WALLET_MIN_PEERS_MODAL => AppConfig::save_wallet_sync_min_peers(value),
+                        _ => AppConfig::save_wallet_sync_max_tip_age(value as u64)
+                    }
+                    cb.hide_keyboard();
+                    modal.close();
+                }
+            };
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
+
+/// Format size in bytes to human-readable value.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
 }
\ No newline at end of file
@@ -12,16 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+use std::path::Path;
 use egui::{Id, RichText};
 use grin_chain::SyncStatus;
 
 use crate::gui::Colors;
-use crate::gui::icons::{BARBELL, HARD_DRIVES, PLUG, POWER, TIMER};
+use crate::gui::icons::{BARBELL, CERTIFICATE, GAUGE, HARD_DRIVES, PLUG, POWER, SHIELD_CHECK, TIMER};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::network::settings::NetworkSettings;
 use crate::gui::views::types::{ModalContainer, ModalPosition, TextEditOptions};
 use crate::gui::views::wallets::modals::WalletsModal;
+use crate::AppConfig;
 use crate::node::{Node, NodeConfig};
 use crate::wallet::{WalletConfig, WalletList};
 
@@ -32,8 +35,10 @@ pub struct StratumSetup {
     /// Wallets [`Modal`] content.
     wallets_modal: WalletsModal,
 
-    /// IP Addresses available at system.
-    available_ips: Vec<String>,
+    /// IP Addresses with interface names available at system.
+    available_ips: Vec<(String, String)>,
+    /// Manually entered IP address.
+    manual_ip_edit: Option<String>,
 
     /// Stratum port value.
     stratum_port_edit: String,
@@ -52,6 +57,19 @@ pub struct StratumSetup {
     /// Minimum share difficulty value to request from miners.
     min_share_diff_edit: String,
 
+    /// Minimal worker share difficulty value for vardiff.
+    vardiff_min_edit: String,
+    /// Maximal worker share difficulty value for vardiff.
+    vardiff_max_edit: String,
+
+    /// IP addresses allowed to connect separated by comma.
+    allowlist_edit: String,
+
+    /// Path to TLS certificate file.
+    tls_cert_edit: String,
+    /// Path to TLS private key file.
+    tls_key_edit: String,
+
     /// [`Modal`] identifiers allowed at this ui container.
     modal_ids: Vec<&'static str>
 }
@@ -64,6 +82,15 @@ const STRATUM_PORT_MODAL: &'static str = "stratum_port";
 const ATTEMPT_TIME_MODAL: &'static str = "stratum_attempt_time";
 /// Identifier for minimum share difficulty [`Modal`].
 const MIN_SHARE_DIFF_MODAL: &'static str = "stratum_min_share_diff";
+/// Identifier for worker share difficulty bounds [`Modal`].
+const VARDIFF_MODAL: &'static str = "stratum_vardiff";
+/// Identifier for allowed IP addresses [`Modal`].
+const ALLOWLIST_MODAL: &'static str = "stratum_allowlist";
+/// Identifier for TLS certificate [`Modal`].
+const TLS_MODAL: &'static str = "stratum_tls";
+
+/// Default worker share difficulty bounds for vardiff.
+const DEFAULT_VARDIFF: (u64, u64) = (1, 256);
 
 impl Default for StratumSetup {
     fn default() -> Self {
@@ -84,18 +111,27 @@ impl Default for StratumSetup {
         Self {
             wallets: WalletList::default(),
             wallets_modal: WalletsModal::new(wallet_id, None, false),
-            available_ips: NodeConfig::get_ip_addrs(),
+            available_ips: NodeConfig::get_ip_interfaces(),
+            manual_ip_edit: None,
             stratum_port_edit: port,
             stratum_port_available_edit: is_port_available,
             is_port_available,
             wallet_name,
             attempt_time_edit: NodeConfig::get_stratum_attempt_time(),
             min_share_diff_edit: NodeConfig::get_stratum_min_share_diff(),
+            vardiff_min_edit: "".to_string(),
+            vardiff_max_edit: "".to_string(),
+            allowlist_edit: "".to_string(),
+            tls_cert_edit: "".to_string(),
+            tls_key_edit: "".to_string(),
             modal_ids: vec![
                 WALLET_SELECTION_MODAL,
                 STRATUM_PORT_MODAL,
                 ATTEMPT_TIME_MODAL,
-                MIN_SHARE_DIFF_MODAL
+                MIN_SHARE_DIFF_MODAL,
+                VARDIFF_MODAL,
+                ALLOWLIST_MODAL,
+                TLS_MODAL
             ]
         }
     }
@@ -121,6 +157,9 @@ impl ModalContainer for StratumSetup {
             STRATUM_PORT_MODAL => self.port_modal(ui, modal, cb),
             ATTEMPT_TIME_MODAL => self.attempt_modal(ui, modal, cb),
             MIN_SHARE_DIFF_MODAL => self.min_diff_modal(ui, modal, cb),
+            VARDIFF_MODAL => self.vardiff_modal(ui, modal, cb),
+            ALLOWLIST_MODAL => self.allowlist_modal(ui, modal, cb),
+            TLS_MODAL => self.tls_modal(ui, modal, cb),
             _ => {}
         }
     }
@@ -221,10 +260,16 @@ impl StratumSetup {
             ui.add_space(6.0);
             // Show stratum IP addresses to select.
             let (ip, port) = NodeConfig::get_stratum_address();
-            NetworkSettings::ip_addrs_ui(ui, &ip, &self.available_ips, |selected_ip| {
+            let ip_id = Id::from("stratum_ip");
+            NetworkSettings::ip_addrs_ui(ui,
+                                         ip_id,
+                                         &ip,
+                                         &self.available_ips,
+                                         &mut self.manual_ip_edit,
+                                         cb,
+                                         |selected_ip| {
                 NodeConfig::save_stratum_address(selected_ip, &port);
                 self.is_port_available = NodeConfig::is_stratum_port_available(selected_ip, &port);
-
             });
             // Show stratum port setup.
             self.port_setup_ui(ui, cb);
@@ -240,6 +285,24 @@ impl StratumSetup {
 
             // Show minimum acceptable share difficulty setup.
             self.min_diff_ui(ui, cb);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show variable share difficulty setup.
+            self.vardiff_ui(ui, cb);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show allowed IP addresses setup.
+            self.allowlist_ui(ui, cb);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show TLS certificate setup.
+            self.tls_ui(ui, cb);
         });
     }
 
@@ -512,6 +575,333 @@ impl StratumSetup {
             ui.add_space(6.0);
         });
     }
+
+    /// Draw variable share difficulty setup content.
+    fn vardiff_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        let vardiff = AppConfig::stratum_vardiff();
+        View::checkbox(ui, vardiff.is_some(), t!("network_settings.vardiff"), || {
+            let value = if vardiff.is_some() {
+                None
+            } else {
+                Some(DEFAULT_VARDIFF)
+            };
+            AppConfig::set_stratum_vardiff(value);
+        });
+        ui.add_space(4.0);
+        ui.label(RichText::new(t!("network_settings.vardiff_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+
+        // Show difficulty bounds setup.
+        if let Some((min, max)) = vardiff {
+            ui.label(RichText::new(t!("network_settings.vardiff_bounds"))
+                .size(16.0)
+                .color(Colors::gray())
+            );
+            ui.add_space(6.0);
+            let bounds_text = format!("{} {} - {}", GAUGE, min, max);
+            View::button(ui, bounds_text, Colors::white_or_black(false), || {
+                // Setup values for modal.
+                self.vardiff_min_edit = min.to_string();
+                self.vardiff_max_edit = max.to_string();
+
+                // Show difficulty bounds setup modal.
+                Modal::new(VARDIFF_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("network_settings.change_value"))
+                    .show();
+                cb.show_keyboard();
+            });
+            ui.add_space(6.0);
+        }
+    }
+
+    /// Get entered worker share difficulty bounds, `None` if values are not valid.
+    fn vardiff_bounds(&self) -> Option<(u64, u64)> {
+        let min = self.vardiff_min_edit.parse::<u64>().ok()?;
+        let max = self.vardiff_max_edit.parse::<u64>().ok()?;
+        if min == 0 || max < min {
+            return None;
+        }
+        Some((min, max))
+    }
+
+    /// Draw worker share difficulty bounds [`Modal`] content.
+    fn vardiff_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.vardiff_min"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut min_edit_opts = TextEditOptions::new(Id::from(modal.id)).h_center();
+            View::text_edit(ui, cb, &mut self.vardiff_min_edit, &mut min_edit_opts);
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("network_settings.vardiff_max"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut max_edit_opts = TextEditOptions::new(Id::from(modal.id).with("max"))
+                .h_center()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.vardiff_max_edit, &mut max_edit_opts);
+
+            // Show error when specified values are not valid or reminder to restart enabled node.
+            if self.vardiff_bounds().is_none() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Save button callback.
+            let on_save = || {
+                if let Some(bounds) = self.vardiff_bounds() {
+                    AppConfig::set_stratum_vardiff(Some(bounds));
+                    cb.hide_keyboard();
+                    modal.close();
+                }
+            };
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw allowed IP addresses setup content.
+    fn allowlist_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.label(RichText::new(t!("network_settings.stratum_allowlist"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+        let allowlist = AppConfig::stratum_allowlist();
+        let list_text = if allowlist.is_empty() {
+            t!("network_settings.stratum_allow_all")
+        } else {
+            allowlist.join(", ")
+        };
+        View::button(ui,
+                     format!("{} {}", SHIELD_CHECK, list_text),
+                     Colors::white_or_black(false), || {
+            // Setup value for modal.
+            self.allowlist_edit = allowlist.join(", ");
+
+            // Show allowed IP addresses setup modal.
+            Modal::new(ALLOWLIST_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+            cb.show_keyboard();
+        });
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("network_settings.stratum_allowlist_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+    }
+
+    /// Get entered allowed IP addresses, `None` if values are not valid.
+    fn allowlist_addrs(&self) -> Option<Vec<String>> {
+        self.allowlist_edit.split(',')
+            .map(|a| a.trim())
+            .filter(|a| !a.is_empty())
+            .map(|a| a.parse::<IpAddr>().ok().map(|ip| ip.to_string()))
+            .collect()
+    }
+
+    /// Draw allowed IP addresses [`Modal`] content.
+    fn allowlist_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.stratum_allowlist_enter"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut allowlist_edit_opts = TextEditOptions::new(Id::from(modal.id)).paste();
+            View::text_edit(ui, cb, &mut self.allowlist_edit, &mut allowlist_edit_opts);
+
+            // Show error when specified values are not valid or reminder to restart enabled node.
+            if self.allowlist_addrs().is_none() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Save button callback.
+            let on_save = || {
+                if let Some(addrs) = self.allowlist_addrs() {
+                    AppConfig::set_stratum_allowlist(addrs);
+                    cb.hide_keyboard();
+                    modal.close();
+                }
+            };
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw TLS certificate setup content.
+    fn tls_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        let tls = AppConfig::stratum_tls();
+        View::checkbox(ui, tls.is_some(), t!("network_settings.stratum_tls"), || {
+            if tls.is_some() {
+                AppConfig::set_stratum_tls(None);
+            } else {
+                self.show_tls_modal(None, cb);
+            }
+        });
+        ui.add_space(4.0);
+        ui.label(RichText::new(t!("network_settings.stratum_tls_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+
+        // Show certificate file setup.
+        if let Some((cert, key)) = tls {
+            let name = Path::new(&cert)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or(cert.clone());
+            let cert_text = format!("{} {}", CERTIFICATE, name);
+            View::button(ui, cert_text, Colors::white_or_black(false), || {
+                self.show_tls_modal(Some((cert, key)), cb);
+            });
+            ui.add_space(6.0);
+        }
+    }
+
+    /// Show TLS certificate setup [`Modal`].
+    fn show_tls_modal(&mut self, files: Option<(String, String)>, cb: &dyn PlatformCallbacks) {
+        let (cert, key) = files.unwrap_or_default();
+        self.tls_cert_edit = cert;
+        self.tls_key_edit = key;
+        Modal::new(TLS_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("network_settings.stratum_tls"))
+            .show();
+        cb.show_keyboard();
+    }
+
+    /// Get entered TLS certificate and key file paths, `None` if files do not exist.
+    fn tls_files(&self) -> Option<(String, String)> {
+        let cert = self.tls_cert_edit.trim();
+        let key = self.tls_key_edit.trim();
+        if !Path::new(cert).is_file() || !Path::new(key).is_file() {
+            return None;
+        }
+        Some((cert.to_string(), key.to_string()))
+    }
+
+    /// Draw TLS certificate [`Modal`] content.
+    fn tls_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.stratum_tls_cert"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut cert_edit_opts = TextEditOptions::new(Id::from(modal.id)).paste();
+            View::text_edit(ui, cb, &mut self.tls_cert_edit, &mut cert_edit_opts);
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("network_settings.stratum_tls_key"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut key_edit_opts = TextEditOptions::new(Id::from(modal.id).with("key"))
+                .paste()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.tls_key_edit, &mut key_edit_opts);
+
+            // Show error when specified files not exist or reminder to restart enabled node.
+            if self.tls_files().is_none() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Save button callback.
+            let on_save = || {
+                if let Some(files) = self.tls_files() {
+                    AppConfig::set_stratum_tls(Some(files));
+                    cb.hide_keyboard();
+                    modal.close();
+                }
+            };
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
 }
 
 /// Reminder to restart enabled node to show on edit setting at [`Modal`].
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::gui::platform::PlatformCallbacks;
 
 /// Integrated node tab content interface.
@@ -21,11 +23,13 @@ pub trait NodeTab {
 }
 
 /// Type of [`NodeTab`] content.
-#[derive(PartialEq)]
+#[derive(PartialEq, Serialize, Deserialize)]
 pub enum NodeTabType {
     Info,
     Metrics,
     Mining,
+    Console,
+    Logs,
     Settings
 }
 
@@ -35,6 +39,8 @@ impl NodeTabType {
             NodeTabType::Info => { t!("network.node") }
             NodeTabType::Metrics => { t!("network.metrics") }
             NodeTabType::Mining => { t!("network.mining") }
+            NodeTabType::Console => { t!("network.console") }
+            NodeTabType::Logs => { t!("network.logs") }
             NodeTabType::Settings => { t!("network.settings") }
         }
     }
@@ -0,0 +1,262 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Align, Id, Layout, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::icons::{CHECK_CIRCLE, COPY, WARNING_CIRCLE, X_CIRCLE};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::node::{Node, NodeConfig};
+use crate::tor::Tor;
+use crate::wallet::WalletList;
+
+/// Port bound or configured to bind by the application.
+struct PortItem {
+    /// Service name.
+    name: String,
+    /// Host address.
+    host: String,
+    /// Port number.
+    port: String,
+    /// Flag to check if port is bound.
+    active: bool,
+}
+
+/// Overview of network ports used by the application with wallets API ports range setup.
+pub struct PortsContent {
+    /// First port of wallets API range value.
+    range_start_edit: String,
+    /// Last port of wallets API range value.
+    range_end_edit: String,
+}
+
+impl Default for PortsContent {
+    fn default() -> Self {
+        Self {
+            range_start_edit: "".to_string(),
+            range_end_edit: "".to_string(),
+        }
+    }
+}
+
+impl PortsContent {
+    /// Identifier for ports overview [`Modal`].
+    pub const MODAL_ID: &'static str = "ports_overview_modal";
+
+    /// Show ports overview [`Modal`].
+    pub fn show(&mut self) {
+        let range = AppConfig::wallet_api_ports();
+        self.range_start_edit = range.map(|r| r.0.to_string()).unwrap_or_default();
+        self.range_end_edit = range.map(|r| r.1.to_string()).unwrap_or_default();
+        Modal::new(Self::MODAL_ID)
+            .position(ModalPosition::CenterTop)
+            .title(t!("network_ports"))
+            .show();
+    }
+
+    /// Draw ports overview [`Modal`] content.
+    pub fn modal_ui(&mut self,
+                    ui: &mut egui::Ui,
+                    modal: &Modal,
+                    wallets: &WalletList,
+                    cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_ports_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(8.0);
+
+        // Show list of ports.
+        let items = Self::items(wallets);
+        ScrollArea::vertical()
+            .id_salt("ports_overview_scroll")
+            .max_height(View::window_size(ui.ctx()).1 * 0.4)
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for (index, item) in items.iter().enumerate() {
+                    let conflict = items.iter().filter(|i| i.port == item.port).count() > 1;
+                    Self::item_ui(ui, item, conflict, index, items.len(), cb);
+                }
+            });
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show wallets API ports range setup.
+        self.range_ui(ui, modal, cb);
+        ui.add_space(8.0);
+
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                cb.hide_keyboard();
+                modal.close();
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Get ports used by integrated node and wallets.
+    fn items(wallets: &WalletList) -> Vec<PortItem> {
+        let node_running = Node::is_running();
+        let (api_host, api_port) = NodeConfig::get_api_ip_port();
+        let (stratum_host, stratum_port) = NodeConfig::get_stratum_address();
+        let mut items = vec![
+            PortItem {
+                name: t!("network_settings.api_server"),
+                host: api_host,
+                port: api_port,
+                active: node_running,
+            },
+            PortItem {
+                name: t!("network_settings.p2p_server"),
+                host: "0.0.0.0".to_string(),
+                port: NodeConfig::get_p2p_port(),
+                active: node_running,
+            },
+            PortItem {
+                name: t!("network_mining.server"),
+                host: stratum_host,
+                port: stratum_port,
+                active: Node::get_stratum_stats().is_running,
+            },
+        ];
+        for wallet in wallets.list() {
+            let config = wallet.get_config();
+            let port = match wallet.foreign_api_port().or(config.api_port) {
+                Some(p) => p,
+                None => continue
+            };
+            let name = if Tor::is_service_running(&wallet.identifier()) {
+                format!("{} (Tor)", config.name)
+            } else {
+                config.name
+            };
+            items.push(PortItem {
+                name,
+                host: "127.0.0.1".to_string(),
+                port: port.to_string(),
+                active: wallet.foreign_api_port().is_some(),
+            });
+        }
+        items
+    }
+
+    /// Draw port item content.
+    fn item_ui(ui: &mut egui::Ui,
+               item: &PortItem,
+               conflict: bool,
+               index: usize,
+               len: usize,
+               cb: &dyn PlatformCallbacks) {
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(52.0);
+
+        // Draw round background.
+        let item_rounding = View::item_rounding(index, len, false);
+        ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke());
+
+        let address = format!("{}:{}", item.host, item.port);
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            // Draw button to copy address.
+            View::item_button(ui, View::item_rounding(index, len, true), COPY, None, || {
+                cb.copy_string_to_buffer(address.clone());
+            });
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(6.0);
+                ui.vertical(|ui| {
+                    ui.add_space(4.0);
+                    View::ellipsize_text(ui, item.name.clone(), 17.0, Colors::title(false));
+                    let (status_text, color) = if conflict {
+                        (format!("{} {}  {}", WARNING_CIRCLE, address, t!("port_conflict")),
+                         Colors::red())
+                    } else if item.active {
+                        (format!("{} {}", CHECK_CIRCLE, address), Colors::green())
+                    } else {
+                        (format!("{} {}", X_CIRCLE, address), Colors::gray())
+                    };
+                    View::ellipsize_text(ui, status_text, 15.0, color);
+                    ui.add_space(3.0);
+                });
+            });
+        });
+    }
+
+    /// Draw wallets API ports range setup.
+    fn range_ui(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallet_api_ports"))
+                .size(16.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+
+        let start_before = self.range_start_edit.clone();
+        let end_before = self.range_end_edit.clone();
+        ui.scope(|ui| {
+            // Setup spacing between inputs.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    let mut start_opts = TextEditOptions::new(Id::from(modal.id).with("start"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.range_start_edit, &mut start_opts);
+                });
+                columns[1].vertical_centered(|ui| {
+                    let mut end_opts = TextEditOptions::new(Id::from(modal.id).with("end"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.range_end_edit, &mut end_opts);
+                });
+            });
+        });
+
+        // Save range on change.
+        let start = self.range_start_edit.trim();
+        let end = self.range_end_edit.trim();
+        let range = match (start.parse::<u16>(), end.parse::<u16>()) {
+            (Ok(s), Ok(e)) if s > 0 && s <= e => Some(Some((s, e))),
+            _ if start.is_empty() && end.is_empty() => Some(None),
+            _ => None
+        };
+        if start_before != self.range_start_edit || end_before != self.range_end_edit {
+            if let Some(range) = range {
+                AppConfig::set_wallet_api_ports(range);
+            }
+        }
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            if range.is_none() {
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            } else {
+                ui.label(RichText::new(t!("wallet_api_ports_desc"))
+                    .size(15.0)
+                    .color(Colors::inactive_text()));
+            }
+        });
+    }
+}
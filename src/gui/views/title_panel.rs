@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{Margin, Id, Layout, Align, UiBuilder};
+use egui::{Margin, Id, Layout, Align, UiBuilder, Align2, FontId, Rounding, Color32};
+use grin_core::global::ChainTypes;
 
+use crate::AppConfig;
 use crate::gui::Colors;
 use crate::gui::views::{Content, View};
 use crate::gui::views::types::{LinePosition, TitleContentType, TitleType};
@@ -27,6 +29,8 @@ pub struct TitlePanel {
 impl TitlePanel {
     /// Content height.
     pub const HEIGHT: f32 = 54.0;
+    /// Height of banner to show when Testnet is used.
+    const TESTNET_BANNER_HEIGHT: f32 = 18.0;
 
     /// Create new title panel with provided identifier.
     pub fn new(id: Id) -> Self {
@@ -40,10 +44,18 @@ impl TitlePanel {
               mut left_content: impl FnMut(&mut egui::Ui),
               mut right_content: impl FnMut(&mut egui::Ui),
               ui: &mut egui::Ui) {
+        // Show banner under title content when Testnet is used.
+        let testnet = AppConfig::chain_type() == ChainTypes::Testnet;
+        let banner_height = if testnet {
+            Self::TESTNET_BANNER_HEIGHT
+        } else {
+            0.0
+        };
+
         // Draw title panel.
         egui::TopBottomPanel::top(self.id)
             .resizable(false)
-            .exact_height(Self::HEIGHT + View::get_top_inset())
+            .exact_height(Self::HEIGHT + View::get_top_inset() + banner_height)
             .frame(egui::Frame {
                 inner_margin:  Margin {
                     left: View::far_left_inset_margin(ui),
@@ -54,7 +66,11 @@ impl TitlePanel {
                 ..Default::default()
             })
             .show_inside(ui, |ui| {
-                let rect = ui.available_rect_before_wrap();
+                let rect = {
+                    let mut r = ui.available_rect_before_wrap();
+                    r.max.y -= banner_height;
+                    r
+                };
                 ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Max), |ui| {
                     ui.horizontal_centered(|ui| {
                         (right_content)(ui);
@@ -109,9 +125,25 @@ impl TitlePanel {
                     r.max.x += View::far_right_inset_margin(ui);
                     r
                 };
-                if Content::is_dual_panel_mode(ui.ctx()) {
+                if Content::is_dual_panel_mode(ui.ctx()) && !testnet {
                     View::line(ui, LinePosition::BOTTOM, &r, Colors::stroke());
                 }
+
+                // Draw Testnet banner.
+                if testnet {
+                    let banner_rect = {
+                        let mut b = r.clone();
+                        b.min.y = r.max.y;
+                        b.max.y = r.max.y + banner_height;
+                        b
+                    };
+                    ui.painter().rect_filled(banner_rect, Rounding::ZERO, Colors::blue());
+                    ui.painter().text(banner_rect.center(),
+                                      Align2::CENTER_CENTER,
+                                      t!("network.testnet_banner").to_uppercase(),
+                                      FontId::proportional(13.0),
+                                      Color32::WHITE);
+                }
             });
     }
 
@@ -44,11 +44,20 @@ pub enum ModalPosition {
     Center
 }
 
-/// Global [`Modal`] state.
+/// [`Modal`] state of viewport.
 #[derive(Default)]
 pub struct ModalState {
     /// Opened [`Modal`].
     pub modal: Option<Modal>,
+    /// Widgets of opened [`Modal`] to navigate with keyboard with flag for text input,
+    /// collected while drawing the content.
+    pub focus_widgets: Vec<(egui::Id, bool)>,
+    /// Widgets of opened [`Modal`] collected at previous frame.
+    pub last_focus_widgets: Vec<(egui::Id, bool)>,
+    /// Requested keyboard focus direction, `true` to move forward.
+    pub focus_direction: Option<bool>,
+    /// Flag to check if focus was moved by keyboard.
+    pub keyboard_focus: bool,
 }
 
 /// Contains identifiers to draw opened [`Modal`] content for current ui container.
@@ -94,6 +103,12 @@ pub struct TextEditOptions {
     pub scan_qr: bool,
     /// Callback when scan button was pressed.
     pub scan_pressed: bool,
+    /// Keypad layout to use instead of soft keyboard on Android.
+    pub keypad: Option<KeypadLayout>,
+    /// Show button to enter maximum value at numeric keypad.
+    pub keypad_max: bool,
+    /// Callback when maximum value button was pressed.
+    pub max_pressed: bool,
 }
 
 impl TextEditOptions {
@@ -107,6 +122,9 @@ impl TextEditOptions {
             paste: false,
             scan_qr: false,
             scan_pressed: false,
+            keypad: None,
+            keypad_max: false,
+            max_pressed: false,
         }
     }
 
@@ -146,6 +164,29 @@ impl TextEditOptions {
         self.scan_pressed = false;
         self
     }
+
+    /// Use numeric keypad with optional maximum value button to enter amount on Android.
+    pub fn keypad(mut self, max: bool) -> Self {
+        self.keypad = Some(KeypadLayout::Amount);
+        self.keypad_max = max;
+        self.max_pressed = false;
+        self
+    }
+
+    /// Use keypad with Slatepack address characters to enter address on Android.
+    pub fn address_keypad(mut self) -> Self {
+        self.keypad = Some(KeypadLayout::Address);
+        self
+    }
+}
+
+/// Layout of keypad shown instead of soft keyboard at [`egui::TextEdit`] view.
+#[derive(Clone, Copy, PartialEq)]
+pub enum KeypadLayout {
+    /// Digits with decimal separator to enter amount.
+    Amount,
+    /// Lowercase bech32 characters to enter Slatepack address.
+    Address,
 }
 
 /// QR code scan result.
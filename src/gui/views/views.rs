@@ -25,12 +25,14 @@ use egui::os::OperatingSystem;
 use egui::text::{LayoutJob, TextFormat};
 use egui::text_edit::TextEditState;
 use egui_extras::image::load_svg_bytes_with_size;
+use grin_core::global::ChainTypes;
 
 use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{CHECK_SQUARE, CLIPBOARD_TEXT, COPY, EYE, EYE_SLASH, SCAN, SQUARE};
+use crate::gui::icons::{BACKSPACE, CHECK_SQUARE, CLIPBOARD_TEXT, COPY, EYE, EYE_SLASH, SCAN, SQUARE};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::types::{LinePosition, TextEditOptions};
+use crate::gui::views::Modal;
+use crate::gui::views::types::{KeypadLayout, LinePosition, TextEditOptions};
 
 pub struct View;
 
@@ -93,6 +95,10 @@ impl View {
 
     /// Callback on Enter key press event.
     pub fn on_enter_key(ui: &mut egui::Ui, cb: impl FnOnce()) {
+        // Focused modal button is activated by Enter key instead of default action.
+        if Modal::button_focused(ui.ctx()) {
+            return;
+        }
         if ui.ctx().input(|i| i.key_pressed(egui::Key::Enter)) {
             (cb)();
         }
@@ -121,7 +127,7 @@ impl View {
     }
 
     /// Cut long text with ﹍ character.
-    fn ellipsize(text: String, size: f32, color: Color32) -> LayoutJob {
+    pub fn ellipsize(text: String, size: f32, color: Color32) -> LayoutJob {
         let mut job = LayoutJob::single_section(text, TextFormat {
             font_id: FontId::proportional(size), color, ..Default::default()
         });
@@ -258,11 +264,13 @@ impl View {
     /// Draw [`Button`] with specified background fill and text color.
     fn button_resp(ui: &mut egui::Ui, text: String, text_color: Color32, bg: Color32) -> Response {
         let button_text = Self::ellipsize(text.to_uppercase(), 17.0, text_color);
-        Button::new(button_text)
+        let br = Button::new(button_text)
             .stroke(Self::default_stroke())
             .fill(bg)
             .ui(ui)
-            .on_hover_cursor(CursorIcon::PointingHand)
+            .on_hover_cursor(CursorIcon::PointingHand);
+        Modal::add_focusable(ui, br.id, false);
+        br
     }
 
     /// Draw [`Button`] with specified background fill color and default text color.
@@ -314,6 +322,7 @@ impl View {
             .fill(fill)
             .ui(ui)
             .on_hover_cursor(CursorIcon::PointingHand);
+        Modal::add_focusable(ui, br.id, false);
         if Self::touched(ui, br) {
             (action)(ui);
         }
@@ -378,6 +387,10 @@ impl View {
                      cb: &dyn PlatformCallbacks,
                      value: &mut String,
                      options: &mut TextEditOptions) {
+        // Use keypad instead of soft keyboard on Android.
+        let keypad = options.keypad
+            .filter(|_| OperatingSystem::from_target_os() == OperatingSystem::Android);
+        let use_keypad = keypad.is_some();
         let mut layout_rect = ui.available_rect_before_wrap();
         layout_rect.set_height(Self::TEXT_EDIT_HEIGHT);
         ui.allocate_ui_with_layout(layout_rect.size(), Layout::right_to_left(Align::Center), |ui| {
@@ -449,14 +462,25 @@ impl View {
                     .vertical_align(Align::Center)
                     .password(show_pass)
                     .cursor_at_end(true)
+                    .interactive(!use_keypad)
                     .ui(ui);
+                if use_keypad {
+                    // Select field to show its keypad on click or initial focus.
+                    if options.focus || text_edit_resp.interact(Sense::click()).clicked() {
+                        ui.data_mut(|data| {
+                            data.insert_temp(egui::Id::new(Self::KEYPAD_FOCUS_ID), options.id);
+                        });
+                    }
+                    return;
+                }
+                Modal::add_focusable(ui, options.id, true);
                 // Show keyboard on click.
                 if text_edit_resp.clicked() {
                     text_edit_resp.request_focus();
                     cb.show_keyboard();
                 }
-                // Setup focus on input field.
-                if options.focus {
+                // Setup focus on input field if it was not moved by keyboard.
+                if options.focus && !Modal::focus_moved(ui, options.id) {
                     text_edit_resp.request_focus();
                     cb.show_keyboard();
                 }
@@ -467,6 +491,102 @@ impl View {
                 }
             });
         });
+
+        // Show keypad for selected field.
+        if let Some(layout) = keypad {
+            let focused_id = ui.data(|data| {
+                data.get_temp::<egui::Id>(egui::Id::new(Self::KEYPAD_FOCUS_ID))
+            });
+            if focused_id == Some(options.id) {
+                ui.add_space(8.0);
+                match layout {
+                    KeypadLayout::Amount => Self::keypad_ui(ui, value, options),
+                    KeypadLayout::Address => Self::address_keypad_ui(ui, value)
+                }
+            }
+        }
+    }
+
+    /// Identifier to store [`egui::TextEdit`] identifier with shown keypad.
+    const KEYPAD_FOCUS_ID: &'static str = "text_edit_keypad_focus";
+
+    /// Draw numeric keypad to enter amount with optional maximum value button.
+    fn keypad_ui(ui: &mut egui::Ui, value: &mut String, options: &mut TextEditOptions) {
+        let rows = [["1", "2", "3"], ["4", "5", "6"], ["7", "8", "9"], [".", "0", BACKSPACE]];
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            for row in rows {
+                ui.columns(3, |columns| {
+                    for (i, key) in row.iter().enumerate() {
+                        columns[i].vertical_centered_justified(|ui| {
+                            View::button(ui, key.to_string(), Colors::white_or_black(false), || {
+                                if *key == BACKSPACE {
+                                    value.pop();
+                                } else if *key != "." || !value.contains(".") {
+                                    value.push_str(key);
+                                }
+                            });
+                        });
+                    }
+                });
+                ui.add_space(6.0);
+            }
+            if options.keypad_max {
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("max"), Colors::white_or_black(false), || {
+                        options.max_pressed = true;
+                    });
+                });
+                ui.add_space(6.0);
+            }
+        });
+    }
+
+    /// Draw keypad with lowercase bech32 characters to enter Slatepack address.
+    fn address_keypad_ui(ui: &mut egui::Ui, value: &mut String) {
+        let rows: [&[&str]; 4] = [
+            &["1", "2", "3", "4", "5", "6", "7", "8", "9", "0"],
+            &["q", "w", "e", "r", "t", "y", "u", "i", "p"],
+            &["a", "s", "d", "f", "g", "h", "j", "k", "l"],
+            &["z", "x", "c", "v", "n", "m", BACKSPACE],
+        ];
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(4.0, 0.0);
+
+            for row in rows {
+                ui.columns(row.len(), |columns| {
+                    for (i, key) in row.iter().enumerate() {
+                        columns[i].vertical_centered_justified(|ui| {
+                            View::button(ui, key.to_string(), Colors::white_or_black(false), || {
+                                if *key == BACKSPACE {
+                                    value.pop();
+                                } else {
+                                    value.push_str(key);
+                                }
+                            });
+                        });
+                    }
+                });
+                ui.add_space(6.0);
+            }
+
+            // Show button to start address with prefix for current network.
+            let prefix = match AppConfig::chain_type() {
+                ChainTypes::Mainnet => "grin1",
+                _ => "tgrin1"
+            };
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, prefix.to_string(), Colors::white_or_black(false), || {
+                    if !value.starts_with(prefix) {
+                        *value = prefix.to_string();
+                    }
+                });
+            });
+            ui.add_space(6.0);
+        });
     }
 
     /// Apply soft keyboard input data to provided String.
@@ -625,6 +745,7 @@ impl View {
             .fill(Colors::TRANSPARENT)
             .ui(ui)
             .on_hover_cursor(CursorIcon::PointingHand);
+        Modal::add_focusable(ui, br.id, false);
         if Self::touched(ui, br) {
             (callback)();
         }
@@ -639,6 +760,7 @@ impl View {
             // Draw radio button.
             let mut response = ui.radio(*current == value, text)
                 .on_hover_cursor(CursorIcon::PointingHand);
+            Modal::add_focusable(ui, response.id, false);
             if Self::touched(ui, response.clone()) && *current != value {
                 *current = value;
                 response.mark_changed();
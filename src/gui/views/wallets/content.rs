@@ -15,20 +15,22 @@
 use std::time::Duration;
 use egui::{Align, Id, Layout, Margin, RichText, Rounding, ScrollArea};
 use egui::scroll_area::ScrollBarVisibility;
+use egui::epaint::RectShape;
+use grin_core::global::ChainTypes;
 
 use crate::AppConfig;
-use crate::gui::Colors;
-use crate::gui::icons::{ARROW_LEFT, CARET_RIGHT, COMPUTER_TOWER, FOLDER_OPEN, FOLDER_PLUS, GEAR, GLOBE, GLOBE_SIMPLE, LOCK_KEY, PLUS, SIDEBAR_SIMPLE, SUITCASE};
+use crate::gui::{AppRoute, Colors, RouteScreen};
+use crate::gui::icons::{ARROW_LEFT, ARROW_SQUARE_OUT, CARET_RIGHT, COMPUTER_TOWER, DOTS_THREE_CIRCLE, DOWNLOAD_SIMPLE, FOLDER_OPEN, FOLDER_PLUS, GEAR, GLOBE, GLOBE_SIMPLE, LOCK_KEY, MAGNIFYING_GLASS, PLUS, SIDEBAR_SIMPLE, SUITCASE};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, Content, TitlePanel, View};
-use crate::gui::views::types::{ModalContainer, ModalPosition, LinePosition, TitleContentType, TitleType};
+use crate::gui::views::types::{ModalContainer, ModalPosition, LinePosition, TextEditOptions, TitleContentType, TitleType};
 use crate::gui::views::wallets::creation::WalletCreation;
-use crate::gui::views::wallets::modals::{AddWalletModal, OpenWalletModal, WalletConnectionModal, WalletsModal};
-use crate::gui::views::wallets::types::WalletTabType;
+use crate::gui::views::wallets::modals::{AddWalletModal, AutoOpenModal, ImportWalletModal, OpenWalletModal, WalletConnectionModal, WalletsModal};
+use crate::gui::views::wallets::types::{amount_to_display, full_amount_hover, GRIN, WalletTabType};
 use crate::gui::views::wallets::wallet::types::wallet_status_text;
 use crate::gui::views::wallets::WalletContent;
-use crate::wallet::{ExternalConnection, Wallet, WalletList};
-use crate::wallet::types::ConnectionMethod;
+use crate::wallet::{CliWallet, ExternalConnection, Wallet, WalletList};
+use crate::wallet::types::{ConnectionMethod, WalletsBalance};
 
 /// Wallets content.
 pub struct WalletsContent {
@@ -43,6 +45,18 @@ pub struct WalletsContent {
     conn_selection_content: Option<WalletConnectionModal>,
     /// Wallet selection [`Modal`] content.
     wallet_selection_content: Option<WalletsModal>,
+    /// Opening of wallets on launch [`Modal`] content.
+    auto_open_content: Option<AutoOpenModal>,
+    /// Import of command line application wallet [`Modal`] content.
+    import_wallet_content: Option<ImportWalletModal>,
+    /// Wallet of command line application detected for [`ChainTypes`].
+    cli_wallet: Option<(ChainTypes, Option<CliWallet>)>,
+    /// Wallet requested to show at separate window.
+    detach_request: Option<Wallet>,
+    /// Identifiers of wallets shown at separate windows.
+    detached: Vec<i64>,
+    /// Flag to check if wallets to open on launch were checked.
+    auto_open_checked: bool,
 
     /// Selected [`Wallet`] content.
     wallet_content: Option<WalletContent>,
@@ -51,6 +65,8 @@ pub struct WalletsContent {
 
     /// Flag to show [`Wallet`] list at dual panel mode.
     show_wallets_at_dual_panel: bool,
+    /// Text to search wallets by name.
+    search_edit: String,
 
     /// [`Modal`] identifiers allowed at this ui container.
     modal_ids: Vec<&'static str>
@@ -60,6 +76,15 @@ const ADD_WALLET_MODAL: &'static str = "wallets_add_modal";
 const OPEN_WALLET_MODAL: &'static str = "wallets_open_wallet";
 const SELECT_CONNECTION_MODAL: &'static str = "wallets_select_conn_modal";
 const SELECT_WALLET_MODAL: &'static str = "wallets_select_modal";
+const AUTO_OPEN_MODAL: &'static str = "wallets_auto_open_modal";
+const IMPORT_WALLET_MODAL: &'static str = "wallets_import_modal";
+
+/// Height of wallet list item.
+const WALLET_ITEM_HEIGHT: f32 = 78.0;
+/// Space between wallet list items.
+const WALLET_ITEM_SPACE: f32 = 5.0;
+/// Minimal amount of wallets to show search input instead of application logo.
+const MIN_WALLETS_TO_SEARCH: usize = 5;
 
 impl Default for WalletsContent {
     fn default() -> Self {
@@ -68,14 +93,23 @@ impl Default for WalletsContent {
             wallet_selection_content: None,
             open_wallet_content: None,
             conn_selection_content: None,
+            auto_open_content: None,
+            import_wallet_content: None,
+            cli_wallet: None,
+            detach_request: None,
+            detached: vec![],
+            auto_open_checked: false,
             wallet_content: None,
             creation_content: None,
             show_wallets_at_dual_panel: AppConfig::show_wallets_at_dual_panel(),
+            search_edit: "".to_string(),
             modal_ids: vec![
                 ADD_WALLET_MODAL,
                 OPEN_WALLET_MODAL,
                 SELECT_CONNECTION_MODAL,
                 SELECT_WALLET_MODAL,
+                AUTO_OPEN_MODAL,
+                IMPORT_WALLET_MODAL,
             ],
             add_wallet_modal_content: None,
         }
@@ -94,15 +128,22 @@ impl ModalContainer for WalletsContent {
         match modal.id {
             ADD_WALLET_MODAL => {
                 if let Some(content) = self.add_wallet_modal_content.as_mut() {
-                    content.ui(ui, modal, cb, |name, pass| {
+                    content.ui(ui, modal, cb, |name, pass, kdf_rounds| {
                         self.creation_content = Some(
-                            WalletCreation::new(name.clone(), pass.clone())
+                            WalletCreation::new(name.clone(), pass.clone(), kdf_rounds)
                         );
                     });
                 }
-                if self.creation_content.is_some() {
+                let import = self.add_wallet_modal_content
+                    .as_ref()
+                    .map(|c| c.import_requested)
+                    .unwrap_or(false);
+                if self.creation_content.is_some() || import {
                     self.add_wallet_modal_content = None;
                 }
+                if import {
+                    self.show_import_wallet_modal(cb);
+                }
             },
             OPEN_WALLET_MODAL => {
                 let mut open = false;
@@ -137,6 +178,33 @@ impl ModalContainer for WalletsContent {
                     self.wallet_selection_content = None;
                 }
             }
+            AUTO_OPEN_MODAL => {
+                let mut finished = false;
+                if let Some(content) = self.auto_open_content.as_mut() {
+                    content.ui(ui, modal, cb, |opened| {
+                        if let Some(wallet) = opened.first() {
+                            self.wallet_content = Some(WalletContent::new(wallet.clone(), None));
+                        }
+                        finished = true;
+                    });
+                }
+                if finished {
+                    self.auto_open_content = None;
+                }
+            }
+            IMPORT_WALLET_MODAL => {
+                let mut imported = None;
+                if let Some(content) = self.import_wallet_content.as_mut() {
+                    content.ui(ui, modal, cb, |wallet| {
+                        imported = Some(wallet);
+                    });
+                }
+                if let Some(wallet) = imported {
+                    self.import_wallet_content = None;
+                    self.wallets.add(wallet.clone());
+                    self.show_opening_modal(wallet, None, cb);
+                }
+            }
             _ => {}
         }
     }
@@ -145,6 +213,12 @@ impl ModalContainer for WalletsContent {
 impl WalletsContent {
     /// Draw wallets content.
     pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        // Show wallets to open on launch when no other modal is opened.
+        if !self.auto_open_checked && Modal::opened().is_none() {
+            self.auto_open_checked = true;
+            self.show_auto_open_modal(cb);
+        }
+
         self.current_modal_ui(ui, cb);
 
         let creating_wallet = self.creating_wallet();
@@ -261,6 +335,7 @@ impl WalletsContent {
                     creation.ui(ui, cb, |wallet| {
                         self.wallets.add(wallet.clone());
                         if let Ok(_) = wallet.open(pass.clone()) {
+                            cb.start_background_sync();
                             self.wallet_content = Some(WalletContent::new(wallet, None));
                         }
                         created = true;
@@ -269,7 +344,9 @@ impl WalletsContent {
                         self.creation_content = None;
                     }
                 } else if self.wallets.list().is_empty() {
-                    View::center_content(ui, 350.0 + View::get_bottom_inset(), |ui| {
+                    let import_available = self.cli_wallet().is_some();
+                    let height = if import_available { 400.0 } else { 350.0 };
+                    View::center_content(ui, height + View::get_bottom_inset(), |ui| {
                         View::app_logo_name_version(ui);
                         ui.add_space(4.0);
 
@@ -284,6 +361,16 @@ impl WalletsContent {
                         View::button(ui, add_text, Colors::white_or_black(false), || {
                             self.show_add_wallet_modal(cb);
                         });
+                        // Show button to import wallet of command line application.
+                        if import_available {
+                            ui.add_space(8.0);
+                            let import_text = format!("{} {}",
+                                                      DOWNLOAD_SIMPLE,
+                                                      t!("wallets.cli_import"));
+                            View::button(ui, import_text, Colors::white_or_black(false), || {
+                                self.show_import_wallet_modal(cb);
+                            });
+                        }
                     });
                 } else {
                     return;
@@ -301,6 +388,74 @@ impl WalletsContent {
         false
     }
 
+    /// Close opened wallets of previous network and reset wallet content on network change.
+    pub fn on_chain_change(&mut self, prev_chain_type: ChainTypes) {
+        for wallet in self.wallets.chain_list(prev_chain_type) {
+            if wallet.is_open() && !wallet.is_closing() {
+                wallet.close();
+            }
+        }
+        self.wallet_content = None;
+        self.creation_content = None;
+        self.add_wallet_modal_content = None;
+        self.open_wallet_content = None;
+        self.wallet_selection_content = None;
+        self.import_wallet_content = None;
+    }
+
+    /// Take wallet requested to show at separate window.
+    pub fn take_detach_request(&mut self) -> Option<Wallet> {
+        self.detach_request.take()
+    }
+
+    /// Setup identifiers of wallets shown at separate windows, hiding their content.
+    pub fn set_detached(&mut self, ids: Vec<i64>) {
+        if let Some(content) = &self.wallet_content {
+            if ids.contains(&content.wallet.get_config().id) {
+                self.wallet_content = None;
+            }
+        }
+        self.detached = ids;
+    }
+
+    /// Close all opened wallets.
+    pub fn lock_wallets(&mut self) {
+        for wallet in self.wallets.list() {
+            if wallet.is_open() && !wallet.is_closing() {
+                wallet.close();
+            }
+        }
+    }
+
+    /// Get list of wallets.
+    pub fn wallet_list(&self) -> &WalletList {
+        &self.wallets
+    }
+
+    /// Check if any wallet is running tasks which should be finished before exit.
+    pub fn wallet_tasks_running(&self) -> bool {
+        self.wallets.has_active_tasks()
+    }
+
+    /// Check if config of any wallet was changed outside of the application.
+    pub fn configs_changed_externally(&self) -> bool {
+        self.wallets.list().iter().any(|w| w.config_changed_externally())
+    }
+
+    /// Reload wallets configs from files or save current configs on external changes.
+    pub fn resolve_configs_changes(&self, reload: bool) {
+        for wallet in self.wallets.list() {
+            if !wallet.config_changed_externally() {
+                continue;
+            }
+            if reload {
+                wallet.reload_config();
+            } else {
+                wallet.save_config();
+            }
+        }
+    }
+
     /// Check if wallet is creating.
     pub fn creating_wallet(&self) -> bool {
         self.creation_content.is_some()
@@ -325,13 +480,68 @@ impl WalletsContent {
                 self.show_opening_modal(w, data, cb);
             }
         } else {
-            self.show_wallet_selection_modal(data);
+            let guess = data.as_ref().and_then(|d| self.guess_data_wallet(d));
+            self.show_wallet_selection_modal(guess, data);
+        }
+    }
+
+    /// Find opened wallet to handle provided Slatepack message, preferring the wallet with
+    /// existing transaction for the message or single wallet which is able to read it.
+    fn guess_data_wallet(&self, data: &String) -> Option<i64> {
+        let matches = self.wallets.list()
+            .iter()
+            .filter_map(|w| w.slatepack_match(data).map(|tx| (w.get_config().id, tx)))
+            .collect::<Vec<(i64, bool)>>();
+        if let Some((id, _)) = matches.iter().find(|(_, tx)| *tx) {
+            return Some(*id);
+        }
+        if matches.len() == 1 {
+            return Some(matches[0].0);
+        }
+        None
+    }
+
+    /// Open wallet screen from provided navigation request.
+    pub fn on_route(&mut self, ui: &mut egui::Ui, route: AppRoute, cb: &dyn PlatformCallbacks) {
+        let wallets = self.wallets.list();
+        let wallet = match &route.wallet {
+            Some(name) => wallets.iter().find(|w| &w.get_config().name == name),
+            None => if wallets.len() == 1 { wallets.first() } else { None }
+        };
+        let w = match wallet {
+            Some(w) => w.clone(),
+            None => {
+                // Pass message to wallet selection.
+                if route.message.is_some() {
+                    self.on_data(ui, route.message, cb);
+                }
+                return;
+            }
+        };
+        // Close network panel on single panel mode.
+        if !Content::is_dual_panel_mode(ui.ctx()) && Content::is_network_panel_open() {
+            Content::toggle_network_panel();
+        }
+        // Show message at wallet instead of requested tab.
+        let tab = if route.message.is_some() { None } else { route.tab };
+        let accounts = route.screen == RouteScreen::Accounts;
+        if w.is_open() {
+            let mut content = WalletContent::new(w, route.message);
+            content.on_route(tab, accounts);
+            self.wallet_content = Some(content);
+        } else {
+            self.show_opening_modal(w, route.message, cb);
+            if let Some(content) = self.wallet_content.as_mut() {
+                content.on_route(tab, false);
+            }
         }
     }
 
     /// Show initial wallet creation [`Modal`].
     pub fn show_add_wallet_modal(&mut self, cb: &dyn PlatformCallbacks) {
-        self.add_wallet_modal_content = Some(AddWalletModal::default());
+        let mut content = AddWalletModal::default();
+        content.import_available = self.cli_wallet().is_some();
+        self.add_wallet_modal_content = Some(content);
         Modal::new(ADD_WALLET_MODAL)
             .position(ModalPosition::CenterTop)
             .title(t!("wallets.add"))
@@ -339,9 +549,71 @@ impl WalletsContent {
         cb.show_keyboard();
     }
 
-    /// Show wallet selection with provided optional data.
-    fn show_wallet_selection_modal(&mut self, data: Option<String>) {
-        self.wallet_selection_content = Some(WalletsModal::new(None, data, true));
+    /// Show command line application wallet import [`Modal`].
+    fn show_import_wallet_modal(&mut self, cb: &dyn PlatformCallbacks) {
+        if let Some(cli) = self.cli_wallet() {
+            self.import_wallet_content = Some(ImportWalletModal::new(cli));
+            Modal::new(IMPORT_WALLET_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("wallets.cli_import"))
+                .show();
+            cb.show_keyboard();
+        }
+    }
+
+    /// Get not imported wallet of command line application for current [`ChainTypes`].
+    fn cli_wallet(&mut self) -> Option<CliWallet> {
+        let chain_type = AppConfig::chain_type();
+        if self.cli_wallet.as_ref().map(|(c, _)| *c != chain_type).unwrap_or(true) {
+            self.cli_wallet = Some((chain_type, CliWallet::detect(chain_type)));
+        }
+        let cli = self.cli_wallet.as_ref().and_then(|(_, w)| w.clone())?;
+        let dir = cli.top_level_dir.to_str().map(|d| d.to_string());
+        if self.wallets.list().iter().any(|w| w.get_config().data_dir == dir) {
+            return None;
+        }
+        Some(cli)
+    }
+
+    /// Show aggregated password prompt for closed wallets to open on launch,
+    /// including last opened wallet when enabled.
+    fn show_auto_open_modal(&mut self, cb: &dyn PlatformCallbacks) {
+        let list = self.wallets.list();
+        let last_id = if !AppConfig::open_last_wallet() {
+            None
+        } else if list.len() == 1 {
+            Some(list[0].get_config().id)
+        } else {
+            AppConfig::last_wallet_id()
+        };
+        let is_last = |w: &Wallet| Some(w.get_config().id) == last_id;
+        let mut wallets: Vec<Wallet> = list
+            .iter()
+            .filter(|w| (w.auto_open() || is_last(w)) && !w.is_open())
+            .cloned()
+            .collect();
+        if wallets.is_empty() {
+            return;
+        }
+        // Show last opened wallet first to select it after opening.
+        wallets.sort_by_key(|w| !is_last(w));
+        let use_bio = wallets.iter().all(|w| w.has_unlock_biometric()) && cb.biometric_available();
+        self.auto_open_content = Some(AutoOpenModal::new(wallets));
+        Modal::new(AUTO_OPEN_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("wallets.open"))
+            .show();
+        // Unlock wallets with biometric without password input when possible.
+        if use_bio {
+            cb.start_biometric_auth();
+        } else {
+            cb.show_keyboard();
+        }
+    }
+
+    /// Show wallet selection with provided optional data and suggested wallet.
+    fn show_wallet_selection_modal(&mut self, guess: Option<i64>, data: Option<String>) {
+        self.wallet_selection_content = Some(WalletsModal::new(guess, data, true));
         Modal::new(SELECT_WALLET_MODAL)
             .position(ModalPosition::Center)
             .title(t!("network_settings.choose_wallet"))
@@ -483,10 +755,7 @@ impl WalletsContent {
         }, |ui| {
             View::title_button_big(ui, GEAR, |_| {
                 // Show settings modal.
-                Modal::new(Content::SETTINGS_MODAL)
-                    .position(ModalPosition::CenterTop)
-                    .title(t!("settings"))
-                    .show();
+                Content::show_settings_modal();
             });
         }, ui);
     }
@@ -495,32 +764,84 @@ impl WalletsContent {
     fn wallet_list_ui(&mut self,
                       ui: &mut egui::Ui,
                       cb: &dyn PlatformCallbacks) {
+        let list = self.wallets.list().clone();
+        for w in &list {
+            // Remove deleted.
+            if w.is_deleted() {
+                self.wallet_content = None;
+                self.wallets.remove(w.get_config().id);
+                ui.ctx().request_repaint();
+                continue;
+            }
+            // Check if wallet reopen is needed.
+            if w.reopen_needed() && !w.is_open() {
+                w.set_reopen(false);
+                self.show_opening_modal(w.clone(), None, cb);
+            }
+        }
+
+        let show_search = self.wallets.list().len() >= MIN_WALLETS_TO_SEARCH;
+        ui.vertical_centered(|ui| {
+            View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                if show_search {
+                    // Show search input.
+                    ui.add_space(4.0);
+                    let search_text = format!("{} {}", MAGNIFYING_GLASS, t!("wallets.search"));
+                    ui.label(RichText::new(search_text)
+                        .size(16.0)
+                        .color(Colors::gray()));
+                    ui.add_space(6.0);
+                    let mut search_opts = TextEditOptions::new(Id::from("wallet_list_search"))
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.search_edit, &mut search_opts);
+                    ui.add_space(8.0);
+                } else {
+                    // Show application logo and name.
+                    View::app_logo_name_version(ui);
+                    ui.add_space(15.0);
+                }
+
+                // Show summary balance when multiple wallets are opened.
+                let balance = self.wallets.balance();
+                if balance.wallets.len() > 1 {
+                    Self::balance_ui(ui, &balance);
+                    ui.add_space(5.0);
+                }
+            });
+        });
+
+        // Show wallets found by name.
+        let search = if show_search {
+            self.search_edit.trim().to_lowercase()
+        } else {
+            "".to_string()
+        };
+        let wallets = self.wallets.list()
+            .iter()
+            .filter(|w| search.is_empty() || w.get_config().name.to_lowercase().contains(&search))
+            .cloned()
+            .collect::<Vec<Wallet>>();
+        if wallets.is_empty() {
+            if !search.is_empty() {
+                ui.add_space(8.0);
+                ui.vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("wallets.search_empty"))
+                        .size(16.0)
+                        .color(Colors::inactive_text()));
+                });
+            }
+            return;
+        }
+        let row_height = WALLET_ITEM_HEIGHT + WALLET_ITEM_SPACE;
         ScrollArea::vertical()
             .id_salt("wallet_list_scroll")
             .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
             .auto_shrink([false; 2])
-            .show(ui, |ui| {
+            .show_rows(ui, row_height, wallets.len(), |ui, row_range| {
                 View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
-                    // Show application logo and name.
-                    View::app_logo_name_version(ui);
-                    ui.add_space(15.0);
-
-                    let list = self.wallets.list().clone();
-                    for w in &list {
-                        // Remove deleted.
-                        if w.is_deleted() {
-                            self.wallet_content = None;
-                            self.wallets.remove(w.get_config().id);
-                            ui.ctx().request_repaint();
-                            continue;
-                        }
-                        // Check if wallet reopen is needed.
-                        if w.reopen_needed() && !w.is_open() {
-                            w.set_reopen(false);
-                            self.show_opening_modal(w.clone(), None, cb);
-                        }
-                        self.wallet_item_ui(ui, w, cb);
-                        ui.add_space(5.0);
+                    for wallet in &wallets[row_range] {
+                        self.wallet_item_ui(ui, wallet, cb);
+                        ui.add_space(WALLET_ITEM_SPACE);
                     }
                 });
             });
@@ -540,7 +861,7 @@ impl WalletsContent {
 
         // Draw round background.
         let mut rect = ui.available_rect_before_wrap();
-        rect.set_height(78.0);
+        rect.set_height(WALLET_ITEM_HEIGHT);
         let rounding = View::item_rounding(0, 1, false);
         let (bg, stroke) = if current {
             (Colors::fill_deep(), View::item_stroke())
@@ -574,7 +895,9 @@ impl WalletsContent {
                     }
                 }
             } else {
-                if !current {
+                let detached = self.detached.contains(&config.id);
+                let can_select = !current && !detached;
+                if can_select {
                     // Show button to select opened wallet.
                     View::item_button(ui, View::item_rounding(0, 1, true), CARET_RIGHT, None, || {
                         self.wallet_content = Some(WalletContent::new(wallet.clone(), None));
@@ -582,7 +905,7 @@ impl WalletsContent {
                 }
                 // Show button to close opened wallet.
                 if !wallet.is_closing()  {
-                    View::item_button(ui, if !current {
+                    View::item_button(ui, if can_select {
                         Rounding::default()
                     } else {
                         View::item_rounding(0, 1, true)
@@ -590,6 +913,12 @@ impl WalletsContent {
                         wallet.close();
                     });
                 }
+                // Show button to open wallet at separate window on desktop.
+                if View::is_desktop() && !detached && !wallet.is_closing() {
+                    View::item_button(ui, Rounding::default(), ARROW_SQUARE_OUT, None, || {
+                        self.detach_request = Some(wallet.clone());
+                    });
+                }
             }
 
             let layout_size = ui.available_size();
@@ -627,6 +956,68 @@ impl WalletsContent {
         });
     }
 
+    /// Draw summary balance of opened wallets.
+    fn balance_ui(ui: &mut egui::Ui, balance: &WalletsBalance) {
+        // Draw round background.
+        let rect = ui.available_rect_before_wrap();
+        let mut bg = RectShape::new(rect,
+                                    View::item_rounding(0, 1, false),
+                                    Colors::fill(),
+                                    View::item_stroke());
+        let bg_idx = ui.painter().add(bg);
+
+        let resp = ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            // Show total spendable amount.
+            let amount = amount_to_display(balance.spendable);
+            let amount_resp = ui.label(RichText::new(format!("{} {}", amount, GRIN))
+                .size(18.0)
+                .color(Colors::white_or_black(true)));
+            full_amount_hover(amount_resp, balance.spendable);
+            // Show total awaiting amount.
+            let desc = if balance.awaiting != 0 {
+                let awaiting = amount_to_display(balance.awaiting);
+                format!("{} ({}: {} {})",
+                        t!("wallets.total_balance"),
+                        t!("wallets.awaiting_amount"),
+                        awaiting,
+                        GRIN)
+            } else {
+                t!("wallets.total_balance").to_string()
+            };
+            ui.label(RichText::new(desc).size(15.0).color(Colors::gray()));
+            ui.add_space(6.0);
+
+            // Show balance of each opened wallet.
+            for (name, spendable, awaiting) in &balance.wallets {
+                let line_rect = ui.available_rect_before_wrap();
+                View::line(ui, LinePosition::TOP, &line_rect, Colors::item_stroke());
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.add_space(8.0);
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        ui.add_space(8.0);
+                        let mut amount = format!("{} {}", amount_to_display(*spendable), GRIN);
+                        if *awaiting != 0 {
+                            amount = format!("{} {} {}",
+                                             amount,
+                                             DOTS_THREE_CIRCLE,
+                                             amount_to_display(*awaiting));
+                        }
+                        ui.label(RichText::new(amount).size(15.0).color(Colors::text(false)));
+                        ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                            View::ellipsize_text(ui, name.clone(), 15.0, Colors::title(false));
+                        });
+                    });
+                });
+                ui.add_space(4.0);
+            }
+        }).response;
+
+        bg.rect = resp.rect;
+        ui.painter().set(bg_idx, bg);
+    }
+
     /// Show [`Modal`] to select and open wallet.
     fn show_opening_modal(&mut self,
                           wallet: Wallet,
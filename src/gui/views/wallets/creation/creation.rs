@@ -34,6 +34,8 @@ pub struct WalletCreation {
     pub name: String,
     /// Wallet password.
     pub pass: ZeroingString,
+    /// Additional key derivation rounds of seed file password.
+    kdf_rounds: Option<u32>,
 
     /// Wallet creation step.
     step: Step,
@@ -89,11 +91,12 @@ impl ModalContainer for WalletCreation {
 }
 
 impl WalletCreation {
-    /// Create new wallet creation instance from name and password.
-    pub fn new(name: String, pass: ZeroingString) -> Self {
+    /// Create new wallet creation instance from name, password and seed file protection.
+    pub fn new(name: String, pass: ZeroingString, kdf_rounds: Option<u32>) -> Self {
         Self {
             name,
             pass,
+            kdf_rounds,
             step: Step::EnterMnemonic,
             scan_modal_content: None,
             mnemonic_setup: MnemonicSetup::default(),
@@ -325,7 +328,8 @@ impl WalletCreation {
                     match Wallet::create(&self.name,
                                          &self.pass,
                                          &self.mnemonic_setup.mnemonic,
-                                         &self.network_setup.method) {
+                                         &self.network_setup.method,
+                                         self.kdf_rounds) {
                         Ok(w) => {
                             self.mnemonic_setup.reset();
                             // Pass created wallet to callback.
@@ -19,4 +19,7 @@ mod content;
 pub use content::*;
 
 mod wallet;
-use wallet::*;
\ No newline at end of file
+use wallet::*;
+
+mod window;
+pub use window::WalletWindow;
\ No newline at end of file
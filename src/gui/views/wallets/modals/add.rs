@@ -16,9 +16,11 @@ use egui::{Id, RichText};
 use grin_util::ZeroingString;
 
 use crate::gui::Colors;
+use crate::gui::icons::DOWNLOAD_SIMPLE;
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::TextEditOptions;
+use crate::gui::views::wallets::wallet::types::{kdf_rounds_ui, password_strength_ui};
 
 /// Initial wallet creation [`Modal`] content.
 pub struct AddWalletModal {
@@ -28,6 +30,12 @@ pub struct AddWalletModal {
     pub name_edit: String,
     /// Password to encrypt created wallet.
     pub pass_edit: String,
+    /// Additional key derivation rounds of seed file password.
+    kdf_rounds: Option<u32>,
+    /// Flag to show button to import wallet of command line application.
+    pub import_available: bool,
+    /// Flag to check if import of command line application wallet was requested.
+    pub import_requested: bool,
 }
 
 impl Default for AddWalletModal {
@@ -36,6 +44,9 @@ impl Default for AddWalletModal {
             first_draw: true,
             name_edit: t!("wallets.default_wallet"),
             pass_edit: "".to_string(),
+            kdf_rounds: None,
+            import_available: false,
+            import_requested: false,
         }
     }
 }
@@ -46,7 +57,7 @@ impl AddWalletModal {
               ui: &mut egui::Ui,
               modal: &Modal,
               cb: &dyn PlatformCallbacks,
-              mut on_input: impl FnMut(String, ZeroingString)) {
+              mut on_input: impl FnMut(String, ZeroingString, Option<u32>)) {
         ui.add_space(6.0);
         ui.vertical_centered(|ui| {
             ui.label(RichText::new(t!("wallets.name"))
@@ -74,6 +85,14 @@ impl AddWalletModal {
                 .password()
                 .no_focus();
             View::text_edit(ui, cb, &mut self.pass_edit, &mut pass_text_edit_opts);
+            if !self.pass_edit.is_empty() {
+                ui.add_space(6.0);
+                password_strength_ui(ui, &self.pass_edit);
+            }
+            ui.add_space(8.0);
+
+            // Show seed file protection setup.
+            kdf_rounds_ui(ui, &mut self.kdf_rounds);
             ui.add_space(12.0);
         });
 
@@ -99,7 +118,7 @@ impl AddWalletModal {
                         }
                         cb.hide_keyboard();
                         modal.close();
-                        on_input(name, ZeroingString::from(pass));
+                        on_input(name, ZeroingString::from(pass), self.kdf_rounds);
                     };
 
                     // Go to next creation step on Enter button press.
@@ -112,5 +131,18 @@ impl AddWalletModal {
             });
             ui.add_space(6.0);
         });
+
+        // Show button to import wallet of command line application.
+        if self.import_available {
+            ui.add_space(4.0);
+            ui.vertical_centered_justified(|ui| {
+                let import_text = format!("{} {}", DOWNLOAD_SIMPLE, t!("wallets.cli_import"));
+                View::button(ui, import_text, Colors::white_or_black(false), || {
+                    self.import_requested = true;
+                    modal.close();
+                });
+            });
+            ui.add_space(6.0);
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,269 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use grin_util::ZeroingString;
+
+use crate::gui::Colors;
+use crate::gui::icons::{CHECK_CIRCLE, FINGERPRINT};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::TextEditOptions;
+use crate::gui::views::wallets::modals::MAX_PIN_ATTEMPTS;
+use crate::gui::views::wallets::UnlockSettings;
+use crate::wallet::Wallet;
+use crate::wallet::types::WalletOpenError;
+
+/// Wallet to open at [`AutoOpenModal`].
+struct AutoOpenItem {
+    /// Wallet to open.
+    wallet: Wallet,
+    /// Password or PIN to open wallet.
+    pass_edit: String,
+    /// Flag to check if PIN is used instead of password.
+    use_pin: bool,
+    /// Error on last wallet opening attempt.
+    error: Option<WalletOpenError>,
+}
+
+/// Aggregated [`Modal`] content to open multiple wallets on application launch.
+pub struct AutoOpenModal {
+    /// Wallets to open.
+    items: Vec<AutoOpenItem>,
+    /// Wallets opened at this modal.
+    opened: Vec<Wallet>,
+}
+
+impl AutoOpenModal {
+    /// Create new content instance for provided wallets.
+    pub fn new(wallets: Vec<Wallet>) -> Self {
+        let items = wallets.into_iter().map(|wallet| {
+            let use_pin = wallet.has_unlock_pin();
+            AutoOpenItem {
+                wallet,
+                pass_edit: "".to_string(),
+                use_pin,
+                error: None,
+            }
+        }).collect();
+        Self {
+            items,
+            opened: vec![],
+        }
+    }
+
+    /// Draw [`Modal`] content.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks,
+              mut on_finish: impl FnMut(Vec<Wallet>)) {
+        // Open wallets with biometric data after successful authentication.
+        if let Some(success) = cb.biometric_auth_result() {
+            if success {
+                for item in self.items.iter_mut().filter(|i| i.wallet.has_unlock_biometric()) {
+                    if let Some(pass) = UnlockSettings::unlock_password(&item.wallet, None, cb) {
+                        Self::open_item(item, pass);
+                    }
+                }
+                self.on_opened(modal, cb, &mut on_finish);
+                if self.items.is_empty() {
+                    return;
+                }
+            }
+        }
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.auto_open_desc"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+        });
+
+        // Show wallets to open.
+        ScrollArea::vertical()
+            .id_salt("auto_open_wallets_scroll")
+            .max_height(View::window_size(ui.ctx()).1 * 0.5)
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for w in &self.opened {
+                    ui.vertical_centered(|ui| {
+                        let name = format!("{} {}", CHECK_CIRCLE, w.get_config().name);
+                        ui.label(RichText::new(name).size(17.0).color(Colors::green()));
+                    });
+                    ui.add_space(8.0);
+                }
+                for (index, item) in self.items.iter_mut().enumerate() {
+                    Self::item_ui(ui, item, index == 0, modal, cb);
+                }
+            });
+        ui.add_space(4.0);
+
+        // Show button to unlock wallets with biometric.
+        let has_bio = self.items.iter().any(|i| i.wallet.has_unlock_biometric());
+        if has_bio && cb.biometric_available() {
+            ui.vertical_centered(|ui| {
+                let bio_text = format!("{} {}", FINGERPRINT, t!("wallets.use_biometric"));
+                View::button(ui, bio_text, Colors::white_or_black(false), || {
+                    cb.hide_keyboard();
+                    cb.start_biometric_auth();
+                });
+            });
+            ui.add_space(12.0);
+        }
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("wallets.skip"), Colors::white_or_black(false), || {
+                        cb.hide_keyboard();
+                        modal.close();
+                        on_finish(self.opened.clone());
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Callback for button to continue.
+                    let mut on_continue = || {
+                        for item in self.items.iter_mut() {
+                            if item.pass_edit.is_empty() {
+                                continue;
+                            }
+                            let pass = if item.use_pin {
+                                let pin = item.pass_edit.clone();
+                                UnlockSettings::unlock_password(&item.wallet, Some(&pin), cb)
+                            } else {
+                                Some(item.pass_edit.clone())
+                            };
+                            match pass {
+                                Some(pass) => Self::open_item(item, pass),
+                                None => Self::on_wrong_pin(item)
+                            }
+                        }
+                        self.on_opened(modal, cb, &mut on_finish);
+                    };
+
+                    // Continue on Enter key press.
+                    View::on_enter_key(ui, || {
+                        (on_continue)();
+                    });
+
+                    View::button(ui, t!("continue"), Colors::white_or_black(false), on_continue);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw password input for the wallet.
+    fn item_ui(ui: &mut egui::Ui,
+               item: &mut AutoOpenItem,
+               focus: bool,
+               modal: &Modal,
+               cb: &dyn PlatformCallbacks) {
+        let config = item.wallet.get_config();
+        ui.vertical_centered(|ui| {
+            let title = if item.use_pin {
+                format!("{} ({})", config.name, t!("wallets.pin"))
+            } else {
+                config.name
+            };
+            ui.label(RichText::new(title).size(17.0).color(Colors::title(false)));
+            ui.add_space(6.0);
+
+            let pass_before = item.pass_edit.clone();
+            let mut pass_edit_opts = TextEditOptions::new(Id::from(modal.id).with(config.id))
+                .password();
+            if !focus {
+                pass_edit_opts = pass_edit_opts.no_focus();
+            }
+            View::text_edit(ui, cb, &mut item.pass_edit, &mut pass_edit_opts);
+            if pass_before != item.pass_edit {
+                item.error = None;
+            }
+
+            if let Some(err) = &item.error {
+                ui.add_space(6.0);
+                let err_text = match err {
+                    WalletOpenError::WrongPassword => if item.use_pin {
+                        let attempts = MAX_PIN_ATTEMPTS - item.wallet.unlock_pin_attempts();
+                        t!("wallets.wrong_pin", "attempts" => attempts)
+                    } else {
+                        t!("wallets.wrong_pass")
+                    },
+                    WalletOpenError::SeedMissing => t!("wallets.seed_missing"),
+                    WalletOpenError::SeedCorrupted => t!("wallets.seed_corrupted"),
+                    WalletOpenError::DatabaseFull => t!("wallets.db_full"),
+                    WalletOpenError::DatabaseLocked => t!("wallets.db_locked"),
+                    WalletOpenError::Other(e) => t!("wallets.open_error", "err" => e)
+                };
+                ui.label(RichText::new(err_text).size(16.0).color(Colors::red()));
+            }
+            ui.add_space(10.0);
+        });
+    }
+
+    /// Open the wallet with provided password, saving an error on failure.
+    fn open_item(item: &mut AutoOpenItem, pass: String) {
+        match item.wallet.open(ZeroingString::from(pass)) {
+            Ok(_) => {
+                item.pass_edit = "".to_string();
+                item.error = None;
+            }
+            Err(e) => {
+                item.error = Some(item.wallet.open_error(&e));
+            }
+        }
+    }
+
+    /// Register wrong PIN attempt, resetting quick unlock on maximum attempts.
+    fn on_wrong_pin(item: &mut AutoOpenItem) {
+        let attempts = item.wallet.unlock_pin_attempts() + 1;
+        item.pass_edit = "".to_string();
+        if attempts >= MAX_PIN_ATTEMPTS {
+            item.wallet.update_unlock_data(None, None);
+            item.use_pin = false;
+            item.error = None;
+        } else {
+            item.wallet.update_unlock_pin_attempts(attempts);
+            item.error = Some(WalletOpenError::WrongPassword);
+        }
+    }
+
+    /// Move opened wallets out of the list, finishing when all wallets were opened.
+    fn on_opened(&mut self,
+                 modal: &Modal,
+                 cb: &dyn PlatformCallbacks,
+                 on_finish: &mut impl FnMut(Vec<Wallet>)) {
+        let (opened, rest): (Vec<AutoOpenItem>, Vec<AutoOpenItem>) = self.items
+            .drain(..)
+            .partition(|i| i.wallet.is_open());
+        self.items = rest;
+        if !opened.is_empty() {
+            cb.start_background_sync();
+        }
+        self.opened.extend(opened.into_iter().map(|i| i.wallet));
+        if self.items.is_empty() {
+            cb.hide_keyboard();
+            modal.close();
+            on_finish(self.opened.clone());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+
+use crate::gui::Colors;
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::TextEditOptions;
+use crate::wallet::{CliWallet, Wallet};
+
+/// Import of command line application wallet [`Modal`] content.
+pub struct ImportWalletModal {
+    /// Flag to check if it's first draw to focus on first field.
+    first_draw: bool,
+    /// Wallet name.
+    name_edit: String,
+    /// Command line application wallet to import.
+    cli: CliWallet,
+    /// Flag to use wallet data in place instead of copying.
+    in_place: bool,
+    /// Import error.
+    error: Option<String>,
+}
+
+impl ImportWalletModal {
+    /// Create new content instance for provided command line application wallet.
+    pub fn new(cli: CliWallet) -> Self {
+        Self {
+            first_draw: true,
+            name_edit: "grin-wallet".to_string(),
+            cli,
+            in_place: false,
+            error: None,
+        }
+    }
+
+    /// Draw wallet import [`Modal`] content.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks,
+              mut on_import: impl FnMut(Wallet)) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.cli_import_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(6.0);
+            let dir = self.cli.data_dir.to_str().unwrap_or_default().to_string();
+            View::ellipsize_text(ui, dir, 15.0, Colors::gray());
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("wallets.name"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Show wallet name text edit.
+            let mut name_edit_opts = TextEditOptions::new(Id::from(modal.id).with("name"))
+                .no_focus();
+            if self.first_draw {
+                self.first_draw = false;
+                name_edit_opts.focus = true;
+            }
+            View::text_edit(ui, cb, &mut self.name_edit, &mut name_edit_opts);
+            ui.add_space(8.0);
+
+            // Show checkbox to use wallet data in place.
+            if self.cli.can_use_in_place() {
+                View::checkbox(ui, self.in_place, t!("wallets.cli_in_place"), || {
+                    self.in_place = !self.in_place;
+                });
+                ui.add_space(4.0);
+                let desc = if self.in_place {
+                    t!("wallets.cli_in_place_desc")
+                } else {
+                    t!("wallets.cli_copy_desc")
+                };
+                ui.label(RichText::new(desc).size(15.0).color(Colors::inactive_text()));
+                ui.add_space(8.0);
+            }
+
+            // Show import error.
+            if let Some(err) = &self.error {
+                ui.label(RichText::new(format!("{}: {}", t!("wallets.cli_import_error"), err))
+                    .size(16.0)
+                    .color(Colors::red()));
+                ui.add_space(8.0);
+            }
+            ui.add_space(4.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let mut on_next = || {
+                        let name = self.name_edit.trim().to_string();
+                        if name.is_empty() {
+                            return;
+                        }
+                        match Wallet::import_cli(&name, &self.cli, self.in_place) {
+                            Ok(wallet) => {
+                                cb.hide_keyboard();
+                                modal.close();
+                                on_import(wallet);
+                            }
+                            Err(e) => self.error = Some(e.to_string())
+                        }
+                    };
+
+                    // Import wallet on Enter button press.
+                    View::on_enter_key(ui, || {
+                        (on_next)();
+                    });
+
+                    let import_text = t!("wallets.cli_import");
+                    View::button(ui, import_text, Colors::white_or_black(false), on_next);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
@@ -22,4 +22,10 @@ mod open;
 pub use open::*;
 
 mod add;
-pub use add::*;
\ No newline at end of file
+pub use add::*;
+
+mod auto_open;
+pub use auto_open::*;
+
+mod import;
+pub use import::*;
\ No newline at end of file
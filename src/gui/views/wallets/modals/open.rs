@@ -16,10 +16,13 @@ use egui::{Id, RichText};
 use grin_util::ZeroingString;
 
 use crate::gui::Colors;
+use crate::gui::icons::{ARROW_COUNTER_CLOCKWISE, FINGERPRINT, LOCK_SIMPLE_OPEN, PASSWORD};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::TextEditOptions;
-use crate::wallet::Wallet;
+use crate::gui::views::wallets::UnlockSettings;
+use crate::wallet::{Mnemonic, Wallet};
+use crate::wallet::types::{PhraseMode, RepairReason, WalletOpenError};
 
 /// Wallet opening [`Modal`] content.
 pub struct OpenWalletModal {
@@ -30,50 +33,272 @@ pub struct OpenWalletModal {
     pass_edit: String,
     /// Flag to check if wrong password was entered.
     wrong_pass: bool,
+    /// Error on wallet opening not related to password.
+    open_error: Option<WalletOpenError>,
+
+    /// Recovery phrase to restore wallet seed, restoration is not active if not set.
+    restore_phrase_edit: Option<String>,
+    /// Flag to check if entered recovery phrase is not valid.
+    restore_phrase_error: bool,
+
+    /// Flag to confirm clearing of database lock.
+    clear_lock_confirm: bool,
+    /// Flag to check if database lock is held by another application.
+    lock_in_use: bool,
+
+    /// Flag to check if PIN is used instead of password.
+    use_pin: bool,
 
     /// Optional data to pass after wallet opening.
     data: Option<String>,
 }
 
+/// Maximal amount of wrong PIN attempts before quick unlock reset.
+pub const MAX_PIN_ATTEMPTS: u8 = 5;
+
 impl OpenWalletModal {
     /// Create new content instance.
     pub fn new(wallet: Wallet, data: Option<String>) -> Self {
+        let use_pin = wallet.has_unlock_pin();
         Self {
             wallet,
             pass_edit: "".to_string(),
             wrong_pass: false,
+            open_error: None,
+            restore_phrase_edit: None,
+            restore_phrase_error: false,
+            clear_lock_confirm: false,
+            lock_in_use: false,
+            use_pin,
             data,
         }
     }
+
+    /// Get password to open the wallet from entered value.
+    fn password(&mut self, cb: &dyn PlatformCallbacks) -> Option<String> {
+        if !self.use_pin {
+            return Some(self.pass_edit.clone());
+        }
+        let pass = UnlockSettings::unlock_password(&self.wallet, Some(self.pass_edit.as_str()), cb);
+        if pass.is_none() {
+            let attempts = self.wallet.unlock_pin_attempts() + 1;
+            // Reset quick unlock on maximum attempts.
+            if attempts >= MAX_PIN_ATTEMPTS {
+                self.wallet.update_unlock_data(None, None);
+                self.use_pin = false;
+                self.pass_edit = "".to_string();
+            } else {
+                self.wallet.update_unlock_pin_attempts(attempts);
+            }
+        }
+        pass
+    }
+
+    /// Open the wallet with provided password.
+    fn open(&mut self, pass: String) -> bool {
+        match self.wallet.open(ZeroingString::from(pass)) {
+            Ok(_) => {
+                self.open_error = None;
+                true
+            }
+            Err(e) => {
+                match self.wallet.open_error(&e) {
+                    WalletOpenError::WrongPassword => self.wrong_pass = true,
+                    err => self.open_error = Some(err)
+                }
+                false
+            }
+        }
+    }
+
+    /// Restore wallet seed from entered recovery phrase and open the wallet.
+    fn restore(&mut self) -> bool {
+        let phrase = self.restore_phrase_edit.clone().unwrap_or_default();
+        let mut mnemonic = Mnemonic::default();
+        mnemonic.set_mode(PhraseMode::Import);
+        mnemonic.import(&ZeroingString::from(phrase.trim()));
+        if !mnemonic.valid() || mnemonic.has_empty_or_invalid() {
+            self.restore_phrase_error = true;
+            return false;
+        }
+        let pass = ZeroingString::from(self.pass_edit.clone());
+        if let Err(e) = self.wallet.restore_seed(&mnemonic, &pass) {
+            self.open_error = Some(WalletOpenError::Other(e.to_string()));
+            return false;
+        }
+        self.restore_phrase_edit = None;
+        if self.open(self.pass_edit.clone()) {
+            // Rescan outputs for restored seed.
+            self.wallet.repair(RepairReason::Restore);
+            return true;
+        }
+        false
+    }
+
     /// Draw [`Modal`] content.
     pub fn ui(&mut self,
               ui: &mut egui::Ui,
               modal: &Modal,
               cb: &dyn PlatformCallbacks,
               mut on_continue: impl FnMut(Wallet, Option<String>)) {
+        // Open the wallet after successful biometric authentication.
+        if let Some(success) = cb.biometric_auth_result() {
+            if success {
+                if let Some(pass) = UnlockSettings::unlock_password(&self.wallet, None, cb) {
+                    if self.wallet.open(ZeroingString::from(pass)).is_ok() {
+                        self.pass_edit = "".to_string();
+                        cb.hide_keyboard();
+                        cb.start_background_sync();
+                        modal.close();
+                        on_continue(self.wallet.clone(), self.data.clone());
+                        return;
+                    }
+                }
+            }
+        }
+
         ui.add_space(6.0);
         ui.vertical_centered(|ui| {
-            ui.label(RichText::new(t!("wallets.pass"))
+            // Show recovery phrase input to restore wallet seed.
+            if let Some(phrase_edit) = self.restore_phrase_edit.as_mut() {
+                ui.label(RichText::new(t!("wallets.restore_phrase_desc"))
+                    .size(17.0)
+                    .color(Colors::gray()));
+                ui.add_space(8.0);
+                let phrase_id = Id::from(modal.id).with("restore_phrase");
+                let mut phrase_edit_opts = TextEditOptions::new(phrase_id).paste();
+                let phrase_before = phrase_edit.clone();
+                View::text_edit(ui, cb, phrase_edit, &mut phrase_edit_opts);
+                if *phrase_edit != phrase_before {
+                    self.restore_phrase_error = false;
+                }
+                if self.restore_phrase_error {
+                    ui.add_space(10.0);
+                    ui.label(RichText::new(t!("wallets.not_valid_phrase"))
+                        .size(17.0)
+                        .color(Colors::red()));
+                }
+                ui.add_space(8.0);
+            }
+
+            let title = if self.use_pin {
+                t!("wallets.pin")
+            } else {
+                t!("wallets.pass")
+            };
+            ui.label(RichText::new(title)
                 .size(17.0)
                 .color(Colors::gray()));
             ui.add_space(8.0);
 
             // Show password input.
             let mut pass_edit_opts = TextEditOptions::new(Id::from(modal.id)).password();
+            if self.restore_phrase_edit.is_some() {
+                pass_edit_opts = pass_edit_opts.no_focus();
+            }
             View::text_edit(ui, cb, &mut self.pass_edit, &mut pass_edit_opts);
 
             // Show information when password is empty.
             if self.pass_edit.is_empty() {
                 self.wrong_pass = false;
+                self.open_error = None;
                 ui.add_space(10.0);
                 ui.label(RichText::new(t!("wallets.pass_empty"))
                     .size(17.0)
                     .color(Colors::inactive_text()));
             } else if self.wrong_pass {
                 ui.add_space(10.0);
-                ui.label(RichText::new(t!("wallets.wrong_pass"))
+                let wrong_text = if self.use_pin {
+                    let attempts = MAX_PIN_ATTEMPTS - self.wallet.unlock_pin_attempts();
+                    t!("wallets.wrong_pin", "attempts" => attempts)
+                } else {
+                    t!("wallets.wrong_pass")
+                };
+                ui.label(RichText::new(wrong_text)
                     .size(17.0)
                     .color(Colors::red()));
+            } else if let Some(err) = &self.open_error {
+                ui.add_space(10.0);
+                let err_text = match err {
+                    WalletOpenError::SeedMissing => t!("wallets.seed_missing"),
+                    WalletOpenError::SeedCorrupted => t!("wallets.seed_corrupted"),
+                    WalletOpenError::DatabaseFull => t!("wallets.db_full"),
+                    WalletOpenError::DatabaseLocked => t!("wallets.db_locked"),
+                    WalletOpenError::Other(e) => t!("wallets.open_error", "err" => e),
+                    WalletOpenError::WrongPassword => t!("wallets.wrong_pass")
+                };
+                let locked = *err == WalletOpenError::DatabaseLocked;
+                ui.label(RichText::new(err_text)
+                    .size(17.0)
+                    .color(Colors::red()));
+                // Show button to restore wallet seed from recovery phrase.
+                if err.can_restore() && self.restore_phrase_edit.is_none() {
+                    ui.add_space(10.0);
+                    let restore_text = format!("{} {}",
+                                               ARROW_COUNTER_CLOCKWISE,
+                                               t!("wallets.restore_from_phrase"));
+                    View::button(ui, restore_text, Colors::white_or_black(false), || {
+                        self.restore_phrase_edit = Some("".to_string());
+                        self.restore_phrase_error = false;
+                        self.use_pin = false;
+                        self.pass_edit = "".to_string();
+                    });
+                }
+                // Show button to clear stale database lock after confirmation.
+                if locked && self.wallet.can_clear_db_lock() {
+                    if self.lock_in_use {
+                        ui.add_space(10.0);
+                        ui.label(RichText::new(t!("wallets.db_lock_in_use"))
+                            .size(16.0)
+                            .color(Colors::inactive_text()));
+                    } else if self.clear_lock_confirm {
+                        ui.add_space(10.0);
+                        ui.label(RichText::new(t!("wallets.db_clear_lock_warning"))
+                            .size(16.0)
+                            .color(Colors::red()));
+                    }
+                    ui.add_space(10.0);
+                    let clear_text = format!("{} {}",
+                                             LOCK_SIMPLE_OPEN,
+                                             t!("wallets.db_clear_lock"));
+                    View::button(ui, clear_text, Colors::white_or_black(false), || {
+                        if !self.clear_lock_confirm {
+                            self.clear_lock_confirm = true;
+                            self.lock_in_use = false;
+                            return;
+                        }
+                        self.clear_lock_confirm = false;
+                        if self.wallet.clear_db_lock() {
+                            self.open_error = None;
+                        } else {
+                            self.lock_in_use = true;
+                        }
+                    });
+                }
+            }
+
+            // Show buttons to switch unlock method.
+            if self.wallet.has_unlock_pin() && self.restore_phrase_edit.is_none() {
+                ui.add_space(10.0);
+                let switch_text = if self.use_pin {
+                    format!("{} {}", PASSWORD, t!("wallets.use_pass"))
+                } else {
+                    format!("{} {}", PASSWORD, t!("wallets.use_pin"))
+                };
+                View::button(ui, switch_text, Colors::white_or_black(false), || {
+                    self.use_pin = !self.use_pin;
+                    self.pass_edit = "".to_string();
+                    self.wrong_pass = false;
+                });
+            }
+            if self.wallet.has_unlock_biometric() && cb.biometric_available() &&
+                self.restore_phrase_edit.is_none() {
+                ui.add_space(10.0);
+                let bio_text = format!("{} {}", FINGERPRINT, t!("wallets.use_biometric"));
+                View::button(ui, bio_text, Colors::white_or_black(false), || {
+                    cb.hide_keyboard();
+                    cb.start_biometric_auth();
+                });
             }
             ui.add_space(12.0);
         });
@@ -94,18 +319,26 @@ impl OpenWalletModal {
                 columns[1].vertical_centered_justified(|ui| {
                     // Callback for button to continue.
                     let mut on_continue = || {
-                        let pass = self.pass_edit.clone();
-                        if pass.is_empty() {
+                        if self.pass_edit.is_empty() {
                             return;
                         }
-                        match self.wallet.open(ZeroingString::from(pass)) {
-                            Ok(_) => {
-                                self.pass_edit = "".to_string();
-                                cb.hide_keyboard();
-                                modal.close();
-                                on_continue(self.wallet.clone(), self.data.clone());
+                        let opened = if self.restore_phrase_edit.is_some() {
+                            self.restore()
+                        } else {
+                            match self.password(cb) {
+                                Some(pass) => self.open(pass),
+                                None => {
+                                    self.wrong_pass = true;
+                                    false
+                                }
                             }
-                            Err(_) => self.wrong_pass = true
+                        };
+                        if opened {
+                            self.pass_edit = "".to_string();
+                            cb.hide_keyboard();
+                            cb.start_background_sync();
+                            modal.close();
+                            on_continue(self.wallet.clone(), self.data.clone());
                         }
                     };
 
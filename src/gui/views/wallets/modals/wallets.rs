@@ -16,7 +16,7 @@ use egui::scroll_area::ScrollBarVisibility;
 use egui::{Align, Layout, RichText, ScrollArea};
 
 use crate::gui::Colors;
-use crate::gui::icons::{CHECK, CHECK_FAT, COMPUTER_TOWER, FOLDER_OPEN, GLOBE_SIMPLE, PLUGS_CONNECTED};
+use crate::gui::icons::{CHECK, CHECK_CIRCLE, CHECK_FAT, COMPUTER_TOWER, FOLDER_OPEN, GLOBE_SIMPLE, PLUGS_CONNECTED};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::ModalPosition;
@@ -27,7 +27,7 @@ use crate::wallet::types::ConnectionMethod;
 
 /// Wallet list [`Modal`] content
 pub struct WalletsModal {
-    /// Selected wallet id.
+    /// Selected wallet id or suggested wallet id to handle data when wallet can be opened.
     selected_id: Option<i64>,
 
     /// Optional data to pass after wallet selection.
@@ -71,7 +71,14 @@ impl WalletsModal {
                 ui.add_space(2.0);
                 ui.vertical_centered(|ui| {
                     let data = self.data.clone();
-                    for wallet in wallets.list() {
+                    // Show suggested wallet first.
+                    let mut list = wallets.list().iter().collect::<Vec<&Wallet>>();
+                    if self.can_open {
+                        if let Some(id) = self.selected_id {
+                            list.sort_by_key(|w| w.get_config().id != id);
+                        }
+                    }
+                    for wallet in list {
                         // Draw wallet list item.
                         self.wallet_item_ui(ui, wallet, || {
                             modal.close();
@@ -164,7 +171,11 @@ impl WalletsModal {
                     ui.add_space(1.0);
 
                     // Show wallet API text or open status.
-                    if self.can_open {
+                    let suggested = self.can_open && self.selected_id == Some(id);
+                    if suggested {
+                        let text = format!("{} {}", CHECK_CIRCLE, t!("wallets.data_suggested"));
+                        ui.label(RichText::new(text).size(15.0).color(Colors::green()));
+                    } else if self.can_open {
                         ui.label(RichText::new(wallet_status_text(wallet))
                             .size(15.0)
                             .color(Colors::gray()));
@@ -16,20 +16,20 @@ use std::time::Duration;
 use egui::{Align, Id, Layout, Margin, RichText, ScrollArea};
 use egui::scroll_area::ScrollBarVisibility;
 use grin_chain::SyncStatus;
-use grin_core::core::amount_to_hr_string;
+use grin_wallet_libwallet::SlatepackAddress;
 
 use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{ARROWS_CLOCKWISE, BRIDGE, CAMERA_ROTATE, CHAT_CIRCLE_TEXT, FOLDER_USER, GEAR_FINE, GRAPH, PACKAGE, POWER, SCAN, SPINNER, USERS_THREE};
+use crate::gui::icons::{ARROW_SQUARE_OUT, ARROWS_CLOCKWISE, BRIDGE, BUG, CAMERA_ROTATE, CHAT_CIRCLE_TEXT, CLIPBOARD_TEXT, CLOCK_COUNTER_CLOCKWISE, FOLDER_USER, GEAR_FINE, GRAPH, PACKAGE, POWER, RECEIPT, SCAN, SPINNER, USERS_THREE, X};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, Content, View, CameraContent};
 use crate::gui::views::types::{LinePosition, ModalContainer, ModalPosition};
-use crate::gui::views::wallets::{WalletTransactions, WalletMessages, WalletTransport};
-use crate::gui::views::wallets::types::{GRIN, WalletTab, WalletTabType};
-use crate::gui::views::wallets::wallet::modals::WalletAccountsModal;
+use crate::gui::views::wallets::{WalletTransactions, WalletInvoices, WalletMessages, WalletTransport};
+use crate::gui::views::wallets::types::{account_label, amount_to_display, full_amount_hover, GRIN, WalletTab, WalletTabType};
+use crate::gui::views::wallets::wallet::modals::{RepairReportModal, WalletAccountsModal};
 use crate::gui::views::wallets::wallet::WalletSettings;
 use crate::node::Node;
-use crate::wallet::{ExternalConnection, Wallet, WalletConfig};
+use crate::wallet::{ExternalConnection, PaymentUri, Wallet};
 use crate::wallet::types::{ConnectionMethod, WalletData};
 
 /// Wallet content.
@@ -41,16 +41,23 @@ pub struct WalletContent {
 
     /// Wallet accounts [`Modal`] content.
     accounts_modal_content: Option<WalletAccountsModal>,
+    /// Completed wallet repair [`Modal`] content.
+    repair_report_content: Option<RepairReportModal>,
 
     /// QR code scan content.
     pub qr_scan_content: Option<CameraContent>,
 
+    /// Slatepack message or address detected at clipboard.
+    clipboard_data: Option<String>,
+
     /// List of allowed [`Modal`] ids for this [`ModalContainer`].
     allowed_modal_ids: Vec<&'static str>
 }
 
 /// Identifier for account list [`Modal`].
 const ACCOUNT_LIST_MODAL: &'static str = "account_list_modal";
+/// Identifier for completed wallet repair [`Modal`].
+const REPAIR_REPORT_MODAL: &'static str = "repair_report_modal";
 
 impl ModalContainer for WalletContent {
     fn modal_ids(&self) -> &Vec<&'static str> {
@@ -66,6 +73,11 @@ impl ModalContainer for WalletContent {
                     });
                 }
             }
+            REPAIR_REPORT_MODAL => {
+                if let Some(content) = self.repair_report_content.as_mut() {
+                    content.ui(ui, modal);
+                }
+            }
             _ => {}
         }
     }
@@ -77,10 +89,13 @@ impl WalletContent {
         let mut content = Self {
             wallet,
             accounts_modal_content: None,
+            repair_report_content: None,
             qr_scan_content: None,
+            clipboard_data: None,
             current_tab: Box::new(WalletTransactions::default()),
             allowed_modal_ids: vec![
                 ACCOUNT_LIST_MODAL,
+                REPAIR_REPORT_MODAL,
             ],
         };
         if data.is_some() {
@@ -91,14 +106,126 @@ impl WalletContent {
 
     /// Handle data from deeplink or opened file.
     pub fn on_data(&mut self, data: Option<String>) {
+        // Show sending over Tor for payment request.
+        if let Some(uri) = data.as_ref().and_then(|d| PaymentUri::parse(d)) {
+            self.current_tab = Box::new(WalletTransport::from_uri(&uri));
+            return;
+        }
         self.current_tab = Box::new(WalletMessages::new(data));
     }
 
+    /// Detect Slatepack message or address copied to clipboard and draw banner to open it.
+    fn clipboard_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        // Check clipboard when window is focused.
+        let focused = ui.ctx().input(|i| i.viewport().focused.unwrap_or(true));
+        if AppConfig::clipboard_watch() && focused {
+            if let Some(text) = cb.clipboard_changed() {
+                let text = text.trim().to_string();
+                let is_address = SlatepackAddress::try_from(text.as_str()).is_ok();
+                if is_address || self.wallet.parse_slatepack(&text).is_ok() {
+                    self.clipboard_data = Some(text);
+                }
+            }
+        }
+        let show_banner = self.clipboard_data.is_some() && self.qr_scan_content.is_none() &&
+            !Self::block_navigation_on_sync(&self.wallet);
+        egui::TopBottomPanel::top(Id::from("wallet_clipboard").with(self.wallet.identifier()))
+            .frame(egui::Frame {
+                inner_margin: Margin {
+                    left: View::far_left_inset_margin(ui) + 4.0,
+                    right: View::get_right_inset() + 4.0,
+                    top: 4.0,
+                    bottom: 4.0,
+                },
+                fill: Colors::fill(),
+                ..Default::default()
+            })
+            .show_animated_inside(ui, show_banner, |ui| {
+                let data = self.clipboard_data.clone().unwrap_or_default();
+                let is_address = SlatepackAddress::try_from(data.as_str()).is_ok();
+                View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        // Show button to hide banner.
+                        View::button(ui, X.to_string(), Colors::white_or_black(false), || {
+                            self.clipboard_data = None;
+                        });
+                        ui.add_space(4.0);
+                        // Show button to open copied data.
+                        let open_text = format!("{} {}",
+                                                ARROW_SQUARE_OUT,
+                                                t!("wallets.clipboard_open"));
+                        View::button(ui, open_text, Colors::white_or_black(false), || {
+                            let data = if is_address {
+                                PaymentUri::new(data.clone(), None, None).to_uri()
+                            } else {
+                                data.clone()
+                            };
+                            self.clipboard_data = None;
+                            self.on_data(Some(data));
+                        });
+                        ui.add_space(6.0);
+                        let text = if is_address {
+                            t!("wallets.clipboard_address")
+                        } else {
+                            t!("wallets.clipboard_message")
+                        };
+                        ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                            View::ellipsize_text(ui,
+                                                 format!("{} {}", CLIPBOARD_TEXT, text),
+                                                 16.0,
+                                                 Colors::text(false));
+                        });
+                    });
+                });
+            });
+    }
+
+    /// Show provided tab or list of accounts from navigation request.
+    pub fn on_route(&mut self, tab: Option<WalletTabType>, accounts: bool) {
+        if let Some(tab) = tab {
+            self.current_tab = match tab {
+                WalletTabType::Txs => Box::new(WalletTransactions::default()),
+                WalletTabType::Invoices => Box::new(WalletInvoices::default()),
+                WalletTabType::Messages => Box::new(WalletMessages::new(None)),
+                WalletTabType::Transport => Box::new(WalletTransport::default()),
+                WalletTabType::Settings => Box::new(WalletSettings::default())
+            };
+        }
+        if accounts {
+            self.accounts_modal_content = Some(WalletAccountsModal::new(self.wallet.accounts()));
+            Modal::new(ACCOUNT_LIST_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("wallets.accounts"))
+                .show();
+        }
+    }
+
     /// Draw wallet content.
     pub fn ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         ui.ctx().request_repaint_after(Duration::from_millis(1000));
         self.current_modal_ui(ui, cb);
 
+        // Show report of completed wallet repair.
+        if Modal::opened().is_none() {
+            if let Some(entry) = self.wallet.completed_repair() {
+                self.wallet.clear_completed_repair();
+                self.repair_report_content = Some(RepairReportModal::new(vec![entry]));
+                Modal::new(REPAIR_REPORT_MODAL)
+                    .position(ModalPosition::Center)
+                    .title(t!("wallets.repair_report"))
+                    .show();
+            }
+        }
+
+        // Show replayed wallet data fixture in read-only mode instead of wallet content.
+        if let Some(data) = self.wallet.fixture_data() {
+            self.fixture_ui(ui, &data);
+            return;
+        }
+
+        // Show banner for Slatepack message or address copied to clipboard.
+        self.clipboard_ui(ui, cb);
+
         let dual_panel = Content::is_dual_panel_mode(ui.ctx());
         let show_wallets_dual = AppConfig::show_wallets_at_dual_panel();
 
@@ -249,15 +376,49 @@ impl WalletContent {
             });
     }
 
+    /// Draw read-only content of replayed wallet data fixture.
+    fn fixture_ui(&mut self, ui: &mut egui::Ui, data: &WalletData) {
+        egui::CentralPanel::default()
+            .frame(egui::Frame {
+                inner_margin: Margin {
+                    left: View::far_left_inset_margin(ui) + 4.0,
+                    right: View::get_right_inset() + 4.0,
+                    top: 4.0,
+                    bottom: View::get_bottom_inset() + 4.0,
+                },
+                ..Default::default()
+            })
+            .show_inside(ui, |ui| {
+                View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(4.0);
+                        ui.label(RichText::new(format!("{} {}",
+                                                       BUG,
+                                                       t!("wallets.fixture_replayed")))
+                            .size(16.0)
+                            .color(Colors::inactive_text()));
+                        ui.add_space(6.0);
+                        let close_text = format!("{} {}", X, t!("close"));
+                        View::button(ui, close_text, Colors::white_or_black(false), || {
+                            self.wallet.close_fixture();
+                        });
+                        ui.add_space(8.0);
+                    });
+                });
+                WalletTransactions::fixture_ui(ui, data);
+            });
+    }
+
     /// Check when to block tabs navigation on sync progress.
     pub fn block_navigation_on_sync(wallet: &Wallet) -> bool {
         let sync_error = wallet.sync_error();
         let integrated_node = wallet.get_current_connection() == ConnectionMethod::Integrated;
         let integrated_node_ready = Node::get_sync_status() == Some(SyncStatus::NoSync);
         let sync_after_opening = wallet.get_data().is_none() && !wallet.sync_error();
-        // Block navigation if wallet is repairing and integrated node is not launching
-        // and if wallet is closing or syncing after opening when there is no data to show.
-        (wallet.is_repairing() && (integrated_node_ready || !integrated_node) && !sync_error)
+        // Block navigation if wallet is repairing without data to browse and integrated node
+        // is not launching and if wallet is closing or syncing after opening when there is no data.
+        (wallet.is_repairing() && wallet.get_data().is_none() &&
+            (integrated_node_ready || !integrated_node) && !sync_error)
             || wallet.is_closing() || (sync_after_opening &&
             (!integrated_node || integrated_node_ready))
     }
@@ -297,51 +458,58 @@ impl WalletContent {
                 ui.vertical(|ui| {
                     ui.add_space(3.0);
                     // Show spendable amount.
-                    let amount = amount_to_hr_string(data.info.amount_currently_spendable, true);
-                    let amount_text = format!("{} {}", amount, GRIN);
+                    let spendable = data.info.amount_currently_spendable;
+                    let amount_text = format!("{} {}", amount_to_display(spendable), GRIN);
                     ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
                         ui.add_space(1.0);
-                        ui.label(RichText::new(amount_text)
+                        let amount_resp = ui.label(RichText::new(amount_text)
                             .size(18.0)
                             .color(Colors::white_or_black(true)));
+                        full_amount_hover(amount_resp, spendable);
                     });
                     ui.add_space(-2.0);
 
                     // Show account label.
                     let account = self.wallet.get_config().account;
-                    let default_acc_label = WalletConfig::DEFAULT_ACCOUNT_LABEL.to_string();
-                    let acc_label = if account == default_acc_label {
-                        t!("wallets.default_account")
-                    } else {
-                        account.to_owned()
-                    };
+                    let acc_label = account_label(&self.wallet, &account);
                     let acc_text = format!("{} {}", FOLDER_USER, acc_label);
                     View::ellipsize_text(ui, acc_text, 15.0, Colors::text(false));
 
                     // Show confirmed height or sync progress.
-                    let status_text = if !self.wallet.syncing() {
+                    let status_text = if self.wallet.is_repairing() {
+                        let rep_progress = self.wallet.repairing_progress();
+                        let mut text = if rep_progress == 0 {
+                            format!("{} {}", SPINNER, t!("wallets.wallet_checking"))
+                        } else {
+                            format!("{} {}: {}%",
+                                    SPINNER,
+                                    t!("wallets.wallet_checking"),
+                                    rep_progress)
+                        };
+                        let queued = self.wallet.queued_actions_count();
+                        if queued > 0 {
+                            text = format!("{} ({})",
+                                           text,
+                                           t!("wallets.actions_queued", "count" => queued));
+                        }
+                        text
+                    } else if self.wallet.is_data_stale() {
+                        // Show saved data badge until sync will be finished.
+                        format!("{} {} ({})",
+                                CLOCK_COUNTER_CLOCKWISE,
+                                data.info.last_confirmed_height,
+                                t!("wallets.stale_data"))
+                    } else if !self.wallet.syncing() {
                         format!("{} {}", PACKAGE, data.info.last_confirmed_height)
                     } else {
                         let info_progress = self.wallet.info_sync_progress();
                         if info_progress == 100 || info_progress == 0 {
                             format!("{} {}", SPINNER, t!("wallets.wallet_loading"))
                         } else {
-                            if self.wallet.is_repairing() {
-                                let rep_progress = self.wallet.repairing_progress();
-                                if rep_progress == 0 {
-                                    format!("{} {}", SPINNER, t!("wallets.wallet_checking"))
-                                } else {
-                                    format!("{} {}: {}%",
-                                            SPINNER,
-                                            t!("wallets.wallet_checking"),
-                                            rep_progress)
-                                }
-                            } else {
-                                format!("{} {}: {}%",
-                                        SPINNER,
-                                        t!("wallets.wallet_loading"),
-                                        info_progress)
-                            }
+                            format!("{} {}: {}%",
+                                    SPINNER,
+                                    t!("wallets.wallet_loading"),
+                                    info_progress)
                         }
                     };
                     View::animate_text(ui,
@@ -377,13 +545,19 @@ impl WalletContent {
             ui.style_mut().spacing.button_padding = egui::vec2(0.0, 4.0);
 
             let current_type = self.current_tab.get_type();
-            ui.columns(4, |columns| {
+            ui.columns(5, |columns| {
                 columns[0].vertical_centered_justified(|ui| {
                     View::tab_button(ui, GRAPH, current_type == WalletTabType::Txs, |_| {
                         self.current_tab = Box::new(WalletTransactions::default());
                     });
                 });
                 columns[1].vertical_centered_justified(|ui| {
+                    let is_invoices = current_type == WalletTabType::Invoices;
+                    View::tab_button(ui, RECEIPT, is_invoices, |_| {
+                        self.current_tab = Box::new(WalletInvoices::default());
+                    });
+                });
+                columns[2].vertical_centered_justified(|ui| {
                     let is_messages = current_type == WalletTabType::Messages;
                     View::tab_button(ui, CHAT_CIRCLE_TEXT, is_messages, |_| {
                         self.current_tab = Box::new(
@@ -391,12 +565,12 @@ impl WalletContent {
                         );
                     });
                 });
-                columns[2].vertical_centered_justified(|ui| {
+                columns[3].vertical_centered_justified(|ui| {
                     View::tab_button(ui, BRIDGE, current_type == WalletTabType::Transport, |_| {
                         self.current_tab = Box::new(WalletTransport::default());
                     });
                 });
-                columns[3].vertical_centered_justified(|ui| {
+                columns[4].vertical_centered_justified(|ui| {
                     View::tab_button(ui, GEAR_FINE, current_type == WalletTabType::Settings, |ui| {
                         ExternalConnection::check(None, ui.ctx());
                         self.current_tab = Box::new(WalletSettings::default());
@@ -409,7 +583,7 @@ impl WalletContent {
 
 /// Draw content when wallet is syncing and not ready to use, returns `true` at this case.
 fn sync_ui(ui: &mut egui::Ui, wallet: &Wallet) -> bool {
-    if wallet.is_repairing() && !wallet.sync_error() {
+    if wallet.is_repairing() && !wallet.sync_error() && wallet.get_data().is_none() {
         sync_progress_ui(ui, wallet);
         return true;
     } else if wallet.is_closing() {
@@ -475,13 +649,16 @@ fn sync_progress_ui(ui: &mut egui::Ui, wallet: &Wallet) {
             // Setup sync progress text.
             let text = {
                 let int_node = wallet.get_current_connection() == ConnectionMethod::Integrated;
-                let int_ready = Node::get_sync_status() == Some(SyncStatus::NoSync);
+                let int_synced = Node::get_sync_status() == Some(SyncStatus::NoSync);
+                let int_ready = Node::is_ready_for_wallets();
                 let info_progress = wallet.info_sync_progress();
 
                 if wallet.is_closing() {
                     t!("wallets.wallet_closing")
-                } else if int_node && !int_ready {
+                } else if int_node && !int_synced {
                     t!("wallets.node_loading", "settings" => GEAR_FINE)
+                } else if int_node && !int_ready {
+                    t!("wallets.node_not_ready")
                 } else if wallet.is_repairing() {
                     let repair_progress = wallet.repairing_progress();
                     if repair_progress == 0 {
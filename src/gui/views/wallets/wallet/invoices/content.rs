@@ -0,0 +1,341 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Align, Layout, RichText, Rounding};
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::Colors;
+use crate::gui::icons::{ARCHIVE, ARCHIVE_TRAY, CALENDAR_CHECK, CARET_LEFT, CARET_RIGHT, CHECK, DOTS_THREE_CIRCLE, HOURGLASS_LOW, PROHIBIT, RECEIPT, SHARE_FAT, TIMER};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::ModalPosition;
+use crate::gui::views::wallets::wallet::types::{amount_to_display, full_amount_hover, GRIN, WalletTab, WalletTabType};
+use crate::gui::views::wallets::wallet::WalletTransactionModal;
+use crate::wallet::types::WalletTransaction;
+use crate::wallet::Wallet;
+
+/// Wallet invoices tab content.
+pub struct WalletInvoices {
+    /// Invoice transaction information [`Modal`] content.
+    tx_info_content: Option<WalletTransactionModal>,
+
+    /// Invoice transaction identifier to use at cancellation confirmation [`Modal`].
+    confirm_cancel_tx_id: Option<u32>,
+
+    /// Flag to show invoices marked as abandoned.
+    show_abandoned: bool,
+}
+
+impl Default for WalletInvoices {
+    fn default() -> Self {
+        Self {
+            tx_info_content: None,
+            confirm_cancel_tx_id: None,
+            show_abandoned: false,
+        }
+    }
+}
+
+impl WalletTab for WalletInvoices {
+    fn get_type(&self) -> WalletTabType {
+        WalletTabType::Invoices
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        self.modal_content_ui(ui, wallet, cb);
+        self.invoices_ui(ui, wallet, cb);
+    }
+}
+
+/// Identifier for invoice information [`Modal`].
+const INVOICE_INFO_MODAL: &'static str = "invoice_info_modal";
+/// Identifier for invoice cancellation confirmation [`Modal`].
+const CANCEL_INVOICE_CONFIRMATION_MODAL: &'static str = "cancel_invoice_conf_modal";
+
+/// Available periods in hours after which invoice is considered expired.
+const EXPIRY_OPTIONS: [i64; 6] = [1, 6, 12, 24, 72, 168];
+
+impl WalletInvoices {
+    /// Height of invoice list item.
+    const INVOICE_ITEM_HEIGHT: f32 = 75.0;
+
+    /// Draw invoices content.
+    fn invoices_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        let loaded = wallet.get_data().map(|d| d.txs.is_some()).unwrap_or(false);
+        if !loaded {
+            ui.centered_and_justified(|ui| {
+                View::big_loading_spinner(ui);
+            });
+            return;
+        }
+
+        // Draw expiration period selection.
+        self.expiry_ui(ui, wallet);
+
+        let invoices = wallet.invoices();
+        let abandoned_count = invoices.iter().filter(|tx| tx.abandoned.is_some()).count();
+        if abandoned_count > 0 {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                let text = format!("{} ({})", t!("wallets.invoices_show_abandoned"), abandoned_count);
+                View::checkbox(ui, self.show_abandoned, text, || {
+                    self.show_abandoned = !self.show_abandoned;
+                });
+            });
+        }
+        ui.add_space(8.0);
+
+        let invoices = invoices.into_iter()
+            .filter(|tx| self.show_abandoned || tx.abandoned.is_none())
+            .collect::<Vec<WalletTransaction>>();
+        if invoices.is_empty() {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                let empty_text = format!("{} {}", RECEIPT, t!("wallets.invoices_empty"));
+                ui.label(RichText::new(empty_text)
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+            return;
+        }
+
+        // Show list of invoices.
+        let expiry = wallet.invoice_expiry() * 60 * 60;
+        let wallet_loaded = wallet.foreign_api_port().is_some();
+        for (index, tx) in invoices.iter().enumerate() {
+            let mut rect = ui.available_rect_before_wrap();
+            rect.set_height(Self::INVOICE_ITEM_HEIGHT);
+
+            // Draw invoice item background.
+            let mut r = View::item_rounding(index, invoices.len(), false);
+            let p = ui.painter();
+            p.rect(rect, r, Colors::fill_lite(), View::item_stroke());
+
+            invoice_item_ui(ui, tx, rect, expiry, |ui| {
+                // Draw button to share invoice message.
+                r.nw = 0.0;
+                r.sw = 0.0;
+                View::item_button(ui, r, SHARE_FAT, None, || {
+                    self.show_info_modal(wallet, tx, false);
+                });
+
+                // Draw button to finalize invoice.
+                if wallet_loaded && tx.can_finalize {
+                    let (icon, color) = (CHECK, Some(Colors::green()));
+                    View::item_button(ui, Rounding::default(), icon, color, || {
+                        cb.hide_keyboard();
+                        self.show_info_modal(wallet, tx, true);
+                    });
+                }
+
+                // Draw button to mark invoice as abandoned or restore it.
+                let abandoned = tx.abandoned.is_some();
+                let icon = if abandoned { ARCHIVE_TRAY } else { ARCHIVE };
+                View::item_button(ui, Rounding::default(), icon, None, || {
+                    wallet.update_invoice_abandoned(&tx.data, !abandoned);
+                });
+
+                // Draw button to cancel invoice.
+                if wallet_loaded && tx.can_cancel() {
+                    let (icon, color) = (PROHIBIT, Some(Colors::red()));
+                    View::item_button(ui, Rounding::default(), icon, color, || {
+                        self.confirm_cancel_tx_id = Some(tx.data.id);
+                        // Show invoice cancellation confirmation modal.
+                        Modal::new(CANCEL_INVOICE_CONFIRMATION_MODAL)
+                            .position(ModalPosition::Center)
+                            .title(t!("confirmation"))
+                            .show();
+                    });
+                }
+            });
+        }
+        ui.add_space(6.0);
+    }
+
+    /// Draw invoice expiration period selection.
+    fn expiry_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) {
+        let expiry = wallet.invoice_expiry();
+        let index = EXPIRY_OPTIONS.iter().position(|h| *h == expiry).unwrap_or(3);
+        ui.add_space(4.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.invoice_expiry"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                // Center content horizontally.
+                let w = ui.available_width();
+                ui.add_space((w - 164.0).max(0.0) / 2.0);
+                View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                    let prev = if index == 0 { EXPIRY_OPTIONS.len() - 1 } else { index - 1 };
+                    wallet.update_invoice_expiry(EXPIRY_OPTIONS[prev]);
+                });
+                let layout = egui::Layout::centered_and_justified(egui::Direction::LeftToRight);
+                ui.allocate_ui_with_layout(egui::vec2(100.0, 32.0), layout, |ui| {
+                    let text = format!("{} {}", TIMER, t!("wallets.hours", "value" => expiry));
+                    ui.label(RichText::new(text)
+                        .size(17.0)
+                        .color(Colors::white_or_black(true)));
+                });
+                View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                    let next = (index + 1) % EXPIRY_OPTIONS.len();
+                    wallet.update_invoice_expiry(EXPIRY_OPTIONS[next]);
+                });
+            });
+        });
+    }
+
+    /// Draw [`Modal`] content for this ui container.
+    fn modal_content_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        cb: &dyn PlatformCallbacks) {
+        match Modal::opened() {
+            None => {}
+            Some(id) => {
+                match id {
+                    INVOICE_INFO_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            if let Some(content) = self.tx_info_content.as_mut() {
+                                content.ui(ui, wallet, modal, cb);
+                            }
+                        });
+                    }
+                    CANCEL_INVOICE_CONFIRMATION_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.cancel_confirmation_modal(ui, wallet, modal);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Show invoice information [`Modal`] to share message or finalize.
+    fn show_info_modal(&mut self, wallet: &Wallet, tx: &WalletTransaction, finalize: bool) {
+        let modal = WalletTransactionModal::new(wallet, tx, finalize);
+        self.tx_info_content = Some(modal);
+        Modal::new(INVOICE_INFO_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("wallets.tx"))
+            .show();
+    }
+
+    /// Confirmation [`Modal`] to cancel invoice.
+    fn cancel_confirmation_modal(&mut self, ui: &mut egui::Ui, wallet: &Wallet, modal: &Modal) {
+        let tx = wallet.invoices()
+            .into_iter()
+            .find(|tx| Some(tx.data.id) == self.confirm_cancel_tx_id);
+        if tx.is_none() {
+            modal.close();
+            return;
+        }
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            let amount = amount_to_hr_string(tx.unwrap().amount, true);
+            let text = t!("wallets.tx_receive_cancel_conf", "amount" => amount);
+            ui.label(RichText::new(text)
+                .size(17.0)
+                .color(Colors::text(false)));
+            ui.add_space(8.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        self.confirm_cancel_tx_id = None;
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, "OK".to_string(), Colors::white_or_black(false), || {
+                        wallet.cancel(self.confirm_cancel_tx_id.unwrap());
+                        self.confirm_cancel_tx_id = None;
+                        modal.close();
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
+
+/// Draw invoice item content with provided expiration period in seconds.
+fn invoice_item_ui(ui: &mut egui::Ui,
+                   tx: &WalletTransaction,
+                   rect: egui::Rect,
+                   expiry: i64,
+                   buttons_ui: impl FnOnce(&mut egui::Ui)) {
+    let created = tx.data.creation_ts.timestamp();
+    let expires = created + expiry;
+    let expired = chrono::Utc::now().timestamp() > expires;
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Max), |ui| {
+        ui.horizontal_centered(|ui| {
+            // Draw buttons.
+            buttons_ui(ui);
+        });
+
+        ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+
+                // Draw invoice amount.
+                ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                    ui.add_space(1.0);
+                    let amount_text = format!("+{} {}", amount_to_display(tx.amount), GRIN);
+                    let amount_job = View::ellipsize(amount_text,
+                                                     18.0,
+                                                     Colors::white_or_black(true));
+                    full_amount_hover(ui.label(amount_job), tx.amount);
+                });
+                ui.add_space(-2.0);
+
+                // Draw invoice state.
+                let (status_text, status_color) = if tx.cancelling {
+                    (format!("{} {}", DOTS_THREE_CIRCLE, t!("wallets.tx_cancelling")),
+                     Colors::text(false))
+                } else if tx.finalizing {
+                    (format!("{} {}", DOTS_THREE_CIRCLE, t!("wallets.tx_finalizing")),
+                     Colors::text(false))
+                } else if tx.abandoned.is_some() {
+                    (format!("{} {}", ARCHIVE, t!("wallets.invoice_abandoned")),
+                     Colors::inactive_text())
+                } else if expired {
+                    (format!("{} {}", HOURGLASS_LOW, t!("wallets.invoice_expired")),
+                     Colors::red())
+                } else {
+                    (format!("{} {}", DOTS_THREE_CIRCLE, t!("wallets.invoice_awaiting")),
+                     Colors::text(false))
+                };
+                ui.label(RichText::new(status_text).size(15.0).color(status_color));
+
+                // Draw creation and expiration time.
+                let time_text = format!("{} {}  {} {}",
+                                        CALENDAR_CHECK,
+                                        View::format_time(created),
+                                        TIMER,
+                                        View::format_time(expires));
+                View::ellipsize_text(ui, time_text, 15.0, Colors::gray());
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
@@ -29,7 +29,7 @@ use crate::gui::views::wallets::wallet::messages::request::MessageRequestModal;
 use crate::gui::views::wallets::wallet::types::{SLATEPACK_MESSAGE_HINT, WalletTab, WalletTabType};
 use crate::gui::views::wallets::wallet::WalletTransactionModal;
 use crate::wallet::types::WalletTransaction;
-use crate::wallet::Wallet;
+use crate::wallet::{SlateHandlers, Wallet};
 
 /// Slatepack messages interaction tab content.
 pub struct WalletMessages {
@@ -106,6 +106,17 @@ impl WalletMessages {
         }
         ui.add_space(3.0);
 
+        // Show only information while wallet is repairing.
+        if wallet.is_repairing() {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.repair_read_only"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+            return;
+        }
+
         // Show creation of request to send or receive funds.
         self.request_ui(ui, wallet, cb);
 
@@ -447,32 +458,13 @@ impl WalletMessages {
             }
 
             // Create response or finalize at separate thread.
-            let sl = slate.clone();
             let message = self.message_edit.clone();
             let message_result = self.message_result.clone();
             let wallet = wallet.clone();
 
             self.message_loading = true;
             thread::spawn(move || {
-                let result = match slate.state {
-                    SlateState::Standard1 | SlateState::Invoice1 => {
-                        if sl.state != SlateState::Standard1 {
-                            wallet.pay(&message)
-                        } else {
-                            wallet.receive(&message)
-                        }
-                    }
-                    SlateState::Standard2 | SlateState::Invoice2 => {
-                        wallet.finalize(&message)
-                    }
-                    _ => {
-                        if let Some(tx) = wallet.tx_by_slate(&slate) {
-                            Ok(tx)
-                        } else {
-                            Err(Error::GenericError(t!("wallets.parse_slatepack_err")))
-                        }
-                    }
-                };
+                let result = SlateHandlers::handle(&wallet, &slate, &message);
                 let mut w_res = message_result.write();
                 *w_res = Some((slate, result));
             });
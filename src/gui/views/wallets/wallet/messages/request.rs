@@ -23,6 +23,7 @@ use crate::gui::Colors;
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::TextEditOptions;
+use crate::gui::views::wallets::wallet::types::{fee_factor_ui, send_preview_ui};
 use crate::gui::views::wallets::wallet::WalletTransactionModal;
 use crate::wallet::types::WalletTransaction;
 use crate::wallet::Wallet;
@@ -42,6 +43,15 @@ pub struct MessageRequestModal {
     /// Flag to check if there is an error happened on request creation.
     request_error: Option<String>,
 
+    /// Estimated fee to confirm sending request.
+    send_fee: Option<u64>,
+    /// Flag to check if fee is estimating.
+    fee_estimating: bool,
+    /// Fee estimation result with used fee base multiplier.
+    fee_result: Arc<RwLock<Option<(u64, Result<u64, Error>)>>>,
+    /// Multiplier of network fee base for sending request.
+    fee_factor: u64,
+
     /// Request result transaction content.
     result_tx_content: Option<WalletTransactionModal>,
 }
@@ -55,6 +65,10 @@ impl MessageRequestModal {
             request_loading: false,
             request_result: Arc::new(RwLock::new(None)),
             request_error: None,
+            send_fee: None,
+            fee_estimating: false,
+            fee_result: Arc::new(RwLock::new(None)),
+            fee_factor: 1,
             result_tx_content: None,
         }
     }
@@ -79,7 +93,22 @@ impl MessageRequestModal {
             return;
         }
 
-        // Draw amount input content.
+        // Draw content on fee estimation.
+        self.check_fee_result(cb);
+        if self.fee_estimating && self.send_fee.is_none() {
+            ui.add_space(34.0);
+            ui.vertical_centered(|ui| {
+                View::big_loading_spinner(ui);
+            });
+            ui.add_space(50.0);
+            return;
+        }
+
+        // Draw sending preview to confirm or amount input content.
+        if let Some(fee) = self.send_fee {
+            self.send_confirmation_ui(ui, wallet, modal, cb, fee);
+            return;
+        }
         self.amount_input_ui(ui, wallet, modal, cb);
 
         // Show request creation error.
@@ -107,29 +136,20 @@ impl MessageRequestModal {
                 });
             });
             columns[1].vertical_centered_justified(|ui| {
-                // Button to create Slatepack message request.
-                View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                // Continue button callback to create Slatepack message request.
+                let mut on_continue = || {
                     if self.amount_edit.is_empty() {
                         return;
                     }
                     if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
                         cb.hide_keyboard();
-                        modal.disable_closing();
-                        // Setup data for request.
-                        let wallet = wallet.clone();
-                        let invoice = self.invoice.clone();
-                        let result = self.request_result.clone();
-                        // Send request at another thread.
-                        self.request_loading = true;
-                        thread::spawn(move || {
-                            let res = if invoice {
-                                wallet.issue_invoice(a)
-                            } else {
-                                wallet.send(a, None)
-                            };
-                            let mut w_result = result.write();
-                            *w_result = Some(res);
-                        });
+                        if self.invoice {
+                            self.create_request(wallet, modal, a);
+                            return;
+                        }
+                        // Estimate fee to show sending preview before outputs locking.
+                        self.request_error = None;
+                        self.estimate_fee(wallet, a);
                     } else {
                         let err = if self.invoice {
                             t!("wallets.invoice_slatepack_err")
@@ -138,12 +158,141 @@ impl MessageRequestModal {
                         };
                         self.request_error = Some(err);
                     }
+                };
+
+                View::on_enter_key(ui, || {
+                    (on_continue)();
                 });
+
+                View::button(ui, t!("continue"), Colors::white_or_black(false), on_continue);
             });
         });
         ui.add_space(6.0);
     }
 
+    /// Draw sending preview content with confirmation buttons.
+    fn send_confirmation_ui(&mut self,
+                            ui: &mut egui::Ui,
+                            wallet: &Wallet,
+                            modal: &Modal,
+                            cb: &dyn PlatformCallbacks,
+                            fee: u64) {
+        let amount = amount_from_hr_string(self.amount_edit.as_str()).unwrap_or(0);
+        send_preview_ui(ui, wallet, amount, fee);
+
+        // Show fee setup and update estimated fee on change.
+        if fee_factor_ui(ui, &mut self.fee_factor) {
+            self.estimate_fee(wallet, amount);
+        }
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.send_fee = None;
+                    self.fee_estimating = false;
+                    cb.show_keyboard();
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                // Wait for fee estimation after multiplier change to confirm.
+                if self.fee_estimating {
+                    View::small_loading_spinner(ui);
+                    return;
+                }
+                View::button(ui, t!("confirm"), Colors::white_or_black(false), || {
+                    self.send_fee = None;
+                    self.create_request(wallet, modal, amount);
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Estimate fee for provided amount at separate thread.
+    fn estimate_fee(&mut self, wallet: &Wallet, amount: u64) {
+        let wallet = wallet.clone();
+        let fee_factor = self.fee_factor;
+        let result = self.fee_result.clone();
+        self.fee_estimating = true;
+        {
+            let mut w_result = self.fee_result.write();
+            *w_result = None;
+        }
+        thread::spawn(move || {
+            let res = wallet.estimate_fee(amount, fee_factor);
+            let mut w_result = result.write();
+            *w_result = Some((fee_factor, res));
+        });
+    }
+
+    /// Check fee estimation result to show sending preview or an error.
+    fn check_fee_result(&mut self, cb: &dyn PlatformCallbacks) {
+        if !self.fee_estimating {
+            return;
+        }
+        let res = {
+            let mut w_result = self.fee_result.write();
+            w_result.take()
+        };
+        if let Some((fee_factor, res)) = res {
+            // Skip result of previous estimation when multiplier was changed.
+            if fee_factor != self.fee_factor {
+                return;
+            }
+            self.fee_estimating = false;
+            match res {
+                Ok(fee) => self.send_fee = Some(fee),
+                Err(e) => {
+                    self.send_fee = None;
+                    self.on_request_error(&e);
+                    cb.show_keyboard();
+                }
+            }
+        }
+    }
+
+    /// Create invoice or sending request for provided amount at separate thread.
+    fn create_request(&mut self, wallet: &Wallet, modal: &Modal, amount: u64) {
+        modal.disable_closing();
+        // Setup data for request.
+        let wallet = wallet.clone();
+        let invoice = self.invoice.clone();
+        let fee_factor = self.fee_factor;
+        let result = self.request_result.clone();
+        // Send request at another thread.
+        self.request_loading = true;
+        thread::spawn(move || {
+            let res = if invoice {
+                wallet.issue_invoice(amount)
+            } else {
+                wallet.send(amount, None, fee_factor)
+            };
+            let mut w_result = result.write();
+            *w_result = Some(res);
+        });
+    }
+
+    /// Setup error text on request creation error.
+    fn on_request_error(&mut self, err: &Error) {
+        let m = match err {
+            Error::NotEnoughFunds { .. } => {
+                t!("wallets.pay_balance_error", "amount" => self.amount_edit)
+            }
+            _ => {
+                if self.invoice {
+                    t!("wallets.invoice_slatepack_err")
+                } else {
+                    t!("wallets.send_slatepack_err")
+                }
+            }
+        };
+        self.request_error = Some(m);
+    }
+
     /// Draw amount input content.
     fn amount_input_ui(&mut self,
                        ui: &mut egui::Ui,
@@ -166,9 +315,17 @@ impl MessageRequestModal {
 
         // Draw request amount text input.
         let amount_edit_id = Id::from(modal.id).with(wallet.get_config().id);
-        let mut amount_edit_opts = TextEditOptions::new(amount_edit_id).h_center();
+        let mut amount_edit_opts = TextEditOptions::new(amount_edit_id)
+            .h_center()
+            .keypad(!self.invoice);
         let amount_edit_before = self.amount_edit.clone();
         View::text_edit(ui, cb, &mut self.amount_edit, &mut amount_edit_opts);
+        // Enter maximum amount to send if requested.
+        if amount_edit_opts.max_pressed {
+            if let Ok(max) = wallet.max_send_amount(self.fee_factor) {
+                self.amount_edit = amount_to_hr_string(max, true);
+            }
+        }
 
         // Check value if input was changed.
         if amount_edit_before != self.amount_edit {
@@ -235,23 +392,9 @@ impl MessageRequestModal {
                     self.result_tx_content = Some(WalletTransactionModal::new(wallet, tx, false));
                 }
                 Err(err) => {
-                    match err {
-                        Error::NotEnoughFunds { .. } => {
-                            let m = t!(
-                                    "wallets.pay_balance_error",
-                                    "amount" => self.amount_edit
-                                );
-                            self.request_error = Some(m);
-                        }
-                        _ => {
-                            let m = if self.invoice {
-                                t!("wallets.invoice_slatepack_err")
-                            } else {
-                                t!("wallets.send_slatepack_err")
-                            };
-                            self.request_error = Some(m);
-                        }
-                    }
+                    let err = err.clone();
+                    drop(r_request);
+                    self.on_request_error(&err);
                     self.request_loading = false;
                 }
             }
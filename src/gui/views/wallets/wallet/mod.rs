@@ -20,6 +20,9 @@ pub use settings::*;
 mod txs;
 pub use txs::*;
 
+mod invoices;
+pub use invoices::WalletInvoices;
+
 mod messages;
 pub use messages::WalletMessages;
 
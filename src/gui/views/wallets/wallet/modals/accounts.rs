@@ -12,18 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{Align, Id, Layout, RichText, ScrollArea};
+use egui::{Align, Id, Layout, RichText, Rounding, ScrollArea};
 use egui::scroll_area::ScrollBarVisibility;
-use grin_core::core::amount_to_hr_string;
 
 use crate::gui::Colors;
-use crate::gui::icons::{CHECK, CHECK_FAT, FOLDER_USER, PATH};
+use crate::gui::icons::{ARCHIVE, ARCHIVE_TRAY, CHECK, CHECK_FAT, FOLDER_USER, PATH, PENCIL};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::TextEditOptions;
-use crate::gui::views::wallets::wallet::types::GRIN;
+use crate::gui::views::wallets::wallet::types::{account_label, amount_to_display, full_amount_hover, GRIN};
 use crate::wallet::types::WalletAccount;
-use crate::wallet::{Wallet, WalletConfig};
+use crate::wallet::Wallet;
 
 /// Wallet accounts [`Modal`] content.
 pub struct WalletAccountsModal {
@@ -35,6 +34,10 @@ pub struct WalletAccountsModal {
     account_label_edit: String,
     /// Flag to check if error occurred during account creation.
     account_creation_error: bool,
+    /// Label of account to rename.
+    account_renaming: Option<String>,
+    /// Flag to show archived accounts.
+    show_archived: bool,
 }
 
 impl Default for WalletAccountsModal {
@@ -44,6 +47,8 @@ impl Default for WalletAccountsModal {
             account_creating: false,
             account_label_edit: "".to_string(),
             account_creation_error: false,
+            account_renaming: None,
+            show_archived: false,
         }
     }
 }
@@ -56,6 +61,8 @@ impl WalletAccountsModal {
             account_creating: false,
             account_label_edit: "".to_string(),
             account_creation_error: false,
+            account_renaming: None,
+            show_archived: false,
         }
     }
 
@@ -65,7 +72,9 @@ impl WalletAccountsModal {
               wallet: &Wallet,
               modal: &Modal,
               cb: &dyn PlatformCallbacks) {
-        if self.account_creating {
+        if self.account_renaming.is_some() {
+            self.rename_ui(ui, wallet, modal, cb);
+        } else if self.account_creating {
             ui.add_space(6.0);
             ui.vertical_centered(|ui| {
                 ui.label(RichText::new(t!("wallets.new_account_desc"))
@@ -127,8 +136,12 @@ impl WalletAccountsModal {
         } else {
             ui.add_space(3.0);
 
-            // Show list of accounts.
-            let size = self.accounts.len();
+            // Show list of accounts without archived if not requested.
+            let accounts: Vec<WalletAccount> = self.accounts.iter()
+                .filter(|a| self.show_archived || !wallet.is_account_hidden(&a.label))
+                .cloned()
+                .collect();
+            let size = accounts.len();
             ScrollArea::vertical()
                 .id_salt("account_list_modal_scroll")
                 .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
@@ -140,14 +153,29 @@ impl WalletAccountsModal {
                         if index == 0 {
                             ui.add_space(4.0);
                         }
-                        let acc = self.accounts.get(index).unwrap();
-                        account_item_ui(ui, modal, wallet, acc, index, size);
+                        let acc = accounts.get(index).unwrap();
+                        account_item_ui(ui, modal, wallet, acc, index, size, |label| {
+                            self.account_label_edit = account_label(wallet, &label);
+                            self.account_renaming = Some(label);
+                            cb.show_keyboard();
+                        });
                         if index == size - 1 {
                             ui.add_space(4.0);
                         }
                     }
                 });
 
+            // Show checkbox to show archived accounts.
+            let has_archived = self.accounts.iter().any(|a| wallet.is_account_hidden(&a.label));
+            if has_archived {
+                ui.add_space(4.0);
+                ui.vertical_centered(|ui| {
+                    View::checkbox(ui, self.show_archived, t!("wallets.show_archived"), || {
+                        self.show_archived = !self.show_archived;
+                    });
+                });
+            }
+
             ui.add_space(2.0);
             View::horizontal_line(ui, Colors::item_stroke());
             ui.add_space(6.0);
@@ -173,6 +201,52 @@ impl WalletAccountsModal {
         }
     }
 
+    /// Draw account renaming content.
+    fn rename_ui(&mut self,
+                 ui: &mut egui::Ui,
+                 wallet: &Wallet,
+                 modal: &Modal,
+                 cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.rename_account_desc"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw account name edit.
+            let text_edit_id = Id::from(modal.id).with("rename").with(wallet.get_config().id);
+            let mut text_edit_opts = TextEditOptions::new(text_edit_id);
+            View::text_edit(ui, cb, &mut self.account_label_edit, &mut text_edit_opts);
+            ui.add_space(12.0);
+        });
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        // Show modal buttons.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    cb.hide_keyboard();
+                    self.account_renaming = None;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                let mut on_save = || {
+                    if let Some(label) = self.account_renaming.take() {
+                        wallet.rename_account(&label, self.account_label_edit.clone());
+                    }
+                    cb.hide_keyboard();
+                };
+                View::on_enter_key(ui, || {
+                    (on_save)();
+                });
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+            });
+        });
+        ui.add_space(6.0);
+    }
 }
 
 const ACCOUNT_ITEM_HEIGHT: f32 = 75.0;
@@ -183,7 +257,8 @@ fn account_item_ui(ui: &mut egui::Ui,
                    wallet: &Wallet,
                    acc: &WalletAccount,
                    index: usize,
-                   size: usize) {
+                   size: usize,
+                   on_rename: impl FnOnce(String)) {
     // Setup layout size.
     let mut rect = ui.available_rect_before_wrap();
     rect.set_height(ACCOUNT_ITEM_HEIGHT);
@@ -206,26 +281,39 @@ fn account_item_ui(ui: &mut egui::Ui,
             } else {
                 ui.add_space(12.0);
                 ui.label(RichText::new(CHECK_FAT).size(20.0).color(Colors::green()));
+                ui.add_space(12.0);
             }
 
+            // Draw button to archive or restore account.
+            if !is_current_account {
+                let hidden = wallet.is_account_hidden(&acc.label);
+                let archive_icon = if hidden { ARCHIVE_TRAY } else { ARCHIVE };
+                View::item_button(ui, Rounding::ZERO, archive_icon, None, || {
+                    wallet.toggle_account_hidden(&acc.label);
+                });
+            }
+
+            // Draw button to rename account.
+            View::item_button(ui, Rounding::ZERO, PENCIL, None, || {
+                on_rename(acc.label.clone());
+            });
+
             let layout_size = ui.available_size();
             ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
                 ui.add_space(6.0);
                 ui.vertical(|ui| {
                     ui.add_space(4.0);
                     // Show spendable amount.
-                    let amount = amount_to_hr_string(acc.spendable_amount, true);
+                    let amount = amount_to_display(acc.spendable_amount);
                     let amount_text = format!("{} {}", amount, GRIN);
-                    ui.label(RichText::new(amount_text).size(18.0).color(Colors::white_or_black(true)));
+                    let amount_resp = ui.label(RichText::new(amount_text)
+                        .size(18.0)
+                        .color(Colors::white_or_black(true)));
+                    full_amount_hover(amount_resp, acc.spendable_amount);
                     ui.add_space(-2.0);
 
                     // Show account name.
-                    let default_acc_label = WalletConfig::DEFAULT_ACCOUNT_LABEL.to_string();
-                    let acc_label = if acc.label == default_acc_label {
-                        t!("wallets.default_account")
-                    } else {
-                        acc.label.to_owned()
-                    };
+                    let acc_label = account_label(wallet, &acc.label);
                     let acc_name = format!("{} {}", FOLDER_USER, acc_label);
                     View::ellipsize_text(ui, acc_name, 15.0, Colors::text(false));
 
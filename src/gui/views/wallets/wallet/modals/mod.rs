@@ -13,4 +13,7 @@
 // limitations under the License.
 
 mod accounts;
-pub use accounts::*;
\ No newline at end of file
+pub use accounts::*;
+
+mod repair;
+pub use repair::*;
\ No newline at end of file
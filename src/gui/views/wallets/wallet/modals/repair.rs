@@ -0,0 +1,128 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{RichText, ScrollArea};
+use egui::scroll_area::ScrollBarVisibility;
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::Colors;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::wallets::types::GRIN;
+use crate::wallet::types::{RepairLogEntry, RepairReason, RepairReport};
+
+/// Completed wallet repairs [`Modal`] content.
+pub struct RepairReportModal {
+    /// Completed repairs from newest to oldest.
+    entries: Vec<RepairLogEntry>,
+}
+
+impl RepairReportModal {
+    /// Create new content instance for provided completed repairs.
+    pub fn new(entries: Vec<RepairLogEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Draw [`Modal`] content.
+    pub fn ui(&mut self, ui: &mut egui::Ui, modal: &Modal) {
+        ui.add_space(6.0);
+        if self.entries.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.repair_history_empty"))
+                    .size(17.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            ScrollArea::vertical()
+                .id_salt(("repair_report_modal_scroll", modal.id))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(320.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        for (i, entry) in self.entries.iter().enumerate() {
+                            if i != 0 {
+                                ui.add_space(6.0);
+                                View::horizontal_line(ui, Colors::item_stroke());
+                                ui.add_space(6.0);
+                            }
+                            Self::entry_ui(ui, entry);
+                        }
+                    });
+                });
+        }
+        ui.add_space(12.0);
+
+        // Show button to close modal.
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                modal.close();
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Draw completed repair time, reason and changes.
+    fn entry_ui(ui: &mut egui::Ui, entry: &RepairLogEntry) {
+        let reason = match entry.reason {
+            RepairReason::Manual => t!("wallets.repair_reason_manual"),
+            RepairReason::Check => t!("wallets.repair_reason_check"),
+            RepairReason::Restore => t!("wallets.repair_reason_restore")
+        };
+        ui.label(RichText::new(format!("{}, {}", View::format_time(entry.time), reason))
+            .size(15.0)
+            .color(Colors::gray()));
+        ui.add_space(6.0);
+        Self::report_ui(ui, &entry.report);
+    }
+
+    /// Draw changes made by wallet repair.
+    fn report_ui(ui: &mut egui::Ui, report: &RepairReport) {
+        if report.is_empty() && report.balance_delta == 0 {
+            ui.label(RichText::new(t!("wallets.repair_no_changes"))
+                .size(17.0)
+                .color(Colors::green()));
+            return;
+        }
+        let amount_text = |values: &Vec<u64>| {
+            let sum: u64 = values.iter().sum();
+            format!("{} {}", amount_to_hr_string(sum, true), GRIN)
+        };
+        let restored_text = t!("wallets.repair_restored",
+            "count" => report.restored_outputs.len(),
+            "amount" => amount_text(&report.restored_outputs));
+        let removed_text = t!("wallets.repair_removed",
+            "count" => report.spent_outputs.len(),
+            "amount" => amount_text(&report.spent_outputs));
+        let cancelled_text = t!("wallets.repair_cancelled",
+            "count" => report.cancelled_txs.len());
+        for text in [restored_text, removed_text, cancelled_text] {
+            ui.label(RichText::new(text).size(17.0).color(Colors::text(false)));
+            ui.add_space(4.0);
+        }
+
+        // Show balance difference.
+        let delta = report.balance_delta;
+        let (sign, color) = if delta > 0 {
+            ("+", Colors::green())
+        } else if delta < 0 {
+            ("-", Colors::red())
+        } else {
+            ("", Colors::text(false))
+        };
+        let delta_amount = amount_to_hr_string(delta.unsigned_abs(), true);
+        let balance_text = t!("wallets.repair_balance_delta",
+            "amount" => format!("{}{} {}", sign, delta_amount, GRIN));
+        ui.label(RichText::new(balance_text).size(17.0).color(color));
+    }
+}
@@ -15,11 +15,12 @@
 use egui::{Id, RichText};
 
 use crate::gui::Colors;
-use crate::gui::icons::{CLOCK_COUNTDOWN, PASSWORD, PENCIL};
+use crate::gui::icons::{CLOCK_COUNTDOWN, COPY, PASSWORD, PENCIL};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::{ModalPosition, TextEditOptions};
-use crate::wallet::Wallet;
+use crate::gui::views::wallets::wallet::types::{kdf_rounds_ui, password_strength_ui};
+use crate::wallet::{OwnerAPIHandler, StatusAPIHandler, Wallet};
 
 /// Common wallet settings content.
 pub struct CommonSettings {
@@ -34,6 +35,8 @@ pub struct CommonSettings {
     old_pass_edit: String,
     /// New wallet password [`Modal`] value.
     new_pass_edit: String,
+    /// Seed file password key derivation rounds [`Modal`] value.
+    kdf_rounds: Option<u32>,
 
     /// Minimum confirmations number value.
     min_confirmations_edit: String,
@@ -54,6 +57,7 @@ impl Default for CommonSettings {
             wrong_pass: false,
             old_pass_edit: "".to_string(),
             new_pass_edit: "".to_string(),
+            kdf_rounds: None,
             min_confirmations_edit: "".to_string(),
         }
     }
@@ -103,6 +107,7 @@ impl CommonSettings {
                 self.first_edit_pass_opening = true;
                 self.old_pass_edit = "".to_string();
                 self.new_pass_edit = "".to_string();
+                self.kdf_rounds = wallet.kdf_rounds();
                 self.wrong_pass = false;
                 // Show wallet password modal.
                 Modal::new(PASS_EDIT_MODAL)
@@ -137,12 +142,80 @@ impl CommonSettings {
                 wallet.update_use_dandelion(!wallet.can_use_dandelion());
             });
 
+            ui.add_space(6.0);
+
+            // Setup wallet opening on application launch.
+            View::checkbox(ui, wallet.auto_open(), t!("wallets.auto_open"), || {
+                wallet.update_auto_open(!wallet.auto_open());
+            });
+
+            ui.add_space(6.0);
+
+            // Setup read-only status API access.
+            let status_token = wallet.status_api_token();
+            View::checkbox(ui, status_token.is_some(), t!("wallets.status_api"), || {
+                wallet.update_status_api(status_token.is_none());
+            });
+            if let Some(token) = status_token {
+                if let Some(port) = wallet.owner_api_port() {
+                    let url = format!("http://127.0.0.1:{}{}", port, StatusAPIHandler::ROUTE);
+                    Self::api_access_ui(ui, url, token, cb);
+                }
+            }
+
+            ui.add_space(6.0);
+
+            // Setup read-only Owner API server for external tools.
+            let owner_token = wallet.owner_api_token();
+            View::checkbox(ui, owner_token.is_some(), t!("wallets.owner_api"), || {
+                wallet.update_owner_api(owner_token.is_none());
+            });
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("wallets.owner_api_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+            if let Some(token) = owner_token {
+                if let Some(port) = wallet.owner_api_port() {
+                    let url = format!("http://127.0.0.1:{}{}", port, OwnerAPIHandler::ROUTE);
+                    Self::api_access_ui(ui, url, token, cb);
+                }
+            }
+
             ui.add_space(6.0);
             View::horizontal_line(ui, Colors::stroke());
             ui.add_space(6.0);
         });
     }
 
+    /// Draw local API URL and access token with buttons to copy them.
+    fn api_access_ui(ui: &mut egui::Ui, url: String, token: String, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.label(RichText::new(&url).size(15.0).color(Colors::inactive_text()));
+        ui.add_space(4.0);
+        let token_text = format!("{}: {}", t!("wallets.api_token"), token);
+        ui.label(RichText::new(token_text).size(15.0).color(Colors::inactive_text()));
+        ui.add_space(6.0);
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let copy_url = format!("{} URL", COPY);
+                    View::button(ui, copy_url, Colors::white_or_black(false), || {
+                        cb.copy_string_to_buffer(url.clone());
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let copy_token = format!("{} {}", COPY, t!("wallets.api_token"));
+                    View::button(ui, copy_token, Colors::white_or_black(false), || {
+                        cb.copy_string_to_buffer(token.clone());
+                    });
+                });
+            });
+        });
+    }
+
     /// Draw [`Modal`] content for this ui container.
     fn modal_content_ui(&mut self,
                         ui: &mut egui::Ui,
@@ -263,6 +336,16 @@ impl CommonSettings {
                 .password()
                 .no_focus();
             View::text_edit(ui, cb, &mut self.new_pass_edit, &mut new_pass_edit_opts);
+            if !self.new_pass_edit.is_empty() {
+                ui.add_space(6.0);
+                password_strength_ui(ui, &self.new_pass_edit);
+            }
+            ui.add_space(8.0);
+
+            // Show seed file protection setup.
+            if wallet.kdf_allowed() {
+                kdf_rounds_ui(ui, &mut self.kdf_rounds);
+            }
 
             // Show information when password is empty.
             if self.old_pass_edit.is_empty() || self.new_pass_edit.is_empty() {
@@ -300,7 +383,7 @@ impl CommonSettings {
                         }
                         let old_pass = self.old_pass_edit.clone();
                         let new_pass = self.new_pass_edit.clone();
-                        match wallet.change_password(old_pass, new_pass) {
+                        match wallet.change_password(old_pass, new_pass, self.kdf_rounds) {
                             Ok(_) => {
                                 // Clear password values.
                                 self.old_pass_edit = "".to_string();
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::wallets::{CommonSettings, ConnectionSettings, RecoverySettings};
+use crate::gui::views::wallets::{CommonSettings, ConnectionSettings, DuressSettings, DustSettings, ExportSettings, MessageSettings, OutputsSettings, PrivacySettings, RecoverySettings, ReservesSettings, UnlockSettings};
 use crate::gui::views::wallets::types::{WalletTab, WalletTabType};
 use crate::wallet::Wallet;
 
@@ -24,7 +24,21 @@ pub struct WalletSettings {
     /// Connection setup content.
     conn_setup: ConnectionSettings,
     /// Recovery setup content.
-    recovery_setup: RecoverySettings
+    recovery_setup: RecoverySettings,
+    /// Proof of reserves setup content.
+    reserves_setup: ReservesSettings,
+    /// Message signing setup content.
+    message_setup: MessageSettings,
+    /// Transactions export setup content.
+    export_setup: ExportSettings,
+    /// Outputs consolidation setup content.
+    outputs_setup: OutputsSettings,
+    /// Quick unlock setup content.
+    unlock_setup: UnlockSettings,
+    /// Duress password setup content.
+    duress_setup: DuressSettings,
+    /// Incoming dust protection setup content.
+    dust_setup: DustSettings
 }
 
 impl Default for WalletSettings {
@@ -32,7 +46,14 @@ impl Default for WalletSettings {
         Self {
             common_setup: CommonSettings::default(),
             conn_setup: ConnectionSettings::default(),
-            recovery_setup: RecoverySettings::default()
+            recovery_setup: RecoverySettings::default(),
+            reserves_setup: ReservesSettings::default(),
+            message_setup: MessageSettings::default(),
+            export_setup: ExportSettings::default(),
+            outputs_setup: OutputsSettings::default(),
+            unlock_setup: UnlockSettings::default(),
+            duress_setup: DuressSettings::default(),
+            dust_setup: DustSettings::default()
         }
     }
 }
@@ -50,6 +71,22 @@ impl WalletTab for WalletSettings {
         self.common_setup.ui(ui, wallet, cb);
         // Show wallet connections setup.
         self.conn_setup.wallet_ui(ui, wallet, cb);
+        // Show wallet outputs consolidation setup.
+        self.outputs_setup.ui(ui, wallet, cb);
+        // Show wallet incoming dust protection setup.
+        self.dust_setup.ui(ui, wallet, cb);
+        // Show wallet transactions privacy hints.
+        PrivacySettings::ui(ui, wallet);
+        // Show wallet proof of reserves setup.
+        self.reserves_setup.ui(ui, wallet, cb);
+        // Show wallet message signing setup.
+        self.message_setup.ui(ui, wallet, cb);
+        // Show wallet transactions export setup.
+        self.export_setup.ui(ui, wallet, cb);
+        // Show wallet quick unlock setup.
+        self.unlock_setup.ui(ui, wallet, cb);
+        // Show wallet duress password setup.
+        self.duress_setup.ui(ui, wallet, cb);
         // Show wallet recovery setup.
         self.recovery_setup.ui(ui, wallet, cb);
     }
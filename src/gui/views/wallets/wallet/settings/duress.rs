@@ -0,0 +1,179 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+
+use crate::gui::Colors;
+use crate::gui::icons::{DETECTIVE, PASSWORD, SHIELD_CHECK, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::wallet::Wallet;
+
+/// Duress password setup content to open decoy wallet under coercion.
+pub struct DuressSettings {
+    /// Wallet password [`Modal`] value.
+    pass_edit: String,
+    /// Duress password [`Modal`] value.
+    duress_pass_edit: String,
+    /// Flag to check if wrong password was entered.
+    wrong_pass: bool,
+}
+
+/// Identifier for duress password setup [`Modal`].
+const DURESS_SETUP_MODAL: &'static str = "wallet_duress_setup_modal";
+
+impl Default for DuressSettings {
+    fn default() -> Self {
+        Self {
+            pass_edit: "".to_string(),
+            duress_pass_edit: "".to_string(),
+            wrong_pass: false,
+        }
+    }
+}
+
+impl DuressSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        if Modal::opened() == Some(DURESS_SETUP_MODAL) {
+            Modal::ui(ui.ctx(), |ui, modal| {
+                self.setup_modal_ui(ui, wallet, modal, cb);
+            });
+        }
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", DETECTIVE, t!("wallets.duress")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            // Show decoy wallet as wallet without duress password to not reveal it.
+            if wallet.has_duress() && !wallet.is_duress() {
+                let status = format!("{} {}", SHIELD_CHECK, t!("wallets.duress_enabled"));
+                ui.label(RichText::new(status).size(16.0).color(Colors::text(false)));
+                ui.add_space(8.0);
+
+                // Show button to remove decoy wallet.
+                let remove_text = format!("{} {}", TRASH, t!("wallets.duress_remove"));
+                View::button(ui, remove_text, Colors::white_or_black(false), || {
+                    wallet.remove_duress();
+                });
+            } else {
+                ui.label(RichText::new(t!("wallets.duress_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+                ui.add_space(8.0);
+
+                // Show button to setup duress password.
+                let setup_text = format!("{} {}", PASSWORD, t!("wallets.duress_setup"));
+                View::button(ui, setup_text, Colors::white_or_black(false), || {
+                    self.pass_edit = "".to_string();
+                    self.duress_pass_edit = "".to_string();
+                    self.wrong_pass = false;
+                    Modal::new(DURESS_SETUP_MODAL)
+                        .position(ModalPosition::CenterTop)
+                        .title(t!("wallets.duress"))
+                        .show();
+                    cb.show_keyboard();
+                });
+            }
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Draw duress password setup [`Modal`] content.
+    fn setup_modal_ui(&mut self,
+                      ui: &mut egui::Ui,
+                      wallet: &Wallet,
+                      modal: &Modal,
+                      cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            // Show password input.
+            ui.label(RichText::new(t!("wallets.pass"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut pass_edit_opts = TextEditOptions::new(Id::from(modal.id)).password();
+            View::text_edit(ui, cb, &mut self.pass_edit, &mut pass_edit_opts);
+            if self.wrong_pass {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.wrong_pass"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(8.0);
+
+            // Show duress password input.
+            ui.label(RichText::new(t!("wallets.duress_pass"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut duress_edit_opts = TextEditOptions::new(Id::from(modal.id).with("duress"))
+                .password()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.duress_pass_edit, &mut duress_edit_opts);
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("wallets.duress_pass_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let mut on_save = || {
+                        if self.pass_edit.is_empty() || self.duress_pass_edit.is_empty() {
+                            return;
+                        }
+                        let pass = self.pass_edit.clone();
+                        let duress_pass = self.duress_pass_edit.clone();
+                        if wallet.setup_duress(pass, duress_pass).is_err() {
+                            self.wrong_pass = true;
+                            return;
+                        }
+                        self.pass_edit = "".to_string();
+                        self.duress_pass_edit = "".to_string();
+                        cb.hide_keyboard();
+                        modal.close();
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
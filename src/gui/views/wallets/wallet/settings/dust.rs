@@ -0,0 +1,172 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+use grin_core::core::amount_from_hr_string;
+
+use crate::gui::Colors;
+use crate::gui::icons::{BROOM, PENCIL};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::wallet::types::{amount_to_display, GRIN};
+use crate::wallet::Wallet;
+
+/// Wallet incoming dust protection setup content.
+pub struct DustSettings {
+    /// Minimal incoming amount [`Modal`] value.
+    amount_edit: String,
+}
+
+/// Identifier for minimal incoming amount [`Modal`].
+const MIN_AMOUNT_EDIT_MODAL: &'static str = "min_receive_amount_edit_modal";
+
+impl Default for DustSettings {
+    fn default() -> Self {
+        Self {
+            amount_edit: "".to_string(),
+        }
+    }
+}
+
+impl DustSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        if Modal::opened() == Some(MIN_AMOUNT_EDIT_MODAL) {
+            Modal::ui(ui.ctx(), |ui, modal| {
+                self.amount_modal_ui(ui, wallet, modal, cb);
+            });
+        }
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", BROOM, t!("wallets.dust")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("wallets.min_receive_amount"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(6.0);
+
+            // Show button to setup minimal incoming amount.
+            let min_amount = wallet.min_receive_amount();
+            let amount_text = match min_amount {
+                Some(a) => format!("{} {} {}", PENCIL, amount_to_display(a), GRIN),
+                None => format!("{} {}", PENCIL, t!("wallets.dust_disabled"))
+            };
+            View::button(ui, amount_text, Colors::white_or_black(false), || {
+                self.amount_edit = min_amount.map(|a| amount_to_display(a)).unwrap_or_default();
+                Modal::new(MIN_AMOUNT_EDIT_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.dust"))
+                    .show();
+                cb.show_keyboard();
+            });
+            ui.add_space(6.0);
+
+            if min_amount.is_some() {
+                // Show checkbox to reject dust instead of marking it.
+                let reject = wallet.reject_dust();
+                View::checkbox(ui, reject, t!("wallets.dust_reject"), || {
+                    wallet.update_reject_dust(!reject);
+                });
+                ui.add_space(4.0);
+                let desc = if reject {
+                    t!("wallets.dust_reject_desc")
+                } else {
+                    t!("wallets.dust_flag_desc")
+                };
+                ui.label(RichText::new(desc).size(16.0).color(Colors::inactive_text()));
+                ui.add_space(6.0);
+            }
+            ui.add_space(2.0);
+        });
+    }
+
+    /// Draw minimal incoming amount [`Modal`] content.
+    fn amount_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        let amount = self.amount_edit.trim();
+        let value = if amount.is_empty() {
+            Some(None)
+        } else {
+            amount_from_hr_string(amount).ok().map(|a| Some(a).filter(|a| *a > 0))
+        };
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.min_receive_amount"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Minimal amount text edit.
+            let mut text_edit_opts = TextEditOptions::new(Id::from(modal.id)).h_center();
+            View::text_edit(ui, cb, &mut self.amount_edit, &mut text_edit_opts);
+
+            // Show error when specified value is not valid.
+            ui.add_space(12.0);
+            if value.is_none() {
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                ui.label(RichText::new(t!("wallets.min_receive_amount_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let on_save = || {
+                        if let Some(value) = value {
+                            wallet.update_min_receive_amount(value);
+                            cb.hide_keyboard();
+                            modal.close();
+                        }
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
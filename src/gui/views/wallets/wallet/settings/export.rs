@@ -0,0 +1,291 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::RichText;
+
+use crate::gui::Colors;
+use crate::gui::icons::{BUG, CARET_LEFT, CARET_RIGHT, EXPORT, FILE_ARROW_UP, FILE_CSV, SHARE_FAT};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::ModalPosition;
+use crate::gui::views::wallets::wallet::types::account_label;
+use crate::wallet::types::TxExportFormat;
+use crate::wallet::{Wallet, WalletFixture};
+
+/// Wallet transactions export settings content.
+pub struct ExportSettings {
+    /// Selected account label, all accounts are exported when empty.
+    account: Option<String>,
+    /// Selected export format.
+    format: TxExportFormat,
+    /// Flag to add category column from transaction memos.
+    with_category: bool,
+    /// Flag to check if export failed.
+    export_error: bool,
+
+    /// Flag to check if fixture file is picking.
+    fixture_picking: bool,
+    /// Flag to check if fixture recording or loading failed.
+    fixture_error: bool,
+}
+
+/// Identifier for transactions export [`Modal`].
+const EXPORT_TXS_MODAL: &'static str = "export_txs_modal";
+
+impl Default for ExportSettings {
+    fn default() -> Self {
+        Self {
+            account: None,
+            format: TxExportFormat::Csv,
+            with_category: true,
+            export_error: false,
+            fixture_picking: false,
+            fixture_error: false,
+        }
+    }
+}
+
+impl ExportSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        self.modal_content_ui(ui, wallet, cb);
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", FILE_CSV, t!("wallets.txs_export")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("wallets.txs_export_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(8.0);
+
+            // Draw button to export transactions.
+            let export_text = format!("{} {}", EXPORT, t!("wallets.txs_export"));
+            View::button(ui, export_text, Colors::white_or_black(false), || {
+                self.account = Some(wallet.get_config().account);
+                self.export_error = false;
+                Modal::new(EXPORT_TXS_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.txs_export"))
+                    .show();
+            });
+            ui.add_space(8.0);
+        });
+
+        // Show UI fixture recording and replay.
+        ui.add_space(4.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+        self.fixture_ui(ui, wallet, cb);
+    }
+
+    /// Draw content to record or replay anonymized wallet data fixture.
+    fn fixture_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(format!("{} {}", BUG, t!("wallets.fixture_desc")))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(8.0);
+
+            // Check fixture file pick result.
+            if self.fixture_picking {
+                View::small_loading_spinner(ui);
+                if let Some(path) = cb.picked_file() {
+                    self.fixture_picking = false;
+                    if !path.is_empty() {
+                        self.replay_fixture(wallet, path);
+                    }
+                }
+                ui.add_space(8.0);
+                return;
+            }
+
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        let record_text = format!("{} {}", SHARE_FAT, t!("wallets.fixture_record"));
+                        View::button(ui, record_text, Colors::white_or_black(false), || {
+                            match wallet.record_fixture() {
+                                Some(data) => {
+                                    self.fixture_error = false;
+                                    let name = format!("fixture-{}.{}",
+                                                       wallet.get_config().id,
+                                                       WalletFixture::FILE_EXT);
+                                    let _ = cb.share_data(name, data);
+                                }
+                                None => self.fixture_error = true
+                            }
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        let load_text = format!("{} {}", FILE_ARROW_UP, t!("wallets.fixture_load"));
+                        View::button(ui, load_text, Colors::white_or_black(false), || {
+                            if let Some(path) = cb.pick_file() {
+                                // Wait for asynchronous file pick result if path is empty.
+                                if path.is_empty() {
+                                    self.fixture_picking = true;
+                                } else {
+                                    self.replay_fixture(wallet, path);
+                                }
+                            }
+                        });
+                    });
+                });
+            });
+
+            if self.fixture_error {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.fixture_error"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Replay wallet data fixture from provided file path.
+    fn replay_fixture(&mut self, wallet: &Wallet, path: String) {
+        let replayed = std::fs::read(path)
+            .map(|data| wallet.replay_fixture(&data))
+            .unwrap_or(false);
+        self.fixture_error = !replayed;
+    }
+
+    /// Draw [`Modal`] content for this ui container.
+    fn modal_content_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        cb: &dyn PlatformCallbacks) {
+        match Modal::opened() {
+            None => {}
+            Some(id) => {
+                match id {
+                    EXPORT_TXS_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.export_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Draw transactions export [`Modal`] content.
+    fn export_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.accounts"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Show account selection with all accounts option.
+            let mut options: Vec<Option<String>> = vec![None];
+            options.extend(wallet.accounts().into_iter().map(|a| Some(a.label)));
+            let index = options.iter().position(|o| o == &self.account).unwrap_or(0);
+            ui.horizontal(|ui| {
+                View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                    let prev = if index == 0 { options.len() - 1 } else { index - 1 };
+                    self.account = options[prev].clone();
+                });
+                ui.add_space(4.0);
+                let name = match &self.account {
+                    Some(label) => account_label(wallet, label),
+                    None => t!("wallets.all_accounts")
+                };
+                let width = ui.available_width() - 48.0;
+                ui.allocate_ui(egui::Vec2::new(width, 36.0), |ui| {
+                    ui.centered_and_justified(|ui| {
+                        View::ellipsize_text(ui, name, 17.0, Colors::white_or_black(true));
+                    });
+                });
+                ui.add_space(4.0);
+                View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                    let next = (index + 1) % options.len();
+                    self.account = options[next].clone();
+                });
+            });
+            ui.add_space(10.0);
+
+            // Show format selection.
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    View::radio_value(ui, &mut self.format, TxExportFormat::Csv, "CSV".to_string());
+                });
+                columns[1].vertical_centered(|ui| {
+                    View::radio_value(ui, &mut self.format, TxExportFormat::Json, "JSON".to_string());
+                });
+            });
+            ui.add_space(8.0);
+
+            // Show category column checkbox.
+            View::checkbox(ui, self.with_category, t!("wallets.txs_export_category"), || {
+                self.with_category = !self.with_category;
+            });
+
+            if self.export_error {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.txs_export_error"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("share"), Colors::white_or_black(false), || {
+                        match wallet.export_txs(self.account.clone(),
+                                                self.format,
+                                                self.with_category) {
+                            Ok(data) => {
+                                let name = format!("txs-{}-{}.{}",
+                                                   wallet.get_config().name,
+                                                   self.account.clone().unwrap_or("all".into()),
+                                                   self.format.extension());
+                                let _ = cb.share_data(name, data.as_bytes().to_vec());
+                                modal.close();
+                            }
+                            Err(_) => self.export_error = true
+                        }
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+
+use crate::gui::Colors;
+use crate::gui::icons::{CLIPBOARD_TEXT, COPY, PEN_NIB, SEAL_QUESTION, SIGNATURE};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::wallet::types::SignedMessage;
+use crate::wallet::Wallet;
+
+/// Wallet message signing settings content to prove Slatepack address ownership.
+pub struct MessageSettings {
+    /// Message to sign at [`Modal`] value.
+    message_edit: String,
+    /// Signed message data to share.
+    signed: Option<String>,
+    /// Flag to check if message signing failed.
+    sign_error: bool,
+    /// Signed message data to verify at [`Modal`] value.
+    verify_edit: String,
+    /// Result of signed message verification.
+    verify_result: Option<Option<SignedMessage>>,
+}
+
+/// Identifier for message signing [`Modal`].
+const SIGN_MESSAGE_MODAL: &'static str = "sign_message_modal";
+/// Identifier for signed message verification [`Modal`].
+const VERIFY_MESSAGE_MODAL: &'static str = "verify_message_modal";
+
+impl Default for MessageSettings {
+    fn default() -> Self {
+        Self {
+            message_edit: "".to_string(),
+            signed: None,
+            sign_error: false,
+            verify_edit: "".to_string(),
+            verify_result: None,
+        }
+    }
+}
+
+impl MessageSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        self.modal_content_ui(ui, wallet, cb);
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", SIGNATURE, t!("wallets.sign_message")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("wallets.sign_message_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(8.0);
+
+            // Draw button to sign message.
+            let sign_text = format!("{} {}", PEN_NIB, t!("wallets.sign"));
+            View::button(ui, sign_text, Colors::white_or_black(false), || {
+                self.message_edit = "".to_string();
+                self.signed = None;
+                self.sign_error = false;
+                Modal::new(SIGN_MESSAGE_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.sign_message"))
+                    .show();
+                cb.show_keyboard();
+            });
+            ui.add_space(8.0);
+
+            // Draw button to verify signed message.
+            let verify_text = format!("{} {}", SEAL_QUESTION, t!("wallets.verify_message"));
+            View::button(ui, verify_text, Colors::white_or_black(false), || {
+                self.verify_edit = "".to_string();
+                self.verify_result = None;
+                Modal::new(VERIFY_MESSAGE_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.sign_message"))
+                    .show();
+            });
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Draw [`Modal`] content for this ui container.
+    fn modal_content_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        cb: &dyn PlatformCallbacks) {
+        match Modal::opened() {
+            None => {}
+            Some(id) => {
+                match id {
+                    SIGN_MESSAGE_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.sign_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    VERIFY_MESSAGE_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.verify_modal_ui(ui, modal, cb);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Draw message signing [`Modal`] content.
+    fn sign_modal_ui(&mut self,
+                     ui: &mut egui::Ui,
+                     wallet: &Wallet,
+                     modal: &Modal,
+                     cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        if let Some(signed) = self.signed.clone() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.message_signed"))
+                    .size(17.0)
+                    .color(Colors::green()));
+                ui.add_space(8.0);
+                View::ellipsize_text(ui, signed.clone(), 15.0, Colors::gray());
+                ui.add_space(12.0);
+            });
+
+            // Show modal buttons.
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(3, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            modal.close();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        let copy_text = format!("{} {}", COPY, t!("copy"));
+                        View::button(ui, copy_text, Colors::white_or_black(false), || {
+                            cb.copy_string_to_buffer(signed.clone());
+                        });
+                    });
+                    columns[2].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("share"), Colors::white_or_black(false), || {
+                            let name = "signed-message.json".to_string();
+                            let _ = cb.share_data(name, signed.as_bytes().to_vec());
+                        });
+                    });
+                });
+                ui.add_space(6.0);
+            });
+            return;
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.message_to_sign"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Show message text edit.
+            let mut message_edit_opts = TextEditOptions::new(Id::from(modal.id));
+            View::text_edit(ui, cb, &mut self.message_edit, &mut message_edit_opts);
+
+            if self.sign_error {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.sign_message_error"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("wallets.sign"), Colors::white_or_black(false), || {
+                        if self.message_edit.trim().is_empty() {
+                            return;
+                        }
+                        match wallet.sign_message(self.message_edit.clone()) {
+                            Ok(signed) => {
+                                self.signed = serde_json::to_string_pretty(&signed).ok();
+                                cb.hide_keyboard();
+                            }
+                            Err(_) => self.sign_error = true
+                        }
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw signed message verification [`Modal`] content.
+    fn verify_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            if let Some(res) = &self.verify_result {
+                match res {
+                    Some(signed) => {
+                        ui.label(RichText::new(t!("wallets.message_valid"))
+                            .size(17.0)
+                            .color(Colors::green()));
+                        ui.add_space(6.0);
+                        ui.label(RichText::new(&signed.address)
+                            .size(16.0)
+                            .color(Colors::white_or_black(true)));
+                        ui.add_space(6.0);
+                        ui.label(RichText::new(&signed.message)
+                            .size(16.0)
+                            .color(Colors::inactive_text()));
+                    }
+                    None => {
+                        ui.label(RichText::new(t!("wallets.message_invalid"))
+                            .size(17.0)
+                            .color(Colors::red()));
+                    }
+                }
+            } else {
+                ui.label(RichText::new(t!("wallets.message_paste"))
+                    .size(17.0)
+                    .color(Colors::gray()));
+                ui.add_space(8.0);
+                let paste_text = format!("{} {}", CLIPBOARD_TEXT, t!("paste"));
+                View::button(ui, paste_text, Colors::white_or_black(false), || {
+                    self.verify_edit = cb.get_string_from_buffer();
+                });
+                if !self.verify_edit.is_empty() {
+                    ui.add_space(8.0);
+                    View::ellipsize_text(ui, self.verify_edit.clone(), 15.0, Colors::gray());
+                }
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    if self.verify_result.is_none() {
+                        View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                            let signed = serde_json::from_str::<SignedMessage>(&self.verify_edit);
+                            self.verify_result = Some(signed.ok().filter(|s| s.verify()));
+                        });
+                    } else {
+                        View::button(ui, t!("repeat"), Colors::white_or_black(false), || {
+                            self.verify_edit = "".to_string();
+                            self.verify_result = None;
+                        });
+                    }
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
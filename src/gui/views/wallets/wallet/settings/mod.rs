@@ -22,4 +22,28 @@ mod common;
 pub use common::CommonSettings;
 
 mod recovery;
-pub use recovery::RecoverySettings;
\ No newline at end of file
+pub use recovery::RecoverySettings;
+
+mod reserves;
+pub use reserves::ReservesSettings;
+
+mod message;
+pub use message::MessageSettings;
+
+mod export;
+pub use export::ExportSettings;
+
+mod outputs;
+pub use outputs::OutputsSettings;
+
+mod unlock;
+pub use unlock::UnlockSettings;
+
+mod duress;
+pub use duress::DuressSettings;
+
+mod privacy;
+pub use privacy::PrivacySettings;
+
+mod dust;
+pub use dust::DustSettings;
\ No newline at end of file
@@ -0,0 +1,290 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::thread;
+use egui::{Id, RichText};
+use grin_wallet_libwallet::Error;
+use parking_lot::RwLock;
+
+use crate::gui::Colors;
+use crate::gui::icons::{ARROWS_IN, COINS, TARGET};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::wallet::WalletTransactionModal;
+use crate::wallet::types::WalletTransaction;
+use crate::wallet::Wallet;
+
+/// Wallet outputs consolidation setup content.
+pub struct OutputsSettings {
+    /// Amount of outputs to keep [`Modal`] value.
+    target_edit: String,
+
+    /// Amount of spendable outputs before consolidation.
+    outputs_count: usize,
+
+    /// Flag to check if consolidation is in progress.
+    consolidating: bool,
+    /// Consolidation result.
+    consolidation_result: Arc<RwLock<Option<Result<WalletTransaction, Error>>>>,
+    /// Consolidation error text.
+    consolidation_error: Option<String>,
+    /// Consolidation transaction content.
+    result_tx_content: Option<WalletTransactionModal>,
+}
+
+/// Identifier for amount of outputs to keep [`Modal`].
+const TARGET_EDIT_MODAL: &'static str = "consolidation_target_edit_modal";
+/// Identifier for outputs consolidation [`Modal`].
+const CONSOLIDATE_MODAL: &'static str = "consolidate_outputs_modal";
+
+impl Default for OutputsSettings {
+    fn default() -> Self {
+        Self {
+            target_edit: "".to_string(),
+            outputs_count: 0,
+            consolidating: false,
+            consolidation_result: Arc::new(RwLock::new(None)),
+            consolidation_error: None,
+            result_tx_content: None,
+        }
+    }
+}
+
+impl OutputsSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        self.modal_content_ui(ui, wallet, cb);
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", COINS, t!("wallets.outputs")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            // Show button to setup amount of outputs to keep.
+            ui.label(RichText::new(t!("wallets.consolidation_target"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(6.0);
+            let target = wallet.consolidation_target();
+            let target_text = format!("{} {}", TARGET, target);
+            View::button(ui, target_text, Colors::white_or_black(false), || {
+                self.target_edit = target.to_string();
+                Modal::new(TARGET_EDIT_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.outputs"))
+                    .show();
+                cb.show_keyboard();
+            });
+            ui.add_space(8.0);
+
+            // Show button to consolidate outputs.
+            let consolidate_text = format!("{} {}", ARROWS_IN, t!("wallets.consolidate"));
+            View::button(ui, consolidate_text, Colors::white_or_black(false), || {
+                self.outputs_count = wallet.spendable_outputs_count().unwrap_or(0);
+                self.consolidation_error = None;
+                self.result_tx_content = None;
+                Modal::new(CONSOLIDATE_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.consolidate"))
+                    .show();
+            });
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Draw [`Modal`] content for this ui container.
+    fn modal_content_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        cb: &dyn PlatformCallbacks) {
+        match Modal::opened() {
+            None => {}
+            Some(id) => {
+                match id {
+                    TARGET_EDIT_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.target_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    CONSOLIDATE_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.consolidate_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Draw amount of outputs to keep [`Modal`] content.
+    fn target_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.consolidation_target"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Amount of outputs text edit.
+            let mut text_edit_opts = TextEditOptions::new(Id::from(modal.id)).h_center();
+            View::text_edit(ui, cb, &mut self.target_edit, &mut text_edit_opts);
+
+            // Show error when specified value is not valid.
+            let valid = self.target_edit.parse::<usize>().map(|t| t > 0).unwrap_or(false);
+            if !valid {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let on_save = || {
+                        if let Ok(target) = self.target_edit.parse::<usize>() {
+                            if target > 0 {
+                                wallet.update_consolidation_target(target);
+                                cb.hide_keyboard();
+                                modal.close();
+                            }
+                        }
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw outputs consolidation [`Modal`] content.
+    fn consolidate_modal_ui(&mut self,
+                            ui: &mut egui::Ui,
+                            wallet: &Wallet,
+                            modal: &Modal,
+                            cb: &dyn PlatformCallbacks) {
+        // Show consolidation transaction information.
+        if let Some(tx) = self.result_tx_content.as_mut() {
+            tx.ui(ui, wallet, modal, cb);
+            return;
+        }
+
+        ui.add_space(6.0);
+
+        // Show progress and check consolidation result.
+        if self.consolidating {
+            ui.vertical_centered(|ui| {
+                ui.add_space(16.0);
+                View::big_loading_spinner(ui);
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("wallets.consolidating"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+                ui.add_space(16.0);
+            });
+            let res = {
+                let r_res = self.consolidation_result.read();
+                r_res.clone()
+            };
+            if let Some(res) = res {
+                self.consolidating = false;
+                modal.enable_closing();
+                match res {
+                    Ok(tx) => {
+                        self.result_tx_content = Some(
+                            WalletTransactionModal::new(wallet, &tx, false)
+                        );
+                    }
+                    Err(e) => self.consolidation_error = Some(e.to_string())
+                }
+                let mut w_res = self.consolidation_result.write();
+                *w_res = None;
+            }
+            return;
+        }
+
+        ui.vertical_centered(|ui| {
+            let target = wallet.consolidation_target();
+            ui.label(RichText::new(t!("wallets.consolidate_desc",
+                                      "count" => self.outputs_count,
+                                      "target" => target))
+                .size(17.0)
+                .color(Colors::text(false)));
+            if let Some(err) = &self.consolidation_error {
+                ui.add_space(8.0);
+                ui.label(RichText::new(err).size(16.0).color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                        self.consolidation_error = None;
+                        self.consolidating = true;
+                        modal.disable_closing();
+                        let wallet = wallet.clone();
+                        let result = self.consolidation_result.clone();
+                        thread::spawn(move || {
+                            let res = wallet.consolidate_outputs();
+                            let mut w_res = result.write();
+                            *w_res = Some(res);
+                        });
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
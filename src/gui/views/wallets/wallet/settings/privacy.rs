@@ -0,0 +1,71 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::RichText;
+
+use crate::gui::Colors;
+use crate::gui::icons::{EYE_SLASH, SHIELD_CHECK, SHIELD_WARNING};
+use crate::gui::views::View;
+use crate::wallet::types::PrivacyHint;
+use crate::wallet::Wallet;
+
+/// Advisory content with privacy-relevant patterns found at wallet transactions.
+pub struct PrivacySettings {}
+
+impl PrivacySettings {
+    pub fn ui(ui: &mut egui::Ui, wallet: &Wallet) {
+        let hints = match wallet.get_data() {
+            Some(data) => data.privacy_hints(),
+            None => return
+        };
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", EYE_SLASH, t!("wallets.privacy")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            if hints.is_empty() {
+                let text = format!("{} {}", SHIELD_CHECK, t!("wallets.privacy_no_hints"));
+                ui.label(RichText::new(text).size(16.0).color(Colors::text(false)));
+                ui.add_space(8.0);
+                return;
+            }
+            for hint in &hints {
+                let (title, suggestion) = match hint {
+                    PrivacyHint::AddressReuse(count) => {
+                        (t!("wallets.privacy_address_reuse", "count" => count),
+                         t!("wallets.privacy_address_reuse_desc"))
+                    }
+                    PrivacyHint::ConsolidationBeforeSend(count) => {
+                        (t!("wallets.privacy_consolidation", "count" => count),
+                         t!("wallets.privacy_consolidation_desc"))
+                    }
+                    PrivacyHint::RoundAmounts(count) => {
+                        (t!("wallets.privacy_round_amounts", "count" => count),
+                         t!("wallets.privacy_round_amounts_desc"))
+                    }
+                };
+                let title = format!("{} {}", SHIELD_WARNING, title);
+                ui.label(RichText::new(title).size(16.0).color(Colors::gold()));
+                ui.add_space(2.0);
+                ui.label(RichText::new(suggestion).size(15.0).color(Colors::inactive_text()));
+                ui.add_space(8.0);
+            }
+        });
+    }
+}
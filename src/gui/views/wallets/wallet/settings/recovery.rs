@@ -14,15 +14,18 @@
 
 use egui::{Id, RichText};
 use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
 use grin_util::ZeroingString;
 
 use crate::gui::Colors;
-use crate::gui::icons::{EYE, LIFEBUOY, STETHOSCOPE, TRASH, WRENCH};
+use crate::gui::icons::{CLOCK_COUNTER_CLOCKWISE, EYE, LIFEBUOY, LIST_MAGNIFYING_GLASS, STETHOSCOPE, TRASH, WRENCH};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, View};
 use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::types::GRIN;
+use crate::gui::views::wallets::wallet::modals::RepairReportModal;
 use crate::node::Node;
-use crate::wallet::types::ConnectionMethod;
+use crate::wallet::types::{ConnectionMethod, RepairReason};
 use crate::wallet::Wallet;
 
 /// Wallet recovery settings content.
@@ -34,10 +37,17 @@ pub struct RecoverySettings {
 
     /// Recovery phrase value.
     recovery_phrase: Option<ZeroingString>,
+
+    /// Wallet repair history [`Modal`] content.
+    repair_history_content: Option<RepairReportModal>,
 }
 
 /// Identifier for recovery phrase [`Modal`].
 const RECOVERY_PHRASE_MODAL: &'static str = "recovery_phrase_modal";
+/// Identifier for repair check [`Modal`].
+const REPAIR_CHECK_MODAL: &'static str = "repair_check_modal";
+/// Identifier for repair history [`Modal`].
+const REPAIR_HISTORY_MODAL: &'static str = "repair_history_modal";
 /// Identifier to confirm wallet deletion [`Modal`].
 const DELETE_CONFIRMATION_MODAL: &'static str = "delete_wallet_confirmation_modal";
 
@@ -47,6 +57,7 @@ impl Default for RecoverySettings {
             wrong_pass: false,
             pass_edit: "".to_string(),
             recovery_phrase: None,
+            repair_history_content: None,
         }
     }
 }
@@ -78,13 +89,35 @@ impl RecoverySettings {
                 // Draw button to repair the wallet.
                 let repair_text = format!("{} {}", STETHOSCOPE, t!("wallets.repair_wallet"));
                 View::action_button(ui, repair_text, || {
-                    wallet.repair();
+                    wallet.repair(RepairReason::Manual);
                 });
 
                 ui.add_space(6.0);
                 ui.label(RichText::new(t!("wallets.repair_desc"))
                     .size(16.0)
                     .color(Colors::inactive_text()));
+                ui.add_space(6.0);
+
+                // Draw button to check wallet without repair.
+                let check_text = format!("{} {}", LIST_MAGNIFYING_GLASS, t!("wallets.repair_check"));
+                View::button(ui, check_text, Colors::white_or_black(false), || {
+                    self.show_repair_check_modal(wallet, cb);
+                });
+                ui.add_space(8.0);
+
+                // Draw button to show repair history.
+                let history_text = format!("{} {}",
+                                           CLOCK_COUNTER_CLOCKWISE,
+                                           t!("wallets.repair_history"));
+                View::button(ui, history_text, Colors::white_or_black(false), || {
+                    self.repair_history_content = Some(
+                        RepairReportModal::new(wallet.repair_history())
+                    );
+                    Modal::new(REPAIR_HISTORY_MODAL)
+                        .position(ModalPosition::Center)
+                        .title(t!("wallets.repair_history"))
+                        .show();
+                });
             }
 
             ui.add_space(6.0);
@@ -152,6 +185,18 @@ impl RecoverySettings {
                             self.recovery_phrase_modal_ui(ui, wallet, modal, cb);
                         });
                     }
+                    REPAIR_CHECK_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.repair_check_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    REPAIR_HISTORY_MODAL => {
+                        if let Some(content) = self.repair_history_content.as_mut() {
+                            Modal::ui(ui.ctx(), |ui, modal| {
+                                content.ui(ui, modal);
+                            });
+                        }
+                    }
                     DELETE_CONFIRMATION_MODAL => {
                         Modal::ui(ui.ctx(), |ui, modal| {
                             self.deletion_modal_ui(ui, wallet, modal);
@@ -262,6 +307,168 @@ impl RecoverySettings {
         ui.add_space(6.0);
     }
 
+    /// Show repair check [`Modal`].
+    fn show_repair_check_modal(&mut self, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Setup modal values.
+        self.pass_edit = "".to_string();
+        self.wrong_pass = false;
+        if !wallet.is_repair_checking() {
+            wallet.clear_repair_report();
+        }
+        // Show repair check modal.
+        Modal::new(REPAIR_CHECK_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("wallets.repair_check"))
+            .show();
+        cb.show_keyboard();
+    }
+
+    /// Draw repair check [`Modal`] content.
+    fn repair_check_modal_ui(&mut self,
+                             ui: &mut egui::Ui,
+                             wallet: &Wallet,
+                             modal: &Modal,
+                             cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        if wallet.is_repair_checking() {
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.repair_checking"))
+                    .size(17.0)
+                    .color(Colors::inactive_text()));
+            });
+            ui.add_space(12.0);
+            return;
+        }
+        if let Some(report) = wallet.repair_report() {
+            ui.vertical_centered(|ui| {
+                match report {
+                    Ok(report) => {
+                        if report.is_empty() {
+                            ui.label(RichText::new(t!("wallets.repair_check_empty"))
+                                .size(17.0)
+                                .color(Colors::green()));
+                        } else {
+                            let restored: u64 = report.restored_outputs.iter().sum();
+                            let restored_text = t!("wallets.repair_check_restored",
+                                "count" => report.restored_outputs.len(),
+                                "amount" => format!("{} {}",
+                                                    amount_to_hr_string(restored, true),
+                                                    GRIN));
+                            let spent_text = t!("wallets.repair_check_spent",
+                                "count" => report.spent_outputs.len());
+                            let cancelled_text = t!("wallets.repair_check_cancelled",
+                                "count" => report.cancelled_txs.len());
+                            for text in [restored_text, spent_text, cancelled_text] {
+                                ui.label(RichText::new(text)
+                                    .size(17.0)
+                                    .color(Colors::text(false)));
+                                ui.add_space(4.0);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        ui.label(RichText::new(t!("wallets.repair_check_error"))
+                            .size(17.0)
+                            .color(Colors::red()));
+                        ui.add_space(6.0);
+                        ui.label(RichText::new(e).size(16.0).color(Colors::inactive_text()));
+                    }
+                }
+            });
+            ui.add_space(12.0);
+
+            // Show modal buttons.
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            wallet.clear_repair_report();
+                            modal.close();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("wallets.repair_wallet"), Colors::white_or_black(false), || {
+                            wallet.clear_repair_report();
+                            wallet.repair(RepairReason::Check);
+                            modal.close();
+                        });
+                    });
+                });
+            });
+            ui.add_space(6.0);
+            return;
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.pass"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw current wallet password text edit.
+            let pass_edit_id = Id::from(modal.id).with(wallet.get_config().id);
+            let mut pass_edit_opts = TextEditOptions::new(pass_edit_id).password();
+            View::text_edit(ui, cb, &mut self.pass_edit, &mut pass_edit_opts);
+
+            // Show information when password is wrong.
+            if self.wrong_pass {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("wallets.wrong_pass"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+            ui.label(RichText::new(t!("wallets.repair_check_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(12.0);
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let mut on_next = || {
+                        if self.pass_edit.is_empty() {
+                            return;
+                        }
+                        match wallet.check_repair(self.pass_edit.clone()) {
+                            Ok(_) => {
+                                self.wrong_pass = false;
+                                self.pass_edit = "".to_string();
+                                cb.hide_keyboard();
+                            }
+                            Err(_) => {
+                                self.wrong_pass = true;
+                            }
+                        }
+                    };
+                    View::on_enter_key(ui, || {
+                        (on_next)();
+                    });
+                    View::button(ui, "OK".to_owned(), Colors::white_or_black(false), || {
+                        on_next();
+                    });
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
     /// Draw wallet deletion [`Modal`] content.
     fn deletion_modal_ui(&mut self,
                          ui: &mut egui::Ui,
@@ -0,0 +1,261 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::Colors;
+use crate::gui::icons::{CLIPBOARD_TEXT, EXPORT, SEAL_CHECK, SEAL_QUESTION};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::types::GRIN;
+use crate::wallet::types::ReservesProof;
+use crate::wallet::Wallet;
+
+/// Wallet proof of reserves settings content.
+pub struct ReservesSettings {
+    /// Message to sign at proof [`Modal`] value.
+    message_edit: String,
+    /// Proof to verify [`Modal`] value.
+    proof_edit: String,
+    /// Result of proof verification with total amount or error.
+    verify_result: Option<Result<u64, String>>,
+    /// Flag to check if proof creation failed.
+    export_error: bool,
+}
+
+/// Identifier for proof export [`Modal`].
+const EXPORT_PROOF_MODAL: &'static str = "export_reserves_proof_modal";
+/// Identifier for proof verification [`Modal`].
+const VERIFY_PROOF_MODAL: &'static str = "verify_reserves_proof_modal";
+
+impl Default for ReservesSettings {
+    fn default() -> Self {
+        Self {
+            message_edit: "".to_string(),
+            proof_edit: "".to_string(),
+            verify_result: None,
+            export_error: false,
+        }
+    }
+}
+
+impl ReservesSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Show modal content for this ui container.
+        self.modal_content_ui(ui, wallet, cb);
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", SEAL_CHECK, t!("wallets.reserves")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("wallets.reserves_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(8.0);
+
+            // Draw button to export proof.
+            let export_text = format!("{} {}", EXPORT, t!("wallets.reserves_export"));
+            View::button(ui, export_text, Colors::white_or_black(false), || {
+                self.message_edit = "".to_string();
+                self.export_error = false;
+                Modal::new(EXPORT_PROOF_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.reserves"))
+                    .show();
+                cb.show_keyboard();
+            });
+            ui.add_space(8.0);
+
+            // Draw button to verify proof.
+            let verify_text = format!("{} {}", SEAL_QUESTION, t!("wallets.reserves_verify"));
+            View::button(ui, verify_text, Colors::white_or_black(false), || {
+                self.proof_edit = "".to_string();
+                self.verify_result = None;
+                Modal::new(VERIFY_PROOF_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.reserves"))
+                    .show();
+            });
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Draw [`Modal`] content for this ui container.
+    fn modal_content_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        cb: &dyn PlatformCallbacks) {
+        match Modal::opened() {
+            None => {}
+            Some(id) => {
+                match id {
+                    EXPORT_PROOF_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.export_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    VERIFY_PROOF_MODAL => {
+                        Modal::ui(ui.ctx(), |ui, modal| {
+                            self.verify_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Draw proof export [`Modal`] content.
+    fn export_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.reserves_message"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Show message text edit.
+            let mut message_edit_opts = TextEditOptions::new(Id::from(modal.id));
+            View::text_edit(ui, cb, &mut self.message_edit, &mut message_edit_opts);
+
+            if self.export_error {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.reserves_export_error"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("share"), Colors::white_or_black(false), || {
+                        match wallet.reserves_proof(self.message_edit.clone()) {
+                            Ok(proof) => {
+                                let data = serde_json::to_string_pretty(&proof).unwrap();
+                                let name = format!("reserves-{}.json", proof.height);
+                                let _ = cb.share_data(name, data.as_bytes().to_vec());
+                                cb.hide_keyboard();
+                                modal.close();
+                            }
+                            Err(_) => self.export_error = true
+                        }
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw proof verification [`Modal`] content.
+    fn verify_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            if let Some(res) = &self.verify_result {
+                match res {
+                    Ok(total) => {
+                        ui.label(RichText::new(t!("wallets.reserves_valid"))
+                            .size(17.0)
+                            .color(Colors::green()));
+                        ui.add_space(6.0);
+                        let amount = amount_to_hr_string(*total, true);
+                        ui.label(RichText::new(format!("{} {}", amount, GRIN))
+                            .size(18.0)
+                            .color(Colors::white_or_black(true)));
+                    }
+                    Err(e) => {
+                        ui.label(RichText::new(t!("wallets.reserves_invalid"))
+                            .size(17.0)
+                            .color(Colors::red()));
+                        ui.add_space(6.0);
+                        ui.label(RichText::new(e).size(16.0).color(Colors::inactive_text()));
+                    }
+                }
+            } else {
+                ui.label(RichText::new(t!("wallets.reserves_paste"))
+                    .size(17.0)
+                    .color(Colors::gray()));
+                ui.add_space(8.0);
+                let paste_text = format!("{} {}", CLIPBOARD_TEXT, t!("paste"));
+                View::button(ui, paste_text, Colors::white_or_black(false), || {
+                    self.proof_edit = cb.get_string_from_buffer();
+                });
+                if !self.proof_edit.is_empty() {
+                    ui.add_space(8.0);
+                    View::ellipsize_text(ui, self.proof_edit.clone(), 15.0, Colors::gray());
+                }
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    if self.verify_result.is_none() {
+                        View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                            let proof = serde_json::from_str::<ReservesProof>(&self.proof_edit);
+                            self.verify_result = Some(match proof {
+                                Ok(p) => wallet.verify_reserves_proof(&p)
+                                    .map_err(|e| e.to_string()),
+                                Err(e) => Err(e.to_string())
+                            });
+                        });
+                    } else {
+                        View::button(ui, t!("repeat"), Colors::white_or_black(false), || {
+                            self.proof_edit = "".to_string();
+                            self.verify_result = None;
+                        });
+                    }
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Id, RichText};
+use grin_util::ZeroingString;
+
+use crate::gui::Colors;
+use crate::gui::icons::{FINGERPRINT, LOCK_OPEN, LOCK_SIMPLE, PASSWORD};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::wallet::{Wallet, WalletUtils};
+
+/// Wallet quick unlock with PIN or biometric setup content.
+pub struct UnlockSettings {
+    /// Wallet password [`Modal`] value.
+    pass_edit: String,
+    /// PIN [`Modal`] value.
+    pin_edit: String,
+    /// Flag to enable biometric unlock at [`Modal`].
+    use_biometric: bool,
+    /// Flag to check if wrong password was entered.
+    wrong_pass: bool,
+    /// PIN data and password to save after biometric authentication.
+    bio_pending: Option<(Vec<u8>, ZeroingString)>,
+}
+
+/// Identifier for PIN setup [`Modal`].
+const PIN_SETUP_MODAL: &'static str = "wallet_pin_setup_modal";
+
+/// Minimal PIN length.
+const MIN_PIN_LENGTH: usize = 4;
+/// Maximal PIN length.
+const MAX_PIN_LENGTH: usize = 8;
+
+impl Default for UnlockSettings {
+    fn default() -> Self {
+        Self {
+            pass_edit: "".to_string(),
+            pin_edit: "".to_string(),
+            use_biometric: false,
+            wrong_pass: false,
+            bio_pending: None,
+        }
+    }
+}
+
+impl UnlockSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        // Quick unlock is not available without platform keystore.
+        if !cb.secure_storage_available() {
+            return;
+        }
+
+        // Show modal content for this ui container.
+        if Modal::opened() == Some(PIN_SETUP_MODAL) {
+            Modal::ui(ui.ctx(), |ui, modal| {
+                self.pin_modal_ui(ui, wallet, modal, cb);
+            });
+        }
+
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", LOCK_SIMPLE, t!("wallets.quick_unlock")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(4.0);
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(6.0);
+            if wallet.has_unlock_pin() {
+                let mut status = format!("{} {}", PASSWORD, t!("wallets.pin_enabled"));
+                if wallet.has_unlock_biometric() {
+                    status = format!("{}  {}", status, FINGERPRINT);
+                }
+                ui.label(RichText::new(status).size(16.0).color(Colors::text(false)));
+                ui.add_space(8.0);
+
+                // Show button to disable quick unlock.
+                let disable_text = format!("{} {}", LOCK_OPEN, t!("wallets.pin_disable"));
+                View::button(ui, disable_text, Colors::white_or_black(false), || {
+                    wallet.update_unlock_data(None, None);
+                });
+            } else {
+                ui.label(RichText::new(t!("wallets.quick_unlock_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+                ui.add_space(8.0);
+
+                // Show button to setup PIN.
+                let setup_text = format!("{} {}", PASSWORD, t!("wallets.pin_setup"));
+                View::button(ui, setup_text, Colors::white_or_black(false), || {
+                    self.pass_edit = "".to_string();
+                    self.pin_edit = "".to_string();
+                    self.use_biometric = false;
+                    self.wrong_pass = false;
+                    self.bio_pending = None;
+                    Modal::new(PIN_SETUP_MODAL)
+                        .position(ModalPosition::CenterTop)
+                        .title(t!("wallets.quick_unlock"))
+                        .show();
+                    cb.show_keyboard();
+                });
+            }
+            ui.add_space(8.0);
+        });
+    }
+
+    /// Draw PIN setup [`Modal`] content.
+    fn pin_modal_ui(&mut self,
+                    ui: &mut egui::Ui,
+                    wallet: &Wallet,
+                    modal: &Modal,
+                    cb: &dyn PlatformCallbacks) {
+        // Wait for biometric authentication to encrypt password with biometric key.
+        if self.bio_pending.is_some() {
+            if let Some(success) = cb.biometric_auth_result() {
+                let (pin_data, pass) = self.bio_pending.take().unwrap();
+                let bio_data = if success {
+                    cb.encrypt_secret(pass.as_bytes().to_vec(), true)
+                } else {
+                    None
+                };
+                wallet.update_unlock_data(Some(pin_data), bio_data);
+                modal.close();
+                return;
+            }
+            ui.add_space(16.0);
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+            });
+            ui.add_space(16.0);
+            return;
+        }
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            // Show password input.
+            ui.label(RichText::new(t!("wallets.pass"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut pass_edit_opts = TextEditOptions::new(Id::from(modal.id)).password();
+            View::text_edit(ui, cb, &mut self.pass_edit, &mut pass_edit_opts);
+            if self.wrong_pass {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.wrong_pass"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(8.0);
+
+            // Show PIN input.
+            ui.label(RichText::new(t!("wallets.pin"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut pin_edit_opts = TextEditOptions::new(Id::from(modal.id).with("pin"))
+                .password()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.pin_edit, &mut pin_edit_opts);
+            if !self.pin_edit.is_empty() && !is_valid_pin(&self.pin_edit) {
+                ui.add_space(10.0);
+                ui.label(RichText::new(t!("wallets.pin_invalid",
+                                          "min" => MIN_PIN_LENGTH,
+                                          "max" => MAX_PIN_LENGTH))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+
+            // Show biometric unlock setup.
+            if cb.biometric_available() {
+                ui.add_space(8.0);
+                let text = format!("{} {}", FINGERPRINT, t!("wallets.use_biometric"));
+                View::checkbox(ui, self.use_biometric, text, || {
+                    self.use_biometric = !self.use_biometric;
+                });
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let mut on_save = || {
+                        if self.pass_edit.is_empty() || !is_valid_pin(&self.pin_edit) {
+                            return;
+                        }
+                        if !wallet.verify_password(self.pass_edit.clone()) {
+                            self.wrong_pass = true;
+                            return;
+                        }
+                        let pass = self.pass_edit.as_bytes();
+                        let pin_data = WalletUtils::encrypt_with_pin(&self.pin_edit, pass)
+                            .and_then(|d| cb.encrypt_secret(d, false));
+                        let pass = ZeroingString::from(self.pass_edit.as_str());
+                        self.pass_edit = "".to_string();
+                        self.pin_edit = "".to_string();
+                        cb.hide_keyboard();
+                        if let Some(pin_data) = pin_data {
+                            // Biometric key is available only after authentication.
+                            if self.use_biometric {
+                                self.bio_pending = Some((pin_data, pass));
+                                cb.start_biometric_auth();
+                                return;
+                            }
+                            wallet.update_unlock_data(Some(pin_data), None);
+                        }
+                        modal.close();
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Get wallet password from quick unlock data with provided PIN
+    /// or after biometric authentication when PIN is not provided.
+    pub fn unlock_password(wallet: &Wallet,
+                           pin: Option<&str>,
+                           cb: &dyn PlatformCallbacks) -> Option<String> {
+        let data = wallet.unlock_data(pin.is_none())?;
+        let pass = match pin {
+            Some(pin) => {
+                let data = cb.decrypt_secret(data, false)?;
+                let pass = WalletUtils::decrypt_with_pin(pin, &data)?;
+                wallet.update_unlock_pin_attempts(0);
+                pass
+            }
+            None => cb.decrypt_secret(data, true)?
+        };
+        String::from_utf8(pass).ok()
+    }
+}
+
+/// Check if PIN contains only digits and has correct length.
+fn is_valid_pin(pin: &String) -> bool {
+    pin.len() >= MIN_PIN_LENGTH && pin.len() <= MAX_PIN_LENGTH &&
+        pin.chars().all(|c| c.is_ascii_digit())
+}
@@ -12,19 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{Align, Layout, RichText, Rounding};
+use std::time::Duration;
+use egui::{Align, Id, Layout, RichText, Rounding};
+use grin_core::core::amount_from_hr_string;
 
+use crate::AppConfig;
 use crate::gui::Colors;
-use crate::gui::icons::{CHECK_CIRCLE, COPY, DOTS_THREE_CIRCLE, EXPORT, GEAR_SIX, GLOBE_SIMPLE, POWER, QR_CODE, SHIELD_CHECKERED, SHIELD_SLASH, WARNING_CIRCLE, X_CIRCLE};
+use crate::gui::icons::{CHART_BAR, CHECK_CIRCLE, CLOCK, COPY, DOTS_THREE_CIRCLE, EXPORT, GEAR_SIX, GLOBE_SIMPLE, POWER, PUSH_PIN, QR_CODE, QUESTION, SHARE_FAT, SHIELD_CHECKERED, SHIELD_SLASH, STOP, TIMER, WARNING_CIRCLE, X_CIRCLE};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, QrCodeContent, View};
-use crate::gui::views::types::ModalPosition;
+use crate::gui::views::{HelpContent, HelpPage, Modal, QrCodeContent, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::wallet::transport::donate::DonationsContent;
 use crate::gui::views::wallets::wallet::transport::send::TransportSendModal;
 use crate::gui::views::wallets::wallet::transport::settings::TransportSettingsModal;
-use crate::gui::views::wallets::wallet::types::{WalletTab, WalletTabType};
+use crate::gui::views::wallets::wallet::types::{account_label, WalletTab, WalletTabType};
 use crate::tor::{Tor, TorConfig};
 use crate::wallet::types::WalletData;
-use crate::wallet::Wallet;
+use crate::wallet::{PaymentUri, Wallet};
 
 /// Wallet transport tab content.
 pub struct WalletTransport {
@@ -33,9 +37,25 @@ pub struct WalletTransport {
 
     /// QR code address image [`Modal`] content.
     qr_address_content: Option<QrCodeContent>,
+    /// Requested amount value for payment request at QR code address [`Modal`].
+    request_amount_edit: String,
+    /// Memo value for payment request at QR code address [`Modal`].
+    request_memo_edit: String,
+    /// Payment request shown at QR code address [`Modal`].
+    request_uri: String,
+    /// Flag to sign payment request with address key at QR code address [`Modal`].
+    request_sign: bool,
+    /// Unsigned payment request with signing flag to check if request was changed.
+    request_key: String,
 
     /// Tor settings [`Modal`] content.
     settings_modal_content: Option<TransportSettingsModal>,
+
+    /// Donation shortcuts content.
+    donations_content: DonationsContent,
+
+    /// Flag to show Tor transport statistics.
+    show_stats: bool,
 }
 
 impl WalletTab for WalletTransport {
@@ -66,12 +86,30 @@ impl Default for WalletTransport {
         Self {
             send_modal_content: None,
             qr_address_content: None,
+            request_amount_edit: "".to_string(),
+            request_memo_edit: "".to_string(),
+            request_uri: "".to_string(),
+            request_sign: false,
+            request_key: "".to_string(),
             settings_modal_content: None,
+            donations_content: DonationsContent::default(),
+            show_stats: false,
         }
     }
 }
 
 impl WalletTransport {
+    /// Create new content to send amount over Tor from provided payment request.
+    pub fn from_uri(uri: &PaymentUri) -> Self {
+        let mut content = Self::default();
+        content.send_modal_content = Some(TransportSendModal::from_uri(uri));
+        Modal::new(SEND_TOR_MODAL)
+            .position(ModalPosition::CenterTop)
+            .title(t!("wallets.send"))
+            .show();
+        content
+    }
+
     /// Draw wallet transport content.
     fn transport_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
         ui.add_space(3.0);
@@ -109,7 +147,7 @@ impl WalletTransport {
                     }
                     QR_ADDRESS_MODAL => {
                         Modal::ui(ui.ctx(), |ui, modal| {
-                            self.qr_address_modal_ui(ui, modal, cb);
+                            self.qr_address_modal_ui(ui, wallet, modal, cb);
                         });
                     }
                     _ => {}
@@ -125,6 +163,11 @@ impl WalletTransport {
         // Draw header content.
         self.tor_header_ui(ui, wallet);
 
+        // Draw statistics content.
+        if self.show_stats {
+            self.tor_stats_ui(ui, wallet);
+        }
+
         // Draw receive info content.
         if wallet.slatepack_address().is_some() {
             self.tor_receive_ui(ui, wallet, &data, cb);
@@ -132,9 +175,31 @@ impl WalletTransport {
 
         // Draw send content.
         let service_id = &wallet.identifier();
-        if data.info.amount_currently_spendable > 0 && wallet.foreign_api_port().is_some() &&
+        if wallet.is_repairing() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.repair_read_only"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else if data.info.amount_currently_spendable > 0 && wallet.foreign_api_port().is_some() &&
             !Tor::is_service_starting(service_id) {
             self.tor_send_ui(ui, cb);
+
+            // Draw donation shortcuts.
+            if AppConfig::show_donations() {
+                let mut donation_uri = None;
+                self.donations_content.ui(ui, wallet, cb, |uri| {
+                    donation_uri = Some(uri);
+                });
+                if let Some(uri) = donation_uri {
+                    self.send_modal_content = Some(TransportSendModal::from_uri(&uri));
+                    Modal::new(SEND_TOR_MODAL)
+                        .position(ModalPosition::CenterTop)
+                        .title(t!("wallets.send"))
+                        .show();
+                }
+            }
         }
     }
 
@@ -163,23 +228,41 @@ impl WalletTransport {
                         .show();
                 });
 
+                // Draw button to show Tor transport statistics.
+                let stats_color = if self.show_stats {
+                    Some(Colors::gold())
+                } else {
+                    None
+                };
+                View::item_button(ui, Rounding::default(), CHART_BAR, stats_color, || {
+                    self.show_stats = !self.show_stats;
+                });
+
                 // Draw button to enable/disable Tor listener for current wallet.
                 let service_id = &wallet.identifier();
-                if  !Tor::is_service_starting(service_id) && wallet.foreign_api_port().is_some() {
+                if  !Tor::is_service_starting(service_id) && wallet.foreign_api_port().is_some() &&
+                    wallet.tor_listener_allowed() {
                     if !Tor::is_service_running(service_id) {
                         View::item_button(ui, Rounding::default(), POWER, Some(Colors::green()), || {
-                            if let Ok(key) = wallet.secret_key() {
+                            if let Ok(key) = wallet.tor_service_key() {
                                 let api_port = wallet.foreign_api_port().unwrap();
                                 Tor::start_service(api_port, key, service_id);
                             }
                         });
                     } else {
-                        View::item_button(ui, Rounding::default(), POWER, Some(Colors::red()), || {
+                        View::item_button(ui, Rounding::default(), STOP, Some(Colors::red()), || {
                             Tor::stop_service(service_id);
                         });
                     }
                 }
 
+                // Draw button to open Tor help page on connection error.
+                if Tor::is_service_failed(service_id) {
+                    View::item_button(ui, Rounding::default(), QUESTION, None, || {
+                        HelpContent::show(Some(HelpPage::Tor));
+                    });
+                }
+
                 let layout_size = ui.available_size();
                 ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
                     ui.add_space(6.0);
@@ -198,12 +281,27 @@ impl WalletTransport {
                         let has_error = Tor::is_service_failed(service_id);
                         let (icon, text) = if wallet.foreign_api_port().is_none() {
                             (DOTS_THREE_CIRCLE, t!("wallets.loading"))
+                        } else if !wallet.tor_listener_allowed() {
+                            let label = wallet.tor_listener_account().unwrap_or_default();
+                            let account = account_label(wallet, &label);
+                            (PUSH_PIN, t!("transport.listener_pinned", "account" => account))
                         } else if is_starting {
                             (DOTS_THREE_CIRCLE, t!("transport.connecting"))
                         } else if has_error {
                             (WARNING_CIRCLE, t!("transport.conn_error"))
                         } else if is_running {
-                            (CHECK_CIRCLE, t!("transport.connected"))
+                            // Refresh service uptime.
+                            ui.ctx().request_repaint_after(Duration::from_millis(1000));
+                            if Tor::is_service_published(service_id) {
+                                let uptime = Tor::service_uptime(service_id)
+                                    .map(|d| d.as_secs())
+                                    .unwrap_or(0);
+                                let uptime_text = format_uptime(uptime);
+                                let text = format!("{} {}", t!("transport.connected"), uptime_text);
+                                (CHECK_CIRCLE, text)
+                            } else {
+                                (DOTS_THREE_CIRCLE, t!("transport.publishing"))
+                            }
                         } else {
                             (X_CIRCLE, t!("transport.disconnected"))
                         };
@@ -232,6 +330,54 @@ impl WalletTransport {
         });
     }
 
+    /// Draw Tor transport statistics content.
+    fn tor_stats_ui(&self, ui: &mut egui::Ui, wallet: &Wallet) {
+        let stats = wallet.tor_stats();
+        let service_id = &wallet.identifier();
+
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(52.0);
+
+        // Draw background.
+        let bg_rect = rect.clone();
+        ui.painter().rect(bg_rect, Rounding::ZERO, Colors::fill_lite(), View::item_stroke());
+
+        ui.allocate_ui_with_layout(rect.size(), Layout::top_down(Align::Center), |ui| {
+            ui.set_min_size(rect.size());
+            ui.add_space(6.0);
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    let sent_text = format!("{} {}: {}",
+                                            CHECK_CIRCLE,
+                                            t!("transport.stats_sent"),
+                                            stats.sent);
+                    ui.label(RichText::new(sent_text).size(15.0).color(Colors::text(false)));
+                    let failed_text = format!("{} {}: {}",
+                                              X_CIRCLE,
+                                              t!("transport.stats_failed"),
+                                              stats.failed);
+                    ui.label(RichText::new(failed_text).size(15.0).color(Colors::text(false)));
+                });
+                columns[1].vertical_centered(|ui| {
+                    let avg = stats.average_send_duration()
+                        .map(|d| format!("{:.1}s", d.as_secs_f32()))
+                        .unwrap_or("-".to_string());
+                    let avg_text = format!("{} {}: {}", TIMER, t!("transport.stats_avg_send"), avg);
+                    ui.label(RichText::new(avg_text).size(15.0).color(Colors::text(false)));
+                    let uptime = Tor::service_uptime(service_id)
+                        .map(|d| format_uptime(d.as_secs()))
+                        .unwrap_or("-".to_string());
+                    let uptime_text = format!("{} {}: {}",
+                                              CLOCK,
+                                              t!("transport.stats_uptime"),
+                                              uptime);
+                    ui.label(RichText::new(uptime_text).size(15.0).color(Colors::text(false)));
+                });
+            });
+        });
+    }
+
     /// Draw Tor receive content.
     fn tor_receive_ui(&mut self,
                       ui: &mut egui::Ui,
@@ -265,6 +411,10 @@ impl WalletTransport {
                 };
                 View::item_button(ui, button_rounding, QR_CODE, None, || {
                     // Show QR code image address modal.
+                    self.request_amount_edit = "".to_string();
+                    self.request_memo_edit = "".to_string();
+                    self.request_uri = addr.clone();
+                    self.request_key = "".to_string();
                     self.qr_address_content = Some(QrCodeContent::new(addr.clone(), false));
                     Modal::new(QR_ADDRESS_MODAL)
                         .position(ModalPosition::CenterTop)
@@ -283,19 +433,19 @@ impl WalletTransport {
                     ui.vertical(|ui| {
                         ui.add_space(3.0);
 
-                        // Show wallet Slatepack address.
-                        let address_color = if Tor::is_service_starting(service_id) ||
+                        // Show wallet Slatepack address with status icon.
+                        let (address_color, status_icon) = if Tor::is_service_starting(service_id) ||
                             wallet.foreign_api_port().is_none() {
-                            Colors::inactive_text()
+                            (Colors::inactive_text(), GLOBE_SIMPLE)
                         } else if Tor::is_service_running(service_id) {
-                            Colors::green()
+                            (Colors::green(), CHECK_CIRCLE)
                         } else {
-                            Colors::red()
+                            (Colors::red(), X_CIRCLE)
                         };
                         View::ellipsize_text(ui, addr, 15.0, address_color);
 
                         let address_label = format!("{} {}",
-                                                    GLOBE_SIMPLE,
+                                                    status_icon,
                                                     t!("network_mining.address"));
                         ui.label(RichText::new(address_label).size(15.0).color(Colors::gray()));
                     });
@@ -307,6 +457,7 @@ impl WalletTransport {
     /// Draw QR code image address [`Modal`] content.
     fn qr_address_modal_ui(&mut self,
                            ui: &mut egui::Ui,
+                           wallet: &Wallet,
                            modal: &Modal,
                            cb: &dyn PlatformCallbacks) {
         ui.add_space(6.0);
@@ -319,10 +470,78 @@ impl WalletTransport {
             return;
         }
 
-        ui.vertical_centered_justified(|ui| {
-            View::button(ui, t!("close"), Colors::white_or_black(false), || {
-                self.qr_address_content = None;
-                modal.close();
+        // Draw payment request amount and memo inputs.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("transport.request_amount"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(6.0);
+            let amount_id = Id::from(modal.id).with("amount");
+            let mut amount_edit_opts = TextEditOptions::new(amount_id).h_center().no_focus();
+            View::text_edit(ui, cb, &mut self.request_amount_edit, &mut amount_edit_opts);
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("transport.request_memo"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(6.0);
+            let memo_id = Id::from(modal.id).with("memo");
+            let mut memo_edit_opts = TextEditOptions::new(memo_id).no_focus();
+            View::text_edit(ui, cb, &mut self.request_memo_edit, &mut memo_edit_opts);
+            ui.add_space(8.0);
+
+            // Show checkbox to sign payment request.
+            View::checkbox(ui, self.request_sign, t!("transport.request_sign"), || {
+                self.request_sign = !self.request_sign;
+            });
+        });
+        ui.add_space(8.0);
+
+        // Update QR code on payment request change.
+        if let Some(addr) = wallet.slatepack_address() {
+            let amount_text = self.request_amount_edit.trim().replace(",", ".");
+            let amount = amount_from_hr_string(amount_text.as_str()).ok().filter(|a| *a > 0);
+            let memo = Some(self.request_memo_edit.clone());
+            let mut uri = PaymentUri::new(addr.clone(), amount, memo);
+            let request_key = format!("{}_{}", uri.to_uri(), self.request_sign);
+            if request_key != self.request_key {
+                self.request_key = request_key;
+                if self.request_sign {
+                    let _ = wallet.sign_payment_uri(&mut uri);
+                }
+                let request = if uri.amount.is_none() && uri.memo.is_none() &&
+                    uri.signature.is_none() {
+                    addr
+                } else {
+                    uri.to_uri()
+                };
+                self.qr_address_content = Some(QrCodeContent::new(request.clone(), false));
+                self.request_uri = request;
+            }
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                    self.qr_address_content = None;
+                    cb.hide_keyboard();
+                    modal.close();
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                let copy_text = format!("{} {}", COPY, t!("copy"));
+                View::button(ui, copy_text, Colors::white_or_black(false), || {
+                    cb.copy_string_to_buffer(self.request_uri.clone());
+                });
+            });
+            columns[2].vertical_centered_justified(|ui| {
+                let share_text = format!("{} {}", SHARE_FAT, t!("share"));
+                View::button(ui, share_text, Colors::white_or_black(false), || {
+                    let name = "grin-payment-request.txt".to_string();
+                    let _ = cb.share_data(name, self.request_uri.as_bytes().to_vec());
+                });
             });
         });
         ui.add_space(6.0);
@@ -361,4 +580,9 @@ impl WalletTransport {
             .show();
         cb.show_keyboard();
     }
+}
+
+/// Format uptime in seconds as hours, minutes and seconds.
+fn format_uptime(secs: u64) -> String {
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
 }
\ No newline at end of file
@@ -0,0 +1,282 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Align, Id, Layout, RichText};
+use grin_core::core::amount_from_hr_string;
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::icons::{HAND_HEART, PLUS_CIRCLE, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::views::types::{ModalPosition, TextEditOptions};
+use crate::gui::views::wallets::wallet::types::{address_error_text, amount_to_display, GRIN};
+use crate::settings::DonationTarget;
+use crate::wallet::types::AddressError;
+use crate::wallet::{PaymentUri, Wallet, WalletUtils};
+
+/// Donation shortcuts content to send preset amounts to configured receivers.
+pub struct DonationsContent {
+    /// Receiver name [`Modal`] value.
+    name_edit: String,
+    /// Receiver address [`Modal`] value.
+    address_edit: String,
+    /// Preset amounts [`Modal`] value.
+    amounts_edit: String,
+    /// Receiver address validation error.
+    address_error: Option<AddressError>,
+    /// Flag to check if entered amounts are not valid.
+    amounts_error: bool,
+}
+
+/// Identifier for donation receiver creation [`Modal`].
+const ADD_DONATION_MODAL: &'static str = "add_donation_target_modal";
+
+/// Maximal amount of preset amounts for receiver.
+const MAX_PRESET_AMOUNTS: usize = 4;
+
+impl Default for DonationsContent {
+    fn default() -> Self {
+        Self {
+            name_edit: "".to_string(),
+            address_edit: "".to_string(),
+            amounts_edit: "".to_string(),
+            address_error: None,
+            amounts_error: false,
+        }
+    }
+}
+
+impl DonationsContent {
+    /// Draw donation shortcuts content, calling back with payment request on amount selection.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              wallet: &Wallet,
+              cb: &dyn PlatformCallbacks,
+              mut on_select: impl FnMut(PaymentUri)) {
+        // Show modal content for this ui container.
+        if Modal::opened() == Some(ADD_DONATION_MODAL) {
+            Modal::ui(ui.ctx(), |ui, modal| {
+                self.add_modal_ui(ui, wallet, modal, cb);
+            });
+        }
+
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", HAND_HEART, t!("wallets.donate")));
+        ui.add_space(4.0);
+
+        let targets = AppConfig::donation_targets();
+        for (index, target) in targets.iter().enumerate() {
+            Self::target_ui(ui, index, target, &mut on_select);
+            ui.add_space(6.0);
+        }
+
+        // Show button to add new receiver.
+        ui.vertical_centered(|ui| {
+            let add_text = format!("{} {}", PLUS_CIRCLE, t!("wallets.donation_add"));
+            View::button(ui, add_text, Colors::white_or_black(false), || {
+                self.name_edit = "".to_string();
+                self.address_edit = "".to_string();
+                self.amounts_edit = "".to_string();
+                self.address_error = None;
+                self.amounts_error = false;
+                Modal::new(ADD_DONATION_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.donate"))
+                    .show();
+                cb.show_keyboard();
+            });
+        });
+        ui.add_space(8.0);
+    }
+
+    /// Draw donation receiver with buttons to send preset amounts.
+    fn target_ui(ui: &mut egui::Ui,
+                 index: usize,
+                 target: &DonationTarget,
+                 on_select: &mut impl FnMut(PaymentUri)) {
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(56.0);
+
+        // Draw round background.
+        let item_rounding = View::item_rounding(0, 2, false);
+        ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke());
+
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            // Draw button to remove receiver.
+            View::item_button(ui, View::item_rounding(0, 2, true), TRASH, None, || {
+                AppConfig::remove_donation_target(index);
+            });
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(8.0);
+                ui.vertical(|ui| {
+                    ui.add_space(4.0);
+                    View::ellipsize_text(ui, target.name.clone(), 17.0, Colors::title(false));
+                    View::ellipsize_text(ui, target.address.clone(), 15.0, Colors::gray());
+                });
+            });
+        });
+
+        // Show buttons to send preset amounts.
+        if !target.amounts.is_empty() {
+            ui.add_space(6.0);
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(target.amounts.len(), |columns| {
+                    for (i, amount) in target.amounts.iter().enumerate() {
+                        columns[i].vertical_centered_justified(|ui| {
+                            let text = format!("{} {}", amount_to_display(*amount), GRIN);
+                            View::button(ui, text, Colors::white_or_black(false), || {
+                                on_select(PaymentUri::new(target.address.clone(),
+                                                          Some(*amount),
+                                                          None));
+                            });
+                        });
+                    }
+                });
+            });
+        }
+    }
+
+    /// Parse preset amounts separated by comma.
+    fn parse_amounts(text: &str) -> Option<Vec<u64>> {
+        let amounts = text.split(',')
+            .map(|a| a.trim())
+            .filter(|a| !a.is_empty())
+            .map(|a| amount_from_hr_string(a).ok().filter(|v| *v > 0))
+            .collect::<Option<Vec<u64>>>()?;
+        if amounts.is_empty() || amounts.len() > MAX_PRESET_AMOUNTS {
+            return None;
+        }
+        Some(amounts)
+    }
+
+    /// Draw donation receiver creation [`Modal`] content.
+    fn add_modal_ui(&mut self,
+                    ui: &mut egui::Ui,
+                    wallet: &Wallet,
+                    modal: &Modal,
+                    cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            // Show receiver name input.
+            ui.label(RichText::new(t!("wallets.name"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut name_edit_opts = TextEditOptions::new(Id::from(modal.id).with("name"));
+            View::text_edit(ui, cb, &mut self.name_edit, &mut name_edit_opts);
+            ui.add_space(8.0);
+
+            // Show receiver address input.
+            ui.label(RichText::new(t!("transport.receiver_address"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let address_before = self.address_edit.clone();
+            let mut address_edit_opts = TextEditOptions::new(Id::from(modal.id).with("address"))
+                .paste()
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.address_edit, &mut address_edit_opts);
+            if address_before != self.address_edit {
+                self.address_error = None;
+            }
+            if let Some(err) = &self.address_error {
+                ui.add_space(6.0);
+                ui.label(RichText::new(address_error_text(err))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(8.0);
+
+            // Show preset amounts input.
+            ui.label(RichText::new(t!("wallets.donation_amounts"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let amounts_before = self.amounts_edit.clone();
+            let mut amounts_edit_opts = TextEditOptions::new(Id::from(modal.id).with("amounts"))
+                .no_focus();
+            View::text_edit(ui, cb, &mut self.amounts_edit, &mut amounts_edit_opts);
+            if amounts_before != self.amounts_edit {
+                self.amounts_error = false;
+            }
+            if self.amounts_error {
+                ui.add_space(6.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        cb.hide_keyboard();
+                        modal.close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    // Save button callback.
+                    let mut on_save = || {
+                        if self.name_edit.trim().is_empty() {
+                            return;
+                        }
+                        let chain_type = wallet.get_config().chain_type;
+                        let address = match WalletUtils::parse_address(&self.address_edit,
+                                                                       chain_type) {
+                            Ok(addr) => addr.to_string(),
+                            Err(e) => {
+                                self.address_error = Some(e);
+                                return;
+                            }
+                        };
+                        let amounts = match Self::parse_amounts(&self.amounts_edit) {
+                            Some(amounts) => amounts,
+                            None => {
+                                self.amounts_error = true;
+                                return;
+                            }
+                        };
+                        AppConfig::add_donation_target(DonationTarget {
+                            name: self.name_edit.trim().to_string(),
+                            address,
+                            amounts,
+                        });
+                        cb.hide_keyboard();
+                        modal.close();
+                    };
+
+                    View::on_enter_key(ui, || {
+                        (on_save)();
+                    });
+
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), on_save);
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
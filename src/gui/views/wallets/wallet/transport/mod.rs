@@ -16,4 +16,5 @@ mod content;
 pub use content::*;
 
 mod send;
-mod settings;
\ No newline at end of file
+mod settings;
+mod donate;
\ No newline at end of file
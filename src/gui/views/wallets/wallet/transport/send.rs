@@ -16,18 +16,21 @@ use std::sync::Arc;
 use std::thread;
 use egui::{Id, RichText};
 use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
-use grin_wallet_libwallet::{Error, SlatepackAddress};
+use grin_wallet_libwallet::Error;
 use parking_lot::RwLock;
 use tor_rtcompat::BlockOn;
 use tor_rtcompat::tokio::TokioNativeTlsRuntime;
+use crate::AppConfig;
 use crate::gui::Colors;
+use crate::gui::icons::{NOTE, SEAL_CHECK, SEAL_WARNING, USER};
 use crate::gui::platform::PlatformCallbacks;
 
 use crate::gui::views::{CameraContent, Modal, View};
 use crate::gui::views::types::TextEditOptions;
+use crate::gui::views::wallets::wallet::types::{account_label, address_error_text, fee_factor_ui, send_preview_ui};
 use crate::gui::views::wallets::wallet::WalletTransactionModal;
-use crate::wallet::types::WalletTransaction;
-use crate::wallet::Wallet;
+use crate::wallet::types::{AddressError, WalletTransaction};
+use crate::wallet::{FiatRate, PaymentUri, PriceFeed, Wallet, WalletConfig, WalletUtils};
 
 /// Transport sending [`Modal`] content.
 pub struct TransportSendModal {
@@ -43,10 +46,33 @@ pub struct TransportSendModal {
 
     /// Entered amount value.
     amount_edit: String,
+    /// Entered amount value in fiat currency.
+    fiat_edit: String,
+    /// Amount value and price used to calculate fiat value.
+    fiat_source: Option<(String, f64)>,
     /// Entered address value.
     address_edit: String,
-    /// Flag to check if entered address is incorrect.
-    address_error: bool,
+    /// Error of entered address validation.
+    address_error: Option<AddressError>,
+    /// Wallet and account names when entered address belongs to opened wallet.
+    own_address: Option<(String, String)>,
+    /// Flag to check if sending to own address was confirmed.
+    own_address_confirmed: bool,
+    /// Memo to save for transaction from payment request.
+    memo: Option<String>,
+    /// Signed payment request with signature verification result.
+    signed_request: Option<(PaymentUri, bool)>,
+
+    /// Estimated fee to confirm sending.
+    send_fee: Option<u64>,
+    /// Flag to check if fee is estimating.
+    fee_estimating: bool,
+    /// Fee estimation result with used fee base multiplier.
+    fee_result: Arc<RwLock<Option<(u64, Result<u64, Error>)>>>,
+    /// Fee estimation error text.
+    fee_error: Option<String>,
+    /// Multiplier of network fee base for sending.
+    fee_factor: u64,
 
     /// Address QR code scanner content.
     address_scan_content: Option<CameraContent>,
@@ -64,13 +90,64 @@ impl TransportSendModal {
             error: false,
             send_result: Arc::new(RwLock::new(None)),
             amount_edit: "".to_string(),
+            fiat_edit: "".to_string(),
+            fiat_source: None,
             address_edit: addr.unwrap_or("".to_string()),
-            address_error: false,
+            address_error: None,
+            own_address: None,
+            own_address_confirmed: false,
+            memo: None,
+            signed_request: None,
+            send_fee: None,
+            fee_estimating: false,
+            fee_result: Arc::new(RwLock::new(None)),
+            fee_error: None,
+            fee_factor: 1,
             address_scan_content: None,
             tx_info_content: None,
         }
     }
 
+    /// Create new instance pre-filled from provided payment request.
+    pub fn from_uri(uri: &PaymentUri) -> Self {
+        let mut content = Self::new(Some(uri.address.clone()));
+        content.set_uri(uri);
+        content
+    }
+
+    /// Setup values from payment request.
+    fn set_uri(&mut self, uri: &PaymentUri) {
+        self.address_edit = uri.address.clone();
+        if let Some(amount) = uri.amount {
+            self.amount_edit = amount_to_hr_string(amount, true);
+        }
+        self.memo = uri.memo.clone();
+        self.signed_request = uri.verify().map(|valid| (uri.clone(), valid));
+    }
+
+    /// Check signature of payment request, `None` if request was not signed or was changed.
+    fn request_signature_valid(&self) -> Option<bool> {
+        let (uri, valid) = self.signed_request.as_ref()?;
+        let amount = amount_from_hr_string(self.amount_edit.trim()).ok();
+        if self.address_edit.trim() != uri.address || (uri.amount.is_some() && amount != uri.amount) {
+            return None;
+        }
+        Some(*valid)
+    }
+
+    /// Draw payment request signature verification result.
+    fn request_signature_ui(&self, ui: &mut egui::Ui) {
+        if let Some(valid) = self.request_signature_valid() {
+            ui.add_space(6.0);
+            let (text, color) = if valid {
+                (format!("{} {}", SEAL_CHECK, t!("transport.request_signed")), Colors::green())
+            } else {
+                (format!("{} {}", SEAL_WARNING, t!("transport.request_sig_invalid")), Colors::red())
+            };
+            ui.label(RichText::new(text).size(16.0).color(color));
+        }
+    }
+
     /// Draw [`Modal`] content.
     pub fn ui(&mut self,
               ui: &mut egui::Ui,
@@ -84,15 +161,115 @@ impl TransportSendModal {
         }
 
         // Draw sending content, progress or an error.
+        self.check_fee_result(cb);
         if self.sending {
             self.progress_ui(ui, wallet);
+        } else if self.fee_estimating && self.send_fee.is_none() {
+            ui.add_space(16.0);
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+            });
+            ui.add_space(16.0);
         } else if self.error {
             self.error_ui(ui, wallet, modal, cb);
+        } else if let Some(fee) = self.send_fee {
+            self.confirmation_ui(ui, wallet, modal, cb, fee);
+        } else if self.own_address.is_some() {
+            self.own_address_ui(ui, wallet, cb);
         } else {
             self.content_ui(ui, wallet, modal, cb);
         }
     }
 
+    /// Draw amount input in fiat currency with conversion by current price.
+    fn fiat_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, modal: &Modal,
+               cb: &dyn PlatformCallbacks) {
+        PriceFeed::check_periodically(ui.ctx());
+        let rate = match PriceFeed::rate() {
+            Some(rate) => rate,
+            None => {
+                let text = if PriceFeed::has_error() {
+                    t!("wallets.fiat_rate_error")
+                } else {
+                    t!("wallets.fiat_rate_loading")
+                };
+                ui.vertical_centered(|ui| {
+                    ui.label(RichText::new(text).size(16.0).color(Colors::inactive_text()));
+                });
+                ui.add_space(8.0);
+                return;
+            }
+        };
+
+        // Update fiat value on amount or price change.
+        let source = (self.amount_edit.clone(), rate.price);
+        if self.fiat_source.as_ref() != Some(&source) {
+            self.fiat_edit = match amount_from_hr_string(self.amount_edit.as_str()) {
+                Ok(a) if !self.amount_edit.is_empty() => format!("{:.2}", rate.to_fiat(a)),
+                _ => "".to_string()
+            };
+            self.fiat_source = Some(source);
+        }
+
+        ui.vertical_centered(|ui| {
+            let fiat_text = t!("wallets.fiat_amount", "currency" => rate.currency.code());
+            ui.label(RichText::new(fiat_text).size(17.0).color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+
+        // Draw fiat amount text edit.
+        let fiat_edit_id = Id::from(modal.id).with("fiat").with(wallet.get_config().id);
+        let mut fiat_edit_opts = TextEditOptions::new(fiat_edit_id)
+            .h_center()
+            .no_focus()
+            .keypad(false);
+        let fiat_edit_before = self.fiat_edit.clone();
+        View::text_edit(ui, cb, &mut self.fiat_edit, &mut fiat_edit_opts);
+
+        // Convert entered value to amount if input was changed.
+        if fiat_edit_before != self.fiat_edit {
+            self.fee_error = None;
+            // Trim text, replace "," by "." and parse value.
+            let value = self.fiat_edit.trim().replace(",", ".");
+            let parts = value.split(".").collect::<Vec<&str>>();
+            let valid = value.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && parts.len() <= 2 && parts.get(1).map(|p| p.len() <= 2).unwrap_or(true);
+            let amount = if value.is_empty() {
+                Some(None)
+            } else if valid {
+                value.parse::<f64>().ok().and_then(|v| rate.from_fiat(v)).map(Some)
+            } else {
+                None
+            };
+            // Do not input amount more than balance in sending.
+            let spendable = wallet.get_data().unwrap().info.amount_currently_spendable;
+            match amount {
+                Some(a) if a.unwrap_or(0) <= spendable => {
+                    self.fiat_edit = value;
+                    self.amount_edit = match a {
+                        Some(a) => amount_to_hr_string(a, true),
+                        None => "".to_string()
+                    };
+                    self.fiat_source = Some((self.amount_edit.clone(), rate.price));
+                }
+                _ => self.fiat_edit = fiat_edit_before
+            }
+        }
+        ui.add_space(4.0);
+        ui.vertical_centered(|ui| {
+            Self::fiat_rate_ui(ui, &rate);
+        });
+        ui.add_space(8.0);
+    }
+
+    /// Draw price and its update time used for conversion.
+    fn fiat_rate_ui(ui: &mut egui::Ui, rate: &FiatRate) {
+        let rate_text = t!("wallets.fiat_rate",
+            "price" => format!("{:.4} {}", rate.price, rate.currency.code()),
+            "time" => View::format_time(rate.time));
+        ui.label(RichText::new(rate_text).size(15.0).color(Colors::inactive_text()));
+    }
+
     /// Draw content to send.
     fn content_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, modal: &Modal,
                   cb: &dyn PlatformCallbacks) {
@@ -106,7 +283,12 @@ impl TransportSendModal {
             };
 
             if let Some(result) = scanner.qr_scan_result() {
-                self.address_edit = result.text();
+                let text = result.text();
+                if let Some(uri) = PaymentUri::parse(&text) {
+                    self.set_uri(&uri);
+                } else {
+                    self.address_edit = text;
+                }
                 on_stop();
                 self.address_scan_content = None;
                 cb.show_keyboard();
@@ -151,17 +333,35 @@ impl TransportSendModal {
 
         // Draw amount text edit.
         let amount_edit_id = Id::from(modal.id).with("amount").with(wallet.get_config().id);
-        let mut amount_edit_opts = TextEditOptions::new(amount_edit_id).h_center().no_focus();
+        let mut amount_edit_opts = TextEditOptions::new(amount_edit_id)
+            .h_center()
+            .no_focus()
+            .keypad(true);
         let amount_edit_before = self.amount_edit.clone();
         if self.first_draw {
             self.first_draw = false;
             amount_edit_opts.focus = true;
         }
         View::text_edit(ui, cb, &mut self.amount_edit, &mut amount_edit_opts);
+        // Enter maximum amount to send if requested.
+        if amount_edit_opts.max_pressed {
+            if let Ok(max) = wallet.max_send_amount(self.fee_factor) {
+                self.amount_edit = amount_to_hr_string(max, true);
+            }
+        }
         ui.add_space(8.0);
 
+        // Show fee estimation error.
+        if let Some(err) = &self.fee_error {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(err).size(17.0).color(Colors::red()));
+            });
+            ui.add_space(8.0);
+        }
+
         // Check value if input was changed.
         if amount_edit_before != self.amount_edit {
+            self.fee_error = None;
             if !self.amount_edit.is_empty() {
                 // Trim text, replace "," by "." and parse amount.
                 self.amount_edit = self.amount_edit.trim().replace(",", ".");
@@ -195,10 +395,15 @@ impl TransportSendModal {
             }
         }
 
+        // Draw amount input in fiat currency.
+        if AppConfig::fiat_currency().is_some() {
+            self.fiat_ui(ui, wallet, modal, cb);
+        }
+
         // Show address error or input description.
         ui.vertical_centered(|ui| {
-            if self.address_error {
-                ui.label(RichText::new(t!("transport.incorrect_addr_err"))
+            if let Some(err) = &self.address_error {
+                ui.label(RichText::new(address_error_text(err))
                     .size(17.0)
                     .color(Colors::red()));
             } else {
@@ -215,8 +420,12 @@ impl TransportSendModal {
         let mut address_edit_opts = TextEditOptions::new(address_edit_id)
             .paste()
             .no_focus()
-            .scan_qr();
+            .scan_qr()
+            .address_keypad();
         View::text_edit(ui, cb, &mut self.address_edit, &mut address_edit_opts);
+        ui.vertical_centered(|ui| {
+            self.request_signature_ui(ui);
+        });
         // Check if scan button was pressed.
         if address_edit_opts.scan_pressed {
             cb.hide_keyboard();
@@ -228,7 +437,8 @@ impl TransportSendModal {
 
         // Check value if input was changed.
         if addr_edit_before != self.address_edit {
-            self.address_error = false;
+            self.address_error = None;
+            self.own_address_confirmed = false;
         }
 
         // Setup spacing between buttons.
@@ -241,7 +451,101 @@ impl TransportSendModal {
                 });
             });
             columns[1].vertical_centered_justified(|ui| {
-                View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                // Continue button callback.
+                let mut on_continue = || {
+                    self.estimate_fee(wallet, cb);
+                };
+
+                View::on_enter_key(ui, || {
+                    (on_continue)();
+                });
+
+                View::button(ui, t!("continue"), Colors::white_or_black(false), on_continue);
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Draw sending preview content with confirmation buttons.
+    fn confirmation_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks,
+                       fee: u64) {
+        ui.add_space(6.0);
+        let amount = amount_from_hr_string(self.amount_edit.as_str()).unwrap_or(0);
+        send_preview_ui(ui, wallet, amount, fee);
+
+        // Show amount value in fiat currency.
+        if let Some(rate) = PriceFeed::rate() {
+            ui.vertical_centered(|ui| {
+                let value = format!("≈ {:.2} {}", rate.to_fiat(amount), rate.currency.code());
+                ui.label(RichText::new(value).size(16.0).color(Colors::text(false)));
+                Self::fiat_rate_ui(ui, &rate);
+            });
+            ui.add_space(6.0);
+        }
+
+        // Show fee setup and update estimated fee on change.
+        if fee_factor_ui(ui, &mut self.fee_factor) {
+            self.start_fee_estimation(wallet, amount);
+        }
+        ui.add_space(6.0);
+
+        ui.vertical_centered(|ui| {
+            // Show account to send from.
+            ui.label(RichText::new(t!("transport.sending_account"))
+                .size(16.0)
+                .color(Colors::gray()));
+            let account = account_label(wallet, &wallet.get_config().account);
+            ui.label(RichText::new(account)
+                .size(17.0)
+                .color(Colors::white_or_black(true)));
+            ui.add_space(6.0);
+
+            ui.label(RichText::new(t!("transport.receiver_address"))
+                .size(16.0)
+                .color(Colors::gray()));
+            View::ellipsize_text(ui, self.address_edit.clone(), 15.0, Colors::text(false));
+            // Show name of known receiver.
+            let receiver_name = wallet.get_data()
+                .and_then(|d| d.receiver_name(self.address_edit.trim()));
+            if let Some(name) = receiver_name {
+                ui.label(RichText::new(format!("{} {}", USER, name))
+                    .size(16.0)
+                    .color(Colors::white_or_black(true)));
+            }
+            // Show memo from payment request.
+            if let Some(memo) = &self.memo {
+                ui.add_space(6.0);
+                ui.label(RichText::new(format!("{} {}", NOTE, memo))
+                    .size(16.0)
+                    .color(Colors::gray()));
+            }
+            // Show payment request signature status.
+            self.request_signature_ui(ui);
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.send_fee = None;
+                    self.fee_estimating = false;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                // Wait for fee estimation after multiplier change to confirm.
+                if self.fee_estimating {
+                    View::small_loading_spinner(ui);
+                    return;
+                }
+                View::button(ui, t!("confirm"), Colors::white_or_black(false), || {
+                    self.send_fee = None;
                     self.send(wallet, modal, cb);
                 });
             });
@@ -249,6 +553,121 @@ impl TransportSendModal {
         ui.add_space(6.0);
     }
 
+    /// Estimate fee for entered amount to show sending preview.
+    fn estimate_fee(&mut self, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        if self.amount_edit.is_empty() {
+            return;
+        }
+        let chain_type = wallet.get_config().chain_type;
+        if let Err(e) = WalletUtils::parse_address(self.address_edit.as_str(), chain_type) {
+            self.address_error = Some(e);
+            return;
+        }
+        // Check if address belongs to opened wallet.
+        if !self.own_address_confirmed {
+            let owner = Wallet::own_address_owner(&self.address_edit.trim().to_string());
+            if owner.is_some() {
+                cb.hide_keyboard();
+                self.own_address = owner;
+                return;
+            }
+        }
+        if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
+            cb.hide_keyboard();
+            self.start_fee_estimation(wallet, a);
+        }
+    }
+
+    /// Estimate fee for provided amount at separate thread.
+    fn start_fee_estimation(&mut self, wallet: &Wallet, amount: u64) {
+        let wallet = wallet.clone();
+        let fee_factor = self.fee_factor;
+        let res = self.fee_result.clone();
+        self.fee_estimating = true;
+        {
+            let mut w_res = self.fee_result.write();
+            *w_res = None;
+        }
+        thread::spawn(move || {
+            let result = wallet.estimate_fee(amount, fee_factor);
+            let mut w_res = res.write();
+            *w_res = Some((fee_factor, result));
+        });
+    }
+
+    /// Check fee estimation result to show sending preview or an error.
+    fn check_fee_result(&mut self, cb: &dyn PlatformCallbacks) {
+        if !self.fee_estimating {
+            return;
+        }
+        let result = {
+            let mut w_res = self.fee_result.write();
+            w_res.take()
+        };
+        if let Some((fee_factor, result)) = result {
+            // Skip result of previous estimation when multiplier was changed.
+            if fee_factor != self.fee_factor {
+                return;
+            }
+            self.fee_estimating = false;
+            match result {
+                Ok(fee) => self.send_fee = Some(fee),
+                Err(e) => {
+                    self.send_fee = None;
+                    self.fee_error = Some(match e {
+                        Error::NotEnoughFunds { .. } => {
+                            t!("wallets.pay_balance_error", "amount" => self.amount_edit)
+                        }
+                        _ => t!("wallets.send_slatepack_err")
+                    });
+                    cb.show_keyboard();
+                }
+            }
+        }
+    }
+
+    /// Draw notice about sending to address of opened wallet.
+    fn own_address_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        let (wallet_name, account) = self.own_address.clone().unwrap();
+        let account = if account == WalletConfig::DEFAULT_ACCOUNT_LABEL {
+            t!("wallets.default_account")
+        } else {
+            account
+        };
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("transport.own_address",
+                                      "wallet" => wallet_name,
+                                      "account" => account))
+                .size(17.0)
+                .color(Colors::text(false)));
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("transport.own_address_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.own_address = None;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                    self.own_address = None;
+                    self.own_address_confirmed = true;
+                    self.estimate_fee(wallet, cb);
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
     /// Draw error content.
     fn error_ui(&mut self,
                 ui: &mut egui::Ui,
@@ -284,7 +703,16 @@ impl TransportSendModal {
     /// Close modal and clear data.
     fn close(&mut self, modal: &Modal, cb: &dyn PlatformCallbacks) {
         self.amount_edit = "".to_string();
+        self.fiat_edit = "".to_string();
+        self.fiat_source = None;
         self.address_edit = "".to_string();
+        self.memo = None;
+        self.signed_request = None;
+        self.own_address = None;
+        self.own_address_confirmed = false;
+        self.send_fee = None;
+        self.fee_estimating = false;
+        self.fee_error = None;
 
         let mut w_res = self.send_result.write();
         *w_res = None;
@@ -301,27 +729,31 @@ impl TransportSendModal {
         if self.amount_edit.is_empty() {
             return;
         }
-        let addr_str = self.address_edit.as_str();
-        if let Ok(addr) = SlatepackAddress::try_from(addr_str) {
-            if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
-                cb.hide_keyboard();
-                modal.disable_closing();
-                // Send amount over Tor.
-                let mut wallet = wallet.clone();
-                let res = self.send_result.clone();
-                self.sending = true;
-                thread::spawn(move || {
-                    let runtime = TokioNativeTlsRuntime::create().unwrap();
-                    runtime
-                        .block_on(async {
-                            let result = wallet.send_tor(a, &addr).await;
-                            let mut w_res = res.write();
-                            *w_res = Some(result);
-                        });
-                });
+        let chain_type = wallet.get_config().chain_type;
+        let addr = match WalletUtils::parse_address(self.address_edit.as_str(), chain_type) {
+            Ok(addr) => addr,
+            Err(e) => {
+                self.address_error = Some(e);
+                return;
             }
-        } else {
-            self.address_error = true;
+        };
+        if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
+            cb.hide_keyboard();
+            modal.disable_closing();
+            // Send amount over Tor.
+            let mut wallet = wallet.clone();
+            let res = self.send_result.clone();
+            let fee_factor = self.fee_factor;
+            self.sending = true;
+            thread::spawn(move || {
+                let runtime = TokioNativeTlsRuntime::create().unwrap();
+                runtime
+                    .block_on(async {
+                        let result = wallet.send_tor(a, &addr, fee_factor).await;
+                        let mut w_res = res.write();
+                        *w_res = Some(result);
+                    });
+            });
         }
     }
 
@@ -347,6 +779,9 @@ impl TransportSendModal {
                 let res = self.send_result.read().clone().unwrap();
                 match res {
                     Ok(tx) => {
+                        if let Some(memo) = &self.memo {
+                            wallet.update_tx_memo(&tx.data, memo.clone());
+                        }
                         self.tx_info_content =
                             Some(WalletTransactionModal::new(wallet, &tx, false));
                     }
@@ -16,12 +16,16 @@ use egui::os::OperatingSystem;
 use egui::{Id, RichText};
 
 use crate::gui::Colors;
+use crate::gui::icons::{COPY, TRASH};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{CameraContent, Modal, View};
 use crate::gui::views::types::TextEditOptions;
 use crate::tor::{Tor, TorBridge, TorConfig};
 use crate::wallet::Wallet;
 
+/// Maximal amount of Foreign API requests to show at log.
+const REQUESTS_LOG_ITEMS: usize = 10;
+
 /// Transport settings [`Modal`] content.
 pub struct TransportSettingsModal {
     /// Flag to check if Tor settings were changed.
@@ -234,6 +238,52 @@ impl TransportSettingsModal {
                 wallet.update_auto_start_tor_listener(!autorun);
             });
         });
+        ui.add_space(6.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            // Show checkbox to pin Tor listener to current account.
+            let account = wallet.get_config().account;
+            let pinned = wallet.tor_listener_account();
+            let is_pinned = pinned.as_ref() == Some(&account);
+            View::checkbox(ui, is_pinned, t!("transport.pin_listener"), || {
+                if is_pinned {
+                    wallet.update_tor_listener_account(None);
+                } else {
+                    wallet.update_tor_listener_account(Some(account.clone()));
+                }
+            });
+            ui.add_space(4.0);
+
+            // Show checkbox to keep Onion services keys and state between launches.
+            let persistent = TorConfig::services_persistent();
+            View::checkbox(ui, persistent, t!("transport.persistent_services"), || {
+                TorConfig::toggle_services_persistence();
+            });
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("transport.persistent_services_desc"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(6.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show Tor client diagnostics.
+        Self::diagnostics_ui(ui);
+        ui.add_space(6.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show Foreign API requests log.
+        Self::requests_log_ui(ui, wallet);
+        ui.add_space(6.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(6.0);
+
+        // Show Onion service key export.
+        Self::service_key_ui(ui, wallet, cb);
+
         ui.add_space(6.0);
         ui.vertical_centered_justified(|ui| {
             View::button(ui, t!("close"), Colors::white_or_black(false), || {
@@ -242,7 +292,7 @@ impl TransportSettingsModal {
                     // Restart running service or rebuild client.
                     let service_id = &wallet.identifier();
                     if Tor::is_service_running(service_id) {
-                        if let Ok(key) = wallet.secret_key() {
+                        if let Ok(key) = wallet.tor_service_key() {
                             let api_port = wallet.foreign_api_port().unwrap();
                             Tor::restart_service(api_port, key, service_id);
                         }
@@ -255,4 +305,119 @@ impl TransportSettingsModal {
         });
         ui.add_space(6.0);
     }
+
+    /// Draw diagnostics of running Tor client.
+    fn diagnostics_ui(ui: &mut egui::Ui) {
+        let diagnostics = Tor::diagnostics();
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("transport.tor_diagnostics"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            let bootstrap_color = if diagnostics.ready {
+                Colors::green()
+            } else {
+                Colors::white_or_black(true)
+            };
+            let bootstrap_text = format!("{}: {}%",
+                                         t!("transport.tor_bootstrap"),
+                                         diagnostics.bootstrap);
+            ui.label(RichText::new(bootstrap_text).size(15.0).color(bootstrap_color));
+            let circuits_text = format!("{}: {}",
+                                        t!("transport.tor_circuits"),
+                                        diagnostics.circuits);
+            ui.label(RichText::new(circuits_text)
+                .size(15.0)
+                .color(Colors::white_or_black(true)));
+            ui.add_space(4.0);
+            match diagnostics.last_error {
+                Some(err) => {
+                    ui.label(RichText::new(format!("{}: {}", t!("transport.tor_last_error"), err))
+                        .size(15.0)
+                        .color(Colors::red()));
+                    ui.add_space(6.0);
+                    let clear_text = format!("{} {}", TRASH, t!("transport.clear_error"));
+                    View::button(ui, clear_text, Colors::white_or_black(false), || {
+                        Tor::clear_error();
+                    });
+                }
+                None => {
+                    ui.label(RichText::new(t!("transport.tor_no_errors"))
+                        .size(15.0)
+                        .color(Colors::inactive_text()));
+                }
+            }
+        });
+    }
+
+    /// Draw log of requests received by wallet Foreign API.
+    fn requests_log_ui(ui: &mut egui::Ui, wallet: &Wallet) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("transport.requests_log"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            let log = wallet.foreign_api_log();
+            if log.is_empty() {
+                ui.label(RichText::new(t!("transport.requests_log_empty"))
+                    .size(15.0)
+                    .color(Colors::inactive_text()));
+                return;
+            }
+            for request in log.iter().take(REQUESTS_LOG_ITEMS) {
+                let text = format!("{} {} ({}: {})",
+                                   View::format_time(request.time),
+                                   request.method,
+                                   t!("transport.claimed_host"),
+                                   request.host);
+                let color = if request.rejected {
+                    Colors::red()
+                } else {
+                    Colors::white_or_black(true)
+                };
+                ui.label(RichText::new(text).size(15.0).color(color));
+            }
+            ui.add_space(6.0);
+            let clear_text = format!("{} {}", TRASH, t!("transport.clear_log"));
+            View::button(ui, clear_text, Colors::white_or_black(false), || {
+                wallet.clear_foreign_api_log();
+            });
+        });
+    }
+
+    /// Draw Onion service key export content.
+    fn service_key_ui(ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("transport.service_key"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            // Show current Onion address.
+            if let Some(addr) = wallet.tor_service_address() {
+                ui.label(RichText::new(addr)
+                    .size(15.0)
+                    .color(Colors::white_or_black(true)));
+                ui.add_space(4.0);
+            }
+            ui.label(RichText::new(t!("transport.service_key_seed"))
+                .size(15.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("transport.service_key_warning"))
+                .size(15.0)
+                .color(Colors::red()));
+            ui.add_space(8.0);
+        });
+
+        // Show button to copy key.
+        ui.vertical_centered_justified(|ui| {
+            let copy_text = format!("{} {}", COPY, t!("copy"));
+            View::button(ui, copy_text, Colors::white_or_black(false), || {
+                if let Ok(key) = wallet.export_tor_service_key() {
+                    cb.copy_string_to_buffer(key);
+                }
+            });
+        });
+        ui.add_space(6.0);
+    }
 }
\ No newline at end of file
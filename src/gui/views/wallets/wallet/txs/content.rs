@@ -12,24 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use chrono::{Datelike, Local, NaiveDate};
 use std::time::{SystemTime, UNIX_EPOCH};
 use egui::{Align, Id, Layout, Rect, RichText, Rounding, ScrollArea};
 use egui::epaint::RectShape;
 use egui::scroll_area::ScrollBarVisibility;
 use grin_core::consensus::COINBASE_MATURITY;
-use grin_core::core::amount_to_hr_string;
+use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
 use grin_wallet_libwallet::TxLogEntryType;
+use parking_lot::RwLock;
 
 use crate::gui::Colors;
-use crate::gui::icons::{ARROW_CIRCLE_DOWN, ARROW_CIRCLE_UP, BRIDGE, CALENDAR_CHECK, CHAT_CIRCLE_TEXT, CHECK, DOTS_THREE_CIRCLE, FILE_TEXT, GEAR_FINE, PROHIBIT, X_CIRCLE};
+use crate::gui::icons::{ARROW_CIRCLE_DOWN, ARROW_CIRCLE_UP, BRIDGE, BROOM, CALENDAR_CHECK, CHAT_CIRCLE_TEXT, CHECK, CLOUD_ARROW_UP, COINS, CUBE, DOTS_THREE_CIRCLE, FILE_TEXT, FUNNEL, GEAR_FINE, NOTE, PROHIBIT, X_CIRCLE};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, PullToRefresh, Content, View};
-use crate::gui::views::types::{LinePosition, ModalPosition};
+use crate::gui::views::types::{LinePosition, ModalPosition, TextEditOptions};
 use crate::gui::views::wallets::types::WalletTab;
-use crate::gui::views::wallets::wallet::types::{GRIN, WalletTabType};
+use crate::gui::views::wallets::wallet::types::{amount_to_display, full_amount_hover, GRIN, WalletTabType};
 use crate::gui::views::wallets::wallet::WalletTransactionModal;
-use crate::wallet::types::{WalletData, WalletTransaction};
+use crate::wallet::types::{TxFilter, TxFilterKind, WalletData, WalletTransaction};
 use crate::wallet::Wallet;
 
 /// Wallet transactions tab content.
@@ -41,7 +47,26 @@ pub struct WalletTransactions {
     confirm_cancel_tx_id: Option<u32>,
 
     /// Flag to check if sync of wallet was initiated manually at time.
-    manual_sync: Option<u128>
+    manual_sync: Option<u128>,
+
+    /// Flag to check if transactions filter is shown.
+    filter_shown: bool,
+    /// Transactions filter to apply.
+    filter: TxFilter,
+    /// Filter start date edit text.
+    from_date_edit: String,
+    /// Filter end date edit text.
+    to_date_edit: String,
+    /// Filter minimal amount edit text.
+    min_amount_edit: String,
+    /// Filter maximal amount edit text.
+    max_amount_edit: String,
+    /// Flag to check if entered filter range is not valid.
+    filter_error: bool,
+    /// Identifiers of transactions matching filter with filter and data version used at query.
+    filter_result: Arc<RwLock<Option<(TxFilter, (usize, usize), HashSet<u32>)>>>,
+    /// Flag to check if transactions query is running.
+    filter_querying: Arc<AtomicBool>,
 }
 
 impl Default for WalletTransactions {
@@ -50,6 +75,15 @@ impl Default for WalletTransactions {
             tx_info_content: None,
             confirm_cancel_tx_id: None,
             manual_sync: None,
+            filter_shown: false,
+            filter: TxFilter::default(),
+            from_date_edit: "".to_string(),
+            to_date_edit: "".to_string(),
+            min_amount_edit: "".to_string(),
+            max_amount_edit: "".to_string(),
+            filter_error: false,
+            filter_result: Arc::new(RwLock::new(None)),
+            filter_querying: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -86,7 +120,13 @@ impl WalletTransactions {
             });
             return;
         }
-        let txs = data.txs.as_ref().unwrap();
+        // Outstanding invoices are shown at separate tab.
+        let txs = data.txs.as_ref()
+            .unwrap()
+            .iter()
+            .filter(|tx| !tx.is_invoice())
+            .cloned()
+            .collect::<Vec<WalletTransaction>>();
         let mut awaiting_amount = false;
         View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
             // Show message when txs are empty.
@@ -106,9 +146,37 @@ impl WalletTransactions {
             }
             // Draw awaiting amount info if exists.
             awaiting_amount = self.awaiting_info_ui(ui, &data);
+            // Draw paid fees info if exists.
+            Self::fees_info_ui(ui, &data);
+            // Draw queued transactions info if exists.
+            self.post_queue_ui(ui, wallet);
+            // Draw transaction heights lookup progress.
+            Self::heights_backfill_ui(ui, wallet);
+            // Draw transactions filter.
+            self.filter_ui(ui, cb);
         });
         ui.add_space(4.0);
 
+        // Apply filter from wallet database query.
+        let txs = match self.filtered_txs(ui, wallet, txs) {
+            Some(txs) => txs,
+            None => {
+                ui.centered_and_justified(|ui| {
+                    View::big_loading_spinner(ui);
+                });
+                return;
+            }
+        };
+        if txs.is_empty() && !self.filter.is_empty() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.txs_not_found"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+            return;
+        }
+
         // Show list of transactions.
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
         let refresh = self.manual_sync.unwrap_or(0) + 1600 > now;
@@ -121,9 +189,13 @@ impl WalletTransactions {
                     .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
                     .auto_shrink([false; 2])
                     .show_rows(ui, Self::TX_ITEM_HEIGHT, txs.len(), |ui, row_range| {
+                        // Load more transactions when the end of the list is visible.
+                        if row_range.end >= txs.len() && wallet.has_more_txs() {
+                            wallet.load_more_txs();
+                        }
                         ui.add_space(1.0);
                         View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
-                            self.tx_list_ui(ui, awaiting_amount, row_range, wallet, txs, cb);
+                            self.tx_list_ui(ui, awaiting_amount, row_range, wallet, &txs, cb);
                         });
                     })
             });
@@ -137,6 +209,195 @@ impl WalletTransactions {
         }
     }
 
+    /// Get transactions matching the filter, querying wallet database at separate thread.
+    fn filtered_txs(&mut self,
+                    ui: &mut egui::Ui,
+                    wallet: &Wallet,
+                    txs: Vec<WalletTransaction>) -> Option<Vec<WalletTransaction>> {
+        if self.filter.is_empty() {
+            return Some(txs);
+        }
+        // Query again on filter or transactions change.
+        let version = (txs.len(), txs.iter().filter(|tx| tx.data.confirmed).count());
+        let result = {
+            let r_result = self.filter_result.read();
+            r_result.clone()
+        };
+        let actual = result.as_ref()
+            .map(|(filter, v, _)| *filter == self.filter && *v == version)
+            .unwrap_or(false);
+        if !actual && !self.filter_querying.load(Ordering::Relaxed) {
+            self.filter_querying.store(true, Ordering::Relaxed);
+            let wallet = wallet.clone();
+            let filter = self.filter.clone();
+            let filter_result = self.filter_result.clone();
+            let querying = self.filter_querying.clone();
+            let ctx = ui.ctx().clone();
+            thread::spawn(move || {
+                let ids = wallet.filter_txs(&filter).unwrap_or_default();
+                {
+                    let mut w_result = filter_result.write();
+                    *w_result = Some((filter, version, ids.into_iter().collect()));
+                }
+                querying.store(false, Ordering::Relaxed);
+                ctx.request_repaint();
+            });
+        }
+        let (_, _, ids) = result?;
+        Some(txs.into_iter().filter(|tx| ids.contains(&tx.data.id)).collect())
+    }
+
+    /// Draw transactions search and filter content.
+    fn filter_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered_justified(|ui| {
+            let filter_text = format!("{} {}", FUNNEL, t!("wallets.tx_filter"));
+            let filter_color = if self.filter.is_empty() {
+                Colors::white_or_black(false)
+            } else {
+                Colors::gold()
+            };
+            View::button(ui, filter_text, filter_color, || {
+                self.filter_shown = !self.filter_shown;
+            });
+        });
+        if !self.filter_shown {
+            return;
+        }
+        ui.add_space(8.0);
+
+        let mut filter = self.filter.clone();
+        ui.vertical_centered(|ui| {
+            // Show search input.
+            let mut search_opts = TextEditOptions::new(Id::from("wallet_tx_search")).no_focus();
+            View::text_edit(ui, cb, &mut filter.search, &mut search_opts);
+            ui.add_space(8.0);
+
+            // Show transaction kind chips.
+            ui.horizontal_wrapped(|ui| {
+                View::radio_value(ui, &mut filter.kind, None, t!("wallets.tx_filter_all"));
+                for kind in TxFilterKind::ALL {
+                    View::radio_value(ui, &mut filter.kind, Some(kind), tx_filter_kind_text(kind));
+                }
+            });
+            ui.add_space(8.0);
+        });
+
+        // Show date and amount range inputs.
+        let edits_before = (self.from_date_edit.clone(),
+                            self.to_date_edit.clone(),
+                            self.min_amount_edit.clone(),
+                            self.max_amount_edit.clone());
+        ui.scope(|ui| {
+            // Setup spacing between inputs.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("wallets.tx_filter_from"))
+                        .size(15.0)
+                        .color(Colors::gray()));
+                    ui.add_space(4.0);
+                    let mut opts = TextEditOptions::new(Id::from("wallet_tx_from_date"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.from_date_edit, &mut opts);
+                    ui.add_space(6.0);
+                    let mut opts = TextEditOptions::new(Id::from("wallet_tx_min_amount"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.min_amount_edit, &mut opts);
+                });
+                columns[1].vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("wallets.tx_filter_to"))
+                        .size(15.0)
+                        .color(Colors::gray()));
+                    ui.add_space(4.0);
+                    let mut opts = TextEditOptions::new(Id::from("wallet_tx_to_date"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.to_date_edit, &mut opts);
+                    ui.add_space(6.0);
+                    let mut opts = TextEditOptions::new(Id::from("wallet_tx_max_amount"))
+                        .h_center()
+                        .no_focus();
+                    View::text_edit(ui, cb, &mut self.max_amount_edit, &mut opts);
+                });
+            });
+        });
+
+        // Parse ranges on change.
+        let edits = (self.from_date_edit.clone(),
+                     self.to_date_edit.clone(),
+                     self.min_amount_edit.clone(),
+                     self.max_amount_edit.clone());
+        if edits != edits_before {
+            let from = parse_filter_date(&self.from_date_edit, false);
+            let to = parse_filter_date(&self.to_date_edit, true);
+            let min = parse_filter_amount(&self.min_amount_edit);
+            let max = parse_filter_amount(&self.max_amount_edit);
+            match (from, to, min, max) {
+                (Some(from), Some(to), Some(min), Some(max)) => {
+                    self.filter_error = false;
+                    filter.from_time = from;
+                    filter.to_time = to;
+                    filter.min_amount = min;
+                    filter.max_amount = max;
+                }
+                _ => self.filter_error = true
+            }
+        }
+        self.filter = filter;
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            if self.filter_error {
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(15.0)
+                    .color(Colors::red()));
+            } else {
+                ui.label(RichText::new(t!("wallets.tx_filter_desc"))
+                    .size(15.0)
+                    .color(Colors::inactive_text()));
+            }
+            // Show button to reset filter.
+            if !self.filter.is_empty() {
+                ui.add_space(6.0);
+                let reset_text = t!("wallets.tx_filter_reset");
+                View::button(ui, reset_text, Colors::white_or_black(false), || {
+                    self.filter = TxFilter::default();
+                    self.from_date_edit = "".to_string();
+                    self.to_date_edit = "".to_string();
+                    self.min_amount_edit = "".to_string();
+                    self.max_amount_edit = "".to_string();
+                    self.filter_error = false;
+                });
+            }
+        });
+    }
+
+    /// Draw read-only list of transactions from replayed wallet data fixture.
+    pub fn fixture_ui(ui: &mut egui::Ui, data: &WalletData) {
+        let txs = data.txs.clone().unwrap_or_default();
+        ScrollArea::vertical()
+            .id_salt(Id::from("wallet_fixture_tx_list_scroll"))
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false; 2])
+            .show_rows(ui, Self::TX_ITEM_HEIGHT, txs.len(), |ui, row_range| {
+                ui.add_space(1.0);
+                View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                    for index in row_range {
+                        let mut rect = ui.available_rect_before_wrap();
+                        rect.set_height(Self::TX_ITEM_HEIGHT);
+                        // Draw tx item background.
+                        let r = View::item_rounding(index, txs.len(), false);
+                        ui.painter().rect(rect, r, Colors::fill_lite(), View::item_stroke());
+                        Self::tx_item_ui(ui, &txs[index], rect, data, |_| {});
+                    }
+                });
+            });
+    }
+
     /// Draw transaction list content.
     fn tx_list_ui(&mut self,
                   ui: &mut egui::Ui,
@@ -241,6 +502,68 @@ impl WalletTransactions {
         true
     }
 
+    /// Draw fees paid by the wallet for current month and all time.
+    fn fees_info_ui(ui: &mut egui::Ui, data: &WalletData) {
+        let total = data.fees_paid(None);
+        if total == 0 {
+            return;
+        }
+        // Calculate fees since the start of current month.
+        let month_start = Local::now()
+            .date_naive()
+            .with_day(1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .and_then(|d| d.and_local_timezone(Local).earliest())
+            .map(|d| d.timestamp());
+        let month = data.fees_paid(month_start);
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            let text = format!("{} {}",
+                               COINS,
+                               t!("wallets.fees_paid",
+                                   "month" => amount_to_display(month),
+                                   "total" => amount_to_display(total)));
+            ui.label(RichText::new(text).size(15.0).color(Colors::inactive_text()));
+        });
+    }
+
+    /// Draw progress of transaction block heights lookup at node.
+    fn heights_backfill_ui(ui: &mut egui::Ui, wallet: &Wallet) {
+        if let Some((checked, total)) = wallet.kernels_backfill_progress() {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                let text = format!("{} {}",
+                                   CUBE,
+                                   t!("wallets.tx_heights_backfill",
+                                       "checked" => checked,
+                                       "total" => total));
+                ui.label(RichText::new(text).size(15.0).color(Colors::inactive_text()));
+            });
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(1000));
+        }
+    }
+
+    /// Draw information about transactions queued to post when node will be available.
+    fn post_queue_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) {
+        let queue = wallet.post_queue();
+        if queue.is_empty() {
+            return;
+        }
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            let text = t!("wallets.tx_post_queue", "count" => queue.len());
+            ui.label(RichText::new(text).size(16.0).color(Colors::inactive_text()));
+            ui.add_space(6.0);
+            let post_text = format!("{} {}", CLOUD_ARROW_UP, t!("wallets.post_now"));
+            View::button(ui, post_text, Colors::white_or_black(false), || {
+                let wallet = wallet.clone();
+                thread::spawn(move || {
+                    wallet.post_queued_txs();
+                });
+            });
+        });
+    }
+
     /// Draw [`Modal`] content for this ui container.
     fn modal_content_ui(&mut self,
                         ui: &mut egui::Ui,
@@ -297,7 +620,7 @@ impl WalletTransactions {
                     }.to_string();
                     amount_text = format!("{}{} {}",
                                           amount_text,
-                                          amount_to_hr_string(tx.amount, true),
+                                          amount_to_display(tx.amount),
                                           GRIN);
 
                     // Setup amount color.
@@ -311,7 +634,8 @@ impl WalletTransactions {
                     };
                     ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
                         ui.add_space(1.0);
-                        View::ellipsize_text(ui, amount_text, 18.0, amount_color);
+                        let amount_job = View::ellipsize(amount_text, 18.0, amount_color);
+                        full_amount_hover(ui.label(amount_job), tx.amount);
                     });
                     ui.add_space(-2.0);
 
@@ -322,6 +646,8 @@ impl WalletTransactions {
                             || tx.data.tx_type == TxLogEntryType::TxReceivedCancelled;
                         if is_canceled {
                             format!("{} {}", X_CIRCLE, t!("wallets.tx_canceled"))
+                        } else if tx.post_queued.is_some() {
+                            format!("{} {}", CLOUD_ARROW_UP, t!("wallets.tx_post_queued"))
                         } else if tx.finalizing {
                             format!("{} {}", DOTS_THREE_CIRCLE, t!("wallets.tx_finalizing"))
                         } else {
@@ -445,12 +771,23 @@ impl WalletTransactions {
                         TxLogEntryType::TxSentCancelled => Colors::inactive_text(),
                         TxLogEntryType::TxReverted => Colors::inactive_text(),
                     };
+                    // Mark incoming dust.
+                    let (status_text, status_color) = if tx.dust {
+                        (format!("{}  {} {}", status_text, BROOM, t!("wallets.tx_dust")),
+                         Colors::gold())
+                    } else {
+                        (status_text, status_color)
+                    };
                     ui.label(RichText::new(status_text).size(15.0).color(status_color));
 
                     // Setup transaction time.
                     let tx_time = View::format_time(tx.data.creation_ts.timestamp());
-                    let tx_time_text = format!("{} {}", CALENDAR_CHECK, tx_time);
-                    ui.label(RichText::new(tx_time_text).size(15.0).color(Colors::gray()));
+                    let tx_time_text = if let Some(memo) = &tx.memo {
+                        format!("{} {}  {} {}", CALENDAR_CHECK, tx_time, NOTE, memo)
+                    } else {
+                        format!("{} {}", CALENDAR_CHECK, tx_time)
+                    };
+                    View::ellipsize_text(ui, tx_time_text, 15.0, Colors::gray());
                     ui.add_space(3.0);
                 });
             });
@@ -527,12 +864,49 @@ fn awaiting_item_ui(ui: &mut egui::Ui, amount: u64, label: String) {
     let rect = ui.available_rect_before_wrap();
     View::line(ui, LinePosition::TOP, &rect, Colors::item_stroke());
     ui.add_space(4.0);
-    let amount_format = amount_to_hr_string(amount, true);
-    ui.label(RichText::new(format!("{} ツ", amount_format))
+    let amount_format = amount_to_display(amount);
+    let amount_resp = ui.label(RichText::new(format!("{} ツ", amount_format))
         .color(Colors::white_or_black(true))
         .size(17.0));
+    full_amount_hover(amount_resp, amount);
     ui.label(RichText::new(label)
         .color(Colors::gray())
         .size(15.0));
     ui.add_space(4.0);
+}
+
+/// Get transactions filter kind text.
+fn tx_filter_kind_text(kind: TxFilterKind) -> String {
+    match kind {
+        TxFilterKind::Sent => t!("wallets.tx_filter_sent"),
+        TxFilterKind::Received => t!("wallets.tx_filter_received"),
+        TxFilterKind::Coinbase => t!("wallets.tx_filter_coinbase"),
+        TxFilterKind::Cancelled => t!("wallets.tx_filter_cancelled"),
+        TxFilterKind::Unconfirmed => t!("wallets.tx_filter_unconfirmed")
+    }
+}
+
+/// Parse filter date in `YYYY-MM-DD` format to local time at start or end of the day,
+/// returning `None` when value is not valid.
+fn parse_filter_date(text: &str, end_of_day: bool) -> Option<Option<i64>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Some(None);
+    }
+    let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    }?;
+    time.and_local_timezone(Local).earliest().map(|t| Some(t.timestamp()))
+}
+
+/// Parse filter amount, returning `None` when value is not valid.
+fn parse_filter_amount(text: &str) -> Option<Option<u64>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Some(None);
+    }
+    amount_from_hr_string(text).ok().map(Some)
 }
\ No newline at end of file
@@ -22,10 +22,12 @@ use grin_core::core::amount_to_hr_string;
 use grin_wallet_libwallet::{Error, Slate, SlateState, TxLogEntryType};
 
 use crate::gui::Colors;
-use crate::gui::icons::{BROOM, CHECK, CLIPBOARD_TEXT, COPY, CUBE, FILE_ARCHIVE, FILE_TEXT, HASH_STRAIGHT, PROHIBIT, QR_CODE, SCAN};
+use crate::gui::icons::{ARROW_FAT_LINES_UP, ARROWS_DOWN_UP, BROADCAST, BROOM, CHECK, CHECK_CIRCLE, CIRCLE_DASHED, CLIPBOARD_TEXT, CLOCK_COUNTER_CLOCKWISE, CLOUD_ARROW_UP, COINS, COPY, CUBE, EYE, FILE_ARCHIVE, FILE_TEXT, GAS_PUMP, HASH_STRAIGHT, NOTE, NOTE_PENCIL, PROHIBIT, QR_CODE, SCAN, SHARE_FAT, TRASH, X};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{CameraContent, FilePickButton, Modal, QrCodeContent, View};
 use crate::gui::views::wallets::wallet::txs::WalletTransactions;
+use crate::gui::views::types::TextEditOptions;
+use crate::gui::views::wallets::types::GRIN;
 use crate::gui::views::wallets::wallet::types::SLATEPACK_MESSAGE_HINT;
 use crate::wallet::types::WalletTransaction;
 use crate::wallet::Wallet;
@@ -57,6 +59,23 @@ pub struct WalletTransactionModal {
 
     /// Button to parse picked file content.
     file_pick_button: FilePickButton,
+
+    /// Transaction memo input value, shown when editing.
+    memo_edit: Option<String>,
+
+    /// Flag to check if transaction is replacing with increased fee.
+    replacing: bool,
+    /// Flag to check if error happened during transaction replacement.
+    replace_error: bool,
+    /// Transaction replacement result.
+    replace_result: Arc<RwLock<Option<Result<WalletTransaction, Error>>>>,
+
+    /// Stored Slatepack message file names, shown when history is opened.
+    messages_history: Option<Vec<String>>,
+    /// Slatepack message file name and text from history to show.
+    history_message: Option<(String, String)>,
+    /// Slatepack message file name required to finish transaction.
+    pending_message_file: Option<String>,
 }
 
 impl WalletTransactionModal {
@@ -95,6 +114,13 @@ impl WalletTransactionModal {
             qr_code_content: None,
             scan_qr_content: None,
             file_pick_button: FilePickButton::default(),
+            memo_edit: None,
+            replacing: false,
+            replace_error: false,
+            replace_result: Arc::new(RwLock::new(None)),
+            messages_history: None,
+            history_message: None,
+            pending_message_file: None,
         }
     }
 
@@ -122,6 +148,18 @@ impl WalletTransactionModal {
         }
         let tx = txs.get(0).unwrap();
 
+        // Show progress on transaction replacement.
+        if self.replacing {
+            self.replacing_ui(ui, wallet, modal);
+            return;
+        }
+
+        // Show stored Slatepack messages history.
+        if self.messages_history.is_some() {
+            self.history_ui(ui, wallet, modal, cb);
+            return;
+        }
+
         // Show transaction information.
         if self.qr_code_content.is_none() && self.scan_qr_content.is_none() {
             self.info_ui(ui, tx, wallet, cb);
@@ -175,13 +213,36 @@ impl WalletTransactionModal {
                 View::horizontal_line(ui, Colors::item_stroke());
                 ui.add_space(8.0);
 
-                // Show button to close modal.
-                ui.vertical_centered_justified(|ui| {
-                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
-                        cb.hide_keyboard();
-                        modal.close();
+                if tx.data.tx_slate_id.is_some() {
+                    ui.columns(2, |cols| {
+                        cols[0].vertical_centered_justified(|ui| {
+                            // Show button to open Slatepack messages history.
+                            let history_text = format!("{} {}",
+                                                       CLOCK_COUNTER_CLOCKWISE,
+                                                       t!("wallets.messages_history"));
+                            View::button(ui, history_text, Colors::white_or_black(false), || {
+                                cb.hide_keyboard();
+                                self.pending_message_file = wallet.read_slate_by_tx(tx)
+                                    .map(|(s, _)| format!("{}.{}.slatepack", s.id, s.state));
+                                self.messages_history = Some(wallet.slatepack_files(tx));
+                            });
+                        });
+                        cols[1].vertical_centered_justified(|ui| {
+                            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                                cb.hide_keyboard();
+                                modal.close();
+                            });
+                        });
                     });
-                });
+                } else {
+                    // Show button to close modal.
+                    ui.vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            cb.hide_keyboard();
+                            modal.close();
+                        });
+                    });
+                }
             }
             ui.add_space(6.0);
         } else {
@@ -286,8 +347,60 @@ impl WalletTransactionModal {
                     wallet.cancel(tx.data.id);
                 });
             }
+            // Draw buttons to post queued transaction or remove it from the queue.
+            if wallet_loaded && tx.post_queued.is_some() {
+                View::item_button(ui, Rounding::default(), X, None, || {
+                    wallet.remove_from_post_queue(&tx.data);
+                });
+                View::item_button(ui, Rounding::default(), CLOUD_ARROW_UP, None, || {
+                    let wallet = wallet.clone();
+                    let tx = tx.clone();
+                    thread::spawn(move || {
+                        let _ = wallet.post_queued(&tx);
+                    });
+                });
+            }
+            // Draw button to replace stuck transaction with increased fee.
+            if wallet_loaded && wallet.can_replace_tx(tx) {
+                View::item_button(ui, Rounding::default(), ARROW_FAT_LINES_UP, None, || {
+                    cb.hide_keyboard();
+                    self.replace_error = false;
+                    self.replacing = true;
+                    let wallet = wallet.clone();
+                    let tx = tx.clone();
+                    let replace_res = self.replace_result.clone();
+                    thread::spawn(move || {
+                        let res = wallet.replace_tx(&tx);
+                        let mut w_res = replace_res.write();
+                        *w_res = Some(res);
+                    });
+                });
+            }
         });
 
+        // Show replacement description.
+        let can_replace = wallet.foreign_api_port().is_some() && wallet.can_replace_tx(tx);
+        if can_replace && !self.finalizing && tx.height.is_none() && !self.replace_error {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.tx_replace_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+            ui.add_space(4.0);
+        }
+
+        // Show replacement error.
+        if self.replace_error {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.tx_replace_error"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            });
+            ui.add_space(4.0);
+        }
+
         // Show identifier.
         if let Some(id) = tx.data.tx_slate_id {
             let label = format!("{} {}", HASH_STRAIGHT, t!("id"));
@@ -298,11 +411,368 @@ impl WalletTransactionModal {
             let label = format!("{} {}", FILE_ARCHIVE, t!("kernel"));
             info_item_ui(ui, kernel.0.to_hex(), label, true, cb);
         }
+        // Show fee.
+        if let Some(fee) = tx.data.fee {
+            let label = format!("{} {}", GAS_PUMP, t!("wallets.tx_fee"));
+            let value = format!("{} {}", amount_to_hr_string(fee.fee(), true), GRIN);
+            info_item_ui(ui, value, label, false, cb);
+        }
+        // Show amount of inputs and outputs.
+        if tx.data.num_inputs > 0 || tx.data.num_outputs > 0 {
+            let label = format!("{} {}", ARROWS_DOWN_UP, t!("wallets.tx_inputs_outputs"));
+            let value = format!("{} / {}", tx.data.num_inputs, tx.data.num_outputs);
+            info_item_ui(ui, value, label, false, cb);
+        }
+        // Show change amount of sent transaction.
+        if tx.data.tx_type == TxLogEntryType::TxSent && tx.data.amount_credited > 0 {
+            let label = format!("{} {}", COINS, t!("wallets.tx_change"));
+            let value = format!("{} {}", amount_to_hr_string(tx.data.amount_credited, true), GRIN);
+            info_item_ui(ui, value, label, false, cb);
+        }
         // Show receiver address.
         if let Some(rec) = tx.receiver() {
             let label = format!("{} {}", CUBE, t!("network_mining.address"));
             info_item_ui(ui, rec.to_string(), label, true, cb);
         }
+        // Show amount of automatic rebroadcast attempts.
+        if tx.reposts > 0 {
+            let label = format!("{} {}", BROADCAST, t!("wallets.tx_reposts"));
+            info_item_ui(ui, tx.reposts.to_string(), label, false, cb);
+        }
+        // Show memo.
+        self.memo_ui(ui, tx, wallet, cb);
+        // Show transaction stages.
+        if tx.data.tx_slate_id.is_some() {
+            Self::stages_ui(ui, tx);
+        }
+    }
+
+    /// Draw timeline of transaction stages.
+    fn stages_ui(ui: &mut egui::Ui, tx: &WalletTransaction) {
+        let cancelled = tx.data.tx_type == TxLogEntryType::TxSentCancelled ||
+            tx.data.tx_type == TxLogEntryType::TxReceivedCancelled;
+        let (last_text, last_time) = if cancelled {
+            (t!("wallets.tx_canceled"), None)
+        } else {
+            let text = match tx.height {
+                Some(h) if h != 0 && tx.data.confirmed => {
+                    t!("wallets.tx_stage_confirmed_at", "height" => h)
+                }
+                _ => t!("wallets.tx_confirmed")
+            };
+            let time = tx.data.confirmation_ts
+                .filter(|_| tx.data.confirmed)
+                .map(|t| t.timestamp());
+            (text, time)
+        };
+        let stages = [
+            (t!("wallets.tx_stage_created"), Some(tx.data.creation_ts.timestamp())),
+            (t!("wallets.tx_stage_signed"), tx.stages.signed),
+            (t!("wallets.tx_stage_finalized"), tx.stages.finalized),
+            (t!("wallets.tx_stage_posted"), tx.stages.posted),
+            (last_text, last_time),
+        ];
+        let count = stages.len();
+        // Stage was passed when its time is known or next stage was passed.
+        let last_passed = if tx.data.confirmed || cancelled {
+            Some(count - 1)
+        } else {
+            stages.iter().rposition(|(_, time)| time.is_some())
+        };
+
+        ui.add_space(8.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.tx_stages")).size(16.0).color(Colors::gray()));
+        });
+        ui.add_space(4.0);
+        for (i, (name, time)) in stages.into_iter().enumerate() {
+            let passed = last_passed.map(|l| i <= l).unwrap_or(false);
+            let (icon, icon_color) = if cancelled && i == count - 1 {
+                (PROHIBIT, Colors::red())
+            } else if passed {
+                (CHECK_CIRCLE, Colors::green())
+            } else {
+                (CIRCLE_DASHED, Colors::inactive_text())
+            };
+            let text_color = if passed {
+                Colors::text(false)
+            } else {
+                Colors::inactive_text()
+            };
+            ui.horizontal(|ui| {
+                ui.add_space(6.0);
+                ui.label(RichText::new(icon).size(16.0).color(icon_color));
+                ui.label(RichText::new(name).size(16.0).color(text_color));
+                if let Some(time) = time {
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        ui.add_space(6.0);
+                        ui.label(RichText::new(View::format_time(time))
+                            .size(15.0)
+                            .color(Colors::gray()));
+                    });
+                }
+            });
+            ui.add_space(2.0);
+        }
+    }
+
+    /// Draw stored Slatepack messages history content.
+    fn history_ui(&mut self,
+                  ui: &mut egui::Ui,
+                  wallet: &Wallet,
+                  modal: &Modal,
+                  cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        if let Some((name, message)) = &self.history_message {
+            // Show Slatepack message text.
+            ui.vertical_centered(|ui| {
+                View::ellipsize_text(ui, format!("{} {}", FILE_TEXT, name), 16.0, Colors::gray());
+                ui.add_space(6.0);
+                View::horizontal_line(ui, Colors::item_stroke());
+                ui.add_space(3.0);
+                ScrollArea::vertical()
+                    .id_salt(Id::from("tx_history_message").with(name))
+                    .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                    .max_height(128.0)
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        ui.add_space(7.0);
+                        ui.label(RichText::new(message)
+                            .monospace()
+                            .size(16.0)
+                            .color(Colors::text(false)));
+                        ui.add_space(6.0);
+                    });
+                ui.add_space(2.0);
+                View::horizontal_line(ui, Colors::item_stroke());
+                ui.add_space(10.0);
+
+                // Draw copy button.
+                let copy_text = format!("{} {}", COPY, t!("copy"));
+                View::button(ui, copy_text, Colors::white_or_black(false), || {
+                    cb.copy_string_to_buffer(message.clone());
+                });
+            });
+        } else {
+            let files = self.messages_history.clone().unwrap_or_default();
+            if files.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(8.0);
+                    ui.label(RichText::new(t!("wallets.messages_history_empty"))
+                        .size(16.0)
+                        .color(Colors::inactive_text()));
+                    ui.add_space(8.0);
+                });
+            }
+            for (index, name) in files.iter().enumerate() {
+                self.history_item_ui(ui, wallet, name, index, files.len(), cb);
+            }
+        }
+
+        ui.add_space(8.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(8.0);
+
+        // Show buttons to close modal or come back to transaction information.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |cols| {
+                cols[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        self.history_message = None;
+                        self.messages_history = None;
+                        modal.close();
+                    });
+                });
+                cols[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                        if self.history_message.is_some() {
+                            self.history_message = None;
+                        } else {
+                            self.messages_history = None;
+                        }
+                    });
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Draw stored Slatepack message file item content.
+    fn history_item_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       name: &String,
+                       index: usize,
+                       size: usize,
+                       cb: &dyn PlatformCallbacks) {
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(HISTORY_ITEM_HEIGHT);
+
+        // Draw round background.
+        let item_rounding = View::item_rounding(index, size, false);
+        ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke());
+
+        ui.vertical(|ui| {
+            ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+                // Draw button to delete message file, except one required for pending transaction.
+                let is_pending = self.pending_message_file.as_ref() == Some(name);
+                if !is_pending {
+                    let r = View::item_rounding(index, size, true);
+                    View::item_button(ui, r, TRASH, Some(Colors::red()), || {
+                        wallet.delete_slatepack_file(name);
+                        if let Some(files) = self.messages_history.as_mut() {
+                            files.retain(|n| n != name);
+                        }
+                    });
+                }
+                // Draw button to share message file.
+                let r = if is_pending {
+                    View::item_rounding(index, size, true)
+                } else {
+                    Rounding::default()
+                };
+                View::item_button(ui, r, SHARE_FAT, None, || {
+                    if let Some(message) = wallet.read_slatepack_file(name) {
+                        cb.share_data(name.clone(), message.as_bytes().to_vec())
+                            .unwrap_or_default();
+                    }
+                });
+                // Draw button to view message.
+                View::item_button(ui, Rounding::default(), EYE, None, || {
+                    if let Some(message) = wallet.read_slatepack_file(name) {
+                        self.history_message = Some((name.clone(), message));
+                    }
+                });
+
+                let layout_size = ui.available_size();
+                ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                    ui.add_space(6.0);
+                    ui.vertical(|ui| {
+                        ui.add_space(4.0);
+                        // Show Slate state from file name.
+                        let state = name.split('.').nth(1).unwrap_or_default();
+                        let state_text = format!("{} {}", FILE_TEXT, state);
+                        ui.label(RichText::new(state_text)
+                            .size(17.0)
+                            .color(Colors::white_or_black(true)));
+                        ui.add_space(-2.0);
+                        View::ellipsize_text(ui, name.clone(), 15.0, Colors::gray());
+                        ui.add_space(3.0);
+                    });
+                });
+            });
+        });
+    }
+
+    /// Draw transaction replacement progress content.
+    fn replacing_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, modal: &Modal) {
+        modal.disable_closing();
+        ui.add_space(16.0);
+        ui.vertical_centered(|ui| {
+            View::small_loading_spinner(ui);
+            ui.add_space(12.0);
+            ui.label(RichText::new(t!("wallets.tx_replacing"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(16.0);
+
+        // Check replacement result.
+        let res = {
+            let r_res = self.replace_result.read();
+            r_res.clone()
+        };
+        if let Some(res) = res {
+            modal.enable_closing();
+            match res {
+                Ok(new_tx) => {
+                    // Show information about new transaction.
+                    *self = Self::new(wallet, &new_tx, false);
+                }
+                Err(_) => {
+                    self.replacing = false;
+                    self.replace_error = true;
+                    let mut w_res = self.replace_result.write();
+                    *w_res = None;
+                }
+            }
+        }
+    }
+
+    /// Draw transaction memo content.
+    fn memo_ui(&mut self,
+               ui: &mut egui::Ui,
+               tx: &WalletTransaction,
+               wallet: &Wallet,
+               cb: &dyn PlatformCallbacks) {
+        if let Some(memo_edit) = self.memo_edit.as_mut() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                let memo_id = Id::from("tx_info_memo_edit").with(tx.data.id);
+                let mut memo_edit_opts = TextEditOptions::new(memo_id);
+                View::text_edit(ui, cb, memo_edit, &mut memo_edit_opts);
+            });
+            ui.add_space(8.0);
+
+            // Show buttons to cancel or save memo.
+            ui.scope(|ui| {
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                            cb.hide_keyboard();
+                            self.memo_edit = None;
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                            let memo = self.memo_edit.take().unwrap_or_default();
+                            wallet.update_tx_memo(&tx.data, memo);
+                            cb.hide_keyboard();
+                        });
+                    });
+                });
+            });
+            return;
+        }
+
+        // Setup layout size.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(50.0);
+
+        // Draw round background.
+        let mut rounding = View::item_rounding(1, 3, false);
+        ui.painter().rect(rect, rounding, Colors::fill(), View::item_stroke());
+
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            // Draw button to edit memo.
+            rounding.nw = 0.0;
+            rounding.sw = 0.0;
+            View::item_button(ui, rounding, NOTE_PENCIL, None, || {
+                self.memo_edit = Some(tx.memo.clone().unwrap_or_default());
+            });
+
+            // Draw memo text.
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(6.0);
+                ui.vertical(|ui| {
+                    ui.add_space(3.0);
+                    let (value, color) = match &tx.memo {
+                        Some(memo) => (memo.clone(), Colors::title(false)),
+                        None => ("-".to_string(), Colors::inactive_text())
+                    };
+                    View::ellipsize_text(ui, value, 15.0, color);
+                    let label = format!("{} {}", NOTE, t!("wallets.tx_memo"));
+                    ui.label(RichText::new(label).size(15.0).color(Colors::gray()));
+                    ui.add_space(3.0);
+                });
+            });
+        });
     }
 
     /// Draw Slatepack message content.
@@ -559,6 +1029,9 @@ impl WalletTransactionModal {
     }
 }
 
+/// Height of stored Slatepack message file item.
+const HISTORY_ITEM_HEIGHT: f32 = 56.0;
+
 /// Draw transaction information item content.
 fn info_item_ui(ui: &mut egui::Ui,
                 value: String,
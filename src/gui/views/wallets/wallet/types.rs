@@ -12,14 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::gui::icons::{FOLDER_LOCK, FOLDER_OPEN, SPINNER, WARNING_CIRCLE};
+use egui::{Response, RichText};
+use grin_core::core::amount_to_hr_string;
+use grin_core::global::ChainTypes;
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::icons::{CARET_LEFT, CARET_RIGHT, FOLDER_LOCK, FOLDER_OPEN, SPINNER, WARNING_CIRCLE};
 use crate::gui::platform::PlatformCallbacks;
-use crate::wallet::Wallet;
+use crate::gui::views::View;
+use crate::wallet::types::AddressError;
+use crate::wallet::{SeedKdf, Wallet, WalletConfig, WalletUtils};
 
 /// GRIN coin symbol.
 pub const GRIN: &str = "ツ";
 /// Hint for Slatepack message input.
 pub const SLATEPACK_MESSAGE_HINT: &'static str = "BEGINSLATEPACK.\n...\n...\n...\nENDSLATEPACK.";
+/// Maximal multiplier of network fee base for sending.
+pub const MAX_FEE_FACTOR: u64 = 10;
+
+/// Get account name to show from custom name or label.
+pub fn account_label(wallet: &Wallet, label: &String) -> String {
+    if let Some(name) = wallet.account_name(label) {
+        return name;
+    }
+    if label == WalletConfig::DEFAULT_ACCOUNT_LABEL {
+        t!("wallets.default_account")
+    } else {
+        label.clone()
+    }
+}
+
+/// Get text to show for Slatepack address validation error.
+pub fn address_error_text(err: &AddressError) -> String {
+    match err {
+        AddressError::Invalid => t!("transport.incorrect_addr_err"),
+        AddressError::WrongNetwork(chain_type) => match chain_type {
+            ChainTypes::Mainnet => t!("transport.mainnet_addr_err"),
+            _ => t!("transport.testnet_addr_err")
+        }
+    }
+}
 
 /// Wallet tab content interface.
 pub trait WalletTab {
@@ -31,9 +64,10 @@ pub trait WalletTab {
 }
 
 /// Type of [`WalletTab`] content.
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum WalletTabType {
     Txs,
+    Invoices,
     Messages,
     Transport,
     Settings
@@ -44,6 +78,7 @@ impl WalletTabType {
     pub fn name(&self) -> String {
         match *self {
             WalletTabType::Txs => t!("wallets.txs"),
+            WalletTabType::Invoices => t!("wallets.invoices"),
             WalletTabType::Messages => t!("wallets.messages"),
             WalletTabType::Transport => t!("wallets.transport"),
             WalletTabType::Settings => t!("wallets.settings")
@@ -84,4 +119,111 @@ pub fn wallet_status_text(wallet: &Wallet) -> String {
     } else {
         format!("{} {}", FOLDER_LOCK, t!("wallets.locked"))
     }
+}
+
+/// Format amount to show at ui rounded to decimal places from [`AppConfig`].
+pub fn amount_to_display(amount: u64) -> String {
+    match AppConfig::amount_precision() {
+        Some(precision) if precision < 9 => {
+            let unit = 10u64.pow(9 - precision as u32);
+            let rounded = amount.saturating_add(unit / 2) / unit * unit;
+            amount_to_hr_string(rounded, true)
+        }
+        _ => amount_to_hr_string(amount, true)
+    }
+}
+
+/// Show full precision amount on label hover or tap when displayed amount was rounded.
+pub fn full_amount_hover(resp: Response, amount: u64) {
+    let full = amount_to_hr_string(amount, true);
+    if amount_to_display(amount) != full {
+        resp.on_hover_text(format!("{} {}", full, GRIN));
+    }
+}
+
+/// Draw sending amount, fee and resulting balance preview before confirmation.
+pub fn send_preview_ui(ui: &mut egui::Ui, wallet: &Wallet, amount: u64, fee: u64) {
+    let spendable = wallet.get_data().map(|d| d.info.amount_currently_spendable).unwrap_or(0);
+    let balance = spendable.saturating_sub(amount + fee);
+    let rows = [
+        (t!("wallets.send_amount"), amount),
+        (t!("wallets.send_fee"), fee),
+        (t!("wallets.send_balance_after"), balance)
+    ];
+    ui.vertical_centered(|ui| {
+        for (label, value) in rows {
+            ui.label(RichText::new(label).size(16.0).color(Colors::gray()));
+            let value_text = format!("{} {}", amount_to_hr_string(value, true), GRIN);
+            ui.label(RichText::new(value_text).size(17.0).color(Colors::white_or_black(true)));
+            ui.add_space(6.0);
+        }
+    });
+}
+
+/// Draw fee base multiplier selection content, returns `true` if value was changed.
+pub fn fee_factor_ui(ui: &mut egui::Ui, factor: &mut u64) -> bool {
+    let before = *factor;
+    ui.vertical_centered(|ui| {
+        ui.label(RichText::new(t!("wallets.fee_factor")).size(16.0).color(Colors::gray()));
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            // Center content horizontally.
+            let w = ui.available_width();
+            ui.add_space((w - 124.0).max(0.0) / 2.0);
+            View::button(ui, CARET_LEFT.to_string(), Colors::white_or_black(false), || {
+                *factor = (*factor - 1).max(1);
+            });
+            let layout = egui::Layout::centered_and_justified(egui::Direction::LeftToRight);
+            ui.allocate_ui_with_layout(egui::vec2(60.0, 32.0), layout, |ui| {
+                ui.label(RichText::new(format!("x{}", factor))
+                    .size(17.0)
+                    .color(Colors::white_or_black(true)));
+            });
+            View::button(ui, CARET_RIGHT.to_string(), Colors::white_or_black(false), || {
+                *factor = (*factor + 1).min(MAX_FEE_FACTOR);
+            });
+        });
+    });
+    before != *factor
+}
+
+/// Draw password strength estimation.
+pub fn password_strength_ui(ui: &mut egui::Ui, password: &str) {
+    let (text, color) = match WalletUtils::password_strength(password) {
+        0 => (t!("wallets.pass_weak"), Colors::red()),
+        1 => (t!("wallets.pass_fair"), Colors::gold()),
+        2 => (t!("wallets.pass_good"), Colors::green()),
+        _ => (t!("wallets.pass_strong"), Colors::green())
+    };
+    ui.label(RichText::new(format!("{} {}", t!("wallets.pass_strength"), text))
+        .size(16.0)
+        .color(color));
+}
+
+/// Draw selection of seed file password key derivation work factor,
+/// returning `true` if value was changed.
+pub fn kdf_rounds_ui(ui: &mut egui::Ui, rounds: &mut Option<u32>) -> bool {
+    let before = *rounds;
+    ui.label(RichText::new(t!("wallets.kdf_protection"))
+        .size(16.0)
+        .color(Colors::gray()));
+    ui.add_space(6.0);
+    ui.columns(3, |columns| {
+        columns[0].vertical_centered(|ui| {
+            View::radio_value(ui, rounds, None, t!("wallets.kdf_default"));
+        });
+        columns[1].vertical_centered(|ui| {
+            View::radio_value(ui, rounds, Some(SeedKdf::ROUNDS[0]), t!("wallets.kdf_strong"));
+        });
+        columns[2].vertical_centered(|ui| {
+            View::radio_value(ui, rounds, Some(SeedKdf::ROUNDS[1]), t!("wallets.kdf_max"));
+        });
+    });
+    if rounds.is_some() {
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("wallets.kdf_desc"))
+            .size(15.0)
+            .color(Colors::inactive_text()));
+    }
+    before != *rounds
 }
\ No newline at end of file
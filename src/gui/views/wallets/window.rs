@@ -0,0 +1,112 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Context, Id, Rounding, ViewportBuilder, ViewportId};
+
+use crate::AppConfig;
+use crate::gui::Colors;
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, TitlePanel, View};
+use crate::gui::views::types::{TitleContentType, TitleType};
+use crate::gui::views::wallets::WalletContent;
+use crate::gui::views::wallets::types::WalletTabType;
+use crate::wallet::Wallet;
+
+/// Opened wallet content shown at separate desktop window with own navigation state.
+pub struct WalletWindow {
+    /// Window viewport identifier.
+    id: ViewportId,
+    /// Wallet content.
+    content: WalletContent,
+}
+
+impl WalletWindow {
+    /// Create new window content for provided opened [`Wallet`].
+    pub fn new(wallet: Wallet) -> Self {
+        let id = ViewportId::from_hash_of(("wallet_window", wallet.get_config().id));
+        Self {
+            id,
+            content: WalletContent::new(wallet, None),
+        }
+    }
+
+    /// Get identifier of shown [`Wallet`].
+    pub fn wallet_id(&self) -> i64 {
+        self.content.wallet.get_config().id
+    }
+
+    /// Draw window, returning `false` when window was closed or wallet is not available.
+    pub fn show(&mut self, ctx: &Context, cb: &dyn PlatformCallbacks) -> bool {
+        let wallet = &self.content.wallet;
+        let config = wallet.get_config();
+        let wrong_chain = config.chain_type != AppConfig::chain_type();
+        if !wallet.is_open() || wallet.is_deleted() || wrong_chain {
+            Modal::remove_viewport(self.id);
+            return false;
+        }
+
+        let builder = ViewportBuilder::default()
+            .with_title(format!("{} - Grim", config.name))
+            .with_min_inner_size([AppConfig::MIN_WIDTH, AppConfig::MIN_HEIGHT])
+            .with_inner_size([AppConfig::MIN_WIDTH, AppConfig::MIN_HEIGHT * 1.2]);
+        let mut open = true;
+        ctx.show_viewport_immediate(self.id, builder, |ctx, _| {
+            // Use own modal state to not show modals of other windows.
+            let prev_viewport = Modal::switch_viewport(self.id);
+            egui::CentralPanel::default()
+                .frame(egui::Frame {
+                    fill: Colors::fill_deep(),
+                    ..Default::default()
+                })
+                .show(ctx, |ui| {
+                    self.title_ui(ui);
+                    self.content.ui(ui, cb);
+                });
+            Modal::switch_viewport(prev_viewport);
+            if ctx.input(|i| i.viewport().close_requested()) {
+                open = false;
+            }
+        });
+        if !open {
+            Modal::remove_viewport(self.id);
+        }
+        open
+    }
+
+    /// Draw window title content.
+    fn title_ui(&mut self, ui: &mut egui::Ui) {
+        // Draw title background.
+        let title_rect = {
+            let mut rect = ui.max_rect();
+            rect.max.y = rect.min.y + View::get_top_inset() + TitlePanel::HEIGHT;
+            rect
+        };
+        ui.painter().rect_filled(title_rect, Rounding::ZERO, Colors::yellow());
+
+        let tab_type = self.content.current_tab.get_type();
+        let title_text = if self.content.qr_scan_content.is_some() {
+            t!("scan_qr")
+        } else {
+            tab_type.name()
+        };
+        let title = if tab_type == WalletTabType::Settings {
+            TitleType::Single(TitleContentType::Title(title_text))
+        } else {
+            let subtitle_text = self.content.wallet.get_config().name;
+            TitleType::Single(TitleContentType::WithSubTitle(title_text, subtitle_text, false))
+        };
+        let id = Id::new("wallet_window_title").with(self.wallet_id());
+        TitlePanel::new(id).ui(title, |_| {}, |_| {}, ui);
+    }
+}
@@ -48,13 +48,17 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 #[no_mangle]
 fn android_main(app: AndroidApp) {
     #[cfg(debug_assertions)]
-    {
+    let logger: Option<(Box<dyn log::Log>, log::LevelFilter)> = {
         std::env::set_var("RUST_BACKTRACE", "full");
+        let level = log::LevelFilter::Info;
         let log_config = android_logger::Config::default()
-            .with_max_level(log::LevelFilter::Info)
+            .with_max_level(level)
             .with_tag("grim");
-        android_logger::init_once(log_config);
-    }
+        Some((Box::new(android_logger::AndroidLogger::new(log_config)), level))
+    };
+    #[cfg(not(debug_assertions))]
+    let logger = None;
+    init_logger(logger);
 
     use gui::platform::Android;
     let platform = Android::new(app.clone());
@@ -102,6 +106,12 @@ fn use_dark_theme(platform: &gui::platform::Android) -> bool {
     unsafe { res.z != 0 }
 }
 
+/// Setup global logger to capture integrated node logs,
+/// passing records to provided logger with its maximal level.
+pub fn init_logger(logger: Option<(Box<dyn log::Log>, log::LevelFilter)>) {
+    node::NodeLogger::init(logger);
+}
+
 /// [`App`] setup for [`eframe`].
 pub fn app_creator<T: 'static>(app: App<T>) -> eframe::AppCreator<'static>
     where App<T>: eframe::App, T: PlatformCallbacks {
@@ -125,6 +135,55 @@ pub fn start(options: NativeOptions, app_creator: eframe::AppCreator) -> eframe:
     eframe::run_native("Grim", options, app_creator)
 }
 
+/// Entry point to run only integrated node without ui, printing its status
+/// until stop is requested by provided callback.
+pub fn start_headless_node(stop_requested: impl Fn() -> bool) {
+    // Setup translations.
+    setup_i18n();
+    println!("Grim {} node is starting at {}", VERSION, AppConfig::chain_type().shortname());
+    Node::start();
+
+    let mut last_status = "".to_string();
+    let mut stopping = false;
+    loop {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        // Stop the node on request.
+        if !stopping && stop_requested() {
+            println!("Node is stopping...");
+            Node::stop(false);
+            stopping = true;
+        }
+        if stopping && !Node::is_running() {
+            println!("Node is stopped");
+            break;
+        }
+        // Exit on node error.
+        if let Some(e) = Node::get_error() {
+            let err = match e {
+                node::NodeError::Storage => "storage",
+                node::NodeError::P2P => "P2P server",
+                node::NodeError::API => "API server",
+                node::NodeError::Configuration => "configuration",
+                node::NodeError::Unknown => "unknown"
+            };
+            eprintln!("Node is stopped with {} error", err);
+            break;
+        }
+        // Print status on change.
+        let mut status = Node::get_sync_status_text();
+        if let Some(stats) = Node::get_stats() {
+            status = format!("{}, height: {}, peers: {}",
+                             status,
+                             stats.chain_stats.height,
+                             stats.peer_count);
+        }
+        if status != last_status {
+            println!("{}", status);
+            last_status = status;
+        }
+    }
+}
+
 /// Setup application [`egui::Style`] and [`egui::Visuals`].
 pub fn setup_visuals(ctx: &Context) {
     let use_dark = AppConfig::dark_theme().unwrap_or_else(|| {
@@ -231,6 +290,15 @@ pub fn setup_fonts(ctx: &Context) {
     ctx.set_style(style);
 }
 
+/// Change application locale at runtime, system locale will be used if not provided.
+pub fn change_locale(ctx: &Context, locale: Option<&str>) {
+    AppConfig::save_locale(locale);
+    setup_i18n();
+    // Refresh fonts and layout for new locale.
+    setup_fonts(ctx);
+    ctx.request_repaint();
+}
+
 /// Setup translations.
 fn setup_i18n() {
     // Set saved locale or get from system.
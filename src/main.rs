@@ -24,15 +24,31 @@ pub fn main() {
 #[cfg(not(target_os = "android"))]
 fn real_main() {
     #[cfg(debug_assertions)]
-    env_logger::builder()
-        .filter_level(log::LevelFilter::Info)
-        .parse_default_env()
-        .init();
+    let logger: Option<(Box<dyn log::Log>, log::LevelFilter)> = {
+        let logger = env_logger::builder()
+            .filter_level(log::LevelFilter::Info)
+            .parse_default_env()
+            .build();
+        let level = logger.filter();
+        Some((Box::new(logger), level))
+    };
+    #[cfg(not(debug_assertions))]
+    let logger = None;
+    grim::init_logger(logger);
 
-    // Handle file path argument passing.
+    // Handle navigation or file path argument passing.
     let args: Vec<_> = std::env::args().collect();
+    let headless_node = args.iter().any(|a| a == HEADLESS_NODE_ARG);
     let mut data = None;
-    if args.len() > 1 {
+    let mut network = None;
+    if let Some(route) = grim::gui::AppRoute::from_args(&args[1..]) {
+        network = route.network;
+        data = Some(route.to_url());
+    } else if args.len() > 1 && !headless_node {
+        if args[1].starts_with('-') {
+            eprintln!("Unknown arguments: {}", args[1..].join(" "));
+            return;
+        }
         let path = std::path::PathBuf::from(&args[1]);
         let content = match std::fs::read_to_string(path) {
             Ok(s) => Some(s),
@@ -74,11 +90,22 @@ fn real_main() {
         println!("{}\n{}", msg, loc);
     }));
 
+    // Start integrated node without GUI.
+    if headless_node {
+        start_headless_node();
+        return;
+    }
+
     // Start GUI.
     let _ = std::panic::catch_unwind(|| {
         if is_app_running(&data) {
             return;
-        } else if let Some(data) = data {
+        }
+        // Switch network before launch.
+        if let Some(chain_type) = network {
+            grim::AppConfig::change_chain_type(&chain_type);
+        }
+        if let Some(data) = data {
             grim::on_data(data);
         }
         let platform = grim::gui::platform::Desktop::new();
@@ -87,6 +114,59 @@ fn real_main() {
     });
 }
 
+/// Argument to run only integrated node without GUI.
+#[allow(dead_code)]
+#[cfg(not(target_os = "android"))]
+const HEADLESS_NODE_ARG: &'static str = "--headless-node";
+
+/// Start integrated node without GUI, stopping it on termination signal.
+#[allow(dead_code)]
+#[cfg(not(target_os = "android"))]
+fn start_headless_node() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // Do not start node when application is already running.
+    if is_app_running(&None) {
+        eprintln!("Grim is already running");
+        return;
+    }
+
+    // Wait for termination signal at separate thread.
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_signal = stop.clone();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            #[cfg(unix)]
+            {
+                use tokio::signal::unix::{signal, SignalKind};
+                match signal(SignalKind::terminate()) {
+                    Ok(mut terminate) => {
+                        tokio::select! {
+                            _ = tokio::signal::ctrl_c() => {}
+                            _ = terminate.recv() => {}
+                        }
+                    }
+                    Err(_) => {
+                        let _ = tokio::signal::ctrl_c().await;
+                    }
+                }
+            }
+            #[cfg(not(unix))]
+            {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        });
+        stop_signal.store(true, Ordering::Relaxed);
+    });
+
+    grim::start_headless_node(|| stop.load(Ordering::Relaxed));
+}
+
 /// Get panic message from crash payload.
 #[allow(dead_code)]
 #[cfg(not(target_os = "android"))]
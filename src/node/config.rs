@@ -282,6 +282,28 @@ impl NodeConfig {
         ip_addrs
     }
 
+    /// Get IPv4 addresses with interface names available at system.
+    pub fn get_ip_interfaces() -> Vec<(String, String)> {
+        let mut interfaces = Vec::new();
+        if let Ok(network_interfaces) = list_afinet_netifas() {
+            for (name, ip) in network_interfaces.iter() {
+                if ip.is_ipv4() {
+                    interfaces.push((ip.to_string(), name.to_string()));
+                }
+            }
+        }
+        interfaces
+    }
+
+    /// Check if provided IP address can be used to bind listener,
+    /// it should be assigned at system or unspecified to listen at all interfaces.
+    pub fn is_ip_assigned(ip: &String) -> bool {
+        match Ipv4Addr::from_str(ip.as_str()) {
+            Ok(addr) => addr.is_unspecified() || Self::get_ip_addrs().contains(ip),
+            Err(_) => false
+        }
+    }
+
     /// Check whether a port is available on the provided host.
     fn is_host_port_available(host: &String, port: &String) -> bool {
         if let Ok(p) = port.parse::<u16>() {
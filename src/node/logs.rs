@@ -0,0 +1,183 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use parking_lot::RwLock;
+
+lazy_static! {
+    /// Last captured log records.
+    static ref LOG_ENTRIES: Arc<RwLock<VecDeque<LogEntry>>> = Arc::new(RwLock::new(VecDeque::new()));
+    /// Last time of detected node warnings.
+    static ref NODE_WARNINGS: Arc<RwLock<HashMap<NodeWarning, i64>>> = Arc::new(
+        RwLock::new(HashMap::new())
+    );
+}
+
+/// Warning about integrated node state detected from log records.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeWarning {
+    /// Peers are sending blocks or headers with timestamp far in the future.
+    FutureBlock,
+    /// Local clock differs from the network time.
+    TimeDrift,
+    /// Chain fork or reorganization was detected.
+    Fork
+}
+
+impl NodeWarning {
+    /// Detect warning from log record message.
+    fn from_message(message: &String) -> Option<Self> {
+        let m = message.to_lowercase();
+        if m.contains("invalidblocktime") || m.contains("from the future") {
+            Some(Self::FutureBlock)
+        } else if m.contains("clock") || m.contains("time drift") {
+            Some(Self::TimeDrift)
+        } else if m.contains("fork") || m.contains("reorg") {
+            Some(Self::Fork)
+        } else {
+            None
+        }
+    }
+}
+
+/// Captured log record.
+#[derive(Clone)]
+pub struct LogEntry {
+    /// Record time.
+    pub time: i64,
+    /// Record level.
+    pub level: Level,
+    /// Record source module.
+    pub target: String,
+    /// Record message.
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Format record to single text line.
+    pub fn to_line(&self) -> String {
+        let time = chrono::DateTime::from_timestamp(self.time, 0)
+            .map(|t| t.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+            .unwrap_or_default();
+        format!("{} {} {} - {}", time, self.level, self.target, self.message)
+    }
+}
+
+/// Logger to capture records of integrated node for viewing at application,
+/// optionally passing them to another logger.
+pub struct NodeLogger {
+    /// Logger to pass records after capture.
+    inner: Option<Box<dyn Log>>,
+}
+
+impl NodeLogger {
+    /// Maximal amount of records to keep.
+    const MAX_ENTRIES: usize = 2000;
+
+    /// Maximal level of node records to capture.
+    const CAPTURE_LEVEL: LevelFilter = LevelFilter::Info;
+
+    /// Target prefix of node modules to capture records.
+    const NODE_TARGET: &'static str = "grin";
+
+    /// Time in seconds to show detected warning.
+    const WARNING_TTL: i64 = 60 * 60;
+
+    /// Setup global logger with optional logger and its maximal level to pass records.
+    pub fn init(inner: Option<(Box<dyn Log>, LevelFilter)>) {
+        let max_level = inner.as_ref()
+            .map(|(_, level)| Self::CAPTURE_LEVEL.max(*level))
+            .unwrap_or(Self::CAPTURE_LEVEL);
+        let inner = inner.map(|(logger, _)| logger);
+        if log::set_boxed_logger(Box::new(NodeLogger { inner })).is_ok() {
+            log::set_max_level(max_level);
+        }
+    }
+
+    /// Check if record should be captured.
+    fn capture(metadata: &Metadata) -> bool {
+        metadata.target().starts_with(Self::NODE_TARGET) && metadata.level() <= Self::CAPTURE_LEVEL
+    }
+
+    /// Get captured records.
+    pub fn entries() -> Vec<LogEntry> {
+        let r_entries = LOG_ENTRIES.read();
+        r_entries.iter().cloned().collect()
+    }
+
+    /// Get warnings detected during last hour with detection time.
+    pub fn warnings() -> Vec<(NodeWarning, i64)> {
+        let now = chrono::Utc::now().timestamp();
+        let r_warnings = NODE_WARNINGS.read();
+        let mut warnings = r_warnings.iter()
+            .filter(|(_, t)| now - **t < Self::WARNING_TTL)
+            .map(|(w, t)| (*w, *t))
+            .collect::<Vec<(NodeWarning, i64)>>();
+        warnings.sort_by_key(|(_, t)| -*t);
+        warnings
+    }
+
+    /// Hide detected warning until next detection.
+    pub fn dismiss_warning(warning: NodeWarning) {
+        let mut w_warnings = NODE_WARNINGS.write();
+        w_warnings.remove(&warning);
+    }
+
+    /// Remove captured records.
+    pub fn clear() {
+        let mut w_entries = LOG_ENTRIES.write();
+        w_entries.clear();
+    }
+}
+
+impl Log for NodeLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        Self::capture(metadata) || self.inner.as_ref().map(|l| l.enabled(metadata)).unwrap_or(false)
+    }
+
+    fn log(&self, record: &Record) {
+        if Self::capture(record.metadata()) {
+            let entry = LogEntry {
+                time: chrono::Utc::now().timestamp(),
+                level: record.level(),
+                target: record.target().to_string(),
+                message: record.args().to_string(),
+            };
+            // Detect node warning from record.
+            if let Some(warning) = NodeWarning::from_message(&entry.message) {
+                let mut w_warnings = NODE_WARNINGS.write();
+                w_warnings.insert(warning, entry.time);
+            }
+            let mut w_entries = LOG_ENTRIES.write();
+            w_entries.push_back(entry);
+            if w_entries.len() > Self::MAX_ENTRIES {
+                w_entries.pop_front();
+            }
+        }
+        if let Some(inner) = &self.inner {
+            if inner.enabled(record.metadata()) {
+                inner.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(inner) = &self.inner {
+            inner.flush();
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+use crate::AppConfig;
+use crate::node::NodeConfig;
+
+lazy_static! {
+    /// Static thread-aware state of [`ExternalMiner`] to be updated from separate thread.
+    static ref MINER_STATE: Arc<ExternalMiner> = Arc::new(ExternalMiner::default());
+}
+
+/// Launches external miner binary connected to integrated stratum server,
+/// restarts it on unexpected exit and collects its output.
+pub struct ExternalMiner {
+    /// Flag to check if miner should be running.
+    running: AtomicBool,
+    /// Identifier of current launch to finish supervision of previous one.
+    launch_id: AtomicUsize,
+    /// Last lines of miner output.
+    output: RwLock<Vec<String>>,
+}
+
+impl Default for ExternalMiner {
+    fn default() -> Self {
+        Self {
+            running: AtomicBool::new(false),
+            launch_id: AtomicUsize::new(0),
+            output: RwLock::new(vec![]),
+        }
+    }
+}
+
+impl ExternalMiner {
+    /// Default miner arguments template.
+    pub const DEFAULT_ARGS: &'static str = "--stratum {host}:{port} --user {login}";
+
+    /// Login to connect to integrated stratum server.
+    const LOGIN: &'static str = "grim";
+
+    /// Maximal amount of output lines to keep.
+    const MAX_OUTPUT_LINES: usize = 200;
+
+    /// Delay before miner restart after unexpected exit.
+    const RESTART_DELAY: Duration = Duration::from_secs(5);
+
+    /// Check if miner launch is supported on current platform.
+    pub fn is_supported() -> bool {
+        !cfg!(target_os = "android")
+    }
+
+    /// Check if miner is running.
+    pub fn is_running() -> bool {
+        MINER_STATE.running.load(Ordering::Relaxed)
+    }
+
+    /// Get last lines of miner output.
+    pub fn output() -> Vec<String> {
+        MINER_STATE.output.read().clone()
+    }
+
+    /// Get miner arguments with values of integrated stratum server.
+    pub fn args() -> Vec<String> {
+        let (mut host, port) = NodeConfig::get_stratum_address();
+        if host == "0.0.0.0" {
+            host = "127.0.0.1".to_string();
+        }
+        AppConfig::miner_args()
+            .split_whitespace()
+            .map(|a| {
+                a.replace("{host}", &host)
+                    .replace("{port}", &port)
+                    .replace("{login}", Self::LOGIN)
+            })
+            .collect()
+    }
+
+    /// Launch miner from saved path in separate thread.
+    pub fn start() {
+        let path = match AppConfig::miner_path() {
+            Some(p) => p,
+            None => return
+        };
+        if Self::is_running() {
+            return;
+        }
+        MINER_STATE.running.store(true, Ordering::Relaxed);
+        MINER_STATE.output.write().clear();
+        let launch_id = MINER_STATE.launch_id.fetch_add(1, Ordering::Relaxed) + 1;
+        thread::spawn(move || {
+            let is_current = || {
+                Self::is_running() && MINER_STATE.launch_id.load(Ordering::Relaxed) == launch_id
+            };
+            while is_current() {
+                let args = Self::args();
+                Self::add_output(format!("$ {} {}", path, args.join(" ")));
+                let child = Command::new(&path)
+                    .args(&args)
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped())
+                    .spawn();
+                let mut child = match child {
+                    Ok(c) => c,
+                    Err(e) => {
+                        Self::add_output(e.to_string());
+                        MINER_STATE.running.store(false, Ordering::Relaxed);
+                        break;
+                    }
+                };
+                // Collect miner output.
+                if let Some(stdout) = child.stdout.take() {
+                    Self::read_output(stdout);
+                }
+                if let Some(stderr) = child.stderr.take() {
+                    Self::read_output(stderr);
+                }
+                // Wait for process exit, kill it when launch was stopped or replaced.
+                let status = loop {
+                    if !is_current() {
+                        let _ = child.kill();
+                        break child.wait().ok();
+                    }
+                    match child.try_wait() {
+                        Ok(None) => thread::sleep(Duration::from_millis(500)),
+                        Ok(Some(s)) => break Some(s),
+                        Err(_) => break None
+                    }
+                };
+                if let Some(s) = status {
+                    Self::add_output(s.to_string());
+                }
+                // Restart miner after delay when it was not stopped.
+                if is_current() {
+                    thread::sleep(Self::RESTART_DELAY);
+                }
+            }
+        });
+    }
+
+    /// Stop running miner, process is killed at launch thread.
+    pub fn stop() {
+        MINER_STATE.running.store(false, Ordering::Relaxed);
+    }
+
+    /// Read miner output lines in separate thread.
+    fn read_output<R: Read + Send + 'static>(source: R) {
+        thread::spawn(move || {
+            let reader = BufReader::new(source);
+            for line in reader.lines() {
+                match line {
+                    Ok(l) => Self::add_output(l),
+                    Err(_) => break
+                }
+            }
+        });
+    }
+
+    /// Save miner output line.
+    fn add_output(line: String) {
+        let mut w_output = MINER_STATE.output.write();
+        w_output.push(line);
+        if w_output.len() > Self::MAX_OUTPUT_LINES {
+            w_output.remove(0);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use grin_core::consensus::graph_weight;
+use grin_servers::StratumStats;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use serde_json::Value;
+
+use crate::AppConfig;
+
+lazy_static! {
+    /// Static thread-aware state of [`MinerApi`] to be updated from separate thread.
+    static ref MINER_API_STATE: Arc<MinerApi> = Arc::new(MinerApi::default());
+}
+
+/// Statistics received from external miner or mining pool API.
+#[derive(Clone)]
+pub struct MinerApiStats {
+    /// Graphs per second.
+    pub hashrate: f64,
+    /// Amount of workers when provided.
+    pub workers: Option<u64>,
+}
+
+/// Queries external miner or mining pool API from saved URL
+/// and estimates hashrate of workers connected to integrated stratum server.
+pub struct MinerApi {
+    /// Last received statistics.
+    stats: RwLock<Option<MinerApiStats>>,
+    /// Flag to check if last request failed.
+    error: AtomicBool,
+    /// Flag to check if request is in progress.
+    loading: AtomicBool,
+    /// Time of last request in seconds.
+    last_check: AtomicI64,
+    /// Time and total accepted work of local stratum workers to calculate hashrate.
+    share_samples: RwLock<VecDeque<(i64, f64)>>,
+}
+
+impl Default for MinerApi {
+    fn default() -> Self {
+        Self {
+            stats: RwLock::new(None),
+            error: AtomicBool::new(false),
+            loading: AtomicBool::new(false),
+            last_check: AtomicI64::new(0),
+            share_samples: RwLock::new(VecDeque::new()),
+        }
+    }
+}
+
+impl MinerApi {
+    /// Interval in seconds between API requests.
+    const CHECK_INTERVAL: i64 = 30;
+
+    /// Interval in seconds between local workers samples.
+    const SAMPLE_INTERVAL: i64 = 10;
+
+    /// Period in seconds to calculate local hashrate.
+    const SAMPLE_PERIOD: i64 = 600;
+
+    /// Expected time between blocks in seconds.
+    const BLOCK_TIME: f64 = 60.0;
+
+    /// Object keys to find hashrate value at API response.
+    const HASHRATE_KEYS: [&'static str; 7] = [
+        "hashrate",
+        "hashRate",
+        "currentHashrate",
+        "graph_rate",
+        "graphRate",
+        "gps",
+        "GPS"
+    ];
+
+    /// Object keys to find workers value at API response.
+    const WORKERS_KEYS: [&'static str; 5] = [
+        "workersOnline",
+        "workers_online",
+        "num_workers",
+        "workers",
+        "devices"
+    ];
+
+    /// Get last received statistics.
+    pub fn remote_stats() -> Option<MinerApiStats> {
+        MINER_API_STATE.stats.read().clone()
+    }
+
+    /// Check if last request failed.
+    pub fn has_error() -> bool {
+        MINER_API_STATE.error.load(Ordering::Relaxed)
+    }
+
+    /// Check if request is in progress.
+    pub fn is_loading() -> bool {
+        MINER_API_STATE.loading.load(Ordering::Relaxed)
+    }
+
+    /// Clear statistics to request them again from saved URL.
+    pub fn refresh() {
+        MINER_API_STATE.last_check.store(0, Ordering::Relaxed);
+        MINER_API_STATE.error.store(false, Ordering::Relaxed);
+        let mut w_stats = MINER_API_STATE.stats.write();
+        *w_stats = None;
+    }
+
+    /// Request statistics from saved URL when interval passed.
+    pub fn check_periodically(ui_ctx: &egui::Context) {
+        let url = match AppConfig::miner_api_url() {
+            Some(url) => url,
+            None => return
+        };
+        let now = chrono::Utc::now().timestamp();
+        let last_check = MINER_API_STATE.last_check.load(Ordering::Relaxed);
+        if now - last_check < Self::CHECK_INTERVAL || Self::is_loading() {
+            return;
+        }
+        MINER_API_STATE.last_check.store(now, Ordering::Relaxed);
+        MINER_API_STATE.loading.store(true, Ordering::Relaxed);
+        let ui_ctx = ui_ctx.clone();
+        std::thread::spawn(move || {
+            let stats = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    Self::request(url.as_str()).await
+                });
+            // Skip result when URL was changed during request.
+            if AppConfig::miner_api_url() == Some(url) {
+                MINER_API_STATE.error.store(stats.is_none(), Ordering::Relaxed);
+                let mut w_stats = MINER_API_STATE.stats.write();
+                *w_stats = stats;
+            }
+            MINER_API_STATE.loading.store(false, Ordering::Relaxed);
+            ui_ctx.request_repaint();
+        });
+    }
+
+    /// Request statistics from provided URL.
+    async fn request(url: &str) -> Option<MinerApiStats> {
+        let uri = url::Url::parse(url).ok()?.to_string();
+        let client = hyper::Client::builder()
+            .build::<_, hyper::Body>(hyper_tls::HttpsConnector::new());
+        let req = hyper::Request::builder()
+            .method(hyper::Method::GET)
+            .uri(uri)
+            .header(hyper::header::ACCEPT, "application/json")
+            .body(hyper::Body::empty())
+            .ok()?;
+        let res = client.request(req).await.ok()?;
+        if !res.status().is_success() {
+            return None;
+        }
+        let bytes = hyper::body::to_bytes(res.into_body()).await.ok()?;
+        let value = serde_json::from_slice::<Value>(&bytes).ok()?;
+        let hashrate = Self::find_hashrate(&value, 0)?;
+        let workers = Self::find_workers(&value, 0);
+        Some(MinerApiStats { hashrate, workers })
+    }
+
+    /// Find hashrate value at API response, summing values of array items.
+    fn find_hashrate(value: &Value, depth: usize) -> Option<f64> {
+        if depth > 4 {
+            return None;
+        }
+        match value {
+            Value::Object(map) => {
+                for key in Self::HASHRATE_KEYS {
+                    if let Some(v) = map.get(key).and_then(Self::as_f64) {
+                        return Some(v);
+                    }
+                }
+                map.values().find_map(|v| Self::find_hashrate(v, depth + 1))
+            }
+            Value::Array(items) => {
+                let rates = items.iter()
+                    .filter_map(|v| Self::find_hashrate(v, depth + 1))
+                    .collect::<Vec<f64>>();
+                if rates.is_empty() {
+                    None
+                } else {
+                    Some(rates.iter().sum())
+                }
+            }
+            _ => None
+        }
+    }
+
+    /// Find amount of workers at API response.
+    fn find_workers(value: &Value, depth: usize) -> Option<u64> {
+        if depth > 4 {
+            return None;
+        }
+        match value {
+            Value::Object(map) => {
+                for key in Self::WORKERS_KEYS {
+                    match map.get(key) {
+                        Some(Value::Array(items)) => return Some(items.len() as u64),
+                        Some(Value::Object(items)) => return Some(items.len() as u64),
+                        Some(v) => {
+                            if let Some(n) = Self::as_f64(v) {
+                                return Some(n as u64);
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                map.values().find_map(|v| Self::find_workers(v, depth + 1))
+            }
+            _ => None
+        }
+    }
+
+    /// Get number from JSON value, parsing it from string if needed.
+    fn as_f64(value: &Value) -> Option<f64> {
+        match value {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse::<f64>().ok(),
+            _ => None
+        }
+    }
+
+    /// Estimate hashrate of workers connected to integrated stratum server
+    /// from accepted shares difficulty.
+    pub fn local_hashrate(stats: &StratumStats) -> Option<f64> {
+        if stats.block_height == 0 || stats.edge_bits == 0 {
+            return None;
+        }
+        let weight = graph_weight(stats.block_height, stats.edge_bits as u8) as f64;
+        let work = stats.worker_stats
+            .iter()
+            .map(|w| w.num_accepted as f64 * w.pow_difficulty as f64)
+            .sum::<f64>() * 42.0 / weight;
+
+        // Save new sample.
+        let now = chrono::Utc::now().timestamp();
+        let mut w_samples = MINER_API_STATE.share_samples.write();
+        if w_samples.back().map(|(_, w)| work < w).unwrap_or(false) {
+            // Reset samples when workers were reconnected.
+            w_samples.clear();
+        }
+        if w_samples.back().map(|(t, _)| now - t >= Self::SAMPLE_INTERVAL).unwrap_or(true) {
+            w_samples.push_back((now, work));
+        }
+        while w_samples.front().map(|(t, _)| now - t > Self::SAMPLE_PERIOD).unwrap_or(false) {
+            w_samples.pop_front();
+        }
+
+        // Calculate graphs per second between first and last samples.
+        let (first_time, first_work) = *w_samples.front()?;
+        let (last_time, last_work) = *w_samples.back()?;
+        if last_time == first_time {
+            return None;
+        }
+        Some((last_work - first_work) / (last_time - first_time) as f64)
+    }
+
+    /// Get estimated time in seconds to find a block with provided hashrate.
+    pub fn time_to_block(network_hashrate: f64, hashrate: f64) -> Option<u64> {
+        if network_hashrate <= 0.0 || hashrate <= 0.0 {
+            return None;
+        }
+        Some((Self::BLOCK_TIME * network_hashrate / hashrate) as u64)
+    }
+}
@@ -18,8 +18,26 @@ mod mine_block;
 mod node;
 pub use node::Node;
 
+mod miner;
+pub use miner::ExternalMiner;
+
+mod miner_api;
+pub use miner_api::{MinerApi, MinerApiStats};
+
+mod test_miner;
+pub use test_miner::TestMiner;
+
+mod logs;
+pub use logs::{LogEntry, NodeLogger, NodeWarning};
+
 mod config;
 pub use config::*;
 
 mod types;
-pub use types::*;
\ No newline at end of file
+pub use types::*;
+
+mod sync_stats;
+pub use sync_stats::{DailyTraffic, InitialSyncStats, SyncStats};
+
+mod monitor;
+pub use monitor::{NodeMonitor, NodeResourceSample};
\ No newline at end of file
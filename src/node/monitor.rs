@@ -0,0 +1,159 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use sysinfo::{get_current_pid, ProcessExt, ProcessRefreshKind, System, SystemExt};
+
+use crate::node::Node;
+
+lazy_static! {
+    /// Static thread-aware state of [`NodeMonitor`] to be updated from separate thread.
+    static ref MONITOR_STATE: Arc<NodeMonitor> = Arc::new(NodeMonitor::default());
+}
+
+/// Resources usage and network state of running node at a moment.
+#[derive(Clone, Copy)]
+pub struct NodeResourceSample {
+    /// Sample time in seconds.
+    pub time: i64,
+    /// Application process CPU usage in percents of all cores.
+    pub cpu: Option<f32>,
+    /// Application process memory usage in bytes.
+    pub memory: Option<u64>,
+    /// Amount of connected peers.
+    pub peers: u32,
+    /// Download speed from peers in bytes per second.
+    pub download_speed: u64,
+    /// Chain height.
+    pub height: u64,
+}
+
+/// Samples application process resources usage and node state while node is running.
+pub struct NodeMonitor {
+    /// Samples from oldest to newest.
+    samples: RwLock<VecDeque<NodeResourceSample>>,
+    /// Node start time in seconds.
+    start_time: AtomicI64,
+    /// Identifier of current launch to finish sampling of previous one.
+    launch_id: AtomicUsize,
+}
+
+impl Default for NodeMonitor {
+    fn default() -> Self {
+        Self {
+            samples: RwLock::new(VecDeque::new()),
+            start_time: AtomicI64::new(0),
+            launch_id: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl NodeMonitor {
+    /// Delay between samples.
+    pub const SAMPLE_DELAY: Duration = Duration::from_secs(5);
+
+    /// Maximal amount of samples to keep, 10 minutes of history.
+    pub const MAX_SAMPLES: usize = 120;
+
+    /// Start sampling at separate thread until node is stopped.
+    pub fn start() {
+        MONITOR_STATE.samples.write().clear();
+        let now = chrono::Utc::now().timestamp();
+        MONITOR_STATE.start_time.store(now, Ordering::Relaxed);
+        let launch_id = MONITOR_STATE.launch_id.fetch_add(1, Ordering::Relaxed) + 1;
+        thread::spawn(move || {
+            let cores = thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f32;
+            let pid = get_current_pid().ok();
+            let mut sys = System::new();
+            loop {
+                let is_current = MONITOR_STATE.launch_id.load(Ordering::Relaxed) == launch_id;
+                if !is_current || !(Node::is_running() || Node::is_restarting()) {
+                    break;
+                }
+                // Refresh process usage, CPU usage is calculated since previous refresh.
+                let process = pid.and_then(|pid| {
+                    let refresh_kind = ProcessRefreshKind::new().with_cpu();
+                    if sys.refresh_process_specifics(pid, refresh_kind) {
+                        sys.process(pid)
+                    } else {
+                        None
+                    }
+                });
+                let (cpu, memory) = match process {
+                    Some(p) => (Some(p.cpu_usage() / cores), Some(p.memory())),
+                    None => (None, None)
+                };
+                if let Some(stats) = Node::get_stats() {
+                    let download_speed = stats.peer_stats
+                        .iter()
+                        .map(|p| p.received_bytes_per_sec)
+                        .sum::<u64>();
+                    let sample = NodeResourceSample {
+                        time: chrono::Utc::now().timestamp(),
+                        cpu,
+                        memory,
+                        peers: stats.peer_count,
+                        download_speed,
+                        height: stats.header_stats.height,
+                    };
+                    let mut w_samples = MONITOR_STATE.samples.write();
+                    w_samples.push_back(sample);
+                    if w_samples.len() > Self::MAX_SAMPLES {
+                        w_samples.pop_front();
+                    }
+                }
+                thread::sleep(Self::SAMPLE_DELAY);
+            }
+        });
+    }
+
+    /// Get samples from oldest to newest.
+    pub fn samples() -> Vec<NodeResourceSample> {
+        MONITOR_STATE.samples.read().iter().copied().collect()
+    }
+
+    /// Get last sample.
+    pub fn last_sample() -> Option<NodeResourceSample> {
+        MONITOR_STATE.samples.read().back().copied()
+    }
+
+    /// Get node uptime in seconds.
+    pub fn uptime() -> Option<u64> {
+        if !Node::is_running() {
+            return None;
+        }
+        let start_time = MONITOR_STATE.start_time.load(Ordering::Relaxed);
+        let now = chrono::Utc::now().timestamp();
+        Some((now - start_time).max(0) as u64)
+    }
+
+    /// Get header sync speed in blocks per minute calculated from saved samples.
+    pub fn sync_speed() -> Option<f64> {
+        let r_samples = MONITOR_STATE.samples.read();
+        let first = r_samples.front()?;
+        let last = r_samples.back()?;
+        let secs = last.time - first.time;
+        if secs <= 0 {
+            return None;
+        }
+        let blocks = last.height.saturating_sub(first.height);
+        Some(blocks as f64 * 60.0 / secs as f64)
+    }
+}
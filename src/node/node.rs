@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::{fs, thread};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -21,7 +23,8 @@ use lazy_static::lazy_static;
 use parking_lot::RwLock;
 use futures::channel::oneshot;
 
-use grin_chain::SyncStatus;
+use grin_chain::{Chain, SyncState, SyncStatus};
+use grin_core::core::hash::{Hash, Hashed};
 use grin_core::global;
 use grin_core::global::ChainTypes;
 use grin_p2p::msg::PeerAddrs;
@@ -29,7 +32,8 @@ use grin_p2p::Seeding;
 use grin_servers::{Server, ServerStats, StratumServerConfig, StratumStats};
 use grin_servers::common::types::Error;
 
-use crate::node::{NodeConfig, NodeError, PeersConfig};
+use crate::AppConfig;
+use crate::node::{BlockTemplate, BootstrapError, ExternalMiner, NodeConfig, NodeError, NodeMonitor, PeersConfig, SyncStats, TestMiner};
 use crate::node::stratum::{StratumStopState, StratumServer};
 
 lazy_static! {
@@ -41,6 +45,8 @@ lazy_static! {
 pub struct Node {
     /// Node [`Server`] statistics information.
     stats: Arc<RwLock<Option<ServerStats>>>,
+    /// Node sync time and traffic statistics.
+    sync_stats: Arc<RwLock<SyncStats>>,
 
     /// [`StratumServer`] statistics information.
     stratum_stats: Arc<grin_util::RwLock<StratumStats>>,
@@ -48,6 +54,8 @@ pub struct Node {
     start_stratum_needed: AtomicBool,
     /// State to stop [`StratumServer`] from outside.
     stratum_stop_state: Arc<StratumStopState>,
+    /// Block template served by [`StratumServer`].
+    block_template: Arc<grin_util::RwLock<Option<BlockTemplate>>>,
 
     /// Indicator if node [`Server`] is starting.
     starting: AtomicBool,
@@ -60,6 +68,22 @@ pub struct Node {
     /// Flag to reset peers data and restart the [`Server`].
     reset_peers: AtomicBool,
 
+    /// Flag to run chain compaction at [`Server`] thread.
+    compact_needed: AtomicBool,
+    /// Flag to check if last chain compaction failed.
+    compaction_error: AtomicBool,
+    /// Chain data and txhashset size on disk in bytes.
+    data_size: Arc<RwLock<Option<(u64, u64)>>>,
+
+    /// Path to txhashset archive file to bootstrap chain state from.
+    bootstrap_path: Arc<RwLock<Option<String>>>,
+    /// Flag to check if chain state is bootstrapping from archive file.
+    bootstrapping: AtomicBool,
+    /// Progress of chain state import from archive file while [`Server`] is stopped.
+    bootstrap_status: Arc<SyncState>,
+    /// An error occurred on last bootstrap from archive file.
+    bootstrap_error: Arc<RwLock<Option<BootstrapError>>>,
+
     /// An error occurred on [`Server`] start.
     error: Arc<RwLock<Option<Error>>>
 }
@@ -68,8 +92,10 @@ impl Default for Node {
     fn default() -> Self {
         Self {
             stats: Arc::new(RwLock::new(None)),
+            sync_stats: Arc::new(RwLock::new(SyncStats::default())),
             stratum_stats: Arc::new(grin_util::RwLock::new(StratumStats::default())),
             stratum_stop_state: Arc::new(StratumStopState::default()),
+            block_template: Arc::new(grin_util::RwLock::new(None)),
             starting: AtomicBool::new(false),
             restart_needed: AtomicBool::new(false),
             stop_needed: AtomicBool::new(false),
@@ -77,6 +103,13 @@ impl Default for Node {
             start_stratum_needed: AtomicBool::new(false),
             error: Arc::new(RwLock::new(None)),
             reset_peers: AtomicBool::new(false),
+            compact_needed: AtomicBool::new(false),
+            compaction_error: AtomicBool::new(false),
+            data_size: Arc::new(RwLock::new(None)),
+            bootstrap_path: Arc::new(RwLock::new(None)),
+            bootstrapping: AtomicBool::new(false),
+            bootstrap_status: Arc::new(SyncState::new()),
+            bootstrap_error: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -85,6 +118,12 @@ impl Node {
     /// Delay for thread to update the stats.
     pub const STATS_UPDATE_DELAY: Duration = Duration::from_millis(1000);
 
+    /// Delay in seconds to update chain data size on disk.
+    const DATA_SIZE_UPDATE_DELAY: i64 = 600;
+
+    /// Delay in seconds to save sync statistics.
+    const SYNC_STATS_SAVE_DELAY: i64 = 60;
+
     /// Default Mainnet DNS Seeds
     pub const MAINNET_DNS_SEEDS: &'static[&'static str] = &[
         "mainnet.seed.grin.lesceller.com",
@@ -97,6 +136,8 @@ impl Node {
 
     /// Stop the [`Server`] and setup exit flag after if needed.
     pub fn stop(exit_after_stop: bool) {
+        ExternalMiner::stop();
+        TestMiner::stop();
         NODE_STATE.stop_needed.store(true, Ordering::Relaxed);
         NODE_STATE.exit_after_stop.store(exit_after_stop, Ordering::Relaxed);
     }
@@ -132,8 +173,15 @@ impl Node {
         NODE_STATE.stratum_stats.read().clone()
     }
 
+    /// Get block template served by [`StratumServer`].
+    pub fn get_block_template() -> Option<BlockTemplate> {
+        NODE_STATE.block_template.read().clone()
+    }
+
     /// Stop [`StratumServer`].
     pub fn stop_stratum() {
+        ExternalMiner::stop();
+        TestMiner::stop();
         NODE_STATE.stratum_stop_state.stop()
     }
 
@@ -142,6 +190,69 @@ impl Node {
         NODE_STATE.stratum_stop_state.is_stopped()
     }
 
+    /// Request chain compaction at running [`Server`].
+    pub fn compact() {
+        if Self::is_running() {
+            NODE_STATE.compaction_error.store(false, Ordering::Relaxed);
+            NODE_STATE.compact_needed.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Check if chain compaction is running.
+    pub fn is_compacting() -> bool {
+        NODE_STATE.compact_needed.load(Ordering::Relaxed)
+    }
+
+    /// Check if last chain compaction failed.
+    pub fn compaction_failed() -> bool {
+        NODE_STATE.compaction_error.load(Ordering::Relaxed)
+    }
+
+    /// Request chain state bootstrap from txhashset archive file at running [`Server`].
+    pub fn bootstrap(path: String) {
+        if !Self::is_running() || Self::is_bootstrapping() {
+            return;
+        }
+        {
+            let mut w_err = NODE_STATE.bootstrap_error.write();
+            *w_err = None;
+        }
+        NODE_STATE.bootstrap_status.update(SyncStatus::Initial);
+        NODE_STATE.bootstrapping.store(true, Ordering::Relaxed);
+        let mut w_path = NODE_STATE.bootstrap_path.write();
+        *w_path = Some(path);
+    }
+
+    /// Check if chain state is bootstrapping from archive file.
+    pub fn is_bootstrapping() -> bool {
+        NODE_STATE.bootstrapping.load(Ordering::Relaxed)
+    }
+
+    /// Get an error occurred on last bootstrap from archive file.
+    pub fn bootstrap_error() -> Option<BootstrapError> {
+        NODE_STATE.bootstrap_error.read().clone()
+    }
+
+    /// Get node sync time and traffic statistics.
+    pub fn sync_stats() -> SyncStats {
+        NODE_STATE.sync_stats.read().clone()
+    }
+
+    /// Get chain data and txhashset size on disk in bytes.
+    pub fn data_size() -> Option<(u64, u64)> {
+        *NODE_STATE.data_size.read()
+    }
+
+    /// Calculate chain data and txhashset size on disk.
+    fn update_data_size() {
+        let db_root = PathBuf::from(NodeConfig::node_server_config().server.db_root);
+        let mut txhashset_path = db_root.clone();
+        txhashset_path.push("txhashset");
+        let size = (dir_size(&db_root), dir_size(&txhashset_path));
+        let mut w_size = NODE_STATE.data_size.write();
+        *w_size = Some(size);
+    }
+
     /// Check if [`Node`] is starting.
     pub fn is_starting() -> bool {
         NODE_STATE.starting.load(Ordering::Relaxed)
@@ -180,6 +291,26 @@ impl Node {
         };
     }
 
+    /// Check if [`Server`] is synced and healthy enough to trust its data at wallets.
+    pub fn is_ready_for_wallets() -> bool {
+        if !Self::is_running() || Self::get_sync_status() != Some(SyncStatus::NoSync) {
+            return false;
+        }
+        if let Some(stats) = Self::get_stats() {
+            if stats.peer_count < AppConfig::wallet_sync_min_peers() {
+                return false;
+            }
+            let max_tip_age = AppConfig::wallet_sync_max_tip_age();
+            if max_tip_age != 0 {
+                let tip_time = stats.chain_stats.latest_timestamp.timestamp();
+                let tip_age = chrono::Utc::now().timestamp() - tip_time;
+                return tip_age <= max_tip_age as i64 * 60;
+            }
+            return true;
+        }
+        false
+    }
+
     /// Get synchronization status, empty when [`Server`] is not running.
     pub fn get_sync_status() -> Option<SyncStatus> {
         // Return Shutdown status when node is stopping.
@@ -193,6 +324,10 @@ impl Node {
         }
 
         let stats = Self::get_stats();
+        // Return import progress when chain state is bootstrapping from archive file.
+        if stats.is_some() && Self::is_bootstrapping() {
+            return Some(NODE_STATE.bootstrap_status.status());
+        }
         // Return sync status when server is running (stats are not empty).
         if stats.is_some() {
             return Some(stats.as_ref().unwrap().sync_status);
@@ -247,6 +382,11 @@ impl Node {
             match start_node_server() {
                 Ok(mut server) => {
                     let mut first_start = true;
+                    let mut data_size_time = 0;
+                    // Load sync statistics for current chain.
+                    *NODE_STATE.sync_stats.write() = SyncStats::load();
+                    let mut sync_stats_time = chrono::Utc::now().timestamp();
+                    let mut sync_stats_saved = sync_stats_time;
                     loop {
                         // Restart server if request or peers clean up is needed
                         if Self::is_restarting() {
@@ -280,6 +420,8 @@ impl Node {
                                 }
                             }
                         } else if Self::is_stopping() {
+                            // Save sync statistics.
+                            NODE_STATE.sync_stats.read().save();
                             // Stop the server.
                             server.stop();
                             // Clean stats and statuses.
@@ -301,6 +443,58 @@ impl Node {
                             }
                         }
 
+                        // Run chain compaction if requested.
+                        if Self::is_compacting() {
+                            let res = server.chain.compact();
+                            NODE_STATE.compaction_error.store(res.is_err(), Ordering::Relaxed);
+                            NODE_STATE.compact_needed.store(false, Ordering::Relaxed);
+                            data_size_time = 0;
+                        }
+
+                        // Bootstrap chain state from archive file if requested.
+                        let bootstrap_path = {
+                            let mut w_path = NODE_STATE.bootstrap_path.write();
+                            w_path.take()
+                        };
+                        if let Some(path) = bootstrap_path {
+                            // Stop the server to not run state sync from peers during import.
+                            let chain = server.chain.clone();
+                            server.stop();
+                            // Wait server after stop.
+                            thread::sleep(Duration::from_millis(5000));
+                            let status = NODE_STATE.bootstrap_status.clone();
+                            let res = bootstrap_from_file(&path, &chain, &status);
+                            drop(chain);
+                            if let Err(e) = res {
+                                let mut w_err = NODE_STATE.bootstrap_error.write();
+                                *w_err = Some(e);
+                            }
+                            // Start the server again.
+                            let start_res = start_node_server();
+                            NODE_STATE.bootstrapping.store(false, Ordering::Relaxed);
+                            match start_res {
+                                Ok(s) => server = s,
+                                Err(e) => {
+                                    // Setup an error.
+                                    {
+                                        let mut w_err = NODE_STATE.error.write();
+                                        *w_err = Some(e);
+                                    }
+                                    // Reset server state.
+                                    Self::reset_server_state(true);
+                                    break;
+                                }
+                            }
+                            data_size_time = 0;
+                        }
+
+                        // Update chain data size on disk.
+                        let now = chrono::Utc::now().timestamp();
+                        if now - data_size_time > Self::DATA_SIZE_UPDATE_DELAY {
+                            Self::update_data_size();
+                            data_size_time = now;
+                        }
+
                         // Update server stats.
                         if let Ok(stats) = server.get_server_stats() {
                             {
@@ -308,9 +502,23 @@ impl Node {
                                 *w_stats = Some(stats.clone());
                             }
 
+                            // Update sync statistics.
+                            {
+                                let secs = (now - sync_stats_time).max(0) as u64;
+                                let mut w_sync_stats = NODE_STATE.sync_stats.write();
+                                let finished = w_sync_stats.update(&stats, secs);
+                                if finished || now - sync_stats_saved > Self::SYNC_STATS_SAVE_DELAY {
+                                    w_sync_stats.save();
+                                    sync_stats_saved = now;
+                                }
+                                sync_stats_time = now;
+                            }
+
                             if first_start {
                                 NODE_STATE.starting.store(false, Ordering::Relaxed);
                                 first_start = false;
+                                // Start resources usage sampling.
+                                NodeMonitor::start();
                             }
                         }
 
@@ -341,6 +549,14 @@ impl Node {
         NODE_STATE.restart_needed.store(false, Ordering::Relaxed);
         NODE_STATE.start_stratum_needed.store(false, Ordering::Relaxed);
         NODE_STATE.stop_needed.store(false, Ordering::Relaxed);
+        NODE_STATE.compact_needed.store(false, Ordering::Relaxed);
+        // Cancel requested bootstrap from archive file.
+        {
+            let mut w_path = NODE_STATE.bootstrap_path.write();
+            if w_path.take().is_some() {
+                NODE_STATE.bootstrapping.store(false, Ordering::Relaxed);
+            }
+        }
 
         // Reset stratum stats.
         {
@@ -630,6 +846,51 @@ fn start_node_server() -> Result<Server, Error>  {
     server_result
 }
 
+/// Import chain state from txhashset archive file, validating its data.
+fn bootstrap_from_file(path: &String,
+                       chain: &Chain,
+                       status: &SyncState) -> Result<(), BootstrapError> {
+    // Check zip archive signature.
+    let mut file = File::open(path).map_err(|_| BootstrapError::InvalidFile)?;
+    let mut signature = [0u8; 4];
+    file.read_exact(&mut signature).map_err(|_| BootstrapError::InvalidFile)?;
+    if signature != [0x50, 0x4B, 0x03, 0x04] {
+        return Err(BootstrapError::InvalidFile);
+    }
+    file.seek(SeekFrom::Start(0)).map_err(|_| BootstrapError::InvalidFile)?;
+
+    // Get archive block hash from file name or from synced headers.
+    let name = PathBuf::from(path)
+        .file_stem()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let hash = name.split(|c| c == '_' || c == '-')
+        .find(|part| part.len() == 64)
+        .and_then(|part| Hash::from_hex(part).ok())
+        .or(chain.txhashset_archive_header().ok().map(|h| h.hash()))
+        .ok_or(BootstrapError::HeaderNotFound)?;
+    chain.get_block_header(&hash).map_err(|_| BootstrapError::HeaderNotFound)?;
+
+    // Write and validate chain state, progress is reported through sync status.
+    chain.txhashset_write(hash, file, status).map_err(|_| BootstrapError::Validation)?;
+    Ok(())
+}
+
+/// Calculate size of directory content recursively.
+fn dir_size(path: &PathBuf) -> u64 {
+    let mut size = 0;
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            match entry.metadata() {
+                Ok(m) if m.is_dir() => size += dir_size(&entry.path()),
+                Ok(m) => size += m.len(),
+                Err(_) => {}
+            }
+        }
+    }
+    size
+}
+
 /// Start stratum mining server on a separate thread.
 pub fn start_stratum_mining_server(server: &Server, config: StratumServerConfig) {
     let proof_size = global::proofsize();
@@ -640,6 +901,7 @@ pub fn start_stratum_mining_server(server: &Server, config: StratumServerConfig)
         server.chain.clone(),
         server.tx_pool.clone(),
         NODE_STATE.stratum_stats.clone(),
+        NODE_STATE.block_template.clone(),
     );
     let stop_state = NODE_STATE.stratum_stop_state.clone();
     stop_state.reset();
@@ -652,6 +914,7 @@ pub fn start_stratum_mining_server(server: &Server, config: StratumServerConfig)
                 let mut w_stratum_stats = NODE_STATE.stratum_stats.write();
                 *w_stratum_stats = StratumStats::default();
             }
+            *NODE_STATE.block_template.write() = None;
         });
 }
 
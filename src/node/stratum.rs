@@ -17,15 +17,18 @@
 use futures::channel::mpsc;
 use futures::pin_mut;
 use futures::{SinkExt, StreamExt, TryStreamExt};
+use tokio_old::io::{AsyncRead, AsyncWrite};
 use tokio_old::net::TcpListener;
 use tokio_old::runtime::Runtime;
+use tokio_tls_old::TlsAcceptor;
 use tokio_util_old::codec::{Framed, LinesCodec};
 
 use grin_util::RwLock;
 use chrono::prelude::Utc;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::fs;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
@@ -45,11 +48,18 @@ use grin_servers::ServerTxPool;
 
 use log::{debug, error};
 use serde_derive::{Deserialize, Serialize};
+use crate::AppConfig;
+use crate::node::BlockTemplate;
 use crate::node::mine_block::get_block;
 use crate::wallet::WalletConfig;
 
 type Tx = mpsc::UnboundedSender<String>;
 
+/// Target time in seconds between shares from single worker for variable difficulty.
+const VARDIFF_SHARE_TIME: f64 = 10.0;
+/// Time in seconds between worker difficulty adjustments.
+const VARDIFF_RETARGET_TIME: i64 = 60;
+
 // ----------------------------------------
 // http://www.jsonrpc.org/specification
 // RPC Methods
@@ -240,6 +250,10 @@ struct Handler {
     sync_state: Arc<SyncState>,
     chain: Arc<grin_chain::Chain>,
     current_state: Arc<RwLock<State>>,
+    /// Minimal and maximal share difficulty of workers when vardiff is enabled.
+    vardiff: Option<(u64, u64)>,
+    /// Information about block template served to workers.
+    block_template: Arc<RwLock<Option<BlockTemplate>>>,
 }
 
 impl Handler {
@@ -249,13 +263,17 @@ impl Handler {
         sync_state: Arc<SyncState>,
         minimum_share_difficulty: u64,
         chain: Arc<grin_chain::Chain>,
+        block_template: Arc<RwLock<Option<BlockTemplate>>>,
     ) -> Self {
+        let vardiff = AppConfig::stratum_vardiff();
         Handler {
             id: id,
-            workers: Arc::new(WorkersList::new(stratum_stats)),
+            workers: Arc::new(WorkersList::new(stratum_stats, vardiff)),
             sync_state: sync_state,
             chain: chain,
             current_state: Arc::new(RwLock::new(State::new(minimum_share_difficulty))),
+            vardiff,
+            block_template,
         }
     }
     pub fn from_stratum(stratum: &StratumServer) -> Self {
@@ -265,6 +283,7 @@ impl Handler {
             stratum.sync_state.clone(),
             stratum.config.minimum_share_difficulty,
             stratum.chain.clone(),
+            stratum.block_template.clone(),
         )
     }
     fn handle_rpc_requests(&self, request: RpcRequest, worker_id: usize) -> String {
@@ -279,6 +298,10 @@ impl Handler {
                 if let Ok((_, true)) = res {
                     self.current_state.write().current_key_id = None;
                 }
+                // adjust worker difficulty on accepted share
+                if res.is_ok() {
+                    self.retarget_worker(worker_id);
+                }
                 res.map(|(v, _)| v)
             }
             "keepalive" => self.handle_keepalive(),
@@ -286,7 +309,7 @@ impl Handler {
                 if self.sync_state.is_syncing() {
                     Err(RpcError::node_is_syncing())
                 } else {
-                    self.handle_getjobtemplate()
+                    self.handle_getjobtemplate(worker_id)
                 }
             }
             "status" => self.handle_status(worker_id),
@@ -348,9 +371,9 @@ impl Handler {
         return Ok(response);
     }
     // Handle GETJOBTEMPLATE message
-    fn handle_getjobtemplate(&self) -> Result<Value, RpcError> {
+    fn handle_getjobtemplate(&self, worker_id: usize) -> Result<Value, RpcError> {
         // Build a JobTemplate from a BlockHeader and return JSON
-        let job_template = self.build_block_template();
+        let job_template = self.build_block_template(self.share_difficulty(worker_id));
         let response = serde_json::to_value(&job_template).unwrap();
         println!(
 			"(Server ID: {}) sending block {} with id {} to single worker",
@@ -359,8 +382,18 @@ impl Handler {
         return Ok(response);
     }
 
+    // Get minimum acceptable share difficulty for the worker,
+    // state value is used when vardiff is disabled
+    fn share_difficulty(&self, worker_id: usize) -> Option<u64> {
+        if self.vardiff.is_none() {
+            return None;
+        }
+        self.workers.get_worker(worker_id).ok().map(|w| w.difficulty)
+    }
+
     // Build and return a JobTemplate for mining the current block
-    fn build_block_template(&self) -> JobTemplate {
+    // with provided or state minimum share difficulty
+    fn build_block_template(&self, difficulty: Option<u64>) -> JobTemplate {
         let bh = self
             .current_state
             .read()
@@ -381,7 +414,7 @@ impl Handler {
         let job_template = JobTemplate {
             height: bh.height,
             job_id: (current_state.current_block_versions.len() - 1) as u64,
-            difficulty: current_state.minimum_share_difficulty,
+            difficulty: difficulty.unwrap_or(current_state.minimum_share_difficulty),
             pre_pow,
         };
         return job_template;
@@ -399,7 +432,12 @@ impl Handler {
         // Validate parameters
         let params: SubmitParams = parse_params(params)?;
 
+        // Accept shares for previous worker difficulty from jobs sent before adjustment
+        let share_difficulty = self.share_difficulty(worker_id).and_then(|_| {
+            self.workers.get_worker(worker_id).ok().map(|w| w.difficulty.min(w.prev_difficulty))
+        });
         let state = self.current_state.read();
+        let minimum_share_difficulty = share_difficulty.unwrap_or(state.minimum_share_difficulty);
         // Find the correct version of the block to match this header
         let b: Option<&Block> = state.current_block_versions.get(params.job_id as usize);
         if params.height != state.current_block_versions.last().unwrap().header.height
@@ -441,11 +479,11 @@ impl Handler {
         // Note:  state.minimum_share_difficulty is unscaled
         //        state.current_difficulty is scaled
         // If the difficulty is too low its an error
-        if unscaled_share_difficulty < state.minimum_share_difficulty {
+        if unscaled_share_difficulty < minimum_share_difficulty {
             // Return error status
             println!(
 					"(Server ID: {}) Share at height {}, hash {}, edge_bits {}, nonce {}, job_id {} rejected due to low difficulty: {}/{}",
-					self.id, params.height, b.hash(), params.edge_bits, params.nonce, params.job_id, unscaled_share_difficulty, state.minimum_share_difficulty,
+					self.id, params.height, b.hash(), params.edge_bits, params.nonce, params.job_id, unscaled_share_difficulty, minimum_share_difficulty,
 				);
             self.workers
                 .update_stats(worker_id, |worker_stats| worker_stats.num_rejected += 1);
@@ -539,11 +577,23 @@ impl Handler {
         ));
     } // handle submit a solution
 
-    fn broadcast_job(&self) {
-        debug!("broadcast job");
-        // Package new block into RpcRequest
-        let job_template = self.build_block_template();
-        let job_template_json = serde_json::to_string(&job_template).unwrap();
+    // Adjust worker share difficulty based on shares rate and send new job on change
+    fn retarget_worker(&self, worker_id: usize) {
+        if let Some(bounds) = self.vardiff {
+            if let Some(difficulty) = self.workers.retarget(worker_id, true, bounds) {
+                debug!(
+					"(Server ID: {}) worker {} share difficulty changed to {}",
+					self.id, worker_id, difficulty,
+				);
+                let job_template = self.build_block_template(Some(difficulty));
+                self.workers.send_to(worker_id, Self::job_request(&job_template));
+            }
+        }
+    }
+
+    // Package job template into RpcRequest
+    fn job_request(job_template: &JobTemplate) -> String {
+        let job_template_json = serde_json::to_string(job_template).unwrap();
         // Issue #1159 - use a serde_json Value type to avoid extra quoting
         let job_template_value: Value = serde_json::from_str(&job_template_json).unwrap();
         let job_request = RpcRequest {
@@ -552,7 +602,24 @@ impl Handler {
             method: String::from("job"),
             params: Some(job_template_value),
         };
-        let job_request_json = serde_json::to_string(&job_request).unwrap();
+        serde_json::to_string(&job_request).unwrap()
+    }
+
+    fn broadcast_job(&self) {
+        debug!("broadcast job");
+        if self.vardiff.is_some() {
+            // Send job with own share difficulty to each worker
+            for worker_id in self.workers.ids() {
+                // lower difficulty for workers without shares
+                self.workers.retarget(worker_id, false, self.vardiff.unwrap());
+                let job_template = self.build_block_template(self.share_difficulty(worker_id));
+                self.workers.send_to(worker_id, Self::job_request(&job_template));
+            }
+            return;
+        }
+        // Package new block into RpcRequest
+        let job_template = self.build_block_template(None);
+        let job_request_json = Self::job_request(&job_template);
         debug!(
 			"(Server ID: {}) sending block {} with id {} to stratum clients",
 			self.id, job_template.height, job_template.job_id,
@@ -635,6 +702,10 @@ impl Handler {
                         self.workers.update_network_difficulty(difficulty.to_num());
                         self.workers.update_network_hashrate();
 
+                        // Save information about new block template.
+                        let job_id = state.current_block_versions.len() as u64;
+                        *self.block_template.write() = Some(BlockTemplate::new(&new_block, job_id));
+
                         // Add this new block candidate onto our list of block versions for height
                         state.current_block_versions.push(new_block);
                     } else {
@@ -658,6 +729,22 @@ fn accept_connections(listen_addr: SocketAddr,
                       handler: Arc<Handler>,
                       stop_state: Arc<StratumStopState>) {
     debug!("Start tokio stratum server");
+    // Setup addresses allowed to connect.
+    let allowlist: Arc<Vec<IpAddr>> = Arc::new(AppConfig::stratum_allowlist()
+        .iter()
+        .filter_map(|a| a.trim().parse::<IpAddr>().ok())
+        .collect());
+    // Setup TLS acceptor from configured certificate.
+    let tls_acceptor = match AppConfig::stratum_tls() {
+        Some((cert, key)) => match tls_acceptor(&cert, &key) {
+            Some(acceptor) => Some(acceptor),
+            None => {
+                error!("Stratum: Failed to load TLS certificate {} with key {}", cert, key);
+                return;
+            }
+        },
+        None => None
+    };
     let task = async move {
         let mut listener = TcpListener::bind(&listen_addr).await.unwrap_or_else(|_| {
             panic!("Stratum: Failed to bind to listen address {}", listen_addr)
@@ -667,50 +754,29 @@ fn accept_connections(listen_addr: SocketAddr,
             .filter_map(|s| async { s.map_err(|e| error!("accept error = {:?}", e)).ok() })
             .for_each(move |socket| {
                 let handler = handler.clone();
+                let allowlist = allowlist.clone();
+                let tls_acceptor = tls_acceptor.clone();
                 async move {
+                    // Check if worker address is allowed to connect.
+                    let peer_ip = socket.peer_addr().map(|a| a.ip()).ok();
+                    if !is_peer_allowed(peer_ip, &allowlist) {
+                        debug!("Worker connection from {:?} is not allowed", peer_ip);
+                        return;
+                    }
                     // Spawn a task to process the connection
-                    let (tx, mut rx) = mpsc::unbounded();
-
-                    let worker_id = handler.workers.add_worker(tx);
-                    debug!("Worker {} connected", worker_id);
-
-                    let framed = Framed::new(socket, LinesCodec::new());
-                    let (mut writer, mut reader) = framed.split();
-
-                    let h = handler.clone();
-                    let read = async move {
-                        while let Some(line) = reader
-                            .try_next()
-                            .await
-                            .map_err(|e| debug!("error reading line: {}", e))?
-                        {
-                            let request = serde_json::from_str(&line)
-                                .map_err(|e| debug!("error serializing line: {}", e))?;
-                            let resp = h.handle_rpc_requests(request, worker_id);
-                            h.workers.send_to(worker_id, resp);
+                    match tls_acceptor {
+                        Some(acceptor) => {
+                            tokio_old::spawn(async move {
+                                match acceptor.accept(socket).await {
+                                    Ok(stream) => serve_worker(stream, handler).await,
+                                    Err(e) => debug!("TLS handshake error: {}", e)
+                                }
+                            });
                         }
-
-                        Result::<_, ()>::Ok(())
-                    };
-
-                    let write = async move {
-                        while let Some(line) = rx.next().await {
-                            writer
-                                .send(line)
-                                .await
-                                .map_err(|e| debug!("error writing line: {}", e))?;
+                        None => {
+                            tokio_old::spawn(serve_worker(socket, handler));
                         }
-
-                        Result::<_, ()>::Ok(())
-                    };
-
-                    let task = async move {
-                        pin_mut!(read, write);
-                        futures::future::select(read, write).await;
-                        handler.workers.remove_worker(worker_id);
-                        debug!("Worker {} disconnected", worker_id);
-                    };
-                    tokio_old::spawn(task);
+                    }
                 }
             });
         server.await
@@ -722,6 +788,71 @@ fn accept_connections(listen_addr: SocketAddr,
     rt.block_on(task).unwrap_or_default();
 }
 
+/// Check if worker with provided IP address is allowed to connect,
+/// local connections and any address are allowed when allowlist is empty.
+fn is_peer_allowed(ip: Option<IpAddr>, allowlist: &[IpAddr]) -> bool {
+    if allowlist.is_empty() {
+        return true;
+    }
+    match ip {
+        Some(ip) => ip.is_loopback() || allowlist.contains(&ip),
+        None => false
+    }
+}
+
+/// Create TLS acceptor from PEM certificate and private key files.
+fn tls_acceptor(cert_path: &str, key_path: &str) -> Option<TlsAcceptor> {
+    let cert = fs::read(cert_path).ok()?;
+    let key = fs::read(key_path).ok()?;
+    let identity = native_tls::Identity::from_pkcs8(&cert, &key).ok()?;
+    let acceptor = native_tls::TlsAcceptor::new(identity).ok()?;
+    Some(TlsAcceptor::from(acceptor))
+}
+
+/// Process messages from connected worker until disconnection.
+async fn serve_worker<S>(socket: S, handler: Arc<Handler>)
+    where S: AsyncRead + AsyncWrite + Unpin + Send + 'static {
+    let (tx, mut rx) = mpsc::unbounded();
+
+    let worker_id = handler.workers.add_worker(tx);
+    debug!("Worker {} connected", worker_id);
+
+    let framed = Framed::new(socket, LinesCodec::new());
+    let (mut writer, mut reader) = framed.split();
+
+    let h = handler.clone();
+    let read = async move {
+        while let Some(line) = reader
+            .try_next()
+            .await
+            .map_err(|e| debug!("error reading line: {}", e))?
+        {
+            let request = serde_json::from_str(&line)
+                .map_err(|e| debug!("error serializing line: {}", e))?;
+            let resp = h.handle_rpc_requests(request, worker_id);
+            h.workers.send_to(worker_id, resp);
+        }
+
+        Result::<_, ()>::Ok(())
+    };
+
+    let write = async move {
+        while let Some(line) = rx.next().await {
+            writer
+                .send(line)
+                .await
+                .map_err(|e| debug!("error writing line: {}", e))?;
+        }
+
+        Result::<_, ()>::Ok(())
+    };
+
+    pin_mut!(read, write);
+    futures::future::select(read, write).await;
+    handler.workers.remove_worker(worker_id);
+    debug!("Worker {} disconnected", worker_id);
+}
+
 async fn check_stop_state(stop_state: Arc<StratumStopState>, handle: AbortHandle) {
     loop {
         // Ping stratum socket on stop to handle TcpListener unbind.
@@ -744,17 +875,29 @@ pub struct Worker {
     login: Option<String>,
     authenticated: bool,
     tx: Tx,
+    /// Current unscaled share difficulty.
+    difficulty: u64,
+    /// Unscaled share difficulty before last adjustment.
+    prev_difficulty: u64,
+    /// Amount of accepted shares since last difficulty adjustment.
+    shares_count: u64,
+    /// Time of last difficulty adjustment.
+    retarget_time: i64,
 }
 
 impl Worker {
     /// Creates a new Stratum Worker.
-    pub fn new(id: usize, tx: Tx) -> Worker {
+    pub fn new(id: usize, tx: Tx, difficulty: u64) -> Worker {
         Worker {
             id: id,
             agent: String::from(""),
             login: None,
             authenticated: false,
             tx: tx,
+            difficulty,
+            prev_difficulty: difficulty,
+            shares_count: 0,
+            retarget_time: Utc::now().timestamp(),
         }
     }
 } // impl Worker
@@ -762,27 +905,33 @@ impl Worker {
 struct WorkersList {
     workers_list: Arc<RwLock<HashMap<usize, Worker>>>,
     stratum_stats: Arc<RwLock<StratumStats>>,
+    vardiff: Option<(u64, u64)>,
 }
 
 impl WorkersList {
-    pub fn new(stratum_stats: Arc<RwLock<StratumStats>>) -> Self {
+    pub fn new(stratum_stats: Arc<RwLock<StratumStats>>,
+               vardiff: Option<(u64, u64)>) -> Self {
         WorkersList {
             workers_list: Arc::new(RwLock::new(HashMap::new())),
             stratum_stats: stratum_stats,
+            vardiff,
         }
     }
 
     pub fn add_worker(&self, tx: Tx) -> usize {
         let mut stratum_stats = self.stratum_stats.write();
         let worker_id = stratum_stats.worker_stats.len();
-        let worker = Worker::new(worker_id, tx);
+        let difficulty = self.vardiff
+            .map(|(min, _)| min)
+            .unwrap_or(stratum_stats.minimum_share_difficulty);
+        let worker = Worker::new(worker_id, tx, difficulty);
         let mut workers_list = self.workers_list.write();
         workers_list.insert(worker_id, worker);
 
         let mut worker_stats = WorkerStats::default();
         worker_stats.is_connected = true;
         worker_stats.id = worker_id.to_string();
-        worker_stats.pow_difficulty = stratum_stats.minimum_share_difficulty;
+        worker_stats.pow_difficulty = difficulty;
         stratum_stats.worker_stats.push(worker_stats);
         stratum_stats.num_workers = workers_list.len();
         worker_id
@@ -841,13 +990,9 @@ impl WorkersList {
     }
 
     pub fn send_to(&self, worker_id: usize, msg: String) {
-        let _ = self
-            .workers_list
-            .read()
-            .get(&worker_id)
-            .unwrap()
-            .tx
-            .unbounded_send(msg);
+        if let Some(worker) = self.workers_list.read().get(&worker_id) {
+            let _ = worker.tx.unbounded_send(msg);
+        }
     }
 
     pub fn broadcast(&self, msg: String) {
@@ -856,6 +1001,51 @@ impl WorkersList {
         }
     }
 
+    pub fn ids(&self) -> Vec<usize> {
+        self.workers_list.read().keys().cloned().collect()
+    }
+
+    /// Adjust worker share difficulty within provided bounds to get shares at target rate,
+    /// returns new difficulty if it was changed.
+    pub fn retarget(&self, worker_id: usize, share: bool, bounds: (u64, u64)) -> Option<u64> {
+        let now = Utc::now().timestamp();
+        let difficulty = {
+            let mut wl = self.workers_list.write();
+            let worker = wl.get_mut(&worker_id)?;
+            if share {
+                worker.shares_count += 1;
+            }
+            let elapsed = now - worker.retarget_time;
+            // Wait for more time to lower difficulty when no shares were found.
+            let retarget_time = if worker.shares_count == 0 {
+                VARDIFF_RETARGET_TIME * 2
+            } else {
+                VARDIFF_RETARGET_TIME
+            };
+            if elapsed < retarget_time {
+                return None;
+            }
+            let factor = if worker.shares_count == 0 {
+                0.5
+            } else {
+                let share_time = elapsed as f64 / worker.shares_count as f64;
+                (VARDIFF_SHARE_TIME / share_time).clamp(0.5, 2.0)
+            };
+            let (min, max) = bounds;
+            let difficulty = ((worker.difficulty as f64 * factor) as u64).clamp(min, max.max(min));
+            worker.shares_count = 0;
+            worker.retarget_time = now;
+            worker.prev_difficulty = worker.difficulty;
+            if difficulty == worker.difficulty {
+                return None;
+            }
+            worker.difficulty = difficulty;
+            difficulty
+        };
+        self.update_stats(worker_id, |ws| ws.pow_difficulty = difficulty);
+        Some(difficulty)
+    }
+
     pub fn count(&self) -> usize {
         self.workers_list.read().len()
     }
@@ -897,6 +1087,7 @@ pub struct StratumServer {
     pub tx_pool: ServerTxPool,
     sync_state: Arc<SyncState>,
     stratum_stats: Arc<RwLock<StratumStats>>,
+    block_template: Arc<RwLock<Option<BlockTemplate>>>,
 }
 
 impl StratumServer {
@@ -906,6 +1097,7 @@ impl StratumServer {
         chain: Arc<grin_chain::Chain>,
         tx_pool: ServerTxPool,
         stratum_stats: Arc<RwLock<StratumStats>>,
+        block_template: Arc<RwLock<Option<BlockTemplate>>>,
     ) -> StratumServer {
         StratumServer {
             id: String::from("0"),
@@ -914,6 +1106,7 @@ impl StratumServer {
             tx_pool,
             sync_state: Arc::new(SyncState::new()),
             stratum_stats: stratum_stats,
+            block_template,
         }
     }
 
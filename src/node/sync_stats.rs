@@ -0,0 +1,146 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::PathBuf;
+use grin_chain::SyncStatus;
+use grin_servers::ServerStats;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{AppConfig, Settings};
+
+/// Node traffic totals for a day.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DailyTraffic {
+    /// Day number since Unix epoch.
+    pub day: i64,
+    /// Downloaded bytes.
+    pub received: u64,
+    /// Uploaded bytes.
+    pub sent: u64,
+}
+
+/// Statistics of completed initial chain sync.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct InitialSyncStats {
+    /// Sync duration in seconds.
+    pub duration: u64,
+    /// Downloaded bytes.
+    pub received: u64,
+}
+
+impl InitialSyncStats {
+    /// Get average download speed in bytes per second.
+    pub fn speed(&self) -> u64 {
+        self.received / self.duration.max(1)
+    }
+}
+
+/// Node sync time and traffic statistics saved between launches for each chain type.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct SyncStats {
+    /// Statistics of completed initial sync.
+    pub initial: Option<InitialSyncStats>,
+    /// Duration in seconds and downloaded bytes of running initial sync.
+    initial_progress: (u64, u64),
+    /// Traffic totals by days, latest day is last.
+    pub daily: Vec<DailyTraffic>,
+}
+
+impl SyncStats {
+    /// Statistics file name.
+    const FILE_NAME: &'static str = "sync_stats.json";
+
+    /// Maximal amount of days to keep traffic totals.
+    const MAX_DAYS: usize = 30;
+
+    /// Seconds in a day.
+    const DAY_SECS: i64 = 86400;
+
+    /// Load statistics for current chain type.
+    pub fn load() -> Self {
+        fs::read(Self::path())
+            .ok()
+            .and_then(|data| serde_json::from_slice::<SyncStats>(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save statistics for current chain type.
+    pub fn save(&self) {
+        if let Ok(data) = serde_json::to_vec(self) {
+            let _ = fs::write(Self::path(), data);
+        }
+    }
+
+    /// Get statistics file path for current chain type.
+    fn path() -> PathBuf {
+        let chain_type = AppConfig::chain_type();
+        Settings::config_path(Self::FILE_NAME, Some(chain_type.shortname()))
+    }
+
+    /// Update statistics from server stats for provided period in seconds,
+    /// returns `true` when initial sync was finished.
+    pub fn update(&mut self, stats: &ServerStats, secs: u64) -> bool {
+        // Peers speed is an average for last minute, so traffic is approximate.
+        let received = stats.peer_stats.iter().map(|p| p.received_bytes_per_sec).sum::<u64>() * secs;
+        let sent = stats.peer_stats.iter().map(|p| p.sent_bytes_per_sec).sum::<u64>() * secs;
+
+        // Update daily totals.
+        let day = chrono::Utc::now().timestamp() / Self::DAY_SECS;
+        if self.daily.last().map(|d| d.day != day).unwrap_or(true) {
+            self.daily.push(DailyTraffic { day, received: 0, sent: 0 });
+            if self.daily.len() > Self::MAX_DAYS {
+                self.daily.remove(0);
+            }
+        }
+        if let Some(today) = self.daily.last_mut() {
+            today.received += received;
+            today.sent += sent;
+        }
+
+        // Update initial sync progress.
+        if self.initial.is_some() {
+            return false;
+        }
+        if stats.sync_status == SyncStatus::NoSync {
+            let (duration, received) = self.initial_progress;
+            if duration > 0 {
+                self.initial = Some(InitialSyncStats { duration, received });
+                self.initial_progress = (0, 0);
+                return true;
+            }
+        } else if stats.sync_status != SyncStatus::Shutdown {
+            self.initial_progress.0 += secs;
+            self.initial_progress.1 += received;
+        }
+        false
+    }
+
+    /// Get traffic totals for today.
+    pub fn today(&self) -> Option<&DailyTraffic> {
+        let day = chrono::Utc::now().timestamp() / Self::DAY_SECS;
+        self.daily.last().filter(|d| d.day == day)
+    }
+
+    /// Get average daily downloaded and uploaded bytes for saved days.
+    pub fn daily_average(&self) -> (u64, u64) {
+        if self.daily.is_empty() {
+            return (0, 0);
+        }
+        let days = self.daily.len() as u64;
+        let received = self.daily.iter().map(|d| d.received).sum::<u64>();
+        let sent = self.daily.iter().map(|d| d.sent).sum::<u64>();
+        (received / days, sent / days)
+    }
+}
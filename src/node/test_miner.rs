@@ -0,0 +1,275 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use grin_core::global;
+use grin_core::global::ChainTypes;
+use grin_core::pow::{Proof, ProofOfWork};
+use grin_util::from_hex;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use rand::{thread_rng, Rng};
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+
+use crate::AppConfig;
+use crate::node::NodeConfig;
+
+lazy_static! {
+    /// Static thread-aware state of [`TestMiner`] to be updated from separate thread.
+    static ref TEST_MINER_STATE: Arc<TestMiner> = Arc::new(TestMiner::default());
+}
+
+/// Job received from stratum server.
+#[derive(Deserialize, Debug, Clone)]
+struct MinerJob {
+    height: u64,
+    job_id: u64,
+    difficulty: u64,
+    pre_pow: String,
+}
+
+/// Built-in cuckatoo CPU miner for testnet connected to integrated stratum server
+/// over loopback interface.
+pub struct TestMiner {
+    /// Flag to check if miner should be running.
+    running: AtomicBool,
+    /// Identifier of current launch to finish previous one.
+    launch_id: AtomicUsize,
+    /// Current job to mine.
+    job: RwLock<Option<MinerJob>>,
+    /// Amount of searched graphs.
+    graphs: AtomicU64,
+    /// Amount of solutions submitted to stratum server.
+    solutions: AtomicU64,
+    /// Amount of blocks found.
+    blocks: AtomicU64,
+}
+
+impl Default for TestMiner {
+    fn default() -> Self {
+        Self {
+            running: AtomicBool::new(false),
+            launch_id: AtomicUsize::new(0),
+            job: RwLock::new(None),
+            graphs: AtomicU64::new(0),
+            solutions: AtomicU64::new(0),
+            blocks: AtomicU64::new(0),
+        }
+    }
+}
+
+impl TestMiner {
+    /// Login to connect to integrated stratum server.
+    const LOGIN: &'static str = "grim-test";
+
+    /// Delay before reconnection to stratum server.
+    const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+    /// Maximal amount of solutions to find at single graph.
+    const MAX_SOLS: u32 = 10;
+
+    /// Check if miner can be launched for current chain type.
+    pub fn is_available() -> bool {
+        AppConfig::chain_type() == ChainTypes::Testnet
+    }
+
+    /// Check if miner is running.
+    pub fn is_running() -> bool {
+        TEST_MINER_STATE.running.load(Ordering::Relaxed)
+    }
+
+    /// Get amount of searched graphs.
+    pub fn graphs() -> u64 {
+        TEST_MINER_STATE.graphs.load(Ordering::Relaxed)
+    }
+
+    /// Get amount of submitted solutions.
+    pub fn solutions() -> u64 {
+        TEST_MINER_STATE.solutions.load(Ordering::Relaxed)
+    }
+
+    /// Get amount of found blocks.
+    pub fn blocks() -> u64 {
+        TEST_MINER_STATE.blocks.load(Ordering::Relaxed)
+    }
+
+    /// Launch miner in separate thread.
+    pub fn start() {
+        if !Self::is_available() || Self::is_running() {
+            return;
+        }
+        TEST_MINER_STATE.running.store(true, Ordering::Relaxed);
+        TEST_MINER_STATE.graphs.store(0, Ordering::Relaxed);
+        TEST_MINER_STATE.solutions.store(0, Ordering::Relaxed);
+        TEST_MINER_STATE.blocks.store(0, Ordering::Relaxed);
+        let launch_id = TEST_MINER_STATE.launch_id.fetch_add(1, Ordering::Relaxed) + 1;
+        thread::spawn(move || {
+            global::set_local_chain_type(AppConfig::chain_type());
+            let is_current = || {
+                Self::is_running() && TEST_MINER_STATE.launch_id.load(Ordering::Relaxed) == launch_id
+            };
+            while is_current() {
+                if let Some(stream) = Self::connect() {
+                    Self::mine(stream, &is_current);
+                }
+                // Reconnect after delay when miner was not stopped.
+                if is_current() {
+                    thread::sleep(Self::RECONNECT_DELAY);
+                }
+            }
+        });
+    }
+
+    /// Stop running miner.
+    pub fn stop() {
+        TEST_MINER_STATE.running.store(false, Ordering::Relaxed);
+        let mut w_job = TEST_MINER_STATE.job.write();
+        *w_job = None;
+    }
+
+    /// Connect to integrated stratum server, login and request a job.
+    fn connect() -> Option<TcpStream> {
+        let (mut host, port) = NodeConfig::get_stratum_address();
+        if host == "0.0.0.0" {
+            host = "127.0.0.1".to_string();
+        }
+        let mut stream = TcpStream::connect(format!("{}:{}", host, port)).ok()?;
+        let login = json!({
+            "login": Self::LOGIN,
+            "pass": "",
+            "agent": "grim"
+        });
+        Self::send(&mut stream, "login", login).ok()?;
+        Self::send(&mut stream, "getjobtemplate", Value::Null).ok()?;
+        Some(stream)
+    }
+
+    /// Send request to stratum server.
+    fn send(stream: &mut TcpStream, method: &str, params: Value) -> std::io::Result<()> {
+        let request = json!({
+            "id": "0",
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params
+        });
+        stream.write_all(format!("{}\n", request).as_bytes())
+    }
+
+    /// Search solutions for received jobs until disconnection or stop.
+    fn mine(mut stream: TcpStream, is_current: &dyn Fn() -> bool) {
+        let connected = Arc::new(AtomicBool::new(true));
+        let reader = match stream.try_clone() {
+            Ok(s) => s,
+            Err(_) => return
+        };
+        Self::read_messages(reader, connected.clone());
+
+        let mut rng = thread_rng();
+        while is_current() && connected.load(Ordering::Relaxed) {
+            let job = TEST_MINER_STATE.job.read().clone();
+            let job = match job {
+                Some(j) => j,
+                None => {
+                    thread::sleep(Duration::from_millis(100));
+                    continue;
+                }
+            };
+            let nonce: u64 = rng.gen();
+            let proof = Self::find_solution(&job, nonce);
+            TEST_MINER_STATE.graphs.fetch_add(1, Ordering::Relaxed);
+            if let Some(proof) = proof {
+                let params = json!({
+                    "height": job.height,
+                    "job_id": job.job_id,
+                    "nonce": nonce,
+                    "edge_bits": proof.edge_bits,
+                    "pow": proof.nonces
+                });
+                if Self::send(&mut stream, "submit", params).is_err() {
+                    break;
+                }
+                TEST_MINER_STATE.solutions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let _ = stream.shutdown(std::net::Shutdown::Both);
+        let mut w_job = TEST_MINER_STATE.job.write();
+        *w_job = None;
+    }
+
+    /// Read stratum server messages in separate thread to update current job.
+    fn read_messages(stream: TcpStream, connected: Arc<AtomicBool>) {
+        thread::spawn(move || {
+            let reader = BufReader::new(stream);
+            for line in reader.lines() {
+                let line = match line {
+                    Ok(l) => l,
+                    Err(_) => break
+                };
+                let message: Value = match serde_json::from_str(&line) {
+                    Ok(m) => m,
+                    Err(_) => continue
+                };
+                let job = match message["method"].as_str() {
+                    Some("job") => message.get("params").cloned(),
+                    Some("getjobtemplate") => message.get("result").cloned(),
+                    Some("submit") => {
+                        let block_found = message["result"]
+                            .as_str()
+                            .map(|r| r.starts_with("blockfound"))
+                            .unwrap_or(false);
+                        if block_found {
+                            TEST_MINER_STATE.blocks.fetch_add(1, Ordering::Relaxed);
+                        }
+                        None
+                    }
+                    _ => None
+                };
+                if let Some(job) = job.and_then(|j| serde_json::from_value::<MinerJob>(j).ok()) {
+                    let mut w_job = TEST_MINER_STATE.job.write();
+                    *w_job = Some(job);
+                }
+            }
+            connected.store(false, Ordering::Relaxed);
+        });
+    }
+
+    /// Search graph for provided job and nonce, returning solution which
+    /// satisfies job difficulty.
+    fn find_solution(job: &MinerJob, nonce: u64) -> Option<Proof> {
+        let mut header = from_hex(&job.pre_pow).ok()?;
+        header.extend_from_slice(&nonce.to_be_bytes());
+        let mut ctx = global::create_pow_context::<u64>(
+            job.height,
+            global::min_edge_bits(),
+            global::proofsize(),
+            Self::MAX_SOLS
+        ).ok()?;
+        ctx.set_header_nonce(header, None, true).ok()?;
+        let proofs = ctx.find_cycles().ok()?;
+        proofs.into_iter().find(|proof| {
+            let pow = ProofOfWork {
+                nonce,
+                proof: proof.clone(),
+                ..Default::default()
+            };
+            pow.to_unscaled_difficulty().to_num() >= job.difficulty
+        })
+    }
+}
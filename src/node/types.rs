@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use grin_core::consensus::reward;
+use grin_core::core::Block;
+
 /// Integrated node error type.
 #[derive(Clone)]
 pub enum NodeError {
@@ -26,3 +29,52 @@ pub enum NodeError {
     /// Unknown error.
     Unknown
 }
+
+/// Error on integrated node bootstrap from txhashset archive file.
+#[derive(Clone, PartialEq)]
+pub enum BootstrapError {
+    /// File can not be read or is not a zip archive.
+    InvalidFile,
+    /// Archive block header was not found at synced headers.
+    HeaderNotFound,
+    /// Archive data validation failed.
+    Validation
+}
+
+/// Block template served by stratum server to miners.
+#[derive(Clone)]
+pub struct BlockTemplate {
+    /// Block height.
+    pub height: u64,
+    /// Mining job identifier.
+    pub job_id: u64,
+    /// Amount of kernels including coinbase.
+    pub kernels: usize,
+    /// Amount of inputs.
+    pub inputs: usize,
+    /// Amount of outputs including coinbase.
+    pub outputs: usize,
+    /// Total transaction fees.
+    pub fees: u64,
+    /// Block reward including fees.
+    pub reward: u64,
+    /// Time of template creation in seconds.
+    pub time: i64,
+}
+
+impl BlockTemplate {
+    /// Create template information from provided block candidate and job identifier.
+    pub fn new(block: &Block, job_id: u64) -> Self {
+        let fees = block.body.fee();
+        Self {
+            height: block.header.height,
+            job_id,
+            kernels: block.body.kernels.len(),
+            inputs: block.inputs().len(),
+            outputs: block.body.outputs.len(),
+            fees,
+            reward: reward(fees),
+            time: chrono::Utc::now().timestamp(),
+        }
+    }
+}
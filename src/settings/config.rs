@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::Timelike;
 use grin_core::global::ChainTypes;
 use serde_derive::{Deserialize, Serialize};
 use crate::gui::views::Content;
 
-use crate::node::NodeConfig;
+use crate::node::{ExternalMiner, NodeConfig};
 use crate::Settings;
-use crate::wallet::ConnectionsConfig;
+use crate::wallet::{ConnectionsConfig, FiatCurrency};
 
 /// Application configuration, stored at toml file.
 #[derive(Serialize, Deserialize)]
@@ -49,6 +50,49 @@ pub struct AppConfig {
 
     /// Flag to check if dark theme should be used, use system settings if not set.
     use_dark_theme: Option<bool>,
+    /// Hours range to use dark theme automatically, no schedule if not set.
+    dark_theme_schedule: Option<(u32, u32)>,
+    /// Flag to check if color-blind friendly palette should be used.
+    color_blind_palette: Option<bool>,
+    /// Minutes of inactivity to close opened wallets, no auto-lock if not set.
+    auto_lock_minutes: Option<u64>,
+    /// Amount of decimal places to show at wallet amounts, full precision if not set.
+    amount_precision: Option<usize>,
+    /// Minimal and maximal share difficulty bounds of stratum workers, no vardiff if not set.
+    stratum_vardiff: Option<(u64, u64)>,
+    /// IP addresses allowed to connect to stratum server, all addresses are allowed if not set.
+    stratum_allowlist: Option<Vec<String>>,
+    /// Paths to PEM certificate and private key files to accept stratum connections over TLS.
+    stratum_tls: Option<(String, String)>,
+    /// Path to external miner binary.
+    miner_path: Option<String>,
+    /// External miner arguments template.
+    miner_args: Option<String>,
+    /// External miner or mining pool API URL to show remote statistics.
+    miner_api_url: Option<String>,
+    /// Endpoint URL to submit crash reports.
+    crash_report_url: Option<String>,
+    /// Index of selected camera device on desktop.
+    camera_index: Option<usize>,
+    /// Flag to detect Slatepack messages and addresses copied to clipboard.
+    clipboard_watch: Option<bool>,
+    /// Flag to show donation shortcuts at wallets.
+    show_donations: Option<bool>,
+    /// Flag to prompt to open last opened wallet on launch.
+    open_last_wallet: Option<bool>,
+    /// Identifier of last opened wallet.
+    last_wallet_id: Option<i64>,
+    /// Fiat currency to enter amounts with conversion, price is not loaded if not set.
+    fiat_currency: Option<FiatCurrency>,
+    /// Receivers of donations with preset amounts.
+    donation_targets: Option<Vec<DonationTarget>>,
+
+    /// Minimal amount of peers at integrated node to trust its data at wallets.
+    wallet_sync_min_peers: Option<u32>,
+    /// Maximal age of the last block in minutes to trust integrated node data at wallets.
+    wallet_sync_max_tip_age: Option<u64>,
+    /// Range of ports to bind wallets Foreign API servers.
+    wallet_api_ports: Option<(u16, u16)>,
 }
 
 impl Default for AppConfig {
@@ -65,6 +109,27 @@ impl Default for AppConfig {
             y: None,
             lang: None,
             use_dark_theme: None,
+            dark_theme_schedule: None,
+            color_blind_palette: None,
+            auto_lock_minutes: None,
+            amount_precision: None,
+            stratum_vardiff: None,
+            stratum_allowlist: None,
+            stratum_tls: None,
+            miner_path: None,
+            miner_args: None,
+            miner_api_url: None,
+            crash_report_url: None,
+            camera_index: None,
+            clipboard_watch: None,
+            show_donations: None,
+            open_last_wallet: None,
+            last_wallet_id: None,
+            fiat_currency: None,
+            donation_targets: None,
+            wallet_sync_min_peers: None,
+            wallet_sync_max_tip_age: None,
+            wallet_api_ports: None,
         }
     }
 }
@@ -84,6 +149,11 @@ impl AppConfig {
     /// Application configuration file name.
     pub const FILE_NAME: &'static str = "app.toml";
 
+    /// Default minimal amount of integrated node peers to trust its data at wallets.
+    pub const DEFAULT_WALLET_SYNC_MIN_PEERS: u32 = 1;
+    /// Default maximal age of the last block in minutes to trust integrated node data at wallets.
+    pub const DEFAULT_WALLET_SYNC_MAX_TIP_AGE: u64 = 60;
+
     /// Default i18n locale.
     pub const DEFAULT_LOCALE: &'static str = "en";
 
@@ -196,10 +266,10 @@ impl AppConfig {
         None
     }
 
-    /// Save locale code.
-    pub fn save_locale(lang: &str) {
+    /// Save locale code, system locale will be used if not provided.
+    pub fn save_locale(lang: Option<&str>) {
         let mut w_app_config = Settings::app_config_to_update();
-        w_app_config.lang = Some(lang.to_string());
+        w_app_config.lang = lang.map(|l| l.to_string());
         w_app_config.save();
     }
 
@@ -237,4 +307,321 @@ impl AppConfig {
         w_config.use_dark_theme = Some(use_dark);
         w_config.save();
     }
+
+    /// Check if color-blind friendly palette should be used.
+    pub fn color_blind_palette() -> bool {
+        let r_config = Settings::app_config_to_read();
+        r_config.color_blind_palette.unwrap_or(false)
+    }
+
+    /// Toggle color-blind friendly palette usage.
+    pub fn toggle_color_blind_palette() {
+        let use_palette = Self::color_blind_palette();
+        let mut w_config = Settings::app_config_to_update();
+        w_config.color_blind_palette = Some(!use_palette);
+        w_config.save();
+    }
+
+    /// Get minutes of inactivity to close opened wallets.
+    pub fn auto_lock_minutes() -> Option<u64> {
+        let r_config = Settings::app_config_to_read();
+        r_config.auto_lock_minutes
+    }
+
+    /// Setup minutes of inactivity to close opened wallets, disable auto-lock if not provided.
+    pub fn set_auto_lock_minutes(minutes: Option<u64>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.auto_lock_minutes = minutes;
+        w_config.save();
+    }
+
+    /// Get amount of decimal places to show at wallet amounts.
+    pub fn amount_precision() -> Option<usize> {
+        let r_config = Settings::app_config_to_read();
+        r_config.amount_precision
+    }
+
+    /// Setup amount of decimal places to show at wallet amounts, full precision if not provided.
+    pub fn set_amount_precision(precision: Option<usize>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.amount_precision = precision;
+        w_config.save();
+    }
+
+    /// Get index of selected camera device.
+    pub fn camera_index() -> usize {
+        let r_config = Settings::app_config_to_read();
+        r_config.camera_index.unwrap_or(0)
+    }
+
+    /// Save index of selected camera device.
+    pub fn save_camera_index(index: usize) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.camera_index = Some(index);
+        w_config.save();
+    }
+
+    /// Check if Slatepack messages and addresses copied to clipboard should be detected.
+    pub fn clipboard_watch() -> bool {
+        let r_config = Settings::app_config_to_read();
+        r_config.clipboard_watch.unwrap_or(false)
+    }
+
+    /// Toggle detection of Slatepack messages and addresses copied to clipboard.
+    pub fn toggle_clipboard_watch() {
+        let watch = Self::clipboard_watch();
+        let mut w_config = Settings::app_config_to_update();
+        w_config.clipboard_watch = Some(!watch);
+        w_config.save();
+    }
+
+    /// Check if last opened wallet should be opened on launch.
+    pub fn open_last_wallet() -> bool {
+        let r_config = Settings::app_config_to_read();
+        r_config.open_last_wallet.unwrap_or(false)
+    }
+
+    /// Toggle opening of last opened wallet on launch.
+    pub fn toggle_open_last_wallet() {
+        let open = Self::open_last_wallet();
+        let mut w_config = Settings::app_config_to_update();
+        w_config.open_last_wallet = Some(!open);
+        w_config.save();
+    }
+
+    /// Get identifier of last opened wallet.
+    pub fn last_wallet_id() -> Option<i64> {
+        let r_config = Settings::app_config_to_read();
+        r_config.last_wallet_id
+    }
+
+    /// Save identifier of last opened wallet.
+    pub fn set_last_wallet_id(id: i64) {
+        if Self::last_wallet_id() == Some(id) {
+            return;
+        }
+        let mut w_config = Settings::app_config_to_update();
+        w_config.last_wallet_id = Some(id);
+        w_config.save();
+    }
+
+    /// Get fiat currency to enter amounts with conversion.
+    pub fn fiat_currency() -> Option<FiatCurrency> {
+        let r_config = Settings::app_config_to_read();
+        r_config.fiat_currency
+    }
+
+    /// Save fiat currency to enter amounts with conversion, disabled if not set.
+    pub fn set_fiat_currency(currency: Option<FiatCurrency>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.fiat_currency = currency;
+        w_config.save();
+    }
+
+    /// Check if donation shortcuts should be shown at wallets.
+    pub fn show_donations() -> bool {
+        let r_config = Settings::app_config_to_read();
+        r_config.show_donations.unwrap_or(false)
+    }
+
+    /// Toggle donation shortcuts at wallets.
+    pub fn toggle_show_donations() {
+        let show = Self::show_donations();
+        let mut w_config = Settings::app_config_to_update();
+        w_config.show_donations = Some(!show);
+        w_config.save();
+    }
+
+    /// Get receivers of donations.
+    pub fn donation_targets() -> Vec<DonationTarget> {
+        let r_config = Settings::app_config_to_read();
+        r_config.donation_targets.clone().unwrap_or_default()
+    }
+
+    /// Save new receiver of donations.
+    pub fn add_donation_target(target: DonationTarget) {
+        let mut w_config = Settings::app_config_to_update();
+        let mut targets = w_config.donation_targets.clone().unwrap_or_default();
+        targets.push(target);
+        w_config.donation_targets = Some(targets);
+        w_config.save();
+    }
+
+    /// Remove receiver of donations by index.
+    pub fn remove_donation_target(index: usize) {
+        let mut w_config = Settings::app_config_to_update();
+        if let Some(targets) = w_config.donation_targets.as_mut() {
+            if index < targets.len() {
+                targets.remove(index);
+            }
+        }
+        w_config.save();
+    }
+
+    /// Get minimal and maximal share difficulty bounds for stratum workers.
+    pub fn stratum_vardiff() -> Option<(u64, u64)> {
+        let r_config = Settings::app_config_to_read();
+        r_config.stratum_vardiff
+    }
+
+    /// Setup share difficulty bounds for stratum workers, disable vardiff if not provided.
+    pub fn set_stratum_vardiff(bounds: Option<(u64, u64)>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.stratum_vardiff = bounds;
+        w_config.save();
+    }
+
+    /// Get IP addresses allowed to connect to stratum server.
+    pub fn stratum_allowlist() -> Vec<String> {
+        let r_config = Settings::app_config_to_read();
+        r_config.stratum_allowlist.clone().unwrap_or_default()
+    }
+
+    /// Save IP addresses allowed to connect to stratum server, allow all if list is empty.
+    pub fn set_stratum_allowlist(addrs: Vec<String>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.stratum_allowlist = if addrs.is_empty() {
+            None
+        } else {
+            Some(addrs)
+        };
+        w_config.save();
+    }
+
+    /// Get paths to certificate and private key files to accept stratum connections over TLS.
+    pub fn stratum_tls() -> Option<(String, String)> {
+        let r_config = Settings::app_config_to_read();
+        r_config.stratum_tls.clone()
+    }
+
+    /// Setup stratum TLS certificate and private key files, disable TLS if not provided.
+    pub fn set_stratum_tls(files: Option<(String, String)>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.stratum_tls = files;
+        w_config.save();
+    }
+
+    /// Get path to external miner binary.
+    pub fn miner_path() -> Option<String> {
+        let r_config = Settings::app_config_to_read();
+        r_config.miner_path.clone()
+    }
+
+    /// Get external miner arguments template.
+    pub fn miner_args() -> String {
+        let r_config = Settings::app_config_to_read();
+        r_config.miner_args.clone().unwrap_or(ExternalMiner::DEFAULT_ARGS.to_string())
+    }
+
+    /// Save external miner binary path and arguments template.
+    pub fn save_miner(path: Option<String>, args: String) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.miner_path = path;
+        w_config.miner_args = Some(args);
+        w_config.save();
+    }
+
+    /// Get external miner or mining pool API URL.
+    pub fn miner_api_url() -> Option<String> {
+        let r_config = Settings::app_config_to_read();
+        r_config.miner_api_url.clone()
+    }
+
+    /// Save external miner or mining pool API URL.
+    pub fn save_miner_api_url(url: Option<String>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.miner_api_url = url;
+        w_config.save();
+    }
+
+    /// Get endpoint URL to submit crash reports.
+    pub fn crash_report_url() -> Option<String> {
+        let r_config = Settings::app_config_to_read();
+        r_config.crash_report_url.clone()
+    }
+
+    /// Save endpoint URL to submit crash reports.
+    pub fn set_crash_report_url(url: Option<String>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.crash_report_url = url;
+        w_config.save();
+    }
+
+    /// Get hours range to use dark theme automatically.
+    pub fn dark_theme_schedule() -> Option<(u32, u32)> {
+        let r_config = Settings::app_config_to_read();
+        r_config.dark_theme_schedule
+    }
+
+    /// Setup hours range to use dark theme automatically, disable schedule if not provided.
+    pub fn set_dark_theme_schedule(schedule: Option<(u32, u32)>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.dark_theme_schedule = schedule;
+        w_config.save();
+    }
+
+    /// Check if dark theme should be used for current time when schedule is enabled.
+    pub fn scheduled_dark_theme() -> Option<bool> {
+        if let Some((from, to)) = Self::dark_theme_schedule() {
+            let hour = chrono::Local::now().hour();
+            let use_dark = if from <= to {
+                hour >= from && hour < to
+            } else {
+                hour >= from || hour < to
+            };
+            return Some(use_dark);
+        }
+        None
+    }
+
+    /// Get minimal amount of integrated node peers to trust its data at wallets.
+    pub fn wallet_sync_min_peers() -> u32 {
+        let r_config = Settings::app_config_to_read();
+        r_config.wallet_sync_min_peers.unwrap_or(Self::DEFAULT_WALLET_SYNC_MIN_PEERS)
+    }
+
+    /// Save minimal amount of integrated node peers to trust its data at wallets.
+    pub fn save_wallet_sync_min_peers(peers: u32) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.wallet_sync_min_peers = Some(peers);
+        w_config.save();
+    }
+
+    /// Get maximal age of the last block in minutes to trust integrated node data at wallets,
+    /// `0` to not check the age.
+    pub fn wallet_sync_max_tip_age() -> u64 {
+        let r_config = Settings::app_config_to_read();
+        r_config.wallet_sync_max_tip_age.unwrap_or(Self::DEFAULT_WALLET_SYNC_MAX_TIP_AGE)
+    }
+
+    /// Save maximal age of the last block in minutes to trust integrated node data at wallets.
+    pub fn save_wallet_sync_max_tip_age(minutes: u64) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.wallet_sync_max_tip_age = Some(minutes);
+        w_config.save();
+    }
+
+    /// Get range of ports to bind wallets Foreign API servers.
+    pub fn wallet_api_ports() -> Option<(u16, u16)> {
+        let r_config = Settings::app_config_to_read();
+        r_config.wallet_api_ports
+    }
+
+    /// Save range of ports to bind wallets Foreign API servers.
+    pub fn set_wallet_api_ports(range: Option<(u16, u16)>) {
+        let mut w_config = Settings::app_config_to_update();
+        w_config.wallet_api_ports = range;
+        w_config.save();
+    }
+}
+
+/// Receiver of donations with preset amounts to send.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DonationTarget {
+    /// Receiver name.
+    pub name: String,
+    /// Receiver Slatepack address.
+    pub address: String,
+    /// Preset amounts to send.
+    pub amounts: Vec<u64>,
 }
\ No newline at end of file
@@ -16,4 +16,7 @@ mod settings;
 pub use settings::Settings;
 
 mod config;
-pub use config::AppConfig;
\ No newline at end of file
+pub use config::{AppConfig, DonationTarget};
+
+mod state;
+pub use state::ViewState;
\ No newline at end of file
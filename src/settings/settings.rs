@@ -12,9 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use lazy_static::lazy_static;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
@@ -23,13 +24,17 @@ use serde::Serialize;
 use grin_config::ConfigError;
 
 use crate::node::NodeConfig;
-use crate::settings::AppConfig;
+use crate::settings::{AppConfig, ViewState};
 use crate::tor::TorConfig;
 use crate::wallet::ConnectionsConfig;
 
 lazy_static! {
     /// Static settings state to be accessible globally.
     static ref SETTINGS_STATE: Arc<Settings> = Arc::new(Settings::init());
+    /// Last read or written content of configuration files to detect external changes.
+    static ref CONFIG_FILES_CONTENT: Arc<RwLock<HashMap<PathBuf, String>>> = Arc::new(
+        RwLock::new(HashMap::new())
+    );
 }
 
 /// Contains initialized configurations.
@@ -41,7 +46,9 @@ pub struct Settings {
     /// Wallet connections configuration.
     conn_config: Arc<RwLock<ConnectionsConfig>>,
     /// Tor server configuration.
-    tor_config: Arc<RwLock<TorConfig>>
+    tor_config: Arc<RwLock<TorConfig>>,
+    /// Persisted views state.
+    view_state: Arc<RwLock<ViewState>>
 }
 
 impl Settings {
@@ -49,6 +56,10 @@ impl Settings {
     pub const MAIN_DIR_NAME: &'static str = ".grim";
     /// Crash report file name.
     pub const CRASH_REPORT_FILE_NAME: &'static str = "crash.log";
+    /// Directory name to keep history of crash reports.
+    pub const CRASH_REPORTS_DIR_NAME: &'static str = "crash_reports";
+    /// Maximal amount of crash reports to keep at history.
+    const MAX_CRASH_REPORTS: usize = 20;
     /// Application socket name.
     pub const SOCKET_NAME: &'static str = "grim.sock";
 
@@ -68,6 +79,7 @@ impl Settings {
             conn_config: Arc::new(RwLock::new(ConnectionsConfig::for_chain_type(chain_type))),
             app_config: Arc::new(RwLock::new(app_config)),
             tor_config: Arc::new(RwLock::new(tor_config)),
+            view_state: Arc::new(RwLock::new(ViewState::load())),
         }
     }
 
@@ -123,6 +135,16 @@ impl Settings {
         SETTINGS_STATE.tor_config.write()
     }
 
+    /// Get views state to read values.
+    pub fn view_state_to_read() -> RwLockReadGuard<'static, ViewState> {
+        SETTINGS_STATE.view_state.read()
+    }
+
+    /// Get views state to update values.
+    pub fn view_state_to_update() -> RwLockWriteGuard<'static, ViewState> {
+        SETTINGS_STATE.view_state.write()
+    }
+
     /// Get base directory path for configuration.
     pub fn base_path(sub_dir: Option<String>) -> PathBuf {
         // Check if dir exists.
@@ -159,20 +181,54 @@ impl Settings {
         path
     }
 
-    /// Delete crash report file.
-    pub fn delete_crash_report() {
+    /// Move crash report file to history, removing oldest reports over the limit.
+    pub fn archive_crash_report() {
         let log = Self::crash_report_path();
-        if log.exists() {
-            let _ = fs::remove_file(log.clone());
+        if !log.exists() {
+            return;
+        }
+        let mut path = Self::base_path(Some(Self::CRASH_REPORTS_DIR_NAME.to_string()));
+        path.push(format!("{}.log", chrono::Utc::now().timestamp()));
+        if fs::rename(&log, &path).is_err() {
+            let _ = fs::remove_file(log);
+        }
+        for old in Self::crash_reports().iter().skip(Self::MAX_CRASH_REPORTS) {
+            let _ = fs::remove_file(old);
         }
     }
 
+    /// Get crash reports from history sorted by time from newest to oldest.
+    pub fn crash_reports() -> Vec<PathBuf> {
+        let dir = Self::base_path(Some(Self::CRASH_REPORTS_DIR_NAME.to_string()));
+        let mut reports = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok().map(|e| e.path()))
+                    .filter(|p| Self::crash_report_time(p).is_some())
+                    .collect::<Vec<PathBuf>>()
+            })
+            .unwrap_or_default();
+        reports.sort_by_key(|p| std::cmp::Reverse(Self::crash_report_time(p)));
+        reports
+    }
+
+    /// Get crash time in seconds from history report file path.
+    pub fn crash_report_time(path: &Path) -> Option<i64> {
+        path.file_stem()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.parse::<i64>().ok())
+    }
+
     /// Read configuration from the file.
     pub fn read_from_file<T: DeserializeOwned>(config_path: PathBuf) -> Result<T, ConfigError> {
         let file_content = fs::read_to_string(config_path.clone())?;
         let parsed = toml::from_str::<T>(file_content.as_str());
         match parsed {
-            Ok(cfg) => Ok(cfg),
+            Ok(cfg) => {
+                let mut w_files = CONFIG_FILES_CONTENT.write();
+                w_files.insert(config_path, file_content);
+                Ok(cfg)
+            },
             Err(e) => {
                 return Err(ConfigError::ParseError(
                     config_path.to_str().unwrap().to_string(),
@@ -187,5 +243,51 @@ impl Settings {
         let conf_out = toml::to_string(config).unwrap();
         let mut file = File::create(path.to_str().unwrap()).unwrap();
         file.write_all(conf_out.as_bytes()).unwrap();
+        let mut w_files = CONFIG_FILES_CONTENT.write();
+        w_files.insert(path, conf_out);
+    }
+
+    /// Check if configuration file was changed outside of the application
+    /// after last reading or writing.
+    pub fn file_changed_externally(path: &PathBuf) -> bool {
+        let r_files = CONFIG_FILES_CONTENT.read();
+        if let Some(known) = r_files.get(path) {
+            if let Ok(content) = fs::read_to_string(path) {
+                return content != *known;
+            }
+        }
+        false
+    }
+
+    /// Check if application or Tor configuration was changed outside of the application.
+    pub fn configs_changed_externally() -> bool {
+        Self::file_changed_externally(&Self::config_path(AppConfig::FILE_NAME, None)) ||
+            Self::file_changed_externally(&Self::config_path(TorConfig::FILE_NAME, None))
+    }
+
+    /// Reload application and Tor configuration from files.
+    pub fn reload_configs() {
+        let app_path = Self::config_path(AppConfig::FILE_NAME, None);
+        match Self::read_from_file::<AppConfig>(app_path) {
+            Ok(config) => {
+                let mut w_config = SETTINGS_STATE.app_config.write();
+                *w_config = config;
+            }
+            Err(_) => Self::app_config_to_read().save()
+        }
+        let tor_path = Self::config_path(TorConfig::FILE_NAME, None);
+        match Self::read_from_file::<TorConfig>(tor_path) {
+            Ok(config) => {
+                let mut w_config = SETTINGS_STATE.tor_config.write();
+                *w_config = config;
+            }
+            Err(_) => Self::tor_config_to_read().save()
+        }
+    }
+
+    /// Save current application and Tor configuration overwriting external changes.
+    pub fn save_configs() {
+        Self::app_config_to_read().save();
+        Self::tor_config_to_read().save();
     }
 }
\ No newline at end of file
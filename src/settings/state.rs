@@ -0,0 +1,119 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+
+/// Persisted state of views (selected tabs, inputs and options), stored at JSON file.
+#[derive(Serialize, Deserialize)]
+pub struct ViewState {
+    /// Version of state format.
+    version: u32,
+    /// Serialized values by view key.
+    entries: BTreeMap<String, serde_json::Value>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl ViewState {
+    /// Views state file name.
+    pub const FILE_NAME: &'static str = "view_state.json";
+
+    /// Current version of state format.
+    const VERSION: u32 = 1;
+
+    /// Maximal size of single serialized value in bytes.
+    const MAX_VALUE_SIZE: usize = 8 * 1024;
+    /// Maximal amount of values to store.
+    const MAX_ENTRIES: usize = 64;
+
+    /// Load state from the file, [`Default`] is used if file is missing or corrupted.
+    pub fn load() -> Self {
+        let path = Self::path();
+        let state = fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| serde_json::from_str::<ViewState>(&data).ok());
+        match state {
+            Some(s) => s.migrate(),
+            None => Self::default()
+        }
+    }
+
+    /// Get state file path.
+    fn path() -> PathBuf {
+        Settings::config_path(Self::FILE_NAME, None)
+    }
+
+    /// Migrate state from older format version.
+    fn migrate(mut self) -> Self {
+        if self.version > Self::VERSION {
+            // Unknown format from newer version, start from scratch.
+            return Self::default();
+        }
+        // Remove values which are not fitting limits.
+        self.entries.retain(|_, v| v.to_string().len() <= Self::MAX_VALUE_SIZE);
+        while self.entries.len() > Self::MAX_ENTRIES {
+            self.entries.pop_last();
+        }
+        self.version = Self::VERSION;
+        self
+    }
+
+    /// Save state to the file.
+    fn save(&self) {
+        if let Ok(data) = serde_json::to_string(self) {
+            let _ = fs::write(Self::path(), data);
+        }
+    }
+
+    /// Get saved value for provided key, `None` if value is missing or can not be parsed.
+    pub fn get<T: DeserializeOwned>(key: &str) -> Option<T> {
+        let r_state = Settings::view_state_to_read();
+        let value = r_state.entries.get(key)?;
+        serde_json::from_value::<T>(value.clone()).ok()
+    }
+
+    /// Save value for provided key, value is ignored when exceeding size limits.
+    pub fn put<T: Serialize>(key: &str, value: &T) {
+        let value = match serde_json::to_value(value) {
+            Ok(v) => v,
+            Err(_) => return
+        };
+        if value.to_string().len() > Self::MAX_VALUE_SIZE {
+            return;
+        }
+        let mut w_state = Settings::view_state_to_update();
+        if w_state.entries.get(key) == Some(&value) {
+            return;
+        }
+        if !w_state.entries.contains_key(key) && w_state.entries.len() >= Self::MAX_ENTRIES {
+            return;
+        }
+        w_state.entries.insert(key.to_string(), value);
+        w_state.save();
+    }
+}
@@ -27,6 +27,8 @@ pub struct TorConfig {
     obfs4: TorBridge,
     /// Snowflake bridge type.
     snowflake: TorBridge,
+    /// Flag to keep Onion services keys and state between launches.
+    persistent_services: Option<bool>,
 }
 
 impl Default for TorConfig {
@@ -41,6 +43,7 @@ impl Default for TorConfig {
                 TorBridge::DEFAULT_SNOWFLAKE_BIN_PATH.to_string(),
                 TorBridge::DEFAULT_SNOWFLAKE_CONN_LINE.to_string()
             ),
+            persistent_services: None,
         }
     }
 }
@@ -123,4 +126,18 @@ impl TorConfig {
         let r_config = Settings::tor_config_to_read();
         r_config.snowflake.clone()
     }
+
+    /// Check if Onion services keys and state should be kept between launches.
+    pub fn services_persistent() -> bool {
+        let r_config = Settings::tor_config_to_read();
+        r_config.persistent_services.unwrap_or(false)
+    }
+
+    /// Enable or disable keeping of Onion services keys and state between launches.
+    pub fn toggle_services_persistence() {
+        let persistent = Self::services_persistent();
+        let mut w_config = Settings::tor_config_to_update();
+        w_config.persistent_services = Some(!persistent);
+        w_config.save();
+    }
 }
\ No newline at end of file
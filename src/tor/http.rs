@@ -30,6 +30,8 @@ use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 use tor_config::deps::educe::Educe;
 use tor_rtcompat::Runtime;
 
+use crate::tor::Tor;
+
 /// Error making or using http connection
 ///
 /// This error ends up being passed to hyper and bundled up into a [`hyper::Error`]
@@ -239,7 +241,16 @@ impl<R: Runtime, TC: TlsConn> Service<Uri> for ArtiHttpConnector<R, TC> {
             let addr = (&host as &str, port)
                 .into_tor_addr()
                 .map_err(arti_client::Error::from)?;
-            let ds = client.connect(addr).await?;
+            let ds = match client.connect(addr).await {
+                Ok(ds) => {
+                    Tor::on_circuit_built();
+                    ds
+                }
+                Err(e) => {
+                    Tor::save_error(e.to_string());
+                    return Err(e.into());
+                }
+            };
 
             let inner = match use_tls {
                 UseTls::Tls => {
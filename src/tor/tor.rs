@@ -19,8 +19,9 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{fs, thread};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use arti_client::config::{CfgPath, TorClientConfigBuilder};
 use arti_client::{TorClient, TorClientConfig};
@@ -49,7 +50,7 @@ use tor_rtcompat::tokio::TokioNativeTlsRuntime;
 use tor_rtcompat::Runtime;
 
 use crate::tor::http::ArtiHttpConnector;
-use crate::tor::TorConfig;
+use crate::tor::{TorConfig, TorDiagnostics};
 
 lazy_static! {
     /// Static thread-aware state of [`Node`] to be updated from separate thread.
@@ -69,14 +70,24 @@ pub struct Tor {
     failed_services: Arc<RwLock<BTreeSet<String>>>,
     /// Checking Onion services identifiers.
     checking_services: Arc<RwLock<BTreeSet<String>>>,
+    /// Onion services identifiers with published descriptor, checked by availability.
+    published_services: Arc<RwLock<BTreeSet<String>>>,
+    /// Mapping of running Onion services identifiers to launch time.
+    services_launch_time: Arc<RwLock<BTreeMap<String, Instant>>>,
+    /// Amount of successfully built circuits.
+    circuits: Arc<AtomicUsize>,
+    /// Last error from Tor client.
+    last_error: Arc<RwLock<Option<String>>>,
 }
 
 impl Default for Tor {
     fn default() -> Self {
-        // Cleanup keys, state and cache on start.
-        fs::remove_dir_all(TorConfig::keystore_path()).unwrap_or_default();
-        fs::remove_dir_all(TorConfig::state_path()).unwrap_or_default();
-        fs::remove_dir_all(TorConfig::cache_path()).unwrap_or_default();
+        // Cleanup keys, state and cache on start if services are not persistent.
+        if !TorConfig::services_persistent() {
+            fs::remove_dir_all(TorConfig::keystore_path()).unwrap_or_default();
+            fs::remove_dir_all(TorConfig::state_path()).unwrap_or_default();
+            fs::remove_dir_all(TorConfig::cache_path()).unwrap_or_default();
+        }
         // Create Tor client.
         let runtime = TokioNativeTlsRuntime::create().unwrap();
         let config = Self::build_config();
@@ -88,6 +99,10 @@ impl Default for Tor {
             starting_services: Arc::new(RwLock::new(BTreeSet::new())),
             failed_services: Arc::new(RwLock::new(BTreeSet::new())),
             checking_services: Arc::new(RwLock::new(BTreeSet::new())),
+            published_services: Arc::new(RwLock::new(BTreeSet::new())),
+            services_launch_time: Arc::new(RwLock::new(BTreeMap::new())),
+            circuits: Arc::new(AtomicUsize::new(0)),
+            last_error: Arc::new(RwLock::new(None)),
             client_config: Arc::new(RwLock::new((client, config))),
         }
     }
@@ -131,7 +146,10 @@ impl Tor {
     pub async fn post(body: String, url: String) -> Option<String> {
         // Bootstrap client.
         let (client, _) = Self::client_config();
-        client.bootstrap().await.unwrap();
+        if let Err(e) = client.bootstrap().await {
+            Self::save_error(e.to_string());
+            return None;
+        }
         // Create http tor-powered client to post data.
         let tls_connector = TlsConnector::builder().unwrap().build().unwrap();
         let tor_connector = ArtiHttpConnector::new(client, tls_connector);
@@ -149,16 +167,77 @@ impl Tor {
                 Ok(raw) => resp = Some(String::from_utf8_lossy(&raw).to_string()),
                 Err(_) => {}
             },
-            Err(_) => {}
+            Err(e) => Self::save_error(e.to_string())
         }
         resp
     }
 
+    /// Send get request using Tor.
+    pub async fn get(url: String) -> Option<String> {
+        // Bootstrap client.
+        let (client, _) = Self::client_config();
+        if let Err(e) = client.bootstrap().await {
+            Self::save_error(e.to_string());
+            return None;
+        }
+        // Create http tor-powered client to get data.
+        let tls_connector = TlsConnector::builder().unwrap().build().unwrap();
+        let tor_connector = ArtiHttpConnector::new(client, tls_connector);
+        let http = hyper::Client::builder().build::<_, Body>(tor_connector);
+        // Send request.
+        let uri = Uri::from_str(url.as_str()).ok()?;
+        let resp = match http.get(uri).await {
+            Ok(r) => r,
+            Err(e) => {
+                Self::save_error(e.to_string());
+                return None;
+            }
+        };
+        if !resp.status().is_success() {
+            return None;
+        }
+        let raw = hyper::body::to_bytes(resp).await.ok()?;
+        Some(String::from_utf8_lossy(&raw).to_string())
+    }
+
     fn client_config() -> (TorClient<TokioNativeTlsRuntime>, TorClientConfig) {
         let r_client_config = TOR_SERVER_STATE.client_config.read();
         r_client_config.clone()
     }
 
+    /// Get diagnostics of running Tor client.
+    pub fn diagnostics() -> TorDiagnostics {
+        let (client, _) = Self::client_config();
+        let status = client.bootstrap_status();
+        let last_error = {
+            let r_error = TOR_SERVER_STATE.last_error.read();
+            r_error.clone()
+        };
+        TorDiagnostics {
+            bootstrap: (status.as_frac() * 100.0).round() as u8,
+            ready: status.ready_for_traffic(),
+            circuits: TOR_SERVER_STATE.circuits.load(Ordering::Relaxed),
+            last_error: last_error.or(status.blocked().map(|b| b.to_string())),
+        }
+    }
+
+    /// Save successfully built circuit.
+    pub fn on_circuit_built() {
+        TOR_SERVER_STATE.circuits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Save last error from Tor client.
+    pub fn save_error(err: String) {
+        let mut w_error = TOR_SERVER_STATE.last_error.write();
+        *w_error = Some(err);
+    }
+
+    /// Clear last error from Tor client.
+    pub fn clear_error() {
+        let mut w_error = TOR_SERVER_STATE.last_error.write();
+        *w_error = None;
+    }
+
     /// Check if Onion service is starting.
     pub fn is_service_starting(id: &String) -> bool {
         let r_services = TOR_SERVER_STATE.starting_services.read();
@@ -183,6 +262,26 @@ impl Tor {
         r_services.contains(id)
     }
 
+    /// Check if Onion service descriptor was published and service is reachable.
+    pub fn is_service_published(id: &String) -> bool {
+        let r_services = TOR_SERVER_STATE.published_services.read();
+        r_services.contains(id)
+    }
+
+    /// Get running Onion service uptime.
+    pub fn service_uptime(id: &String) -> Option<Duration> {
+        let r_services = TOR_SERVER_STATE.services_launch_time.read();
+        r_services.get(id).map(|t| t.elapsed())
+    }
+
+    /// Remove running Onion service status data.
+    fn clear_service_status(id: &String) {
+        let mut w_services = TOR_SERVER_STATE.published_services.write();
+        w_services.remove(id);
+        let mut w_services = TOR_SERVER_STATE.services_launch_time.write();
+        w_services.remove(id);
+    }
+
     // Restart Onion service.
     pub fn restart_service(port: u16, key: SecretKey, id: &String) {
         Self::stop_service(id);
@@ -197,6 +296,7 @@ impl Tor {
             proxy.shutdown();
             drop(svc);
         }
+        Self::clear_service_status(id);
     }
 
     /// Start Onion service from listening local port and [`SecretKey`].
@@ -231,21 +331,28 @@ impl Tor {
                 .spawn(async move {
                     // Add service key to keystore.
                     let hs_nickname = HsNickname::new(service_id.clone()).unwrap();
-                    if let Err(_) = Self::add_service_key(config.fs_mistrust(), &key, &hs_nickname)
+                    if let Err(e) = Self::add_service_key(config.fs_mistrust(), &key, &hs_nickname)
                     {
+                        Self::save_error(e.to_string());
                         on_error(service_id);
                         return;
                     }
                     // Bootstrap client.
-                    client_thread.bootstrap().await.unwrap();
+                    if let Err(e) = client_thread.bootstrap().await {
+                        Self::save_error(e.to_string());
+                        on_error(service_id);
+                        return;
+                    }
                     // Launch Onion service.
                     let service_config = OnionServiceConfigBuilder::default()
                         .nickname(hs_nickname.clone())
                         .build()
                         .unwrap();
-                    if let Ok((service, request)) =
-                        client_thread.launch_onion_service(service_config)
-                    {
+                    let launch_res = client_thread.launch_onion_service(service_config);
+                    if let Err(e) = &launch_res {
+                        Self::save_error(e.to_string());
+                    }
+                    if let Ok((service, request)) = launch_res {
                         // Launch service proxy.
                         let addr = SocketAddr::new(IpAddr::from(Ipv4Addr::LOCALHOST), port);
                         tokio::spawn(Self::run_service_proxy(
@@ -302,10 +409,20 @@ impl Tor {
                                                 let mut w_services =
                                                     TOR_SERVER_STATE.starting_services.write();
                                                 w_services.remove(&service_id);
+                                                // Mark service as published.
+                                                let mut w_services =
+                                                    TOR_SERVER_STATE.published_services.write();
+                                                w_services.insert(service_id.clone());
                                                 // Check again after 50 seconds.
                                                 Duration::from_millis(50000)
                                             }
                                             Err(_) => {
+                                                // Mark service as not published.
+                                                {
+                                                    let mut w_services =
+                                                        TOR_SERVER_STATE.published_services.write();
+                                                    w_services.remove(&service_id);
+                                                }
                                                 // Restart service on 3rd error.
                                                 errors_count += 1;
                                                 if errors_count == MAX_ERRORS {
@@ -361,8 +478,12 @@ impl Tor {
         let proxy = OnionServiceReverseProxy::new(proxy_cfg_builder.build().unwrap());
 
         // Save running service.
-        let mut w_services = TOR_SERVER_STATE.running_services.write();
-        w_services.insert(id.clone(), (service.clone(), proxy.clone()));
+        {
+            let mut w_services = TOR_SERVER_STATE.running_services.write();
+            w_services.insert(id.clone(), (service.clone(), proxy.clone()));
+            let mut w_services = TOR_SERVER_STATE.services_launch_time.write();
+            w_services.insert(id.clone(), Instant::now());
+        }
 
         // Start proxy for launched service.
         client
@@ -376,11 +497,13 @@ impl Tor {
                         // Remove service from running.
                         let mut w_services = TOR_SERVER_STATE.running_services.write();
                         w_services.remove(&id);
+                        Self::clear_service_status(&id);
                     }
                     Err(_) => {
                         // Remove service from running.
                         let mut w_services = TOR_SERVER_STATE.running_services.write();
                         w_services.remove(&id);
+                        Self::clear_service_status(&id);
                         // Save failed service.
                         let mut w_services = TOR_SERVER_STATE.failed_services.write();
                         w_services.insert(id);
@@ -57,4 +57,17 @@ impl TorBridge {
             TorBridge::Snowflake(_, line) => line.clone()
         }
     }
+}
+
+/// Diagnostics of running Tor client.
+#[derive(Clone)]
+pub struct TorDiagnostics {
+    /// Bootstrap progress in percents.
+    pub bootstrap: u8,
+    /// Flag to check if client is ready to send traffic.
+    pub ready: bool,
+    /// Amount of circuits successfully built for connections and services.
+    pub circuits: usize,
+    /// Last error from Tor client.
+    pub last_error: Option<String>,
 }
\ No newline at end of file
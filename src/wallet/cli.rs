@@ -0,0 +1,108 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::PathBuf;
+use grin_core::global::ChainTypes;
+
+/// Wallet installation of grin-wallet command line application.
+#[derive(Clone)]
+pub struct CliWallet {
+    /// Directory containing wallet data and seed file.
+    pub top_level_dir: PathBuf,
+    /// Directory of wallet data.
+    pub data_dir: PathBuf,
+    /// Node API URL.
+    pub node_url: Option<String>,
+    /// Node API secret.
+    pub node_secret: Option<String>,
+    /// Flag to check if Tor listener was enabled.
+    pub tor_listener: bool,
+}
+
+/// Base directory name of command line application.
+const BASE_DIR_NAME: &'static str = ".grin";
+/// Configuration file name of command line wallet.
+const CONFIG_FILE_NAME: &'static str = "grin-wallet.toml";
+/// Wallet data directory name.
+const DATA_DIR_NAME: &'static str = "wallet_data";
+/// Wallet seed file name.
+const SEED_FILE_NAME: &'static str = "wallet.seed";
+
+impl CliWallet {
+    /// Find wallet of command line application at its default directory
+    /// for provided [`ChainTypes`].
+    pub fn detect(chain_type: ChainTypes) -> Option<Self> {
+        let mut dir = dirs::home_dir()?;
+        dir.push(BASE_DIR_NAME);
+        dir.push(chain_type.shortname());
+
+        // Read wallet configuration.
+        let config = fs::read_to_string(dir.join(CONFIG_FILE_NAME))
+            .ok()
+            .and_then(|c| toml::from_str::<toml::Table>(&c).ok())
+            .unwrap_or_default();
+        let wallet = config.get("wallet").and_then(|w| w.as_table());
+        let wallet_str = |key: &str| {
+            wallet.and_then(|w| w.get(key)).and_then(|v| v.as_str()).map(|v| v.to_string())
+        };
+
+        // Check if wallet data exists.
+        let data_dir = wallet_str("data_file_dir")
+            .map(PathBuf::from)
+            .unwrap_or(dir.join(DATA_DIR_NAME));
+        if !data_dir.join(SEED_FILE_NAME).exists() {
+            return None;
+        }
+        let top_level_dir = data_dir.parent()?.to_path_buf();
+
+        let node_secret = wallet_str("node_api_secret_path")
+            .and_then(|p| fs::read_to_string(p).ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        let tor_listener = config.get("tor")
+            .and_then(|t| t.get("use_tor_listener"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        Some(Self {
+            top_level_dir,
+            data_dir,
+            node_url: wallet_str("check_node_api_http_addr"),
+            node_secret,
+            tor_listener,
+        })
+    }
+
+    /// Check if wallet data can be used in place without copying,
+    /// requires default data directory name.
+    pub fn can_use_in_place(&self) -> bool {
+        self.data_dir.file_name().map(|n| n == DATA_DIR_NAME).unwrap_or(false)
+    }
+
+    /// Check if node URL points to local node at default API port.
+    pub fn local_node(&self, chain_type: ChainTypes) -> bool {
+        let port = match chain_type {
+            ChainTypes::Mainnet => 3413,
+            _ => 13413
+        };
+        match &self.node_url {
+            None => true,
+            Some(url) => {
+                let url = url.trim_end_matches('/');
+                url == format!("http://127.0.0.1:{}", port) ||
+                    url == format!("http://localhost:{}", port)
+            }
+        }
+    }
+}
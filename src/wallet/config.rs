@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::string::ToString;
 
 use grin_core::global::ChainTypes;
+use grin_keychain::{ExtKeychain, Identifier, Keychain};
+use grin_util::ToHex;
 use grin_wallet_libwallet::{Slate};
 use rand::Rng;
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{AppConfig, Settings};
-use crate::wallet::ConnectionsConfig;
+use crate::wallet::{ConnectionsConfig, WalletUtils};
 use crate::wallet::types::ConnectionMethod;
 
 /// Wallet configuration.
@@ -44,8 +47,55 @@ pub struct WalletConfig {
     pub use_dandelion: Option<bool>,
     /// Flag to enable Tor listener on start.
     pub enable_tor_listener: Option<bool>,
+    /// Account label to run Tor listener only for, any account if not set.
+    pub tor_listener_account: Option<String>,
     /// Wallet API port.
     pub api_port: Option<u16>,
+    /// Chain height at wallet creation, used as lower bound for outputs scanning.
+    pub creation_height: Option<u64>,
+    /// Flag to save chain height at sync when it was not available at wallet creation.
+    pub creation_height_pending: Option<bool>,
+    /// Token to access read-only status API, disabled when not set.
+    pub status_api_token: Option<String>,
+    /// Amount of spendable outputs to keep after consolidation.
+    pub consolidation_target: Option<usize>,
+    /// Password encrypted with PIN and platform key for quick unlock.
+    pub unlock_pin_data: Option<String>,
+    /// Password encrypted with platform key for biometric unlock.
+    pub unlock_bio_data: Option<String>,
+    /// Format version of quick unlock data, older data is ignored.
+    pub unlock_data_version: Option<u8>,
+    /// Amount of wrong PIN attempts since last quick unlock.
+    pub unlock_pin_attempts: Option<u8>,
+    /// Identifiers of transactions queued to cancel, to continue after launch.
+    pub queued_cancels: Option<Vec<u32>>,
+    /// Custom account names by account label.
+    pub account_names: Option<HashMap<String, String>>,
+    /// Labels of archived accounts hidden from account selection.
+    pub hidden_accounts: Option<Vec<String>>,
+    /// Salted hash of duress password to open decoy wallet from previous versions.
+    pub duress_pass_hash: Option<String>,
+    /// Salted hash of duress password or random value of the same size when not set,
+    /// to not reveal existence of decoy wallet.
+    pub pass_check: Option<String>,
+    /// Period in hours after which issued invoice is considered expired.
+    pub invoice_expiry: Option<i64>,
+    /// Flag to open the wallet on application launch.
+    pub auto_open: Option<bool>,
+    /// Minimal amount of incoming transaction, smaller amounts are considered as dust.
+    pub min_receive_amount: Option<u64>,
+    /// Flag to reject dust at Foreign API instead of marking it at transactions list.
+    pub reject_dust: Option<bool>,
+    /// Directory of imported wallet data to use in place, wallet directory is used if not set.
+    pub data_dir: Option<String>,
+    /// Token to access read-only Owner API server, disabled when not set.
+    pub owner_api_token: Option<String>,
+    /// Owner API server port.
+    pub owner_api_port: Option<u16>,
+
+    /// Flag to use decoy wallet data after opening with duress password.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub duress: bool,
 }
 
 /// Base wallets directory name.
@@ -54,6 +104,10 @@ const BASE_DIR_NAME: &'static str = "wallets";
 const CONFIG_FILE_NAME: &'static str = "grim-wallet.toml";
 /// Slatepacks directory name.
 const SLATEPACKS_DIR_NAME: &'static str = "slatepacks";
+/// Wallet seed file name.
+const SEED_FILE_NAME: &'static str = "wallet.seed";
+/// Decoy wallet directory name.
+const DURESS_DIR_NAME: &'static str = "duress";
 
 /// Default value of minimal amount of confirmations.
 const MIN_CONFIRMATIONS_DEFAULT: u64 = 10;
@@ -61,6 +115,10 @@ const MIN_CONFIRMATIONS_DEFAULT: u64 = 10;
 impl WalletConfig {
     /// Default account name value.
     pub const DEFAULT_ACCOUNT_LABEL: &'static str = "default";
+    /// Default amount of spendable outputs to keep after consolidation.
+    pub const DEFAULT_CONSOLIDATION_TARGET: usize = 1;
+    /// Default period in hours after which issued invoice is considered expired.
+    pub const DEFAULT_INVOICE_EXPIRY: i64 = 24;
 
     /// Create new wallet config.
     pub fn create(name: String, conn_method: &ConnectionMethod) -> WalletConfig {
@@ -81,7 +139,29 @@ impl WalletConfig {
             min_confirmations: MIN_CONFIRMATIONS_DEFAULT,
             use_dandelion: Some(true),
             enable_tor_listener: Some(false),
+            tor_listener_account: None,
             api_port: Some(rand::thread_rng().gen_range(10000..30000)),
+            creation_height: None,
+            creation_height_pending: None,
+            status_api_token: None,
+            consolidation_target: None,
+            unlock_pin_data: None,
+            unlock_bio_data: None,
+            unlock_data_version: None,
+            unlock_pin_attempts: None,
+            queued_cancels: None,
+            account_names: None,
+            hidden_accounts: None,
+            duress_pass_hash: None,
+            pass_check: Some(WalletUtils::random_password_hash().to_hex()),
+            invoice_expiry: None,
+            auto_open: None,
+            min_receive_amount: None,
+            reject_dust: None,
+            data_dir: None,
+            owner_api_token: None,
+            owner_api_port: None,
+            duress: false,
         };
         Settings::write_to_file(&config, config_path);
         config
@@ -117,6 +197,11 @@ impl WalletConfig {
         None
     }
 
+    /// Get chain height to start outputs scanning from.
+    pub fn scan_start_height(&self) -> u64 {
+        self.creation_height.unwrap_or(1).max(1)
+    }
+
     /// Get wallet connection method.
     pub fn connection(&self) -> ConnectionMethod {
         if let Some(ext_conn_id) = self.ext_conn_id {
@@ -135,6 +220,12 @@ impl WalletConfig {
         Settings::write_to_file(self, config_path);
     }
 
+    /// Check if config file was changed outside of the application.
+    pub fn changed_externally(&self) -> bool {
+        let config_path = Self::get_config_file_path(self.chain_type, self.id);
+        Settings::file_changed_externally(&config_path)
+    }
+
     /// Get wallets base directory path for provided [`ChainTypes`].
     pub fn get_base_path(chain_type: ChainTypes) -> PathBuf {
         let sub_dir = Some(chain_type.shortname());
@@ -167,30 +258,99 @@ impl WalletConfig {
         data_path.to_str().unwrap().to_string()
     }
 
+    /// Get path to the directory containing wallet data and seed file.
+    pub fn get_top_level_path(&self) -> String {
+        if self.duress {
+            return self.get_duress_data_path();
+        }
+        match &self.data_dir {
+            Some(dir) => dir.clone(),
+            None => self.get_data_path()
+        }
+    }
+
+    /// Get decoy wallet data path opening with duress password.
+    pub fn get_duress_data_path(&self) -> String {
+        let mut path = PathBuf::from(self.get_data_path());
+        path.push(DURESS_DIR_NAME);
+        path.to_str().unwrap().to_string()
+    }
+
     /// Get wallet database data path.
     pub fn get_db_path(&self) -> String {
-        let mut path = PathBuf::from(self.get_data_path());
+        let mut path = PathBuf::from(self.get_top_level_path());
         path.push("wallet_data");
         path.push("db");
         path.to_str().unwrap().to_string()
     }
 
+    /// Get wallet seed file path.
+    pub fn get_seed_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_top_level_path());
+        path.push("wallet_data");
+        path.push(SEED_FILE_NAME);
+        path
+    }
+
     /// Get Slatepacks data path for current wallet.
     pub fn get_slatepack_path(&self, slate: &Slate) -> PathBuf {
-        let mut path = PathBuf::from(self.get_data_path());
+        let mut path = self.get_slatepacks_dir();
+        let slatepack_file_name = format!("{}.{}.slatepack", slate.id, slate.state);
+        path.push(slatepack_file_name);
+        path
+    }
+
+    /// Get path to Slatepack messages directory.
+    pub fn get_slatepacks_dir(&self) -> PathBuf {
+        let mut path = if self.duress {
+            PathBuf::from(self.get_duress_data_path())
+        } else {
+            PathBuf::from(self.get_data_path())
+        };
         path.push(SLATEPACKS_DIR_NAME);
         if !path.exists() {
             let _ = fs::create_dir_all(path.clone());
         }
-        let slatepack_file_name = format!("{}.{}.slatepack", slate.id, slate.state);
-        path.push(slatepack_file_name);
         path
     }
 
     /// Get path to extra db storage.
     pub fn get_extra_db_path(&self) -> String {
-        let mut path = PathBuf::from(self.get_db_path());
+        // Keep extra data of wallet imported in place at application directory.
+        let mut path = if self.duress {
+            PathBuf::from(self.get_duress_data_path())
+        } else {
+            PathBuf::from(self.get_data_path())
+        };
+        path.push("wallet_data");
+        path.push("db");
         path.push("extra");
+        if !path.exists() {
+            // Move extra data previously stored at command line wallet directory.
+            let mut legacy_path = PathBuf::from(self.get_db_path());
+            legacy_path.push("extra");
+            if legacy_path != path && legacy_path.exists() {
+                let _ = fs::create_dir_all(path.parent().unwrap());
+                let _ = fs::rename(&legacy_path, &path);
+            }
+        }
+        if !path.exists() {
+            let _ = fs::create_dir_all(path.clone());
+        }
+        path.to_str().unwrap().to_string()
+    }
+
+    /// Get path to extra db storage of transactions data for account with provided parent key,
+    /// transaction identifiers are unique only inside account. Data of default account is kept
+    /// at extra db storage root as it was saved by previous versions.
+    pub fn get_tx_db_path(&self, parent_key_id: &Identifier) -> String {
+        let path = self.get_extra_db_path();
+        if *parent_key_id == ExtKeychain::derive_key_id(2, 0, 0, 0, 0) {
+            return path;
+        }
+        let mut path = PathBuf::from(path);
+        path.push("accounts");
+        path.push(parent_key_id.to_hex());
         if !path.exists() {
             let _ = fs::create_dir_all(path.clone());
         }
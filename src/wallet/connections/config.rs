@@ -16,7 +16,7 @@ use grin_core::global::ChainTypes;
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{AppConfig, Settings};
-use crate::wallet::ExternalConnection;
+use crate::wallet::{ExternalConnection, NodesManifest};
 
 /// Wallet connections configuration.
 #[derive(Serialize, Deserialize, Clone)]
@@ -80,6 +80,65 @@ impl ConnectionsConfig {
         w_config.save();
     }
 
+    /// Add default connections from [`NodesManifest`] which are missing at configuration.
+    pub fn add_default_ext_conns() {
+        let chain_type = AppConfig::chain_type();
+        let mut w_config = Settings::conn_config_to_update();
+        let id = chrono::Utc::now().timestamp();
+        let mut added = 0;
+        for url in NodesManifest::urls(&chain_type) {
+            if w_config.external.iter().any(|c| !c.deleted && c.url == url) {
+                continue;
+            }
+            let mut conn = ExternalConnection::new(url, None);
+            conn.id = id + added;
+            w_config.external.push(conn);
+            added += 1;
+        }
+        if added != 0 {
+            w_config.save();
+        }
+    }
+
+    /// Export saved [`ExternalConnection`] list to share with another device.
+    pub fn export_ext_conns() -> Option<Vec<u8>> {
+        let r_config = Settings::conn_config_to_read();
+        let config = ConnectionsConfig {
+            chain_type: r_config.chain_type,
+            external: r_config.external.iter()
+                .filter(|c| !c.deleted)
+                .cloned()
+                .collect::<Vec<ExternalConnection>>(),
+        };
+        serde_json::to_vec_pretty(&config).ok()
+    }
+
+    /// Import [`ExternalConnection`] list from exported data for current network,
+    /// returning amount of added connections.
+    pub fn import_ext_conns(data: &str) -> Option<usize> {
+        let config = serde_json::from_str::<ConnectionsConfig>(data.trim()).ok()?;
+        if config.chain_type != AppConfig::chain_type() {
+            return None;
+        }
+        let mut w_config = Settings::conn_config_to_update();
+        let id = chrono::Utc::now().timestamp();
+        let mut added = 0;
+        for conn in config.external {
+            if url::Url::parse(&conn.url).is_err() ||
+                w_config.external.iter().any(|c| !c.deleted && c.url == conn.url) {
+                continue;
+            }
+            let mut conn = ExternalConnection::new(conn.url, conn.secret);
+            conn.id = id + added as i64;
+            w_config.external.push(conn);
+            added += 1;
+        }
+        if added != 0 {
+            w_config.save();
+        }
+        Some(added)
+    }
+
     /// Get external node connection with provided identifier.
     pub fn ext_conn(id: i64) -> Option<ExternalConnection> {
         let r_config = Settings::conn_config_to_read();
@@ -103,6 +162,22 @@ impl ConnectionsConfig {
         }
     }
 
+    /// Set [`ExternalConnection`] health check results.
+    pub fn update_ext_conn_health(id: i64,
+                                  height: Option<u64>,
+                                  latency: Option<u64>,
+                                  version: Option<String>) {
+        let mut w_config = Settings::conn_config_to_update();
+        for c in w_config.external.iter_mut() {
+            if c.id == id {
+                c.height = height;
+                c.latency = latency;
+                c.version = version;
+                break;
+            }
+        }
+    }
+
     /// Remove [`ExternalConnection`] with provided identifier.
     pub fn remove_ext_conn(id: i64) {
         let mut w_config = Settings::conn_config_to_update();
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Instant;
 use grin_core::global::ChainTypes;
 use grin_util::to_base64;
 use serde_derive::{Deserialize, Serialize};
 
-use crate::wallet::ConnectionsConfig;
+use crate::wallet::{ConnectionsConfig, NodesManifest};
 
 /// External connection for the wallet.
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -31,36 +33,34 @@ pub struct ExternalConnection {
     /// Flag to check if server is available.
     #[serde(skip_serializing, skip_deserializing)]
     pub available: Option<bool>,
+    /// Node chain height from last check.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub height: Option<u64>,
+    /// Node response time in milliseconds from last check.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub latency: Option<u64>,
+    /// Node version from last check.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub version: Option<String>,
 
     /// Flag to check if connection was deleted.
     #[serde(skip_serializing, skip_deserializing)]
     pub deleted: bool
 }
 
-/// Default external node URL for main network.
-const DEFAULT_MAIN_URLS: [&'static str; 2] = [
-        "https://grincoin.org",
-        "https://grinnode.live:3413"
-    ];
-
-/// Default external node URL for main network.
-const DEFAULT_TEST_URLS: [&'static str; 1] = [
-        "https://testnet.grincoin.org"
-    ];
-
 impl ExternalConnection {
     /// Get default connections for provided chain type.
     pub fn default(chain_type: &ChainTypes) -> Vec<ExternalConnection> {
-        let urls = match chain_type {
-            ChainTypes::Mainnet => DEFAULT_MAIN_URLS.to_vec(),
-            _ => DEFAULT_TEST_URLS.to_vec()
-        };
+        let urls = NodesManifest::urls(chain_type);
         urls.iter().enumerate().map(|(index, url)| {
             ExternalConnection {
                 id: index as i64,
                 url: url.to_string(),
                 secret: None,
                 available: None,
+                height: None,
+                latency: None,
+                version: None,
                 deleted: false,
             }
         }).collect::<Vec<ExternalConnection>>()
@@ -74,65 +74,149 @@ impl ExternalConnection {
             url,
             secret,
             available: None,
+            height: None,
+            latency: None,
+            version: None,
             deleted: false
         }
     }
 
     /// Check external connections availability.
     pub fn check(id: Option<i64>, ui_ctx: &egui::Context) {
+        LAST_CHECK_TIME.store(chrono::Utc::now().timestamp(), Ordering::Relaxed);
         let conn_list = ConnectionsConfig::ext_conn_list();
         for conn in conn_list {
             if let Some(id) = id {
                 if id == conn.id {
-                    check_ext_conn(&conn, ui_ctx);
+                    check_ext_conn(&conn, ui_ctx, false);
                 }
             } else {
-                check_ext_conn(&conn, ui_ctx);
+                check_ext_conn(&conn, ui_ctx, false);
+            }
+        }
+    }
+
+    /// Check health of all connections if [`HEALTH_CHECK_INTERVAL`] passed since last check.
+    pub fn check_periodically(ui_ctx: &egui::Context) {
+        let now = chrono::Utc::now().timestamp();
+        if now - LAST_CHECK_TIME.load(Ordering::Relaxed) < HEALTH_CHECK_INTERVAL {
+            return;
+        }
+        LAST_CHECK_TIME.store(now, Ordering::Relaxed);
+        for conn in ConnectionsConfig::ext_conn_list() {
+            if !conn.deleted {
+                check_ext_conn(&conn, ui_ctx, true);
             }
         }
     }
+
+    /// Check if transaction with provided kernel excess is waiting at node pool,
+    /// returns `None` if node is not available.
+    pub fn pool_has_kernel(&self, excess: &str) -> Option<bool> {
+        let conn = self.clone();
+        let txs = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                foreign_api_request(&conn, "get_unconfirmed_transactions").await
+            })?;
+        let found = txs.as_array()?.iter().any(|entry| {
+            entry["tx"]["body"]["kernels"].as_array()
+                .map(|kernels| kernels.iter().any(|k| k["excess"].as_str() == Some(excess)))
+                .unwrap_or(false)
+        });
+        Some(found)
+    }
+
+    /// Get next available connection after provided one to switch on failure.
+    pub fn next_available(id: i64) -> Option<ExternalConnection> {
+        let conn_list = ConnectionsConfig::ext_conn_list()
+            .into_iter()
+            .filter(|c| !c.deleted)
+            .collect::<Vec<ExternalConnection>>();
+        let pos = conn_list.iter().position(|c| c.id == id).unwrap_or(0);
+        let len = conn_list.len();
+        (1..len)
+            .map(|i| &conn_list[(pos + i) % len])
+            .find(|c| c.id != id && c.available == Some(true))
+            .cloned()
+    }
+}
+
+/// Interval in seconds between periodic connections health checks.
+const HEALTH_CHECK_INTERVAL: i64 = 60;
+
+/// Time of last connections check in seconds.
+static LAST_CHECK_TIME: AtomicI64 = AtomicI64::new(0);
+
+/// Send JSON-RPC request to Foreign API of provided connection, returning `Ok` result value.
+async fn foreign_api_request(conn: &ExternalConnection,
+                             method: &str) -> Option<serde_json::Value> {
+    let url = url::Url::parse(conn.url.as_str()).ok()?;
+    let addr = format!("{}v2/foreign", url.to_string());
+    // Setup http client.
+    let client = hyper::Client::builder()
+        .build::<_, hyper::Body>(hyper_tls::HttpsConnector::new());
+    let mut req_setup = hyper::Request::builder()
+        .method(hyper::Method::POST)
+        .uri(addr.clone());
+    // Setup secret key auth.
+    if let Some(key) = &conn.secret {
+        let basic_auth = format!(
+            "Basic {}",
+            to_base64(&format!("grin:{}", key))
+        );
+        req_setup = req_setup
+            .header(hyper::header::AUTHORIZATION, basic_auth.clone());
+    }
+    let body = format!(r#"{{"id":1,"jsonrpc":"2.0","method":"{}","params":{{}} }}"#, method);
+    let req = req_setup.body(hyper::Body::from(body)).unwrap();
+    // Send request, available on 200 HTTP status code.
+    let res = client.request(req).await.ok()?;
+    if res.status().as_u16() != 200 {
+        return None;
+    }
+    let bytes = hyper::body::to_bytes(res.into_body()).await.ok()?;
+    let value = serde_json::from_slice::<serde_json::Value>(&bytes).ok()?;
+    Some(value["result"]["Ok"].clone())
 }
 
-/// Check connection availability.
-fn check_ext_conn(conn: &ExternalConnection, ui_ctx: &egui::Context) {
+/// Check connection availability, height, latency and version.
+fn check_ext_conn(conn: &ExternalConnection, ui_ctx: &egui::Context, periodic: bool) {
     let conn = conn.clone();
     let ui_ctx = ui_ctx.clone();
-    ConnectionsConfig::update_ext_conn_status(conn.id, None);
+    // Keep previous status on periodic check.
+    if !periodic {
+        ConnectionsConfig::update_ext_conn_status(conn.id, None);
+    }
     std::thread::spawn(move || {
         tokio::runtime::Builder::new_multi_thread()
             .enable_all()
             .build()
             .unwrap()
             .block_on(async {
-                let url = url::Url::parse(conn.url.as_str()).unwrap();
-                if let Ok(_) = url.socket_addrs(|| None) {
-                    let addr = format!("{}v2/foreign", url.to_string());
-                    // Setup http client.
-                    let client = hyper::Client::builder()
-                        .build::<_, hyper::Body>(hyper_tls::HttpsConnector::new());
-                    let mut req_setup = hyper::Request::builder()
-                        .method(hyper::Method::POST)
-                        .uri(addr.clone());
-                    // Setup secret key auth.
-                    if let Some(key) = conn.secret {
-                        let basic_auth = format!(
-                            "Basic {}",
-                            to_base64(&format!("grin:{}", key))
-                        );
-                        req_setup = req_setup
-                            .header(hyper::header::AUTHORIZATION, basic_auth.clone());
-                    }
-                    let req = req_setup.body(hyper::Body::from(
-                        r#"{"id":1,"jsonrpc":"2.0","method":"get_version","params":{} }"#)
-                    ).unwrap();
-                    // Send request.
-                    match client.request(req).await {
-                        Ok(res) => {
-                            let status = res.status().as_u16();
-                            // Available on 200 HTTP status code.
-                            ConnectionsConfig::update_ext_conn_status(conn.id, Some(status == 200));
+                let resolved = url::Url::parse(conn.url.as_str())
+                    .map(|url| url.socket_addrs(|| None).is_ok())
+                    .unwrap_or(false);
+                if resolved {
+                    let start = Instant::now();
+                    match foreign_api_request(&conn, "get_version").await {
+                        Some(version) => {
+                            let latency = start.elapsed().as_millis() as u64;
+                            let version = version["node_version"].as_str().map(|v| v.to_string());
+                            let height = foreign_api_request(&conn, "get_tip").await
+                                .and_then(|tip| tip["height"].as_u64());
+                            ConnectionsConfig::update_ext_conn_health(conn.id,
+                                                                      height,
+                                                                      Some(latency),
+                                                                      version);
+                            ConnectionsConfig::update_ext_conn_status(conn.id, Some(true));
+                        }
+                        None => {
+                            ConnectionsConfig::update_ext_conn_health(conn.id, None, None, None);
+                            ConnectionsConfig::update_ext_conn_status(conn.id, Some(false));
                         }
-                        Err(_) => ConnectionsConfig::update_ext_conn_status(conn.id, Some(false))
                     }
                 } else {
                     ConnectionsConfig::update_ext_conn_status(conn.id, Some(false));
@@ -141,4 +225,4 @@ fn check_ext_conn(conn: &ExternalConnection, ui_ctx: &egui::Context) {
                 ui_ctx.request_repaint();
             });
     });
-}
\ No newline at end of file
+}
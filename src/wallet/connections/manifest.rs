@@ -0,0 +1,167 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use grin_core::global::ChainTypes;
+use grin_util::from_hex;
+use lazy_static::lazy_static;
+use log::error;
+use parking_lot::RwLock;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+use crate::tor::Tor;
+
+lazy_static! {
+    /// Loaded manifest to avoid parsing on every access.
+    static ref MANIFEST: Arc<RwLock<Option<NodesManifest>>> = Arc::new(RwLock::new(None));
+}
+
+/// Manifest of default external nodes for each chain type.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NodesManifest {
+    /// Manifest version, newer version replaces older one.
+    version: u64,
+    /// Node URLs for main network.
+    main: Vec<String>,
+    /// Node URLs for test network.
+    test: Vec<String>,
+}
+
+/// Manifest bundled with application.
+const BUNDLED_MANIFEST: &'static str = include_str!("../../../nodes.json");
+
+/// URL to load latest manifest from application repository over HTTPS.
+const MANIFEST_URL: &'static str = "https://raw.githubusercontent.com/GetGrin/grim/master/nodes.json";
+/// URL to load detached signature of latest manifest.
+const MANIFEST_SIG_URL: &'static str = "https://raw.githubusercontent.com/GetGrin/grim/master/nodes.json.sig";
+
+/// Pinned public key to verify manifest signature.
+const MANIFEST_PUBLIC_KEY: &'static str =
+    "18fb90224635d4ba0bd2ec79cd279e9e4f730a08721a89f8b2517f1abca15c1e";
+
+/// Delay between manifest updates.
+const UPDATE_DELAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+impl NodesManifest {
+    /// Manifest file name to save loaded update.
+    pub const FILE_NAME: &'static str = "nodes.json";
+
+    /// Get saved manifest or bundled one if saved is missing or older.
+    pub fn load() -> Result<Self, String> {
+        let bundled = Self::parse(BUNDLED_MANIFEST)?;
+        let path = Settings::config_path(Self::FILE_NAME, None);
+        let saved = fs::read_to_string(path)
+            .ok()
+            .and_then(|data| Self::parse(&data).ok());
+        match saved {
+            Some(m) if m.version > bundled.version => Ok(m),
+            _ => Ok(bundled)
+        }
+    }
+
+    /// Parse manifest from provided data, node URLs should use HTTPS.
+    fn parse(data: &str) -> Result<Self, String> {
+        let manifest = serde_json::from_str::<NodesManifest>(data).map_err(|e| e.to_string())?;
+        let valid = |url: &String| {
+            url::Url::parse(url).map(|u| u.scheme() == "https").unwrap_or(false)
+        };
+        if !manifest.main.iter().chain(manifest.test.iter()).all(valid) {
+            return Err("Invalid node URL".to_string());
+        }
+        Ok(manifest)
+    }
+
+    /// Get default node URLs for provided chain type.
+    pub fn urls(chain_type: &ChainTypes) -> Vec<String> {
+        if MANIFEST.read().is_none() {
+            match Self::load() {
+                Ok(m) => {
+                    let mut w_manifest = MANIFEST.write();
+                    *w_manifest = Some(m);
+                }
+                Err(e) => {
+                    error!("Nodes manifest: {}", e);
+                    return vec![];
+                }
+            }
+        }
+        let r_manifest = MANIFEST.read();
+        let manifest = r_manifest.as_ref().unwrap();
+        match chain_type {
+            ChainTypes::Mainnet => manifest.main.clone(),
+            _ => manifest.test.clone()
+        }
+    }
+
+    /// Load manifest update over Tor at separate thread if update delay passed,
+    /// should be called only when Tor is already used to not bootstrap it for every user.
+    pub fn update() {
+        let path = Settings::config_path(Self::FILE_NAME, None);
+        let updated = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| SystemTime::now().duration_since(t).ok())
+            .map(|d| d < UPDATE_DELAY)
+            .unwrap_or(false);
+        if updated {
+            return;
+        }
+        std::thread::spawn(move || {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    let data = Tor::get(MANIFEST_URL.to_string()).await;
+                    let sig = Tor::get(MANIFEST_SIG_URL.to_string()).await;
+                    if let (Some(data), Some(sig)) = (data, sig) {
+                        // Save only manifest signed with pinned key.
+                        if !verify(&data, sig.trim()) {
+                            error!("Nodes manifest: invalid signature");
+                            return;
+                        }
+                        let m = match Self::parse(&data) {
+                            Ok(m) => m,
+                            Err(_) => return
+                        };
+                        let version = Self::load().map(|m| m.version).unwrap_or(0);
+                        if m.version >= version && fs::write(path, data).is_ok() {
+                            let mut w_manifest = MANIFEST.write();
+                            *w_manifest = Some(m);
+                        }
+                    }
+                });
+        });
+    }
+}
+
+/// Verify manifest data with provided detached signature in hex format.
+fn verify(data: &String, sig: &str) -> bool {
+    let key = from_hex(MANIFEST_PUBLIC_KEY)
+        .ok()
+        .and_then(|k| <[u8; 32]>::try_from(k).ok())
+        .and_then(|k| VerifyingKey::from_bytes(&k).ok());
+    let sig = from_hex(sig)
+        .ok()
+        .and_then(|s| <[u8; 64]>::try_from(s).ok())
+        .map(|s| Signature::from_bytes(&s));
+    match (key, sig) {
+        (Some(key), Some(sig)) => key.verify(data.as_bytes(), &sig).is_ok(),
+        _ => false
+    }
+}
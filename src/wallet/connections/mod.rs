@@ -16,4 +16,7 @@ mod external;
 pub use external::ExternalConnection;
 
 mod config;
-pub use config::ConnectionsConfig;
\ No newline at end of file
+pub use config::ConnectionsConfig;
+
+mod manifest;
+pub use manifest::NodesManifest;
\ No newline at end of file
@@ -0,0 +1,149 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_core::core::FeeFields;
+use rand::Rng;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::wallet::types::{WalletData, WalletTxStats};
+
+/// Anonymized wallet data structure recorded to reproduce display issues at the UI.
+#[derive(Serialize, Deserialize)]
+pub struct WalletFixture {
+    /// Application version which recorded the fixture.
+    pub version: String,
+    /// Recorded wallet data.
+    pub data: WalletData,
+}
+
+impl WalletFixture {
+    /// Fixture file extension.
+    pub const FILE_EXT: &'static str = "json";
+
+    /// Record anonymized fixture from provided wallet data.
+    pub fn record(data: &WalletData) -> Option<Vec<u8>> {
+        let data = Self::anonymize_data(data);
+        let mut value = serde_json::to_value(data).ok()?;
+        if let Some(txs) = value.get_mut("txs").and_then(|txs| txs.as_array_mut()) {
+            for (index, tx) in txs.iter_mut().enumerate() {
+                Self::anonymize_tx(tx, index);
+            }
+        }
+        // Check if anonymized data can be loaded back.
+        let data = serde_json::from_value::<WalletData>(value).ok()?;
+        let fixture = WalletFixture {
+            version: crate::VERSION.to_string(),
+            data,
+        };
+        serde_json::to_vec_pretty(&fixture).ok()
+    }
+
+    /// Load wallet data from recorded fixture.
+    pub fn load(fixture: &[u8]) -> Option<WalletData> {
+        serde_json::from_slice::<WalletFixture>(fixture).ok().map(|f| f.data)
+    }
+
+    /// Strip balances and aggregated data, round amounts and shift times and heights
+    /// by random offset to not reveal real transactions.
+    fn anonymize_data(data: &WalletData) -> WalletData {
+        let mut rng = rand::thread_rng();
+        let time_shift: i64 = rng.gen_range(MIN_TIME_SHIFT..MIN_TIME_SHIFT * 2);
+        let height_shift: u64 = rng.gen_range(MIN_HEIGHT_SHIFT..MIN_HEIGHT_SHIFT * 2);
+        let shift_height = |h: u64| h.saturating_sub(height_shift);
+        let shift_time = |t: i64| {
+            let t = t - time_shift;
+            t - t.rem_euclid(TIME_STEP)
+        };
+
+        let mut data = data.clone();
+        let info = &mut data.info;
+        info.last_confirmed_height = shift_height(info.last_confirmed_height);
+        info.total = 0;
+        info.amount_awaiting_finalization = 0;
+        info.amount_awaiting_confirmation = 0;
+        info.amount_immature = 0;
+        info.amount_currently_spendable = 0;
+        info.amount_locked = 0;
+        info.amount_reverted = 0;
+        data.stats = WalletTxStats::default();
+        if let Some(txs) = data.txs.as_mut() {
+            for tx in txs.iter_mut() {
+                tx.amount = round_amount(tx.amount);
+                tx.height = tx.height.map(|h| if h == 0 { 0 } else { shift_height(h) });
+                tx.post_queued = tx.post_queued.map(shift_time);
+                tx.abandoned = tx.abandoned.map(shift_time);
+                tx.stages.signed = tx.stages.signed.map(shift_time);
+                tx.stages.finalized = tx.stages.finalized.map(shift_time);
+                tx.stages.posted = tx.stages.posted.map(shift_time);
+
+                let entry = &mut tx.data;
+                entry.amount_credited = round_amount(entry.amount_credited);
+                entry.amount_debited = round_amount(entry.amount_debited);
+                entry.fee = entry.fee.and_then(|f| FeeFields::new(0, round_amount(f.fee())).ok());
+                let created = entry.creation_ts.timestamp();
+                entry.creation_ts -= chrono::Duration::seconds(created - shift_time(created));
+                if let Some(ts) = entry.confirmation_ts.as_mut() {
+                    let confirmed = ts.timestamp();
+                    *ts -= chrono::Duration::seconds(confirmed - shift_time(confirmed));
+                }
+                entry.ttl_cutoff_height = entry.ttl_cutoff_height.map(shift_height);
+                entry.kernel_lookup_min_height = entry.kernel_lookup_min_height
+                    .map(shift_height);
+            }
+        }
+        data
+    }
+
+    /// Replace identifying transaction values with placeholders keeping data structure.
+    fn anonymize_tx(tx: &mut Value, index: usize) {
+        if let Some(memo) = tx.get_mut("memo").filter(|m| !m.is_null()) {
+            *memo = Value::from("memo");
+        }
+        if let Some(data) = tx.get_mut("data") {
+            if let Some(id) = data.get_mut("tx_slate_id").filter(|v| !v.is_null()) {
+                *id = Value::from(format!("00000000-0000-4000-8000-{:012x}", index));
+            }
+            if let Some(excess) = data.get_mut("kernel_excess").filter(|v| !v.is_null()) {
+                *excess = Value::from(format!("08{:064x}", index));
+            }
+            if let Some(stored) = data.get_mut("stored_tx").filter(|v| !v.is_null()) {
+                *stored = Value::from(format!("fixture_{}.grintx", index));
+            }
+            for key in ["messages", "payment_proof"] {
+                if let Some(v) = data.get_mut(key) {
+                    *v = Value::Null;
+                }
+            }
+        }
+    }
+}
+
+/// Round amount to the first significant digit.
+fn round_amount(amount: u64) -> u64 {
+    if amount < 10 {
+        return amount;
+    }
+    let step = 10u64.pow(amount.ilog10());
+    amount - amount % step
+}
+
+/// Step in seconds to round recorded times.
+const TIME_STEP: i64 = 60 * 60;
+
+/// Minimal random offset in seconds to shift recorded times back.
+const MIN_TIME_SHIFT: i64 = 30 * 24 * 60 * 60;
+
+/// Minimal random offset to shift recorded block heights back.
+const MIN_HEIGHT_SHIFT: u64 = 10_000;
@@ -0,0 +1,211 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use grin_api::{Handler, response, ResponseFuture};
+use hyper_old::body::HttpBody;
+use hyper_old::{Body, Request, StatusCode};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+
+use crate::wallet::types::ForeignApiRequest;
+use crate::wallet::Wallet;
+
+/// Foreign API handler wrapper to log incoming requests, limit their rate and body size,
+/// rejecting incoming dust when needed.
+pub struct ForeignAPIGuard {
+    /// Wrapped Foreign API handler.
+    inner: Arc<dyn Handler + Send + Sync>,
+    /// Wallet to save requests log.
+    wallet: Wallet,
+    /// Time of recently accepted requests by known method name.
+    recent: Arc<Mutex<HashMap<&'static str, VecDeque<Instant>>>>,
+}
+
+impl ForeignAPIGuard {
+    /// Maximal size of request body in bytes.
+    const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+    /// Maximal amount of requests of the same method per [`Self::RATE_PERIOD`].
+    const RATE_LIMIT: usize = 30;
+
+    /// Maximal amount of requests of all methods per [`Self::RATE_PERIOD`].
+    const TOTAL_RATE_LIMIT: usize = 120;
+
+    /// Known Foreign API methods limited separately, other methods are sharing one limit.
+    const METHODS: [&'static str; 4] = [
+        "check_version", "build_coinbase", "receive_tx", "finalize_tx"
+    ];
+
+    /// Name of unknown or missing method.
+    const UNKNOWN_METHOD: &'static str = "-";
+
+    /// Methods not limited by own rate, used by other wallets before sending.
+    const RATE_EXEMPT_METHODS: [&'static str; 1] = ["check_version"];
+
+    /// Period to check requests rate.
+    const RATE_PERIOD: Duration = Duration::from_secs(60);
+
+    /// Create new guard for provided handler and [`Wallet`].
+    pub fn new(inner: Arc<dyn Handler + Send + Sync>, wallet: Wallet) -> Self {
+        Self {
+            inner,
+            wallet,
+            recent: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Get known method name from request data or name of unknown method.
+    fn method(value: &Option<Value>) -> &'static str {
+        value.as_ref()
+            .and_then(|v| v["method"].as_str())
+            .and_then(|m| Self::METHODS.iter().find(|k| **k == m).copied())
+            .unwrap_or(Self::UNKNOWN_METHOD)
+    }
+
+    /// Check if request rate of provided method and total rate are not exceeded,
+    /// registering new request.
+    fn check_rate(recent: &Mutex<HashMap<&'static str, VecDeque<Instant>>>,
+                  method: &'static str) -> bool {
+        let now = Instant::now();
+        let mut w_recent = recent.lock();
+        // Remove expired requests of all methods.
+        w_recent.retain(|_, times| {
+            while times.front().map(|t| now.duration_since(*t) > Self::RATE_PERIOD)
+                .unwrap_or(false) {
+                times.pop_front();
+            }
+            !times.is_empty()
+        });
+        let total: usize = w_recent.values().map(|times| times.len()).sum();
+        if total >= Self::TOTAL_RATE_LIMIT {
+            return false;
+        }
+        let times = w_recent.entry(method).or_default();
+        if !Self::RATE_EXEMPT_METHODS.contains(&method) && times.len() >= Self::RATE_LIMIT {
+            return false;
+        }
+        times.push_back(now);
+        true
+    }
+
+    /// Get amount from Slate provided at request parameters.
+    fn slate_amount(slate: &Value) -> Option<u64> {
+        let amount = if slate["amt"].is_null() {
+            &slate["amount"]
+        } else {
+            &slate["amt"]
+        };
+        amount.as_u64().or(amount.as_str().and_then(|a| a.parse::<u64>().ok()))
+    }
+
+    /// Get host claimed by the client at request header, requests over Tor are coming
+    /// with Onion address, value is not verified and can be set to anything by the client.
+    fn claimed_host(req: &Request<Body>) -> String {
+        let host = req.headers()
+            .get("Host")
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or("")
+            .to_string();
+        if host.contains(".onion") {
+            "Tor".to_string()
+        } else if host.is_empty() {
+            "-".to_string()
+        } else {
+            host
+        }
+    }
+}
+
+impl Handler for ForeignAPIGuard {
+    fn post(&self, req: Request<Body>) -> ResponseFuture {
+        let inner = self.inner.clone();
+        let wallet = self.wallet.clone();
+        let recent = self.recent.clone();
+        Box::pin(async move {
+            let host = Self::claimed_host(&req);
+            let mut log = ForeignApiRequest {
+                method: "-".to_string(),
+                time: chrono::Utc::now().timestamp(),
+                host,
+                rejected: true,
+            };
+
+            // Check declared body size.
+            let declared_size = req.headers()
+                .get("Content-Length")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|h| h.parse::<usize>().ok())
+                .unwrap_or(0);
+            if declared_size > Self::MAX_BODY_SIZE {
+                wallet.log_foreign_api_request(log);
+                return response(StatusCode::PAYLOAD_TOO_LARGE, "").await;
+            }
+
+            // Read body until size limit.
+            let (parts, mut body) = req.into_parts();
+            let mut data: Vec<u8> = vec![];
+            while let Some(chunk) = body.data().await {
+                let chunk = chunk?;
+                if data.len() + chunk.len() > Self::MAX_BODY_SIZE {
+                    wallet.log_foreign_api_request(log);
+                    return response(StatusCode::PAYLOAD_TOO_LARGE, "").await;
+                }
+                data.extend_from_slice(&chunk);
+            }
+
+            // Save request method to check its rate.
+            let value = serde_json::from_slice::<Value>(&data).ok();
+            let method = Self::method(&value);
+            log.method = method.to_string();
+            if !Self::check_rate(&recent, method) {
+                wallet.log_foreign_api_request(log);
+                return response(StatusCode::TOO_MANY_REQUESTS, "").await;
+            }
+
+            // Reject incoming dust when needed.
+            if let Some(value) = value {
+                if log.method == "receive_tx" && wallet.reject_dust() {
+                    let amount = Self::slate_amount(&value["params"][0]);
+                    let min = wallet.min_receive_amount();
+                    if let (Some(amount), Some(min)) = (amount, min) {
+                        if amount < min {
+                            wallet.log_foreign_api_request(log);
+                            let res = json!({
+                                "jsonrpc": "2.0",
+                                "id": value["id"],
+                                "error": {
+                                    "code": -32000,
+                                    "message": "Amount is below minimal accepted amount",
+                                }
+                            });
+                            return response(StatusCode::OK, res.to_string()).await;
+                        }
+                    }
+                }
+            }
+            log.rejected = false;
+            wallet.log_foreign_api_request(log);
+
+            let req = Request::from_parts(parts, Body::from(data));
+            inner.post(req).await
+        })
+    }
+
+    fn options(&self, req: Request<Body>) -> ResponseFuture {
+        self.inner.options(req)
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use grin_wallet_libwallet::{Error, Slate, SlateState};
+
+use crate::wallet::types::WalletTransaction;
+use crate::wallet::Wallet;
+
+lazy_static! {
+    /// Registered handlers for incoming Slatepack messages.
+    static ref SLATE_HANDLERS: Arc<RwLock<Vec<Arc<dyn SlateHandler>>>> = Arc::new(
+        RwLock::new(vec![])
+    );
+}
+
+/// Handler of incoming Slatepack messages to extend processing of experimental slate types,
+/// e.g. multi-party transactions or atomic swaps.
+pub trait SlateHandler: Send + Sync {
+    /// Check if handler can process provided [`Slate`].
+    fn can_handle(&self, slate: &Slate) -> bool;
+
+    /// Process message with provided [`Slate`] for the wallet.
+    fn handle(&self,
+              wallet: &Wallet,
+              slate: &Slate,
+              message: &String) -> Result<WalletTransaction, Error>;
+}
+
+/// Pipeline to process incoming Slatepack messages.
+pub struct SlateHandlers;
+
+impl SlateHandlers {
+    /// Register handler for incoming messages not processed by default flows.
+    pub fn register(handler: impl SlateHandler + 'static) {
+        let mut w_handlers = SLATE_HANDLERS.write();
+        w_handlers.push(Arc::new(handler));
+    }
+
+    /// Process incoming message with default flow for standard and invoice transactions or
+    /// with registered handler for other slate states.
+    pub fn handle(wallet: &Wallet,
+                  slate: &Slate,
+                  message: &String) -> Result<WalletTransaction, Error> {
+        match slate.state {
+            SlateState::Standard1 => wallet.receive(message),
+            SlateState::Invoice1 => wallet.pay(message),
+            SlateState::Standard2 | SlateState::Invoice2 => wallet.finalize(message),
+            _ => {
+                if let Some(handler) = Self::find(slate) {
+                    return handler.handle(wallet, slate, message);
+                }
+                wallet.tx_by_slate(slate)
+                    .ok_or(Error::GenericError("Unsupported slate".to_string()))
+            }
+        }
+    }
+
+    /// Find registered handler for provided [`Slate`].
+    fn find(slate: &Slate) -> Option<Arc<dyn SlateHandler>> {
+        let r_handlers = SLATE_HANDLERS.read();
+        r_handlers.iter().find(|h| h.can_handle(slate)).cloned()
+    }
+}
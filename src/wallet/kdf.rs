@@ -0,0 +1,108 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use grin_util::{from_hex, ToHex, ZeroingString};
+use rand::Rng;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha512;
+
+/// Additional key derivation of wallet password before seed file encryption,
+/// parameters are stored at seed file header.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SeedKdf {
+    /// Amount of PBKDF2 rounds.
+    pub rounds: u32,
+    /// Salt in hex format.
+    pub salt: String,
+}
+
+/// Seed file field to store key derivation parameters.
+const HEADER_KEY: &'static str = "grim_kdf";
+
+impl SeedKdf {
+    /// Available amounts of key derivation rounds.
+    pub const ROUNDS: [u32; 2] = [300_000, 1_200_000];
+
+    /// Create key derivation parameters with random salt.
+    pub fn new(rounds: u32) -> Self {
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        Self {
+            rounds,
+            salt: salt.to_hex(),
+        }
+    }
+
+    /// Derive password to encrypt seed file.
+    pub fn derive(&self, password: &str) -> ZeroingString {
+        let salt = from_hex(&self.salt).unwrap_or_default();
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha512>(password.as_bytes(), &salt, self.rounds, &mut key);
+        ZeroingString::from(key.to_hex())
+    }
+
+    /// Read key derivation parameters from seed file header.
+    pub fn read(seed_path: &Path) -> Option<Self> {
+        let data = fs::read_to_string(seed_path).ok()?;
+        let value = serde_json::from_str::<Value>(&data).ok()?;
+        serde_json::from_value(value.get(HEADER_KEY)?.clone()).ok()
+    }
+
+    /// Save key derivation parameters at seed file header, removing header when not provided,
+    /// file is replaced atomically to not lose seed on failure.
+    pub fn write(kdf: Option<&Self>, seed_path: &Path) -> bool {
+        let data = match fs::read_to_string(seed_path) {
+            Ok(d) => d,
+            Err(_) => return false
+        };
+        let mut value = match serde_json::from_str::<Value>(&data) {
+            Ok(Value::Object(v)) => v,
+            _ => return false
+        };
+        match kdf.and_then(|k| serde_json::to_value(k).ok()) {
+            Some(header) => {
+                value.insert(HEADER_KEY.to_string(), header);
+            }
+            None => {
+                value.remove(HEADER_KEY);
+            }
+        }
+        let data = match serde_json::to_string_pretty(&value) {
+            Ok(d) => d,
+            Err(_) => return false
+        };
+        let tmp_path = seed_path.with_extension("tmp");
+        let written = File::create(&tmp_path).and_then(|mut f| {
+            f.write_all(data.as_bytes())?;
+            f.sync_all()
+        });
+        if written.is_err() || fs::rename(&tmp_path, seed_path).is_err() {
+            let _ = fs::remove_file(&tmp_path);
+            return false;
+        }
+        true
+    }
+
+    /// Get password to decrypt seed file, applying key derivation from its header.
+    pub fn seed_password(seed_path: &Path, password: &str) -> ZeroingString {
+        match Self::read(seed_path) {
+            Some(kdf) => kdf.derive(password),
+            None => ZeroingString::from(password)
+        }
+    }
+}
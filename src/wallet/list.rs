@@ -16,6 +16,7 @@ use grin_core::global::ChainTypes;
 
 use crate::AppConfig;
 use crate::wallet::{Wallet, WalletConfig};
+use crate::wallet::types::WalletsBalance;
 
 /// [`Wallet`] list container.
 #[derive(Clone)]
@@ -64,7 +65,12 @@ impl WalletList {
 
     /// Get [`Wallet`] list for current [`ChainTypes`].
     pub fn list(&self) -> &Vec<Wallet> {
-        if AppConfig::chain_type() == ChainTypes::Mainnet {
+        self.chain_list(AppConfig::chain_type())
+    }
+
+    /// Get [`Wallet`] list for provided [`ChainTypes`].
+    pub fn chain_list(&self, chain_type: ChainTypes) -> &Vec<Wallet> {
+        if chain_type == ChainTypes::Mainnet {
             &self.main_list
         } else {
             &self.test_list
@@ -96,4 +102,25 @@ impl WalletList {
             }
         }
     }
+
+    /// Check if any wallet is running tasks changing its state.
+    pub fn has_active_tasks(&self) -> bool {
+        self.main_list.iter().chain(self.test_list.iter()).any(|w| w.has_active_tasks())
+    }
+
+    /// Calculate summary balance of opened wallets for current [`ChainTypes`].
+    pub fn balance(&self) -> WalletsBalance {
+        let mut balance = WalletsBalance::default();
+        for w in self.list() {
+            if !w.is_open() || w.is_closing() {
+                continue;
+            }
+            if let Some((spendable, awaiting)) = w.balance() {
+                balance.spendable += spendable;
+                balance.awaiting += awaiting;
+                balance.wallets.push((w.get_config().name, spendable, awaiting));
+            }
+        }
+        balance
+    }
 }
\ No newline at end of file
@@ -29,7 +29,37 @@ pub use config::*;
 mod list;
 pub use list::*;
 
+mod status;
+pub use status::StatusAPIHandler;
+
+mod foreign;
+pub use foreign::ForeignAPIGuard;
+
+mod owner;
+pub use owner::OwnerAPIHandler;
+
 mod utils;
 pub use utils::WalletUtils;
 
+mod uri;
+pub use uri::PaymentUri;
+
+mod handler;
+pub use handler::{SlateHandler, SlateHandlers};
+
+mod sync;
+pub use sync::{SyncScheduler, SyncWorker};
+
+mod fixture;
+pub use fixture::WalletFixture;
+
+mod kdf;
+pub use kdf::SeedKdf;
+
+mod cli;
+pub use cli::CliWallet;
+
+mod price;
+pub use price::{FiatCurrency, FiatRate, PriceFeed};
+
 pub mod store;
\ No newline at end of file
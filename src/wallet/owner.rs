@@ -0,0 +1,144 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_api::{Handler, json_response, response, ResponseFuture};
+use hyper_old::body::HttpBody;
+use hyper_old::{Body, Request, StatusCode};
+use serde_json::{json, Value};
+
+use crate::wallet::{Wallet, WalletUtils};
+
+/// Read-only wallet Owner API JSON-RPC handler for external tools,
+/// protected by token from wallet config.
+///
+/// Supported methods: `retrieve_summary_info`, `retrieve_txs`, `accounts`
+/// and `get_slatepack_address`.
+pub struct OwnerAPIHandler {
+    /// Wallet to provide data for.
+    wallet: Wallet,
+}
+
+impl OwnerAPIHandler {
+    /// Route of Owner API.
+    pub const ROUTE: &'static str = "/v1/owner";
+
+    /// Maximal size of request body in bytes.
+    const MAX_BODY_SIZE: usize = 64 * 1024;
+
+    /// Create new handler for provided [`Wallet`].
+    pub fn new(wallet: Wallet) -> Self {
+        Self { wallet }
+    }
+
+    /// Check if request contains valid token at authorization header in `Bearer <token>` form.
+    fn authorized(wallet: &Wallet, req: &Request<Body>) -> bool {
+        let token = match wallet.owner_api_token() {
+            None => return false,
+            Some(t) => t
+        };
+        req.headers()
+            .get("Authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(|v| WalletUtils::constant_time_eq(v.as_bytes(), token.as_bytes()))
+            .unwrap_or(false)
+    }
+
+    /// Call API method, returning result or error code with message.
+    fn call(wallet: &Wallet, method: &str) -> Result<Value, (i64, String)> {
+        let not_loaded = |what: &str| (-32000, format!("Wallet {} not loaded", what));
+        match method {
+            "retrieve_summary_info" => {
+                let data = wallet.get_data().ok_or(not_loaded("data is"))?;
+                Ok(json!({
+                    "account": wallet.get_config().account,
+                    "height": data.info.last_confirmed_height,
+                    "total": data.info.total,
+                    "spendable": data.info.amount_currently_spendable,
+                    "awaiting_confirmation": data.info.amount_awaiting_confirmation,
+                    "awaiting_finalization": data.info.amount_awaiting_finalization,
+                    "immature": data.info.amount_immature,
+                    "locked": data.info.amount_locked,
+                }))
+            }
+            "retrieve_txs" => {
+                // Read all transactions from database, loaded data contains only last page.
+                let txs = wallet.retrieve_account_txs()
+                    .map_err(|_| not_loaded("transactions are"))?;
+                serde_json::to_value(txs).map_err(|e| (-32000, e.to_string()))
+            }
+            "accounts" => {
+                let accounts = wallet.accounts().iter().map(|a| {
+                    json!({
+                        "label": a.label,
+                        "path": a.path,
+                        "spendable": a.spendable_amount,
+                    })
+                }).collect::<Vec<Value>>();
+                Ok(Value::Array(accounts))
+            }
+            "get_slatepack_address" => {
+                Ok(json!(wallet.slatepack_address()))
+            }
+            _ => Err((-32601, "Method not found".to_string()))
+        }
+    }
+}
+
+impl Handler for OwnerAPIHandler {
+    fn post(&self, req: Request<Body>) -> ResponseFuture {
+        let wallet = self.wallet.clone();
+        Box::pin(async move {
+            if wallet.owner_api_token().is_none() {
+                return response(StatusCode::NOT_FOUND, "").await;
+            }
+            if !Self::authorized(&wallet, &req) {
+                return response(StatusCode::UNAUTHORIZED, "").await;
+            }
+
+            // Read body until size limit.
+            let mut body = req.into_body();
+            let mut data: Vec<u8> = vec![];
+            while let Some(chunk) = body.data().await {
+                let chunk = chunk?;
+                if data.len() + chunk.len() > Self::MAX_BODY_SIZE {
+                    return response(StatusCode::PAYLOAD_TOO_LARGE, "").await;
+                }
+                data.extend_from_slice(&chunk);
+            }
+
+            let request = match serde_json::from_slice::<Value>(&data) {
+                Ok(v) => v,
+                Err(_) => return response(StatusCode::BAD_REQUEST, "").await
+            };
+            let method = request["method"].as_str().unwrap_or("");
+            let res = match Self::call(&wallet, method) {
+                Ok(result) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "result": result,
+                }),
+                Err((code, message)) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": {
+                        "code": code,
+                        "message": message,
+                    }
+                })
+            };
+            json_response(&res).await
+        })
+    }
+}
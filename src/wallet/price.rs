@@ -0,0 +1,181 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use grin_core::consensus::GRIN_BASE;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::AppConfig;
+use crate::tor::Tor;
+
+lazy_static! {
+    /// Static thread-aware state of [`PriceFeed`] to be updated from separate thread.
+    static ref PRICE_FEED_STATE: Arc<PriceFeed> = Arc::new(PriceFeed::default());
+}
+
+/// Fiat currency to show amounts in.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum FiatCurrency {
+    Usd,
+    Eur,
+}
+
+impl FiatCurrency {
+    /// All supported currencies.
+    pub const ALL: [FiatCurrency; 2] = [FiatCurrency::Usd, FiatCurrency::Eur];
+
+    /// Get currency code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            FiatCurrency::Usd => "USD",
+            FiatCurrency::Eur => "EUR"
+        }
+    }
+}
+
+/// Price of 1 ツ in fiat currency.
+#[derive(Clone, Copy)]
+pub struct FiatRate {
+    /// Currency of the price.
+    pub currency: FiatCurrency,
+    /// Price of 1 ツ.
+    pub price: f64,
+    /// Time of price update at provider in seconds.
+    pub time: i64,
+}
+
+impl FiatRate {
+    /// Convert amount in nanogrin to fiat value.
+    pub fn to_fiat(&self, amount: u64) -> f64 {
+        amount as f64 / GRIN_BASE as f64 * self.price
+    }
+
+    /// Convert fiat value to amount in nanogrin.
+    pub fn from_fiat(&self, value: f64) -> Option<u64> {
+        if self.price <= 0.0 || !value.is_finite() || value < 0.0 {
+            return None;
+        }
+        Some((value / self.price * GRIN_BASE as f64).round() as u64)
+    }
+}
+
+/// Loads price of ツ in selected fiat currency from public API.
+pub struct PriceFeed {
+    /// Last received price.
+    rate: RwLock<Option<FiatRate>>,
+    /// Flag to check if last request failed.
+    error: AtomicBool,
+    /// Flag to check if request is in progress.
+    loading: AtomicBool,
+    /// Time of last request in seconds.
+    last_check: AtomicI64,
+}
+
+impl Default for PriceFeed {
+    fn default() -> Self {
+        Self {
+            rate: RwLock::new(None),
+            error: AtomicBool::new(false),
+            loading: AtomicBool::new(false),
+            last_check: AtomicI64::new(0),
+        }
+    }
+}
+
+impl PriceFeed {
+    /// Price API URL.
+    const API_URL: &'static str = "https://api.coingecko.com/api/v3/simple/price";
+
+    /// Interval in seconds between API requests.
+    const CHECK_INTERVAL: i64 = 300;
+
+    /// Get last received price for selected currency.
+    pub fn rate() -> Option<FiatRate> {
+        let currency = AppConfig::fiat_currency()?;
+        let r_rate = PRICE_FEED_STATE.rate.read();
+        r_rate.filter(|r| r.currency == currency)
+    }
+
+    /// Check if last request failed.
+    pub fn has_error() -> bool {
+        PRICE_FEED_STATE.error.load(Ordering::Relaxed)
+    }
+
+    /// Check if request is in progress.
+    pub fn is_loading() -> bool {
+        PRICE_FEED_STATE.loading.load(Ordering::Relaxed)
+    }
+
+    /// Clear price to request it again for selected currency.
+    pub fn refresh() {
+        PRICE_FEED_STATE.last_check.store(0, Ordering::Relaxed);
+        PRICE_FEED_STATE.error.store(false, Ordering::Relaxed);
+        let mut w_rate = PRICE_FEED_STATE.rate.write();
+        *w_rate = None;
+    }
+
+    /// Request price for selected currency when interval passed.
+    pub fn check_periodically(ui_ctx: &egui::Context) {
+        let currency = match AppConfig::fiat_currency() {
+            Some(c) => c,
+            None => return
+        };
+        let now = chrono::Utc::now().timestamp();
+        let last_check = PRICE_FEED_STATE.last_check.load(Ordering::Relaxed);
+        if now - last_check < Self::CHECK_INTERVAL || Self::is_loading() {
+            return;
+        }
+        PRICE_FEED_STATE.last_check.store(now, Ordering::Relaxed);
+        PRICE_FEED_STATE.loading.store(true, Ordering::Relaxed);
+        let ui_ctx = ui_ctx.clone();
+        std::thread::spawn(move || {
+            let rate = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    Self::request(currency).await
+                });
+            // Skip result when currency was changed during request.
+            if AppConfig::fiat_currency() == Some(currency) {
+                PRICE_FEED_STATE.error.store(rate.is_none(), Ordering::Relaxed);
+                if rate.is_some() {
+                    let mut w_rate = PRICE_FEED_STATE.rate.write();
+                    *w_rate = rate;
+                }
+            }
+            PRICE_FEED_STATE.loading.store(false, Ordering::Relaxed);
+            ui_ctx.request_repaint();
+        });
+    }
+
+    /// Request price for provided currency over Tor.
+    async fn request(currency: FiatCurrency) -> Option<FiatRate> {
+        let code = currency.code().to_lowercase();
+        let url = format!("{}?ids=grin&vs_currencies={}&include_last_updated_at=true",
+                          Self::API_URL,
+                          code);
+        let data = Tor::get(url).await?;
+        let value = serde_json::from_str::<Value>(&data).ok()?;
+        let price = value["grin"][code.as_str()].as_f64().filter(|p| *p > 0.0)?;
+        let time = value["grin"]["last_updated_at"]
+            .as_i64()
+            .unwrap_or(chrono::Utc::now().timestamp());
+        Some(FiatRate { currency, price, time })
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_api::{Handler, json_response, response, ResponseFuture};
+use hyper_old::{Body, Request, StatusCode};
+use serde_json::json;
+
+use crate::tor::Tor;
+use crate::wallet::{Wallet, WalletUtils};
+
+/// Read-only wallet status API handler served at local Owner API server,
+/// protected by token from wallet config.
+pub struct StatusAPIHandler {
+    /// Wallet to provide status for.
+    wallet: Wallet,
+}
+
+impl StatusAPIHandler {
+    /// Route of status API.
+    pub const ROUTE: &'static str = "/v1/status";
+
+    /// Create new handler for provided [`Wallet`].
+    pub fn new(wallet: Wallet) -> Self {
+        Self { wallet }
+    }
+
+    /// Check if request contains valid token at authorization header.
+    fn authorized(&self, req: &Request<Body>) -> bool {
+        let token = match self.wallet.get_config().status_api_token {
+            None => return false,
+            Some(t) => t
+        };
+        req.headers()
+            .get("Authorization")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| {
+                let value = v.trim_start_matches("Bearer ").trim();
+                WalletUtils::constant_time_eq(value.as_bytes(), token.as_bytes())
+            })
+            .unwrap_or(false)
+    }
+}
+
+impl Handler for StatusAPIHandler {
+    fn get(&self, req: Request<Body>) -> ResponseFuture {
+        if self.wallet.get_config().status_api_token.is_none() {
+            return response(StatusCode::NOT_FOUND, "");
+        }
+        if !self.authorized(&req) {
+            return response(StatusCode::UNAUTHORIZED, "");
+        }
+        let config = self.wallet.get_config();
+        let balance = self.wallet.get_data().map(|data| {
+            let info = data.info;
+            json!({
+                "total": info.total,
+                "spendable": info.amount_currently_spendable,
+                "awaiting_confirmation": info.amount_awaiting_confirmation,
+                "awaiting_finalization": info.amount_awaiting_finalization,
+                "locked": info.amount_locked,
+            })
+        });
+        let height = self.wallet.get_data().map(|data| data.info.last_confirmed_height);
+        let status = json!({
+            "name": config.name,
+            "account": config.account,
+            "balance": balance,
+            "height": height,
+            "syncing": self.wallet.syncing(),
+            "sync_error": self.wallet.sync_error(),
+            "listener": {
+                "api_port": self.wallet.foreign_api_port(),
+                "tor": Tor::is_service_running(&self.wallet.identifier()),
+                "address": self.wallet.slatepack_address(),
+            }
+        });
+        json_response(&status)
+    }
+}
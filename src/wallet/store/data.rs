@@ -0,0 +1,44 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_util::{from_hex, ToHex};
+
+use crate::wallet::store::{ExtraDb, TypedStore};
+
+/// Storage of encrypted wallet data snapshot to show at next launch before sync.
+pub struct WalletDataStore {
+    store: TypedStore<String>
+}
+
+impl WalletDataStore {
+    /// Key of data snapshot at database.
+    const SNAPSHOT_KEY: u32 = 0;
+
+    /// Create new wallet data storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("wallet_data")
+        }
+    }
+
+    /// Read encrypted data snapshot from database.
+    pub fn read_snapshot(&self) -> Option<Vec<u8>> {
+        self.store.get(Self::SNAPSHOT_KEY).and_then(|hex| from_hex(&hex).ok())
+    }
+
+    /// Write encrypted data snapshot to database.
+    pub fn write_snapshot(&self, data: &[u8]) {
+        self.store.put(Self::SNAPSHOT_KEY, &data.to_hex());
+    }
+}
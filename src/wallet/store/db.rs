@@ -0,0 +1,265 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use rkv::backend::{BackendEnvironmentBuilder, BackendInfo, Lmdb, LmdbDatabase, LmdbEnvironment,
+                   LmdbRwTransaction};
+use rkv::{IntegerStore, Manager, Rkv, StoreError, StoreOptions, Value, Writer};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Wallet extra database to keep application data by transaction or other integer identifier,
+/// migrated to [`ExtraDb::VERSION`] on opening.
+#[derive(Clone)]
+pub struct ExtraDb {
+    env_arc: Arc<RwLock<Rkv<LmdbEnvironment>>>
+}
+
+/// Database migration from previous version.
+type Migration = fn(&ExtraDb);
+
+/// Migrations to apply in order, where migration at index `i` upgrades version `i + 1` to `i + 2`,
+/// new migration should be added here on any change of existing stores format.
+const MIGRATIONS: [Migration; 0] = [];
+
+impl ExtraDb {
+    /// Initial database version, also used for databases created before versioning.
+    const INITIAL_VERSION: u64 = 1;
+    /// Current database version.
+    pub const VERSION: u64 = Self::INITIAL_VERSION + MIGRATIONS.len() as u64;
+
+    /// Name of the store with database metadata.
+    const META_STORE: &'static str = "meta";
+    /// Key of database version at metadata store.
+    const VERSION_KEY: u32 = 0;
+
+    /// Maximal amount of stores at database.
+    const MAX_STORES: u32 = 32;
+    /// Initial size of database memory map in bytes.
+    const INITIAL_MAP_SIZE: usize = 16 * 1024 * 1024;
+    /// Maximal amount of memory map growth attempts on single write.
+    const MAX_GROW_ATTEMPTS: usize = 4;
+
+    /// Open database at provided directory, applying migrations if needed.
+    pub fn open(dir: String) -> Self {
+        let mut manager = Manager::<LmdbEnvironment>::singleton().write().unwrap();
+        let mut builder = Rkv::environment_builder::<Lmdb>();
+        builder.set_max_dbs(Self::MAX_STORES);
+        builder.set_map_size(Self::INITIAL_MAP_SIZE);
+        let env_arc = manager.get_or_create_from_builder(std::path::Path::new(&dir),
+                                                         builder,
+                                                         Rkv::from_builder::<Lmdb>).unwrap();
+        let db = Self { env_arc };
+        db.migrate();
+        db
+    }
+
+    /// Double size of database memory map when it is full.
+    fn grow(env_arc: &Arc<RwLock<Rkv<LmdbEnvironment>>>) {
+        // Exclusive access guarantees no active transactions while resizing.
+        let env = env_arc.write().unwrap();
+        let size = env.info()
+            .map(|i| i.map_size())
+            .unwrap_or(Self::INITIAL_MAP_SIZE);
+        let _ = env.set_map_size(size * 2);
+    }
+
+    /// Open typed store with provided name.
+    pub fn store<V: StoreValue>(&self, name: &str) -> TypedStore<V> {
+        let env = self.env_arc.read().unwrap();
+        let store = env.open_integer(name, StoreOptions::create()).unwrap();
+        TypedStore {
+            env_arc: self.env_arc.clone(),
+            store,
+            value: PhantomData
+        }
+    }
+
+    /// Apply migrations from saved database version.
+    fn migrate(&self) {
+        let meta = self.store::<u64>(Self::META_STORE);
+        let saved_version = meta.get(Self::VERSION_KEY);
+        if saved_version.map(|v| v >= Self::VERSION).unwrap_or(false) {
+            return;
+        }
+        let version = saved_version.unwrap_or(Self::INITIAL_VERSION);
+        let applied = version.saturating_sub(Self::INITIAL_VERSION) as usize;
+        for migration in MIGRATIONS.iter().skip(applied) {
+            migration(self);
+        }
+        meta.put(Self::VERSION_KEY, &Self::VERSION);
+    }
+}
+
+/// Value which can be saved at [`TypedStore`].
+pub trait StoreValue: Sized {
+    /// Write value to provided callback as database value.
+    fn write<T>(&self, f: impl FnOnce(&Value) -> T) -> T;
+
+    /// Read value from database value.
+    fn read(value: Value) -> Option<Self>;
+}
+
+impl StoreValue for u64 {
+    fn write<T>(&self, f: impl FnOnce(&Value) -> T) -> T {
+        f(&Value::U64(*self))
+    }
+
+    fn read(value: Value) -> Option<Self> {
+        match value {
+            Value::U64(v) => Some(v),
+            _ => None
+        }
+    }
+}
+
+impl StoreValue for i64 {
+    fn write<T>(&self, f: impl FnOnce(&Value) -> T) -> T {
+        f(&Value::I64(*self))
+    }
+
+    fn read(value: Value) -> Option<Self> {
+        match value {
+            Value::I64(v) => Some(v),
+            _ => None
+        }
+    }
+}
+
+impl StoreValue for bool {
+    fn write<T>(&self, f: impl FnOnce(&Value) -> T) -> T {
+        f(&Value::Bool(*self))
+    }
+
+    fn read(value: Value) -> Option<Self> {
+        match value {
+            Value::Bool(v) => Some(v),
+            _ => None
+        }
+    }
+}
+
+impl StoreValue for String {
+    fn write<T>(&self, f: impl FnOnce(&Value) -> T) -> T {
+        f(&Value::Str(self.as_str()))
+    }
+
+    fn read(value: Value) -> Option<Self> {
+        match value {
+            Value::Str(v) => Some(v.to_string()),
+            _ => None
+        }
+    }
+}
+
+/// Structured value saved at [`TypedStore`] in JSON format.
+pub struct Json<T>(pub T);
+
+impl<T: Serialize + DeserializeOwned> StoreValue for Json<T> {
+    fn write<R>(&self, f: impl FnOnce(&Value) -> R) -> R {
+        let json = serde_json::to_string(&self.0).unwrap();
+        f(&Value::Json(json.as_str()))
+    }
+
+    fn read(value: Value) -> Option<Self> {
+        match value {
+            Value::Json(v) => serde_json::from_str::<T>(v).ok().map(Json),
+            _ => None
+        }
+    }
+}
+
+/// Key-value storage of typed values by integer identifier at [`ExtraDb`].
+pub struct TypedStore<V: StoreValue> {
+    env_arc: Arc<RwLock<Rkv<LmdbEnvironment>>>,
+    store: IntegerStore<LmdbDatabase, u32>,
+    value: PhantomData<V>
+}
+
+impl<V: StoreValue> TypedStore<V> {
+    /// Read value from database.
+    pub fn get(&self, id: u32) -> Option<V> {
+        let env = self.env_arc.read().unwrap();
+        let reader = env.read().unwrap();
+        match self.store.get(&reader, id) {
+            Ok(Some(value)) => V::read(value),
+            _ => None
+        }
+    }
+
+    /// Write value to database.
+    pub fn put(&self, id: u32, value: &V) {
+        self.write(|writer| value.write(|v| self.store.put(writer, id, v)));
+    }
+
+    /// Remove value from database.
+    pub fn delete(&self, id: u32) {
+        self.write(|writer| {
+            let _ = self.store.delete(writer, id);
+            Ok(())
+        });
+    }
+
+    /// Read values for provided identifiers with single transaction.
+    pub fn get_many(&self, ids: &[u32]) -> HashMap<u32, V> {
+        let env = self.env_arc.read().unwrap();
+        let reader = env.read().unwrap();
+        ids.iter().filter_map(|id| {
+            match self.store.get(&reader, *id) {
+                Ok(Some(value)) => V::read(value).map(|v| (*id, v)),
+                _ => None
+            }
+        }).collect()
+    }
+
+    /// Write values with single transaction.
+    pub fn put_many(&self, values: &[(u32, V)]) {
+        self.write(|writer| {
+            for (id, value) in values {
+                value.write(|v| self.store.put(writer, *id, v))?;
+            }
+            Ok(())
+        });
+    }
+
+    /// Remove values with single transaction.
+    pub fn delete_many(&self, ids: &[u32]) {
+        self.write(|writer| {
+            for id in ids {
+                let _ = self.store.delete(writer, *id);
+            }
+            Ok(())
+        });
+    }
+
+    /// Run write transaction, growing database memory map and retrying when it is full.
+    fn write(&self, f: impl Fn(&mut Writer<LmdbRwTransaction>) -> Result<(), StoreError>) {
+        for _ in 0..=ExtraDb::MAX_GROW_ATTEMPTS {
+            let res = {
+                let env = self.env_arc.read().unwrap();
+                let mut writer = match env.write() {
+                    Ok(w) => w,
+                    Err(_) => return
+                };
+                f(&mut writer).and_then(|_| writer.commit())
+            };
+            match res {
+                Err(StoreError::MapFull) => ExtraDb::grow(&self.env_arc),
+                _ => return
+            }
+        }
+    }
+}
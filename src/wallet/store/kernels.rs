@@ -0,0 +1,75 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::wallet::store::{ExtraDb, Json, TypedStore};
+
+/// Lookup state of transaction kernel which was not found at node.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct KernelMiss {
+    /// Amount of lookup attempts.
+    pub attempts: u32,
+    /// Time to retry lookup.
+    pub retry_time: i64,
+}
+
+/// Cache of transaction kernels lookup results by transaction identifier.
+pub struct KernelStore {
+    /// Confirmation heights of found kernels.
+    heights: TypedStore<u64>,
+    /// Lookup state of not found kernels.
+    misses: TypedStore<Json<KernelMiss>>
+}
+
+impl KernelStore {
+    /// Create new kernels storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        let db = ExtraDb::open(dir);
+        Self {
+            heights: db.store("tx_height"),
+            misses: db.store("kernel_miss")
+        }
+    }
+
+    /// Read confirmation heights of found kernels for provided transactions.
+    pub fn read_heights(&self, ids: &[u32]) -> HashMap<u32, u64> {
+        self.heights.get_many(ids)
+    }
+
+    /// Write confirmation heights of found kernels, removing lookup misses.
+    pub fn write_heights(&self, heights: &[(u32, u64)]) {
+        if heights.is_empty() {
+            return;
+        }
+        self.heights.put_many(heights);
+        let ids = heights.iter().map(|(id, _)| *id).collect::<Vec<u32>>();
+        self.misses.delete_many(&ids);
+    }
+
+    /// Read lookup state of not found kernels for provided transactions.
+    pub fn read_misses(&self, ids: &[u32]) -> HashMap<u32, KernelMiss> {
+        self.misses.get_many(ids).into_iter().map(|(id, miss)| (id, miss.0)).collect()
+    }
+
+    /// Write lookup state of not found kernels.
+    pub fn write_misses(&self, misses: &[(u32, KernelMiss)]) {
+        if misses.is_empty() {
+            return;
+        }
+        let values = misses.iter().map(|(id, miss)| (*id, Json(*miss))).collect::<Vec<_>>();
+        self.misses.put_many(&values);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::wallet::store::{ExtraDb, Json, TypedStore};
+use crate::wallet::types::RepairLogEntry;
+
+/// Storage of completed wallet repairs history.
+pub struct RepairLogStore {
+    /// Repairs by sequence number starting from 1.
+    entries: TypedStore<Json<RepairLogEntry>>,
+    /// Amount of saved repairs.
+    count: TypedStore<u64>
+}
+
+/// Key of saved repairs amount.
+const COUNT_KEY: u32 = 0;
+
+impl RepairLogStore {
+    /// Maximal amount of repairs to keep at history.
+    const MAX_ENTRIES: u64 = 50;
+
+    /// Create new repair history storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        let db = ExtraDb::open(dir);
+        Self {
+            entries: db.store("repair_log"),
+            count: db.store("repair_log_count")
+        }
+    }
+
+    /// Save completed repair, removing oldest entry when history is full.
+    pub fn append(&self, entry: RepairLogEntry) {
+        let count = self.count.get(COUNT_KEY).unwrap_or(0) + 1;
+        self.entries.put(count as u32, &Json(entry));
+        self.count.put(COUNT_KEY, &count);
+        if count > Self::MAX_ENTRIES {
+            self.entries.delete((count - Self::MAX_ENTRIES) as u32);
+        }
+    }
+
+    /// Read saved repairs from newest to oldest.
+    pub fn read_all(&self) -> Vec<RepairLogEntry> {
+        let count = self.count.get(COUNT_KEY).unwrap_or(0);
+        let first = count.saturating_sub(Self::MAX_ENTRIES) + 1;
+        let ids = (first..=count).map(|i| i as u32).collect::<Vec<u32>>();
+        let mut entries = self.entries.get_many(&ids);
+        ids.iter().rev().filter_map(|id| entries.remove(id).map(|e| e.0)).collect()
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::wallet::store::{ExtraDb, Json, TypedStore};
+use crate::wallet::types::{TxStage, TxStageTimes, TxStatsState};
+
+/// Transaction user memo storage.
+pub struct TxMemoStore {
+    store: TypedStore<String>
+}
+
+impl TxMemoStore {
+    /// Create new transaction memo storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_memo")
+        }
+    }
+
+    /// Read transaction memo from database.
+    pub fn read_tx_memo(&self, id: u32) -> Option<String> {
+        self.store.get(id)
+    }
+
+    /// Write transaction memo to database, empty value removes memo.
+    pub fn write_tx_memo(&self, id: u32, memo: &str) {
+        if memo.is_empty() {
+            self.store.delete(id);
+        } else {
+            self.store.put(id, &memo.to_string());
+        }
+    }
+}
+
+/// Storage of finalized transactions waiting to be posted when node will be available.
+pub struct TxPostQueueStore {
+    store: TypedStore<i64>
+}
+
+impl TxPostQueueStore {
+    /// Create new transaction post queue storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_post_queue")
+        }
+    }
+
+    /// Read time when transaction was queued from database.
+    pub fn read_queued_time(&self, id: u32) -> Option<i64> {
+        self.store.get(id)
+    }
+
+    /// Write transaction to the queue with provided time.
+    pub fn queue(&self, id: u32, time: i64) {
+        self.store.put(id, &time);
+    }
+
+    /// Remove transaction from the queue.
+    pub fn remove(&self, id: u32) {
+        self.store.delete(id);
+    }
+}
+
+/// Storage of automatic rebroadcast attempts for finalized transactions.
+pub struct TxRepostStore {
+    time_store: TypedStore<i64>,
+    count_store: TypedStore<u64>
+}
+
+impl TxRepostStore {
+    /// Create new transaction rebroadcast storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        let db = ExtraDb::open(dir);
+        Self {
+            time_store: db.store("tx_repost_time"),
+            count_store: db.store("tx_repost_count")
+        }
+    }
+
+    /// Read time of last post and amount of rebroadcast attempts from database.
+    pub fn read(&self, id: u32) -> Option<(i64, u64)> {
+        let time = self.time_store.get(id)?;
+        let count = self.count_store.get(id).unwrap_or(0);
+        Some((time, count))
+    }
+
+    /// Write time of last post and amount of rebroadcast attempts.
+    pub fn write(&self, id: u32, time: i64, count: u64) {
+        self.time_store.put(id, &time);
+        self.count_store.put(id, &count);
+    }
+
+    /// Remove transaction rebroadcast data.
+    pub fn remove(&self, id: u32) {
+        self.time_store.delete(id);
+        self.count_store.delete(id);
+    }
+}
+
+/// Storage of issued invoices marked as abandoned by the user.
+pub struct TxAbandonedStore {
+    store: TypedStore<i64>
+}
+
+impl TxAbandonedStore {
+    /// Create new abandoned invoices storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_abandoned")
+        }
+    }
+
+    /// Read time when invoice was marked as abandoned from database.
+    pub fn read_abandoned_time(&self, id: u32) -> Option<i64> {
+        self.store.get(id)
+    }
+
+    /// Mark invoice as abandoned at provided time.
+    pub fn abandon(&self, id: u32, time: i64) {
+        self.store.put(id, &time);
+    }
+
+    /// Remove abandoned mark from invoice.
+    pub fn remove(&self, id: u32) {
+        self.store.delete(id);
+    }
+}
+
+/// Storage of transaction stage times.
+pub struct TxStageStore {
+    store: TypedStore<Json<TxStageTimes>>
+}
+
+impl TxStageStore {
+    /// Create new transaction stages storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_stages")
+        }
+    }
+
+    /// Read transaction stage times from database.
+    pub fn read(&self, id: u32) -> TxStageTimes {
+        self.store.get(id).map(|t| t.0).unwrap_or_default()
+    }
+
+    /// Save time of transaction stage if it was not saved before, returning all stage times.
+    pub fn write(&self, id: u32, stage: TxStage, time: i64) -> TxStageTimes {
+        let mut times = self.read(id);
+        times.set(stage, time);
+        self.store.put(id, &Json(times));
+        times
+    }
+}
+
+/// Storage of data aggregated from account transactions.
+pub struct TxStatsStore {
+    store: TypedStore<Json<TxStatsState>>
+}
+
+impl TxStatsStore {
+    /// Key of aggregated data at database.
+    const STATS_KEY: u32 = 0;
+
+    /// Create new transactions data storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_stats")
+        }
+    }
+
+    /// Read aggregated data from database.
+    pub fn read(&self) -> Option<TxStatsState> {
+        self.store.get(Self::STATS_KEY).map(|s| s.0)
+    }
+
+    /// Write aggregated data to database.
+    pub fn write(&self, state: &TxStatsState) {
+        self.store.put(Self::STATS_KEY, &Json(state.clone()));
+    }
+}
+
+/// Storage of confirmation time of last received transaction user was notified about.
+pub struct TxNotifyStore {
+    store: TypedStore<i64>
+}
+
+impl TxNotifyStore {
+    /// Key of confirmation time at database.
+    const NOTIFIED_KEY: u32 = 0;
+
+    /// Create new received transactions notification storage at provided directory.
+    pub fn new(dir: String) -> Self {
+        Self {
+            store: ExtraDb::open(dir).store("tx_notified")
+        }
+    }
+
+    /// Read confirmation time of last notified transaction from database.
+    pub fn read_notified_time(&self) -> Option<i64> {
+        self.store.get(Self::NOTIFIED_KEY)
+    }
+
+    /// Write confirmation time of last notified transaction.
+    pub fn write_notified_time(&self, time: i64) {
+        self.store.put(Self::NOTIFIED_KEY, &time);
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+use grin_util::secp::pedersen::Commitment;
+use grin_wallet_impls::HTTPNodeClient;
+use grin_wallet_libwallet::NodeClient;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+use crate::AppConfig;
+use crate::wallet::types::ConnectionMethod;
+
+lazy_static! {
+    /// Global state to coordinate sync of opened wallets.
+    static ref SYNC_STATE: Arc<SyncState> = Arc::new(SyncState::default());
+}
+
+/// State of wallets sync coordination.
+struct SyncState {
+    /// Amount of wallets syncing from node.
+    running: Mutex<usize>,
+    /// Condition to wait for free sync worker.
+    worker_freed: Condvar,
+    /// Chain tip height with request time by node connection.
+    tips: RwLock<HashMap<String, (u64, Instant)>>,
+    /// Heights of found kernels by node connection and kernel excess.
+    kernels: RwLock<HashMap<(String, Commitment), u64>>,
+}
+
+impl Default for SyncState {
+    fn default() -> Self {
+        Self {
+            running: Mutex::new(0),
+            worker_freed: Condvar::new(),
+            tips: RwLock::new(HashMap::new()),
+            kernels: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+/// Scheduler to sync opened wallets in parallel by limited amount of workers,
+/// sharing node queries between wallets connected to the same node.
+pub struct SyncScheduler {}
+
+/// Sync worker taken with [`SyncScheduler::acquire`], released on drop.
+pub struct SyncWorker {}
+
+impl Drop for SyncWorker {
+    fn drop(&mut self) {
+        let mut running = SYNC_STATE.running.lock().unwrap();
+        *running -= 1;
+        SYNC_STATE.worker_freed.notify_one();
+    }
+}
+
+impl SyncScheduler {
+    /// Maximal amount of wallets to sync from node at the same time.
+    const MAX_WORKERS: usize = 3;
+
+    /// Period to reuse requested chain tip height.
+    const TIP_CACHE_PERIOD: Duration = Duration::from_secs(10);
+
+    /// Maximal amount of found kernels to keep.
+    const MAX_KERNELS: usize = 10_000;
+
+    /// Wait for free worker to sync wallet from node.
+    pub fn acquire() -> SyncWorker {
+        let mut running = SYNC_STATE.running.lock().unwrap();
+        while *running >= Self::MAX_WORKERS {
+            running = SYNC_STATE.worker_freed.wait(running).unwrap();
+        }
+        *running += 1;
+        SyncWorker {}
+    }
+
+    /// Get chain tip height from node, reusing recent result requested by another wallet.
+    pub fn chain_tip(conn: &ConnectionMethod, client: &HTTPNodeClient) -> Option<u64> {
+        let key = Self::node_key(conn);
+        if let Some((height, time)) = SYNC_STATE.tips.read().get(&key) {
+            if time.elapsed() < Self::TIP_CACHE_PERIOD {
+                return Some(*height);
+            }
+        }
+        let (height, _) = client.get_chain_tip().ok()?;
+        SYNC_STATE.tips.write().insert(key, (height, Instant::now()));
+        Some(height)
+    }
+
+    /// Get height of kernel found by another wallet.
+    pub fn kernel_height(conn: &ConnectionMethod, excess: &Commitment) -> Option<u64> {
+        let key = (Self::node_key(conn), *excess);
+        SYNC_STATE.kernels.read().get(&key).copied()
+    }
+
+    /// Save height of found kernel to share with other wallets.
+    pub fn save_kernel_height(conn: &ConnectionMethod, excess: &Commitment, height: u64) {
+        let mut w_kernels = SYNC_STATE.kernels.write();
+        if w_kernels.len() >= Self::MAX_KERNELS {
+            w_kernels.clear();
+        }
+        w_kernels.insert((Self::node_key(conn), *excess), height);
+    }
+
+    /// Get key to share node queries for provided connection.
+    fn node_key(conn: &ConnectionMethod) -> String {
+        match conn {
+            ConnectionMethod::Integrated => {
+                format!("integrated_{}", AppConfig::chain_type().shortname())
+            }
+            ConnectionMethod::External(_, url) => url.clone()
+        }
+    }
+}
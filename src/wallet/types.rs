@@ -12,14 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
 
+use grin_core::consensus::GRIN_BASE;
+use grin_core::global::ChainTypes;
 use grin_keychain::ExtKeychain;
 use grin_util::Mutex;
+use grin_util::secp::Message;
 use grin_wallet_impls::{DefaultLCProvider, HTTPNodeClient};
-use grin_wallet_libwallet::{SlatepackAddress, TxLogEntry, TxLogEntryType, WalletInfo, WalletInst};
+use grin_wallet_libwallet::{RetrieveTxQueryArgs, RetrieveTxQuerySortField, RetrieveTxQuerySortOrder, SlatepackAddress, TxLogEntry, TxLogEntryType, WalletInfo, WalletInst};
 use grin_wallet_util::OnionV3Address;
 use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::wallet::WalletUtils;
 
 /// Mnemonic phrase word.
 #[derive(Clone)]
@@ -143,16 +152,501 @@ pub struct WalletAccount {
 }
 
 /// Wallet balance and transactions data.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WalletData {
     /// Balance data for current account.
     pub info: WalletInfo,
-    /// Transactions data.
-    pub txs: Option<Vec<WalletTransaction>>
+    /// Loaded page of transactions data.
+    pub txs: Option<Vec<WalletTransaction>>,
+    /// Data aggregated from all transactions of current account.
+    #[serde(default)]
+    pub stats: WalletTxStats,
 }
 
-/// Wallet transaction data.
+impl WalletData {
+    /// Find name of known receiver from memo of latest transaction sent to provided address.
+    pub fn receiver_name(&self, address: &str) -> Option<String> {
+        self.txs.as_ref()?.iter()
+            .filter(|tx| tx.receiver().map(|r| r.to_string() == address).unwrap_or(false))
+            .max_by_key(|tx| tx.data.creation_ts)
+            .and_then(|tx| tx.memo.clone())
+    }
+
+    /// Calculate fees paid for confirmed sent transactions created since optional timestamp.
+    pub fn fees_paid(&self, since: Option<i64>) -> u64 {
+        self.stats.fees
+            .iter()
+            .filter(|(time, _)| since.map(|t| *time >= t).unwrap_or(true))
+            .map(|(_, fee)| fee)
+            .sum()
+    }
+
+    /// Get privacy-relevant patterns found at transactions.
+    pub fn privacy_hints(&self) -> Vec<PrivacyHint> {
+        self.stats.privacy_hints.clone()
+    }
+}
+
+/// Data aggregated from all account transactions at wallet database,
+/// not limited by loaded transactions page.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct WalletTxStats {
+    /// Creation time in seconds and fee of confirmed sent transactions.
+    pub fees: Vec<(i64, u64)>,
+    /// Privacy-relevant patterns found at transactions.
+    pub privacy_hints: Vec<PrivacyHint>,
+}
+
+impl WalletTxStats {
+    /// Minimal amount of received transactions with the same address to show a hint.
+    const ADDRESS_REUSE_MIN: usize = 10;
+    /// Minimal amount of sent transactions with round amounts to show a hint.
+    const ROUND_AMOUNTS_MIN: usize = 3;
+
+    /// Create data to show from aggregated account transactions.
+    pub fn from_state(state: &TxStatsState) -> Self {
+        let mut privacy_hints = vec![];
+
+        // Check how many received transactions are using the same address.
+        let reuses = state.receivers.values().max().copied().unwrap_or(0);
+        if reuses >= Self::ADDRESS_REUSE_MIN {
+            privacy_hints.push(PrivacyHint::AddressReuse(reuses));
+        }
+
+        // Check sent transactions after consolidation of many outputs.
+        if state.after_consolidation > 0 {
+            privacy_hints.push(PrivacyHint::ConsolidationBeforeSend(state.after_consolidation));
+        }
+
+        // Check sent amounts rounded to whole coins.
+        if state.round >= Self::ROUND_AMOUNTS_MIN && state.round * 2 > state.sent {
+            privacy_hints.push(PrivacyHint::RoundAmounts(state.round));
+        }
+
+        Self {
+            fees: state.fees.clone(),
+            privacy_hints,
+        }
+    }
+}
+
+/// Data aggregated from account transactions, updated incrementally on sync.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct TxStatsState {
+    /// Maximal identifier of processed transaction.
+    last_id: Option<u32>,
+    /// Processed transactions waiting for confirmation or cancellation.
+    pending: Vec<u32>,
+    /// Creation time in seconds and fee of confirmed sent transactions.
+    fees: Vec<(i64, u64)>,
+    /// Amount of confirmed received transactions by receiving address.
+    receivers: HashMap<String, usize>,
+    /// Amount of confirmed sent transactions.
+    sent: usize,
+    /// Amount of confirmed sent transactions with amounts rounded to whole coins.
+    round: usize,
+    /// Amount of confirmed sent transactions shortly after consolidation of many outputs.
+    after_consolidation: usize,
+    /// Creation time in seconds and amount of inputs of last confirmed sent transaction.
+    last_sent: Option<(i64, usize)>,
+}
+
+impl TxStatsState {
+    /// Minimal amount of inputs at transaction to consider it as consolidation.
+    const CONSOLIDATION_INPUTS_MIN: usize = 5;
+    /// Period in seconds after consolidation to consider sending as related.
+    const CONSOLIDATION_SEND_PERIOD: i64 = 60 * 60;
+
+    /// Aggregate data from all account transactions.
+    pub fn from_txs(txs: &Vec<TxLogEntry>) -> Self {
+        let mut state = Self::default();
+        let mut sorted: Vec<&TxLogEntry> = txs.iter().collect();
+        sorted.sort_by_key(|tx| tx.creation_ts);
+        for tx in sorted {
+            state.process(tx);
+        }
+        state
+    }
+
+    /// Update data with loaded transactions, returning `false` if they are not following
+    /// processed ones and data should be aggregated from all transactions.
+    pub fn update(&mut self, txs: &Vec<TxLogEntry>) -> bool {
+        let last_id = match self.last_id {
+            Some(id) => id,
+            None => return txs.is_empty()
+        };
+        // Check if there are no missing transactions after processed ones.
+        let new_min_id = txs.iter().filter(|tx| tx.id > last_id).map(|tx| tx.id).min();
+        if new_min_id.map(|id| id != last_id + 1).unwrap_or(false) {
+            return false;
+        }
+        // Unfinished transactions are always loaded as outstanding.
+        let mut sorted: Vec<&TxLogEntry> = txs.iter()
+            .filter(|tx| tx.id > last_id || self.pending.contains(&tx.id))
+            .collect();
+        sorted.sort_by_key(|tx| tx.creation_ts);
+        for tx in sorted {
+            self.process(tx);
+        }
+        true
+    }
+
+    /// Add transaction to aggregated data once it was confirmed.
+    fn process(&mut self, tx: &TxLogEntry) {
+        self.pending.retain(|id| *id != tx.id);
+        self.last_id = Some(self.last_id.map(|id| id.max(tx.id)).unwrap_or(tx.id));
+
+        // Wait for confirmation or cancellation of sent and received transactions.
+        let unfinished = !tx.confirmed && (tx.tx_type == TxLogEntryType::TxSent ||
+            tx.tx_type == TxLogEntryType::TxReceived);
+        if unfinished {
+            self.pending.push(tx.id);
+            return;
+        }
+        if !tx.confirmed {
+            return;
+        }
+
+        match tx.tx_type {
+            TxLogEntryType::TxReceived => {
+                if let Some(addr) = tx_receiver(tx) {
+                    *self.receivers.entry(addr.to_string()).or_insert(0) += 1;
+                }
+            }
+            TxLogEntryType::TxSent => {
+                let time = tx.creation_ts.timestamp();
+                let fee = tx.fee.map(|f| f.fee()).unwrap_or(0);
+                self.fees.push((time, fee));
+                self.sent += 1;
+                let amount = tx.amount_debited.abs_diff(tx.amount_credited).saturating_sub(fee);
+                if amount > 0 && amount % GRIN_BASE == 0 {
+                    self.round += 1;
+                }
+                if let Some((last_time, inputs)) = self.last_sent {
+                    let period = time - last_time;
+                    if inputs >= Self::CONSOLIDATION_INPUTS_MIN && period >= 0 &&
+                        period <= Self::CONSOLIDATION_SEND_PERIOD {
+                        self.after_consolidation += 1;
+                    }
+                }
+                self.last_sent = Some((time, tx.num_inputs));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Privacy-relevant pattern found at wallet transactions.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum PrivacyHint {
+    /// Same receiving address was used for provided amount of transactions.
+    AddressReuse(usize),
+    /// Provided amount of transactions was sent shortly after consolidation of many outputs.
+    ConsolidationBeforeSend(usize),
+    /// Provided amount of transactions was sent with amounts rounded to whole coins.
+    RoundAmounts(usize),
+}
+
+/// Wallet data snapshot saved to show at next launch before sync.
+#[derive(Serialize, Deserialize)]
+pub struct WalletDataSnapshot {
+    /// Account label of saved data.
+    pub account: String,
+    /// Saved wallet data.
+    pub data: WalletData,
+}
+
+/// Signed proof of ownership for wallet unspent outputs at some chain height.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReservesProof {
+    /// Chain height of the proof.
+    pub height: u64,
+    /// Message to include into signatures.
+    pub message: String,
+    /// Total amount of outputs.
+    pub total: u64,
+    /// Signed outputs.
+    pub outputs: Vec<ReservesOutput>,
+}
+
+/// Output at [`ReservesProof`] signed with its blinding factor.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReservesOutput {
+    /// Output commitment in hex format.
+    pub commit: String,
+    /// Output value.
+    pub value: u64,
+    /// Compact signature in hex format.
+    pub signature: String,
+}
+
+impl ReservesProof {
+    /// Create message to sign for provided output commitment.
+    pub fn output_message(&self, commit: &[u8]) -> Result<Message, grin_util::secp::Error> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.message.as_bytes());
+        hasher.update(self.height.to_be_bytes());
+        hasher.update(commit);
+        Message::from_slice(hasher.finalize().as_slice())
+    }
+}
+
+/// Message signed with Slatepack address key to prove address ownership.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SignedMessage {
+    /// Slatepack address of the signer.
+    pub address: String,
+    /// Signed message.
+    pub message: String,
+    /// Signature in hex format.
+    pub signature: String,
+}
+
+impl SignedMessage {
+    /// Prefix to separate signed messages from other data signed with address key.
+    const PREFIX: &'static str = "Grin Signed Message:\n";
+
+    /// Create data to sign for provided message.
+    pub fn sign_data(message: &str) -> Vec<u8> {
+        format!("{}{}", Self::PREFIX, message).into_bytes()
+    }
+
+    /// Check if signature is valid for the message and address.
+    pub fn verify(&self) -> bool {
+        let data = Self::sign_data(&self.message);
+        WalletUtils::verify_address_signature(&self.address, &data, &self.signature)
+    }
+}
+
+/// Format of exported transactions.
+#[derive(Clone, Copy, PartialEq)]
+pub enum TxExportFormat {
+    Csv,
+    Json
+}
+
+impl TxExportFormat {
+    /// Get file extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TxExportFormat::Csv => "csv",
+            TxExportFormat::Json => "json"
+        }
+    }
+}
+
+/// Kind of transactions to show at the list.
+#[derive(Clone, Copy, PartialEq)]
+pub enum TxFilterKind {
+    Sent,
+    Received,
+    Coinbase,
+    Cancelled,
+    Unconfirmed
+}
+
+impl TxFilterKind {
+    /// All available kinds.
+    pub const ALL: [TxFilterKind; 5] = [
+        TxFilterKind::Sent,
+        TxFilterKind::Received,
+        TxFilterKind::Coinbase,
+        TxFilterKind::Cancelled,
+        TxFilterKind::Unconfirmed
+    ];
+}
+
+/// Filter of wallet transactions applied at database query.
+#[derive(Clone, Default, PartialEq)]
+pub struct TxFilter {
+    /// Text to search by transaction identifier, Slate identifier or memo.
+    pub search: String,
+    /// Kind of transactions to include.
+    pub kind: Option<TxFilterKind>,
+    /// Minimal creation time in seconds.
+    pub from_time: Option<i64>,
+    /// Maximal creation time in seconds.
+    pub to_time: Option<i64>,
+    /// Minimal transaction amount.
+    pub min_amount: Option<u64>,
+    /// Maximal transaction amount.
+    pub max_amount: Option<u64>,
+}
+
+impl TxFilter {
+    /// Check if filter does not change transactions list.
+    pub fn is_empty(&self) -> bool {
+        *self == TxFilter::default()
+    }
+
+    /// Get transactions query arguments for the filter.
+    pub fn query_args(&self) -> RetrieveTxQueryArgs {
+        let timestamp = |t: Option<i64>| t.and_then(|t| DateTime::<Utc>::from_timestamp(t, 0));
+        let only = |kind: TxFilterKind| if self.kind == Some(kind) { Some(true) } else { None };
+        RetrieveTxQueryArgs {
+            exclude_cancelled: Some(false),
+            include_outstanding_only: only(TxFilterKind::Unconfirmed),
+            include_sent_only: only(TxFilterKind::Sent),
+            include_received_only: only(TxFilterKind::Received),
+            include_coinbase_only: only(TxFilterKind::Coinbase),
+            min_amount: self.min_amount,
+            max_amount: self.max_amount,
+            min_creation_timestamp: timestamp(self.from_time),
+            max_creation_timestamp: timestamp(self.to_time),
+            sort_field: Some(RetrieveTxQuerySortField::CreationTimestamp),
+            sort_order: Some(RetrieveTxQuerySortOrder::Desc),
+            ..Default::default()
+        }
+    }
+
+    /// Check if transaction matches parameters not supported by database query.
+    pub fn matches(&self, tx: &TxLogEntry, memo: Option<String>) -> bool {
+        let cancelled = tx.tx_type == TxLogEntryType::TxSentCancelled ||
+            tx.tx_type == TxLogEntryType::TxReceivedCancelled;
+        if self.kind == Some(TxFilterKind::Cancelled) && !cancelled {
+            return false;
+        }
+        let search = self.search.trim().to_lowercase();
+        if search.is_empty() || search.parse::<u32>().map(|id| id == tx.id).unwrap_or(false) {
+            return true;
+        }
+        let slate_match = tx.tx_slate_id
+            .map(|id| id.to_string().contains(&search))
+            .unwrap_or(false);
+        let memo_match = memo
+            .map(|m| m.to_lowercase().contains(&search))
+            .unwrap_or(false);
+        slate_match || memo_match
+    }
+}
+
+/// Changes which wallet repair applied or would apply when scanning a copy of wallet database.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct RepairReport {
+    /// Values of missing outputs to restore.
+    pub restored_outputs: Vec<u64>,
+    /// Values of outputs not found at chain to mark as spent.
+    pub spent_outputs: Vec<u64>,
+    /// Identifiers of transactions to cancel.
+    pub cancelled_txs: Vec<u32>,
+    /// Difference of total wallet balance.
+    pub balance_delta: i64,
+}
+
+impl RepairReport {
+    /// Check if repair would not change wallet database.
+    pub fn is_empty(&self) -> bool {
+        self.restored_outputs.is_empty() && self.spent_outputs.is_empty()
+            && self.cancelled_txs.is_empty()
+    }
+}
+
+/// Reason to start wallet repair.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum RepairReason {
+    /// Repair was started from wallet settings.
+    Manual,
+    /// Repair was started after check of wallet database copy.
+    Check,
+    /// Outputs were scanned after restoring wallet from recovery phrase.
+    Restore,
+}
+
+/// Completed wallet repair saved at repair history.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RepairLogEntry {
+    /// Repair completion time in seconds.
+    pub time: i64,
+    /// Reason to start repair.
+    pub reason: RepairReason,
+    /// Changes applied by repair.
+    pub report: RepairReport,
+}
+
+/// Stage of transaction which time is not stored at transaction log.
+#[derive(Clone, Copy, PartialEq)]
+pub enum TxStage {
+    /// Slate was signed by the wallet or by other party.
+    Signed,
+    /// Transaction was finalized by the wallet.
+    Finalized,
+    /// Transaction was posted to the node.
+    Posted,
+}
+
+/// Times of transaction stages in seconds, saved on Slatepack messages exchange.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct TxStageTimes {
+    /// Time when slate was signed.
+    pub signed: Option<i64>,
+    /// Time when transaction was finalized.
+    pub finalized: Option<i64>,
+    /// Time when transaction was posted first time.
+    pub posted: Option<i64>,
+}
+
+impl TxStageTimes {
+    /// Save time of provided stage if it was not saved before.
+    pub fn set(&mut self, stage: TxStage, time: i64) {
+        let value = match stage {
+            TxStage::Signed => &mut self.signed,
+            TxStage::Finalized => &mut self.finalized,
+            TxStage::Posted => &mut self.posted
+        };
+        if value.is_none() {
+            *value = Some(time);
+        }
+    }
+}
+
+/// Request received by wallet Foreign API.
 #[derive(Clone)]
+pub struct ForeignApiRequest {
+    /// JSON-RPC method name.
+    pub method: String,
+    /// Request time in seconds.
+    pub time: i64,
+    /// Host claimed by the client at request header, not verified.
+    pub host: String,
+    /// Flag to check if request was rejected.
+    pub rejected: bool,
+}
+
+/// Statistics of transport usage since wallet opening.
+#[derive(Clone, Default)]
+pub struct TransportStats {
+    /// Amount of successful sends.
+    pub sent: u64,
+    /// Amount of failed sends.
+    pub failed: u64,
+    /// Total duration of all sends.
+    pub send_duration: Duration,
+}
+
+impl TransportStats {
+    /// Get average duration of single send.
+    pub fn average_send_duration(&self) -> Option<Duration> {
+        let total = self.sent + self.failed;
+        if total == 0 {
+            return None;
+        }
+        Some(self.send_duration / total as u32)
+    }
+}
+
+/// Summary balance of opened wallets.
+#[derive(Clone, Default)]
+pub struct WalletsBalance {
+    /// Spendable amount of all opened wallets.
+    pub spendable: u64,
+    /// Amount awaiting confirmation or finalization at all opened wallets.
+    pub awaiting: u64,
+    /// Balance of each opened wallet as name, spendable and awaiting amounts.
+    pub wallets: Vec<(String, u64, u64)>
+}
+
+/// Wallet transaction data.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WalletTransaction {
     /// Transaction information.
     pub data: TxLogEntry,
@@ -168,6 +662,20 @@ pub struct WalletTransaction {
     pub height: Option<u64>,
     /// Flag to check if tx was received after sync from node.
     pub from_node: bool,
+    /// User note about transaction.
+    pub memo: Option<String>,
+    /// Time when finalized transaction was queued to post when node will be available.
+    pub post_queued: Option<i64>,
+    /// Amount of automatic rebroadcast attempts for unconfirmed finalized transaction.
+    pub reposts: u64,
+    /// Time when issued invoice was marked as abandoned.
+    pub abandoned: Option<i64>,
+    /// Flag to check if incoming amount is below minimal accepted amount.
+    #[serde(default)]
+    pub dust: bool,
+    /// Times of transaction stages not stored at transaction log.
+    #[serde(default)]
+    pub stages: TxStageTimes,
 }
 
 impl WalletTransaction {
@@ -178,14 +686,58 @@ impl WalletTransaction {
             && self.data.tx_type != TxLogEntryType::TxSentCancelled
     }
 
+    /// Check if transaction is outstanding invoice issued by the wallet.
+    pub fn is_invoice(&self) -> bool {
+        self.data.tx_type == TxLogEntryType::TxReceived && !self.data.confirmed &&
+            (self.can_finalize || self.finalizing)
+    }
+
     /// Get receiver address if payment proof was created.
     pub fn receiver(&self) -> Option<SlatepackAddress> {
-        if let Some(proof) = &self.data.payment_proof {
-            let onion_addr = OnionV3Address::from_bytes(proof.receiver_address.to_bytes());
-            if let Ok(addr) = SlatepackAddress::try_from(onion_addr) {
-                return Some(addr);
-            }
+        tx_receiver(&self.data)
+    }
+}
+
+/// Get receiver address from transaction payment proof.
+fn tx_receiver(tx: &TxLogEntry) -> Option<SlatepackAddress> {
+    if let Some(proof) = &tx.payment_proof {
+        let onion_addr = OnionV3Address::from_bytes(proof.receiver_address.to_bytes());
+        if let Ok(addr) = SlatepackAddress::try_from(onion_addr) {
+            return Some(addr);
         }
-        None
     }
-}
\ No newline at end of file
+    None
+}
+
+/// Reason of wallet opening failure.
+#[derive(Clone, PartialEq)]
+pub enum WalletOpenError {
+    /// Entered password is wrong.
+    WrongPassword,
+    /// Seed file was not found.
+    SeedMissing,
+    /// Seed file can not be parsed.
+    SeedCorrupted,
+    /// Database reached its size limit, e.g. when there is no free storage space.
+    DatabaseFull,
+    /// Database is locked by another process or stale lock after crash.
+    DatabaseLocked,
+    /// Other error with description.
+    Other(String)
+}
+
+impl WalletOpenError {
+    /// Check if wallet seed can be restored from recovery phrase.
+    pub fn can_restore(&self) -> bool {
+        *self == WalletOpenError::SeedMissing || *self == WalletOpenError::SeedCorrupted
+    }
+}
+
+/// Reason of Slatepack address validation failure.
+#[derive(Clone, PartialEq)]
+pub enum AddressError {
+    /// Address can not be parsed.
+    Invalid,
+    /// Address belongs to another network with provided chain type.
+    WrongNetwork(ChainTypes)
+}
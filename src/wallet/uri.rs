@@ -0,0 +1,109 @@
+// Copyright 2024 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
+use grin_wallet_libwallet::SlatepackAddress;
+use url::Url;
+
+use crate::wallet::WalletUtils;
+
+/// Payment request to send amount to Slatepack address,
+/// e.g. `grin:grin1...?amount=1.5&memo=Coffee`, optionally signed by the receiver
+/// with address key at `sig` parameter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentUri {
+    /// Receiver Slatepack address.
+    pub address: String,
+    /// Requested amount.
+    pub amount: Option<u64>,
+    /// Payment memo.
+    pub memo: Option<String>,
+    /// Signature of request data created with receiver address key.
+    pub signature: Option<String>,
+}
+
+impl PaymentUri {
+    /// Payment URI scheme.
+    pub const SCHEME: &'static str = "grin";
+
+    /// Create payment request for provided address.
+    pub fn new(address: String, amount: Option<u64>, memo: Option<String>) -> Self {
+        Self {
+            address,
+            amount,
+            memo: memo.filter(|m| !m.trim().is_empty()),
+            signature: None,
+        }
+    }
+
+    /// Get request data to sign.
+    pub fn sign_message(&self) -> Vec<u8> {
+        format!("{}|{}|{}",
+                self.address,
+                self.amount.unwrap_or(0),
+                self.memo.clone().unwrap_or_default()).into_bytes()
+    }
+
+    /// Check if request is signed with receiver address key, `None` if request is not signed.
+    pub fn verify(&self) -> Option<bool> {
+        let sig = self.signature.as_ref()?;
+        Some(WalletUtils::verify_address_signature(&self.address, &self.sign_message(), sig))
+    }
+
+    /// Parse payment request from URI, `None` if URI or its address is not valid.
+    pub fn parse(data: &str) -> Option<Self> {
+        let url = Url::parse(data.trim()).ok()?;
+        if url.scheme() != Self::SCHEME {
+            return None;
+        }
+        // Address can be provided as path `grin:addr` or as host `grin://addr`.
+        let address = match url.host_str() {
+            Some(host) => host.to_string(),
+            None => url.path().trim_matches('/').to_string()
+        };
+        SlatepackAddress::try_from(address.as_str()).ok()?;
+        let mut amount = None;
+        let mut memo = None;
+        let mut signature = None;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "amount" => amount = Some(amount_from_hr_string(value.as_ref()).ok()?),
+                "memo" => memo = Some(value.to_string()),
+                "sig" => signature = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        let mut uri = Self::new(address, amount, memo);
+        uri.signature = signature;
+        Some(uri)
+    }
+
+    /// Convert payment request to URI.
+    pub fn to_uri(&self) -> String {
+        let mut url = Url::parse(&format!("{}:{}", Self::SCHEME, self.address)).unwrap();
+        if self.amount.is_some() || self.memo.is_some() || self.signature.is_some() {
+            let mut query = url.query_pairs_mut();
+            if let Some(amount) = self.amount {
+                query.append_pair("amount", &amount_to_hr_string(amount, true));
+            }
+            if let Some(memo) = &self.memo {
+                query.append_pair("memo", memo);
+            }
+            if let Some(sig) = &self.signature {
+                query.append_pair("sig", sig);
+            }
+        }
+        url.to_string()
+    }
+}
@@ -12,12 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use sha2::{Sha256, Digest};
+use std::collections::HashSet;
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use chacha20poly1305::aead::{Aead, NewAead};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use grin_core::global::ChainTypes;
+use grin_keychain::{ExtKeychain, Keychain, SwitchCommitmentType};
+use grin_util::{from_hex, ToHex, ZeroingString};
+use grin_util::secp::key::SecretKey;
+use grin_wallet_libwallet::SlatepackAddress;
+use rand::Rng;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::wallet::SeedKdf;
+use crate::wallet::types::AddressError;
 
 /// Wallet utilities functions.
 pub struct WalletUtils {}
 
 impl WalletUtils {
+    /// Parse Slatepack address from text checking its network for provided chain type.
+    pub fn parse_address(text: &str,
+                         chain_type: ChainTypes) -> Result<SlatepackAddress, AddressError> {
+        let addr = SlatepackAddress::try_from(text.trim()).map_err(|_| AddressError::Invalid)?;
+        let addr_chain_type = match addr.hrp.as_str() {
+            "grin" => ChainTypes::Mainnet,
+            "tgrin" => ChainTypes::Testnet,
+            _ => return Err(AddressError::Invalid)
+        };
+        if (addr_chain_type == ChainTypes::Mainnet) != (chain_type == ChainTypes::Mainnet) {
+            return Err(AddressError::WrongNetwork(addr_chain_type));
+        }
+        Ok(addr)
+    }
+
     /// Setup entropy data checksum.
     pub fn setup_checksum(data: &mut Vec<u8>) {
         let mut hasher = Sha256::new();
@@ -25,4 +53,166 @@ impl WalletUtils {
         let checksum = hasher.finalize();
         data.extend(checksum);
     }
-}
\ No newline at end of file
+
+    /// Encrypt data with key derived from provided PIN, result contains salt and nonce.
+    pub fn encrypt_with_pin(pin: &str, data: &[u8]) -> Option<Vec<u8>> {
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        let nonce: [u8; 12] = rand::thread_rng().gen();
+        let cipher = ChaCha20Poly1305::new(&Self::pin_kdf_key(pin, &salt));
+        let encrypted = cipher.encrypt(Nonce::from_slice(&nonce), data).ok()?;
+        let mut result = salt.to_vec();
+        result.extend(nonce);
+        result.extend(encrypted);
+        Some(result)
+    }
+
+    /// Decrypt data created with [`WalletUtils::encrypt_with_pin`].
+    pub fn decrypt_with_pin(pin: &str, data: &[u8]) -> Option<Vec<u8>> {
+        if data.len() < 28 {
+            return None;
+        }
+        let (salt, rest) = data.split_at(16);
+        let (nonce, encrypted) = rest.split_at(12);
+        let cipher = ChaCha20Poly1305::new(&Self::pin_kdf_key(pin, salt));
+        cipher.decrypt(Nonce::from_slice(nonce), encrypted).ok()
+    }
+
+    /// Create salted hash of password with key derivation, result contains salt.
+    pub fn hash_password(password: &str) -> Vec<u8> {
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        let mut result = salt.to_vec();
+        result.extend(Self::pin_kdf_key(password, &salt));
+        result
+    }
+
+    /// Create random value of the same size as [`WalletUtils::hash_password`] result.
+    pub fn random_password_hash() -> Vec<u8> {
+        let mut data = vec![0u8; 48];
+        rand::thread_rng().fill(&mut data[..]);
+        data
+    }
+
+    /// Check if password matches hash created with [`WalletUtils::hash_password`].
+    pub fn verify_password_hash(password: &str, data: &[u8]) -> bool {
+        if data.len() != 48 {
+            return false;
+        }
+        let (salt, hash) = data.split_at(16);
+        Self::constant_time_eq(Self::pin_kdf_key(password, salt).as_slice(), hash)
+    }
+
+    /// Check if password matches single round hash created by previous versions.
+    pub fn verify_legacy_password_hash(password: &str, data: &[u8]) -> bool {
+        if data.len() != 48 {
+            return false;
+        }
+        let (salt, hash) = data.split_at(16);
+        let mut hasher = Sha256::new();
+        hasher.update(salt);
+        hasher.update(password.as_bytes());
+        Self::constant_time_eq(hasher.finalize().as_slice(), hash)
+    }
+
+    /// Compare data in constant time to not leak position of first difference.
+    pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+    }
+
+    /// Estimate password strength from 0 (weak) to 3 (strong).
+    pub fn password_strength(password: &str) -> u8 {
+        let len = password.chars().count();
+        if len < 6 {
+            return 0;
+        }
+        let classes = [
+            password.chars().any(|c| c.is_lowercase()),
+            password.chars().any(|c| c.is_uppercase()),
+            password.chars().any(|c| c.is_numeric()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ].iter().filter(|c| **c).count();
+        let mut score = 0;
+        if len >= 10 {
+            score += 1;
+        }
+        if len >= 16 {
+            score += 1;
+        }
+        if classes >= 3 {
+            score += 1;
+        }
+        // Penalize repeated characters.
+        let unique = password.chars().collect::<HashSet<char>>().len();
+        if unique * 2 < len {
+            score = score.saturating_sub(1);
+        }
+        score
+    }
+
+    /// Derive secret from wallet seed to encrypt wallet data saved between launches,
+    /// available only after seed decryption.
+    pub fn data_secret<K: Keychain>(keychain: &K) -> Option<ZeroingString> {
+        let key_id = ExtKeychain::derive_key_id(1, DATA_KEY_INDEX, 0, 0, 0);
+        let key = keychain.derive_key(0, &key_id, SwitchCommitmentType::None).ok()?;
+        let mut hasher = Sha256::new();
+        hasher.update(b"grim_wallet_data");
+        hasher.update(key.0);
+        Some(ZeroingString::from(hasher.finalize().to_hex()))
+    }
+
+    /// Encrypt data with secret created by [`WalletUtils::data_secret`], result contains nonce.
+    pub fn encrypt_with_secret(secret: &str, data: &[u8]) -> Option<Vec<u8>> {
+        let key = from_hex(secret).ok().filter(|k| k.len() == 32)?;
+        let nonce: [u8; 12] = rand::thread_rng().gen();
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+        let encrypted = cipher.encrypt(Nonce::from_slice(&nonce), data).ok()?;
+        let mut result = nonce.to_vec();
+        result.extend(encrypted);
+        Some(result)
+    }
+
+    /// Decrypt data created with [`WalletUtils::encrypt_with_secret`].
+    pub fn decrypt_with_secret(secret: &str, data: &[u8]) -> Option<Vec<u8>> {
+        let key = from_hex(secret).ok().filter(|k| k.len() == 32)?;
+        if data.len() < 12 {
+            return None;
+        }
+        let (nonce, encrypted) = data.split_at(12);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+        cipher.decrypt(Nonce::from_slice(nonce), encrypted).ok()
+    }
+
+    /// Sign data with Slatepack address key, returning signature in hex format.
+    pub fn sign_with_address_key(key: &SecretKey, data: &[u8]) -> String {
+        let signing_key = SigningKey::from_bytes(&key.0);
+        signing_key.sign(data).to_bytes().to_hex()
+    }
+
+    /// Verify signature of data created with key of provided Slatepack address.
+    pub fn verify_address_signature(address: &str, data: &[u8], sig: &str) -> bool {
+        let key = SlatepackAddress::try_from(address.trim())
+            .ok()
+            .and_then(|a| VerifyingKey::from_bytes(&a.pub_key.to_bytes()).ok());
+        let sig = from_hex(sig.trim())
+            .ok()
+            .and_then(|s| <[u8; 64]>::try_from(s).ok())
+            .map(|s| Signature::from_bytes(&s));
+        match (key, sig) {
+            (Some(key), Some(sig)) => key.verify(data, &sig).is_ok(),
+            _ => false
+        }
+    }
+
+    /// Derive encryption key from PIN and salt with high amount of PBKDF2 rounds,
+    /// to slow down brute force of short PINs.
+    fn pin_kdf_key(pin: &str, salt: &[u8]) -> Key {
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha512>(pin.as_bytes(), salt, SeedKdf::ROUNDS[0], &mut key);
+        Key::clone_from_slice(&key)
+    }
+}
+
+/// Key derivation index of wallet data secret.
+const DATA_KEY_INDEX: u32 = 0x4752_494d;
@@ -13,23 +13,28 @@
 // limitations under the License.
 
 use std::{fs, thread};
+use std::collections::{HashMap, VecDeque};
 use std::fs::File;
 use std::io::Write;
 use std::net::{SocketAddr, TcpListener};
 use std::path::PathBuf;
 use std::sync::{Arc, mpsc};
 use parking_lot::RwLock;
-use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::thread::Thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use futures::channel::oneshot;
+use lazy_static::lazy_static;
 use serde_json::{json, Value};
 
 use grin_api::{ApiServer, Router};
-use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
 use grin_core::global;
-use grin_keychain::{ExtKeychain, Identifier, Keychain};
-use grin_util::{Mutex, ToHex};
+use grin_core::libtx::tx_fee;
+use grin_keychain::{ExtKeychain, Identifier, Keychain, SwitchCommitmentType};
+use grin_util::{from_hex, Mutex, static_secp_instance, ToHex};
+use grin_util::secp::Signature;
+use grin_util::secp::pedersen::Commitment;
 use grin_util::secp::SecretKey;
 use grin_util::types::ZeroingString;
 use grin_wallet_api::Owner;
@@ -37,7 +42,7 @@ use grin_wallet_controller::command::parse_slatepack;
 use grin_wallet_controller::controller;
 use grin_wallet_controller::controller::ForeignAPIHandlerV2;
 use grin_wallet_impls::{DefaultLCProvider, DefaultWalletImpl, HTTPNodeClient};
-use grin_wallet_libwallet::{address, Error, InitTxArgs, IssueInvoiceTxArgs, NodeClient, RetrieveTxQueryArgs, RetrieveTxQuerySortField, RetrieveTxQuerySortOrder, Slate, SlatepackAddress, SlateState, SlateVersion, StatusMessage, TxLogEntry, TxLogEntryType, VersionedSlate, WalletInst, WalletLCProvider};
+use grin_wallet_libwallet::{address, Error, InitTxArgs, IssueInvoiceTxArgs, NodeClient, OutputData, OutputStatus, RetrieveTxQueryArgs, RetrieveTxQuerySortField, RetrieveTxQuerySortOrder, Slate, SlatepackAddress, SlateState, SlateVersion, StatusMessage, TxLogEntry, TxLogEntryType, VersionedSlate, WalletInst, WalletLCProvider};
 use grin_wallet_libwallet::api_impl::owner::{cancel_tx, retrieve_summary_info, retrieve_txs};
 use grin_wallet_util::OnionV3Address;
 use rand::Rng;
@@ -45,9 +50,18 @@ use rand::Rng;
 use crate::AppConfig;
 use crate::node::{Node, NodeConfig};
 use crate::tor::Tor;
-use crate::wallet::{ConnectionsConfig, Mnemonic, WalletConfig};
-use crate::wallet::store::TxHeightStore;
-use crate::wallet::types::{ConnectionMethod, WalletAccount, WalletData, WalletInstance, WalletTransaction};
+use crate::wallet::{CliWallet, ConnectionsConfig, ExternalConnection, ForeignAPIGuard, Mnemonic, NodesManifest, OwnerAPIHandler, PaymentUri, SeedKdf, StatusAPIHandler, SyncScheduler, WalletConfig, WalletFixture, WalletUtils};
+use crate::wallet::store::{KernelMiss, KernelStore, RepairLogStore, TxAbandonedStore, TxMemoStore, TxNotifyStore, TxPostQueueStore, TxRepostStore, TxStageStore, TxStatsStore, WalletDataStore};
+use crate::wallet::types::{ConnectionMethod, ForeignApiRequest, PhraseMode, RepairLogEntry, RepairReason, RepairReport, ReservesOutput, ReservesProof, SignedMessage, TransportStats, TxExportFormat, TxFilter, TxStage, TxStatsState, WalletAccount, WalletData, WalletDataSnapshot, WalletInstance, WalletOpenError, WalletTransaction, WalletTxStats};
+
+lazy_static! {
+    /// Received funds to notify about with wallet name and amount.
+    static ref RECEIVED_FUNDS: Arc<RwLock<Vec<(String, u64)>>> = Arc::new(RwLock::new(vec![]));
+    /// Slatepack addresses of opened wallets accounts with wallet identifier, name and account.
+    static ref OWN_ADDRESSES: Arc<RwLock<Vec<(i64, String, String, String)>>> = Arc::new(
+        RwLock::new(vec![])
+    );
+}
 
 /// Contains wallet instance, configuration and state, handles wallet commands.
 #[derive(Clone)]
@@ -67,11 +81,19 @@ pub struct Wallet {
 
     /// Running wallet foreign API server and port.
     foreign_api_server: Arc<RwLock<Option<(ApiServer, u16)>>>,
+    /// Log of requests received by foreign API.
+    foreign_api_log: Arc<RwLock<VecDeque<ForeignApiRequest>>>,
+    /// Running wallet read-only Owner API server and port.
+    owner_api_server: Arc<RwLock<Option<(ApiServer, u16)>>>,
+    /// Statistics of sending over Tor.
+    tor_stats: Arc<RwLock<TransportStats>>,
 
     /// Flag to check if wallet reopening is needed.
     reopen: Arc<AtomicBool>,
     /// Flag to check if wallet is open.
     is_open: Arc<AtomicBool>,
+    /// Flag to check if decoy wallet was opened with duress password.
+    duress: Arc<AtomicBool>,
     /// Flag to check if wallet is closing.
     closing: Arc<AtomicBool>,
 
@@ -88,20 +110,72 @@ pub struct Wallet {
 
     /// Wallet info to show at ui.
     data: Arc<RwLock<Option<WalletData>>>,
+    /// Spendable and awaiting amounts of wallet info to show summary balance.
+    balance: Arc<RwLock<Option<(u64, u64)>>>,
+    /// Flag to check if wallet data was saved at previous launch and is not synced yet.
+    data_stale: Arc<AtomicBool>,
+    /// Recorded wallet data fixture to show in read-only mode separately from wallet data.
+    fixture: Arc<RwLock<Option<WalletData>>>,
+    /// Amount of latest transactions to load.
+    txs_limit: Arc<AtomicUsize>,
+    /// Lowest identifier of loaded transactions to fetch only loaded window at periodic sync.
+    txs_min_id: Arc<RwLock<Option<u32>>>,
+    /// Flag to check if more transactions can be loaded.
+    txs_more: Arc<AtomicBool>,
+    /// Flag to check if more transactions are loading.
+    txs_loading: Arc<AtomicBool>,
+    /// Secret derived from password to encrypt saved wallet data.
+    data_secret: Arc<RwLock<Option<ZeroingString>>>,
     /// Attempts amount to update wallet data.
     sync_attempts: Arc<AtomicU8>,
     /// Flag to check if wallet is syncing.
     syncing: Arc<AtomicBool>,
+    /// Flag to check if sync from node was requested.
+    sync_requested: Arc<AtomicBool>,
+    /// Chain tip height of last successful sync from node.
+    synced_tip: Arc<AtomicU64>,
 
     /// Flag to check if wallet repairing and restoring missing outputs is needed.
     repair_needed: Arc<AtomicBool>,
     /// Wallet repair progress in percents.
-    repair_progress: Arc<AtomicU8>
+    repair_progress: Arc<AtomicU8>,
+    /// Flag to check if wallet repair check is running.
+    repair_checking: Arc<AtomicBool>,
+    /// Result of wallet repair check.
+    repair_report: Arc<RwLock<Option<Result<RepairReport, String>>>>,
+    /// Reason of requested wallet repair.
+    repair_reason: Arc<RwLock<RepairReason>>,
+    /// Last completed wallet repair to show.
+    completed_repair: Arc<RwLock<Option<RepairLogEntry>>>,
+    /// Actions to run after wallet repair.
+    queued_actions: Arc<RwLock<Vec<Box<dyn FnOnce(&Wallet) + Send + Sync>>>>,
+    /// Amount of running tasks changing wallet state.
+    active_tasks: Arc<AtomicUsize>,
+    /// Flag to check if lookup of transaction kernels at node is running.
+    kernels_backfill: Arc<AtomicBool>,
+    /// Amount of checked and total transactions at kernels lookup.
+    kernels_progress: Arc<RwLock<(usize, usize)>>
+}
+
+/// Guard to track running wallet task until it's dropped.
+struct WalletTaskGuard {
+    active_tasks: Arc<AtomicUsize>
+}
+
+impl Drop for WalletTaskGuard {
+    fn drop(&mut self) {
+        self.active_tasks.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 impl Wallet {
     /// Create new [`Wallet`] instance with provided [`WalletConfig`].
-    fn new(config: WalletConfig) -> Self {
+    fn new(mut config: WalletConfig) -> Self {
+        // Setup random duress password check value to not reveal absence of decoy wallet.
+        if config.pass_check.is_none() {
+            config.pass_check = Some(WalletUtils::random_password_hash().to_hex());
+            config.save();
+        }
         let connection = config.connection();
         Self {
             config: Arc::new(RwLock::new(config)),
@@ -110,18 +184,40 @@ impl Wallet {
             slatepack_address: Arc::new(RwLock::new(None)),
             sync_thread: Arc::from(RwLock::new(None)),
             foreign_api_server: Arc::new(RwLock::new(None)),
+            foreign_api_log: Arc::new(RwLock::new(VecDeque::new())),
+            owner_api_server: Arc::new(RwLock::new(None)),
+            tor_stats: Arc::new(RwLock::new(TransportStats::default())),
             reopen: Arc::new(AtomicBool::new(false)),
             is_open: Arc::from(AtomicBool::new(false)),
+            duress: Arc::from(AtomicBool::new(false)),
             closing: Arc::new(AtomicBool::new(false)),
             deleted: Arc::new(AtomicBool::new(false)),
             sync_error: Arc::from(AtomicBool::new(false)),
             info_sync_progress: Arc::from(AtomicU8::new(0)),
             accounts: Arc::new(RwLock::new(vec![])),
             data: Arc::new(RwLock::new(None)),
+            balance: Arc::new(RwLock::new(None)),
+            data_stale: Arc::new(AtomicBool::new(false)),
+            fixture: Arc::new(RwLock::new(None)),
+            txs_limit: Arc::new(AtomicUsize::new(TXS_PAGE_SIZE)),
+            txs_min_id: Arc::new(RwLock::new(None)),
+            txs_more: Arc::new(AtomicBool::new(false)),
+            txs_loading: Arc::new(AtomicBool::new(false)),
+            data_secret: Arc::new(RwLock::new(None)),
             sync_attempts: Arc::new(AtomicU8::new(0)),
             syncing: Arc::new(AtomicBool::new(false)),
+            sync_requested: Arc::new(AtomicBool::new(false)),
+            synced_tip: Arc::new(AtomicU64::new(0)),
             repair_needed: Arc::new(AtomicBool::new(false)),
-            repair_progress: Arc::new(AtomicU8::new(0))
+            repair_progress: Arc::new(AtomicU8::new(0)),
+            repair_checking: Arc::new(AtomicBool::new(false)),
+            repair_report: Arc::new(RwLock::new(None)),
+            repair_reason: Arc::new(RwLock::new(RepairReason::Manual)),
+            completed_repair: Arc::new(RwLock::new(None)),
+            queued_actions: Arc::new(RwLock::new(vec![])),
+            active_tasks: Arc::new(AtomicUsize::new(0)),
+            kernels_backfill: Arc::new(AtomicBool::new(false)),
+            kernels_progress: Arc::new(RwLock::new((0, 0)))
         }
     }
 
@@ -130,10 +226,17 @@ impl Wallet {
         name: &String,
         password: &ZeroingString,
         mnemonic: &Mnemonic,
-        conn_method: &ConnectionMethod
+        conn_method: &ConnectionMethod,
+        kdf_rounds: Option<u32>
     ) -> Result<Wallet, Error> {
         let mut config = WalletConfig::create(name.clone(), conn_method);
         let w = Wallet::new(config.clone());
+        // Derive seed file password with additional work factor.
+        let kdf = kdf_rounds.map(SeedKdf::new);
+        let seed_pass = match &kdf {
+            Some(kdf) => kdf.derive(password),
+            None => password.clone()
+        };
         {
             let instance = Self::create_wallet_instance(&mut config)?;
             let mut w_lock = instance.lock();
@@ -141,13 +244,76 @@ impl Wallet {
             p.create_wallet(None,
                             Some(ZeroingString::from(mnemonic.get_phrase())),
                             mnemonic.size().entropy_size(),
-                            password.clone(),
+                            seed_pass,
                             false,
             )?;
         }
+        if kdf.is_some() && !SeedKdf::write(kdf.as_ref(), &config.get_seed_path()) {
+            return Err(Error::GenericError("Failed to save seed header".to_string()));
+        }
+        // Save current chain height as scanning start point for newly generated phrase
+        // at first successful sync.
+        if mnemonic.mode() == PhraseMode::Generate {
+            let mut w_config = w.config.write();
+            w_config.creation_height_pending = Some(true);
+            w_config.save();
+        }
         Ok(w)
     }
 
+    /// Save synced chain height as outputs scanning start point for newly created wallet
+    /// if it was not saved yet, scanning from the beginning if wallet has transactions.
+    fn save_creation_height(&self, height: u64) {
+        if !self.get_config().creation_height_pending.unwrap_or(false) || self.is_deleted() {
+            return;
+        }
+        let has_txs = match self.get_data().and_then(|d| d.txs) {
+            Some(txs) => !txs.is_empty(),
+            None => return
+        };
+        let mut w_config = self.config.write();
+        if w_config.creation_height.is_none() && !has_txs {
+            w_config.creation_height = Some(height);
+        }
+        w_config.creation_height_pending = None;
+        w_config.save();
+    }
+
+    /// Import wallet of command line application, using its data in place or copying it.
+    pub fn import_cli(name: &String, cli: &CliWallet, in_place: bool) -> Result<Wallet, Error> {
+        // Setup connection from command line wallet config.
+        let chain_type = AppConfig::chain_type();
+        let conn_method = match &cli.node_url {
+            Some(url) if !cli.local_node(chain_type) => {
+                let url_trimmed = url.trim_end_matches('/');
+                let conn = ConnectionsConfig::ext_conn_list()
+                    .into_iter()
+                    .find(|c| !c.deleted && c.url.trim_end_matches('/') == url_trimmed)
+                    .unwrap_or_else(|| {
+                        let conn = ExternalConnection::new(url.clone(), cli.node_secret.clone());
+                        ConnectionsConfig::add_ext_conn(conn.clone());
+                        conn
+                    });
+                ConnectionMethod::External(conn.id, conn.url)
+            }
+            _ => ConnectionMethod::Integrated
+        };
+        let mut config = WalletConfig::create(name.clone(), &conn_method);
+        config.enable_tor_listener = Some(cli.tor_listener);
+        if in_place && cli.can_use_in_place() {
+            config.data_dir = Some(cli.top_level_dir.to_str().unwrap().to_string());
+        } else {
+            let mut data_path = PathBuf::from(config.get_data_path());
+            data_path.push("wallet_data");
+            if let Err(e) = copy_dir(&cli.data_dir, &data_path) {
+                let _ = fs::remove_dir_all(config.get_data_path());
+                return Err(Error::GenericError(e.to_string()));
+            }
+        }
+        config.save();
+        Ok(Wallet::new(config))
+    }
+
     /// Initialize [`Wallet`] from provided data path.
     pub fn init(data_path: PathBuf) -> Option<Wallet> {
         let wallet_config = WalletConfig::load(data_path.clone());
@@ -168,7 +334,18 @@ impl Wallet {
             global::set_local_chain_type(chain_type);
         }
 
-        // Setup node client.
+        // Create wallet instance.
+        let node_client = Self::create_node_client(config)?;
+        let wallet = Self::inst_wallet::<
+            DefaultLCProvider<HTTPNodeClient, ExtKeychain>,
+            HTTPNodeClient,
+            ExtKeychain,
+        >(config, node_client)?;
+        Ok(wallet)
+    }
+
+    /// Create node client from provided [`WalletConfig`] connection.
+    fn create_node_client(config: &WalletConfig) -> Result<HTTPNodeClient, Error> {
         let integrated = || {
             let api_url = format!("http://{}", NodeConfig::get_api_address());
             let api_secret = NodeConfig::get_api_secret(true);
@@ -183,15 +360,7 @@ impl Wallet {
         } else {
             integrated()
         };
-        let node_client = HTTPNodeClient::new(&node_api_url, node_secret)?;
-
-        // Create wallet instance.
-        let wallet = Self::inst_wallet::<
-            DefaultLCProvider<HTTPNodeClient, ExtKeychain>,
-            HTTPNodeClient,
-            ExtKeychain,
-        >(config, node_client)?;
-        Ok(wallet)
+        HTTPNodeClient::new(&node_api_url, node_secret)
     }
 
     /// Instantiate [`WalletInstance`] from provided node client and [`WalletConfig`].
@@ -208,7 +377,7 @@ impl Wallet {
         let mut wallet = Box::new(DefaultWalletImpl::<'static, C>::new(node_client).unwrap())
             as Box<dyn WalletInst<'static, L, C, K>>;
         let lc = wallet.lc_provider()?;
-        lc.set_top_level_directory(config.get_data_path().as_str())?;
+        lc.set_top_level_directory(config.get_top_level_path().as_str())?;
         Ok(Arc::new(Mutex::new(wallet)))
     }
 
@@ -236,6 +405,68 @@ impl Wallet {
         Ok(sec_key)
     }
 
+    /// Sign payment request with Slatepack address key of current account.
+    pub fn sign_payment_uri(&self, uri: &mut PaymentUri) -> Result<(), Error> {
+        let key = self.secret_key()?;
+        uri.signature = Some(WalletUtils::sign_with_address_key(&key, &uri.sign_message()));
+        Ok(())
+    }
+
+    /// Sign message with Slatepack address key of current account to prove address ownership.
+    pub fn sign_message(&self, message: String) -> Result<SignedMessage, Error> {
+        let address = self.slatepack_address()
+            .ok_or_else(|| Error::GenericError("Slatepack address not found".to_string()))?;
+        let key = self.secret_key()?;
+        let signature = WalletUtils::sign_with_address_key(&key,
+                                                           &SignedMessage::sign_data(&message));
+        Ok(SignedMessage {
+            address,
+            message,
+            signature,
+        })
+    }
+
+    /// Check if wallet config file was changed outside of the application.
+    pub fn config_changed_externally(&self) -> bool {
+        self.config.read().changed_externally()
+    }
+
+    /// Reload wallet config from the file, overwriting file with current config on error.
+    pub fn reload_config(&self) {
+        let wallet_dir = PathBuf::from(self.get_config().get_data_path());
+        match WalletConfig::load(wallet_dir) {
+            Some(config) => {
+                let mut w_config = self.config.write();
+                let duress = w_config.duress;
+                *w_config = config;
+                w_config.duress = duress;
+            }
+            None => self.config.read().save()
+        }
+    }
+
+    /// Save current wallet config overwriting external changes.
+    pub fn save_config(&self) {
+        self.config.read().save();
+    }
+
+    /// Get [`SecretKey`] for Tor Onion service, derived from wallet seed.
+    pub fn tor_service_key(&self) -> Result<SecretKey, Error> {
+        self.secret_key()
+    }
+
+    /// Get Onion address of Tor service.
+    pub fn tor_service_address(&self) -> Option<String> {
+        let key = self.tor_service_key().ok()?;
+        let addr = OnionV3Address::from_private(&key.0).ok()?;
+        Some(format!("{}.onion", addr.to_ov3_str()))
+    }
+
+    /// Export Onion service key in hex format.
+    pub fn export_tor_service_key(&self) -> Result<String, Error> {
+        Ok(self.tor_service_key()?.0.to_hex())
+    }
+
     /// Get unique opened wallet identifier, including current account.
     pub fn identifier(&self) -> String {
         let config = self.get_config();
@@ -277,12 +508,198 @@ impl Wallet {
         w_config.save();
     }
 
+    /// Get custom name of account by label.
+    pub fn account_name(&self, label: &String) -> Option<String> {
+        let r_config = self.config.read();
+        r_config.account_names.as_ref().and_then(|names| names.get(label).cloned())
+    }
+
+    /// Save custom name for account by label, empty value restores original label.
+    pub fn rename_account(&self, label: &String, name: String) {
+        let name = name.trim().to_string();
+        let mut w_config = self.config.write();
+        let mut names = w_config.account_names.clone().unwrap_or_default();
+        if name.is_empty() || &name == label {
+            names.remove(label);
+        } else {
+            names.insert(label.clone(), name);
+        }
+        w_config.account_names = if names.is_empty() { None } else { Some(names) };
+        w_config.save();
+    }
+
+    /// Check if account is archived and hidden from account selection.
+    pub fn is_account_hidden(&self, label: &String) -> bool {
+        let r_config = self.config.read();
+        r_config.hidden_accounts.as_ref().map(|a| a.contains(label)).unwrap_or(false)
+    }
+
+    /// Archive or restore account at account selection.
+    pub fn toggle_account_hidden(&self, label: &String) {
+        let mut w_config = self.config.write();
+        let mut hidden = w_config.hidden_accounts.clone().unwrap_or_default();
+        if hidden.contains(label) {
+            hidden.retain(|l| l != label);
+        } else if &w_config.account != label {
+            hidden.push(label.clone());
+        }
+        w_config.hidden_accounts = if hidden.is_empty() { None } else { Some(hidden) };
+        w_config.save();
+    }
+
+    /// Get account label Tor listener is pinned to.
+    pub fn tor_listener_account(&self) -> Option<String> {
+        let r_config = self.config.read();
+        r_config.tor_listener_account.clone()
+    }
+
+    /// Pin Tor listener to provided account label or allow it for any account.
+    pub fn update_tor_listener_account(&self, label: Option<String>) {
+        let mut w_config = self.config.write();
+        w_config.tor_listener_account = label;
+        w_config.save();
+    }
+
+    /// Check if Tor listener can be launched for current account.
+    pub fn tor_listener_allowed(&self) -> bool {
+        let r_config = self.config.read();
+        match &r_config.tor_listener_account {
+            Some(label) => *label == r_config.account,
+            None => true
+        }
+    }
+
     /// Check if Dandelion usage is needed to post transactions.
     pub fn can_use_dandelion(&self) -> bool {
         let r_config = self.config.read();
         r_config.use_dandelion.unwrap_or(true)
     }
 
+    /// Get amount of spendable outputs to keep after consolidation.
+    pub fn consolidation_target(&self) -> usize {
+        let r_config = self.config.read();
+        r_config.consolidation_target.unwrap_or(WalletConfig::DEFAULT_CONSOLIDATION_TARGET)
+    }
+
+    /// Update amount of spendable outputs to keep after consolidation.
+    pub fn update_consolidation_target(&self, target: usize) {
+        let mut w_config = self.config.write();
+        w_config.consolidation_target = Some(target.max(1));
+        w_config.save();
+    }
+
+    /// Get period in hours after which issued invoice is considered expired.
+    pub fn invoice_expiry(&self) -> i64 {
+        let r_config = self.config.read();
+        r_config.invoice_expiry.unwrap_or(WalletConfig::DEFAULT_INVOICE_EXPIRY)
+    }
+
+    /// Update period in hours after which issued invoice is considered expired.
+    pub fn update_invoice_expiry(&self, hours: i64) {
+        let mut w_config = self.config.write();
+        w_config.invoice_expiry = Some(hours.max(1));
+        w_config.save();
+    }
+
+    /// Get minimal amount of incoming transaction, smaller amounts are considered as dust.
+    pub fn min_receive_amount(&self) -> Option<u64> {
+        let r_config = self.config.read();
+        r_config.min_receive_amount.filter(|a| *a > 0)
+    }
+
+    /// Update minimal amount of incoming transaction, disabling dust check when not provided.
+    pub fn update_min_receive_amount(&self, amount: Option<u64>) {
+        let mut w_config = self.config.write();
+        w_config.min_receive_amount = amount.filter(|a| *a > 0);
+        w_config.save();
+    }
+
+    /// Check if dust should be rejected at Foreign API instead of marking it at transactions list.
+    pub fn reject_dust(&self) -> bool {
+        let r_config = self.config.read();
+        r_config.reject_dust.unwrap_or(false)
+    }
+
+    /// Update flag to reject dust at Foreign API.
+    pub fn update_reject_dust(&self, reject: bool) {
+        let mut w_config = self.config.write();
+        w_config.reject_dust = Some(reject);
+        w_config.save();
+    }
+
+    /// Check if wallet should be opened on application launch.
+    pub fn auto_open(&self) -> bool {
+        let r_config = self.config.read();
+        r_config.auto_open.unwrap_or(false)
+    }
+
+    /// Update flag to open wallet on application launch.
+    pub fn update_auto_open(&self, auto_open: bool) {
+        let mut w_config = self.config.write();
+        w_config.auto_open = Some(auto_open);
+        w_config.save();
+    }
+
+    /// Check if quick unlock with PIN is enabled.
+    pub fn has_unlock_pin(&self) -> bool {
+        let r_config = self.config.read();
+        r_config.unlock_data_version == Some(UNLOCK_DATA_VERSION) &&
+            r_config.unlock_pin_data.is_some()
+    }
+
+    /// Check if quick unlock with biometric is enabled.
+    pub fn has_unlock_biometric(&self) -> bool {
+        let r_config = self.config.read();
+        r_config.unlock_data_version == Some(UNLOCK_DATA_VERSION) &&
+            r_config.unlock_bio_data.is_some()
+    }
+
+    /// Get encrypted password data for quick unlock with PIN or biometric.
+    pub fn unlock_data(&self, biometric: bool) -> Option<Vec<u8>> {
+        let r_config = self.config.read();
+        if r_config.unlock_data_version != Some(UNLOCK_DATA_VERSION) {
+            return None;
+        }
+        let data = if biometric {
+            r_config.unlock_bio_data.as_ref()
+        } else {
+            r_config.unlock_pin_data.as_ref()
+        };
+        data.and_then(|d| from_hex(d).ok())
+    }
+
+    /// Save encrypted password data for quick unlock, disable it if data is not provided.
+    pub fn update_unlock_data(&self, pin_data: Option<Vec<u8>>, bio_data: Option<Vec<u8>>) {
+        let mut w_config = self.config.write();
+        w_config.unlock_data_version = if pin_data.is_some() {
+            Some(UNLOCK_DATA_VERSION)
+        } else {
+            None
+        };
+        w_config.unlock_pin_data = pin_data.map(|d| d.to_hex());
+        w_config.unlock_bio_data = bio_data.map(|d| d.to_hex());
+        w_config.unlock_pin_attempts = None;
+        w_config.save();
+    }
+
+    /// Get amount of wrong PIN attempts since last quick unlock.
+    pub fn unlock_pin_attempts(&self) -> u8 {
+        let r_config = self.config.read();
+        r_config.unlock_pin_attempts.unwrap_or(0)
+    }
+
+    /// Save amount of wrong PIN attempts, to keep it between launches.
+    pub fn update_unlock_pin_attempts(&self, attempts: u8) {
+        let mut w_config = self.config.write();
+        w_config.unlock_pin_attempts = if attempts == 0 { None } else { Some(attempts) };
+        w_config.save();
+    }
+
+    /// Check if provided password is correct for opened wallet.
+    pub fn verify_password(&self, password: String) -> bool {
+        self.get_recovery(password).is_ok()
+    }
+
     /// Update usage of Dandelion to post transactions.
     pub fn update_use_dandelion(&self, use_dandelion: bool) {
         let mut w_config = self.config.write();
@@ -290,6 +707,63 @@ impl Wallet {
         w_config.save();
     }
 
+    /// Get token to access read-only status API.
+    pub fn status_api_token(&self) -> Option<String> {
+        self.get_config().status_api_token
+    }
+
+    /// Enable or disable read-only status API, generating new access token on enabling.
+    pub fn update_status_api(&self, enable: bool) {
+        {
+            let mut w_config = self.config.write();
+            w_config.status_api_token = if enable {
+                let token: [u8; 16] = rand::thread_rng().gen();
+                Some(token.to_hex())
+            } else {
+                None
+            };
+            w_config.save();
+        }
+        self.update_owner_api_server();
+    }
+
+    /// Get token to access read-only Owner API.
+    pub fn owner_api_token(&self) -> Option<String> {
+        self.get_config().owner_api_token
+    }
+
+    /// Enable or disable read-only Owner API server, generating new access token on enabling.
+    pub fn update_owner_api(&self, enable: bool) {
+        {
+            let mut w_config = self.config.write();
+            w_config.owner_api_token = if enable {
+                let token: [u8; 32] = rand::thread_rng().gen();
+                Some(token.to_hex())
+            } else {
+                None
+            };
+            w_config.save();
+        }
+        self.update_owner_api_server();
+    }
+
+    /// Start or stop local Owner API server with status API based on wallet state and config.
+    fn update_owner_api_server(&self) {
+        let mut w_api_server = self.owner_api_server.write();
+        let api_enabled = self.owner_api_token().is_some() || self.status_api_token().is_some();
+        let enabled = api_enabled && self.is_open() && !self.is_closing();
+        if enabled && w_api_server.is_none() {
+            if let Ok(api_server) = start_owner_api_server(self) {
+                *w_api_server = Some(api_server);
+            }
+        } else if !enabled {
+            if let Some(api_server) = w_api_server.as_mut() {
+                api_server.0.stop();
+            }
+            *w_api_server = None;
+        }
+    }
+
     /// Update minimal amount of confirmations.
     pub fn update_min_confirmations(&self, min_confirmations: u64) {
         let mut w_config = self.config.write();
@@ -307,18 +781,70 @@ impl Wallet {
         w_config.save();
     }
 
+    /// Switch to next available external connection when current one is not available,
+    /// returning `true` if connection was changed.
+    fn failover_connection(&self) -> bool {
+        let id = match self.get_current_connection() {
+            ConnectionMethod::External(id, _) => id,
+            ConnectionMethod::Integrated => return false
+        };
+        let available = ConnectionsConfig::ext_conn(id)
+            .map(|c| c.available != Some(false))
+            .unwrap_or(false);
+        if available {
+            return false;
+        }
+        let next = match ExternalConnection::next_available(id) {
+            Some(c) => c,
+            None => return false
+        };
+        if self.update_node_client(&next.url, next.secret.clone()).is_err() {
+            return false;
+        }
+        let conn = ConnectionMethod::External(next.id, next.url);
+        self.update_connection(&conn);
+        {
+            let mut w_conn = self.connection.write();
+            *w_conn = conn;
+        }
+        self.reset_sync_attempts();
+        self.set_sync_error(false);
+        true
+    }
+
+    /// Update node connection for current wallet instance.
+    fn update_node_client(&self, url: &str, secret: Option<String>) -> Result<(), Error> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let client = w_inst.w2n_client();
+        client.set_node_url(url);
+        client.set_node_api_secret(secret);
+        Ok(())
+    }
+
     /// Open the wallet and start [`WalletData`] sync at separate thread.
     pub fn open(&self, password: ZeroingString) -> Result<(), Error> {
         if self.is_open() {
             return Err(Error::GenericError("Already opened".to_string()));
         }
 
-        // Create new wallet instance if sync thread was stopped or instance was not created.
+        // Check if decoy wallet should be opened with duress password.
+        let duress = self.check_duress_password(&password);
+        {
+            let mut w_config = self.config.write();
+            w_config.duress = duress;
+        }
+
+        // Create new wallet instance if sync thread was stopped, instance was not created
+        // or another wallet data should be opened.
         let has_instance = {
             let r_inst = self.instance.as_ref().read();
             r_inst.is_some()
         };
-        if self.sync_thread.read().is_none() || !has_instance {
+        if self.sync_thread.read().is_none() || !has_instance || duress != self.is_duress() {
             let mut config = self.get_config();
             // Setup current connection.
             {
@@ -329,6 +855,7 @@ impl Wallet {
             let mut w_inst = self.instance.write();
             *w_inst = Some(new_instance);
         }
+        self.duress.store(duress, Ordering::Relaxed);
 
         // Open the wallet.
         {
@@ -336,20 +863,51 @@ impl Wallet {
                 let r_inst = self.instance.as_ref().read();
                 r_inst.clone().unwrap()
             };
+            // Apply key derivation from seed file header, decoy wallet is not affected.
+            let seed_pass = if duress {
+                password
+            } else {
+                SeedKdf::seed_password(&self.get_config().get_seed_path(), &password)
+            };
             let mut wallet_lock = instance.lock();
             let lc = wallet_lock.lc_provider()?;
-            match lc.open_wallet(None, password, false, false) {
+            match lc.open_wallet(None, seed_pass, false, false) {
                 Ok(_) => {
                     // Reset an error on opening.
                     self.set_sync_error(false);
                     self.reset_sync_attempts();
+                    {
+                        let mut w_stats = self.tor_stats.write();
+                        *w_stats = TransportStats::default();
+                    }
 
-                    // Set current account.
+                    // Set current account or default one if current was not found.
                     let wallet_inst = lc.wallet_inst()?;
                     let label = self.get_config().account.to_owned();
-                    wallet_inst.set_parent_key_id_by_name(label.as_str())?;
+                    if wallet_inst.set_parent_key_id_by_name(label.as_str()).is_err() {
+                        let default_label = WalletConfig::DEFAULT_ACCOUNT_LABEL;
+                        wallet_inst.set_parent_key_id_by_name(default_label)?;
+                        let mut w_config = self.config.write();
+                        w_config.account = default_label.to_string();
+                        w_config.save();
+                    }
+
+                    // Show data saved at previous launch until sync will be finished,
+                    // data of decoy wallet is not saved.
+                    let data_secret = if duress {
+                        None
+                    } else {
+                        let keychain = wallet_inst.keychain((&None).as_ref())?;
+                        WalletUtils::data_secret(&keychain)
+                    };
+                    {
+                        let mut w_secret = self.data_secret.write();
+                        *w_secret = data_secret;
+                    }
+                    self.load_data_snapshot();
 
                     // Start new synchronization thread or wake up existing one.
+                    self.sync_requested.store(true, Ordering::Relaxed);
                     let mut thread_w = self.sync_thread.write();
                     if thread_w.is_none() {
                         let thread = start_sync(self.clone());
@@ -358,6 +916,15 @@ impl Wallet {
                         thread_w.clone().unwrap().unpark();
                     }
                     self.is_open.store(true, Ordering::Relaxed);
+
+                    // Remember wallet to open on next launch.
+                    AppConfig::set_last_wallet_id(self.get_config().id);
+
+                    // Continue cancellations queued before the wallet was closed.
+                    let queued_cancels = self.get_config().queued_cancels.unwrap_or_default();
+                    for id in queued_cancels {
+                        self.queue_cancel(id);
+                    }
                 }
                 Err(e) => {
                     if !self.syncing() {
@@ -370,42 +937,249 @@ impl Wallet {
         }
 
         // Set slatepack address.
+        {
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            let mut api = Owner::new(instance, None);
+            controller::owner_single_use(None, None, Some(&mut api), |api, m| {
+                let mut w_address = self.slatepack_address.write();
+                *w_address = Some(api.get_slatepack_address(m, 0)?.to_string());
+                Ok(())
+            })?;
+        }
+
+        // Save addresses of all accounts to detect sending to own address.
+        let _ = self.update_own_addresses();
+        Ok(())
+    }
+
+    /// Save Slatepack addresses of all wallet accounts to detect sending to own address.
+    fn update_own_addresses(&self) -> Result<(), Error> {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
-        let mut api = Owner::new(instance, None);
-        controller::owner_single_use(None, None, Some(&mut api), |api, m| {
-            let mut w_address = self.slatepack_address.write();
-            *w_address = Some(api.get_slatepack_address(m, 0)?.to_string());
-            Ok(())
-        })?;
-
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let k = w_inst.keychain((&None).as_ref())?;
+        let config = self.get_config();
+        let mut addresses = vec![];
+        for acc in w_inst.acct_path_iter() {
+            let sec_key = address::address_from_derivation_path(&k, &acc.path, 0)
+                .map_err(|e| Error::GenericError(format!("{:?}", e)))?;
+            let addr = SlatepackAddress::try_from(&sec_key)?;
+            let name = self.account_name(&acc.label).unwrap_or(acc.label);
+            addresses.push((config.id, config.name.clone(), name, addr.to_string()));
+        }
+        let mut w_addresses = OWN_ADDRESSES.write();
+        w_addresses.retain(|a| a.0 != config.id);
+        w_addresses.extend(addresses);
         Ok(())
     }
 
-    /// Get external connection URL applied to [`WalletInstance`]
-    /// after wallet opening if sync is running or get it from config.
-    pub fn get_current_connection(&self) -> ConnectionMethod {
-        if self.sync_thread.read().is_some() {
-            let r_conn = self.connection.read();
-            r_conn.clone()
-        } else {
-            let config = self.get_config();
-            config.connection()
+    /// Get wallet name and account name for Slatepack address of opened wallet.
+    pub fn own_address_owner(addr: &String) -> Option<(String, String)> {
+        let r_addresses = OWN_ADDRESSES.read();
+        r_addresses.iter()
+            .find(|a| &a.3 == addr)
+            .map(|a| (a.1.clone(), a.2.clone()))
+    }
+
+    /// Get reason of wallet opening failure to distinguish wrong password from seed problems.
+    pub fn open_error(&self, e: &Error) -> WalletOpenError {
+        if let Error::Lifecycle(_) = e {
+            let seed_path = self.get_config().get_seed_path();
+            if !seed_path.exists() {
+                return WalletOpenError::SeedMissing;
+            }
+            return match fs::read_to_string(seed_path) {
+                Ok(data) => {
+                    // Check if seed file has all encryption fields.
+                    let valid = serde_json::from_str::<Value>(data.as_str())
+                        .map(|v| {
+                            ["encrypted_seed", "salt", "nonce"].iter().all(|k| v[k].is_string())
+                        })
+                        .unwrap_or(false);
+                    if valid {
+                        WalletOpenError::WrongPassword
+                    } else {
+                        WalletOpenError::SeedCorrupted
+                    }
+                }
+                Err(e) => WalletOpenError::Other(e.to_string())
+            };
+        }
+        if Self::is_db_full_error(e) {
+            return WalletOpenError::DatabaseFull;
+        }
+        if Self::is_db_lock_error(e) {
+            return WalletOpenError::DatabaseLocked;
         }
+        WalletOpenError::Other(e.to_string())
     }
 
-    /// Check if wallet is open.
-    pub fn is_open(&self) -> bool {
-        self.is_open.load(Ordering::Relaxed)
+    /// Check if error was caused by reaching database memory map size limit.
+    fn is_db_full_error(e: &Error) -> bool {
+        let err = e.to_string().to_lowercase();
+        ["mdb_map_full", "mapsize", "map full", "no space left"].iter().any(|m| err.contains(m))
     }
 
-    /// Check if wallet is closing.
-    pub fn is_closing(&self) -> bool {
-        self.closing.load(Ordering::Relaxed)
+    /// Check if error was caused by database lock held by another process.
+    fn is_db_lock_error(e: &Error) -> bool {
+        let err = e.to_string().to_lowercase();
+        ["mdb_readers_full", "mdb_bad_rslot", "resource temporarily unavailable", "lock.mdb"]
+            .iter()
+            .any(|m| err.contains(m))
     }
 
-    /// Close the wallet.
-    pub fn close(&self) {
+    /// Check if stale database lock can be cleared, not allowed for wallet imported in place
+    /// which database can be used by command line application.
+    pub fn can_clear_db_lock(&self) -> bool {
+        self.get_config().data_dir.is_none()
+    }
+
+    /// Remove stale wallet database lock file left after crash, can be called only
+    /// when the wallet is not opened and not used by another application.
+    pub fn clear_db_lock(&self) -> bool {
+        if !self.can_clear_db_lock() || self.is_open() || self.syncing() {
+            return false;
+        }
+        let mut lock_path = PathBuf::from(self.get_config().get_db_path());
+        lock_path.push("lmdb");
+        lock_path.push("lock.mdb");
+        if !lock_path.exists() {
+            return true;
+        }
+        !db_lock_held(&lock_path) && fs::remove_file(lock_path).is_ok()
+    }
+
+    /// Restore missing or corrupted wallet seed from recovery phrase
+    /// keeping previous seed file as backup.
+    pub fn restore_seed(&self, mnemonic: &Mnemonic, password: &ZeroingString) -> Result<(), Error> {
+        if self.is_open() {
+            return Err(Error::GenericError("Already opened".to_string()));
+        }
+        let seed_path = self.get_config().get_seed_path();
+        if seed_path.exists() {
+            let mut backup_path = seed_path.clone();
+            backup_path.set_extension("seed.bak");
+            fs::rename(&seed_path, backup_path)
+                .map_err(|e| Error::GenericError(e.to_string()))?;
+        }
+        let mut config = self.get_config();
+        let instance = Self::create_wallet_instance(&mut config)?;
+        let mut w_lock = instance.lock();
+        let p = w_lock.lc_provider()?;
+        p.create_wallet(None,
+                        Some(ZeroingString::from(mnemonic.get_phrase())),
+                        mnemonic.size().entropy_size(),
+                        password.clone(),
+                        false,
+        )?;
+        // Reset quick unlock data encrypted with previous password.
+        self.update_unlock_data(None, None);
+        Ok(())
+    }
+
+    /// Get external connection URL applied to [`WalletInstance`]
+    /// after wallet opening if sync is running or get it from config.
+    pub fn get_current_connection(&self) -> ConnectionMethod {
+        if self.sync_thread.read().is_some() {
+            let r_conn = self.connection.read();
+            r_conn.clone()
+        } else {
+            let config = self.get_config();
+            config.connection()
+        }
+    }
+
+    /// Check if wallet is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open.load(Ordering::Relaxed)
+    }
+
+    /// Check if decoy wallet was opened with duress password.
+    pub fn is_duress(&self) -> bool {
+        self.duress.load(Ordering::Relaxed)
+    }
+
+    /// Check if duress password to open decoy wallet was set.
+    pub fn has_duress(&self) -> bool {
+        let mut seed_path = PathBuf::from(self.get_config().get_duress_data_path());
+        seed_path.push("wallet_data");
+        seed_path.exists()
+    }
+
+    /// Check if provided password opens decoy wallet, migrating hash from previous versions.
+    fn check_duress_password(&self, password: &str) -> bool {
+        let config = self.get_config();
+        if let Some(legacy) = config.duress_pass_hash.and_then(|h| from_hex(&h).ok()) {
+            if !WalletUtils::verify_legacy_password_hash(password, &legacy) {
+                return false;
+            }
+            let hash = WalletUtils::hash_password(password);
+            let mut w_config = self.config.write();
+            w_config.duress_pass_hash = None;
+            w_config.pass_check = Some(hash.to_hex());
+            w_config.save();
+            return true;
+        }
+        config.pass_check
+            .and_then(|h| from_hex(&h).ok())
+            .map(|h| WalletUtils::verify_password_hash(password, &h))
+            .unwrap_or(false)
+    }
+
+    /// Create decoy wallet with new random seed to open with provided duress password,
+    /// after verification of current wallet password.
+    pub fn setup_duress(&self, password: String, duress_password: String) -> Result<(), Error> {
+        if self.is_duress() || password == duress_password || !self.verify_password(password) {
+            return Err(Error::GenericError("Wrong password".to_string()));
+        }
+        let mut config = self.get_config();
+        let duress_path = config.get_duress_data_path();
+        let _ = fs::remove_dir_all(&duress_path);
+        {
+            let instance = Self::create_wallet_instance(&mut config)?;
+            let mut w_lock = instance.lock();
+            let p = w_lock.lc_provider()?;
+            p.set_top_level_directory(duress_path.as_str())?;
+            p.create_wallet(None,
+                            None,
+                            32,
+                            ZeroingString::from(duress_password.clone()),
+                            false,
+            )?;
+        }
+        let hash = WalletUtils::hash_password(&duress_password);
+        let mut w_config = self.config.write();
+        w_config.duress_pass_hash = None;
+        w_config.pass_check = Some(hash.to_hex());
+        w_config.save();
+        Ok(())
+    }
+
+    /// Remove decoy wallet and duress password.
+    pub fn remove_duress(&self) {
+        if self.is_duress() {
+            return;
+        }
+        let duress_path = {
+            let mut w_config = self.config.write();
+            w_config.duress_pass_hash = None;
+            w_config.pass_check = Some(WalletUtils::random_password_hash().to_hex());
+            w_config.save();
+            w_config.get_duress_data_path()
+        };
+        let _ = fs::remove_dir_all(duress_path);
+    }
+
+    /// Check if wallet is closing.
+    pub fn is_closing(&self) -> bool {
+        self.closing.load(Ordering::Relaxed)
+    }
+
+    /// Close the wallet.
+    pub fn close(&self) {
         let has_instance = {
             let r_inst = self.instance.read();
             r_inst.is_some()
@@ -414,6 +1188,13 @@ impl Wallet {
             return;
         }
         self.closing.store(true, Ordering::Relaxed);
+        // Clear secret to save wallet data.
+        {
+            let mut w_secret = self.data_secret.write();
+            *w_secret = None;
+        }
+        // Drop actions queued while repairing.
+        self.queued_actions.write().clear();
 
         // Close wallet at separate thread.
         let wallet_close = self.clone();
@@ -429,9 +1210,17 @@ impl Wallet {
                 w_api_server.as_mut().unwrap().0.stop();
                 *w_api_server = None;
             }
+            wallet_close.update_owner_api_server();
             // Stop running Tor service.
             Tor::stop_service(&service_id);
 
+            // Remove saved accounts addresses.
+            {
+                let id = wallet_close.get_config().id;
+                let mut w_addresses = OWN_ADDRESSES.write();
+                w_addresses.retain(|a| a.0 != id);
+            }
+
             // Close the wallet.
             let r_inst = wallet_close.instance.as_ref().read();
             let instance = r_inst.clone().unwrap();
@@ -457,8 +1246,10 @@ impl Wallet {
 
     /// Create account into wallet.
     pub fn create_account(&self, label: &String) -> Result<(), Error> {
-        let r_inst = self.instance.as_ref().read();
-        let instance = r_inst.clone().unwrap();
+        let instance = {
+            let r_inst = self.instance.as_ref().read();
+            r_inst.clone().unwrap()
+        };
         let mut api = Owner::new(instance, None);
         controller::owner_single_use(None, None, Some(&mut api), |api, m| {
             let id = api.create_account_path(m, label)?;
@@ -475,7 +1266,10 @@ impl Wallet {
                 });
             }
             Ok(())
-        })
+        })?;
+        // Save address of new account.
+        let _ = self.update_own_addresses();
+        Ok(())
     }
 
     /// Set active account from provided label.
@@ -503,6 +1297,10 @@ impl Wallet {
         // Clear wallet info.
         let mut w_data = self.data.write();
         *w_data = None;
+        self.update_balance(None);
+        self.data_stale.store(false, Ordering::Relaxed);
+        self.close_fixture();
+        self.reset_txs_window();
 
         // Reset progress values.
         self.info_sync_progress.store(0, Ordering::Relaxed);
@@ -583,14 +1381,191 @@ impl Wallet {
         self.sync_attempts.store(0, Ordering::Relaxed);
     }
 
+    /// Take funds received at any opened wallet to notify about, with wallet name and amount.
+    pub fn take_received_funds() -> Option<(String, u64)> {
+        let mut w_received = RECEIVED_FUNDS.write();
+        if w_received.is_empty() {
+            return None;
+        }
+        Some(w_received.remove(0))
+    }
+
+    /// Get node client from wallet instance.
+    fn node_client(&self) -> Option<HTTPNodeClient> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone()?;
+        let mut w_lock = instance.lock();
+        w_lock.lc_provider()
+            .and_then(|lc| lc.wallet_inst())
+            .map(|w| w.w2n_client().clone())
+            .ok()
+    }
+
     /// Get wallet data.
     pub fn get_data(&self) -> Option<WalletData> {
         let r_data = self.data.read();
         r_data.clone()
     }
 
+    /// Get cached spendable and awaiting amounts of wallet data.
+    pub fn balance(&self) -> Option<(u64, u64)> {
+        *self.balance.read()
+    }
+
+    /// Update cached balance from provided wallet data.
+    fn update_balance(&self, data: Option<&WalletData>) {
+        let mut w_balance = self.balance.write();
+        *w_balance = data.map(|d| {
+            let awaiting = d.info.amount_awaiting_confirmation +
+                d.info.amount_awaiting_finalization;
+            (d.info.amount_currently_spendable, awaiting)
+        });
+    }
+
+    /// Get identifiers of current account transactions matching provided filter
+    /// from wallet database.
+    pub fn filter_txs(&self, filter: &TxFilter) -> Result<Vec<u32>, Error> {
+        let instance = {
+            let r_inst = self.instance.as_ref().read();
+            r_inst.clone().ok_or_else(|| Error::GenericError("Not opened".to_string()))?
+        };
+        let parent_key_id = self.get_parent_key_id()?;
+        let txs = retrieve_txs(instance,
+                               None,
+                               &None,
+                               false,
+                               None,
+                               None,
+                               Some(filter.query_args()))?.1;
+        let memo_store = TxMemoStore::new(self.get_config().get_tx_db_path(&parent_key_id));
+        Ok(txs.iter()
+            .filter(|tx| tx.parent_key_id == parent_key_id)
+            .filter(|tx| filter.matches(tx, memo_store.read_tx_memo(tx.id)))
+            .map(|tx| tx.id)
+            .collect())
+    }
+
+    /// Get all transactions of current account from wallet database.
+    pub fn retrieve_account_txs(&self) -> Result<Vec<TxLogEntry>, Error> {
+        let instance = {
+            let r_inst = self.instance.as_ref().read();
+            r_inst.clone().ok_or_else(|| Error::GenericError("Not opened".to_string()))?
+        };
+        let parent_key_id = self.get_parent_key_id()?;
+        let txs = retrieve_txs(instance, None, &None, false, None, None, None)?.1;
+        Ok(txs.into_iter().filter(|tx| tx.parent_key_id == parent_key_id).collect())
+    }
+
+    /// Check if more transactions can be loaded from wallet database.
+    pub fn has_more_txs(&self) -> bool {
+        self.txs_more.load(Ordering::Relaxed)
+    }
+
+    /// Check if more transactions are loading from wallet database.
+    pub fn txs_loading(&self) -> bool {
+        self.txs_loading.load(Ordering::Relaxed)
+    }
+
+    /// Load next page of transactions from wallet database.
+    pub fn load_more_txs(&self) {
+        if !self.has_more_txs() || self.txs_loading() {
+            return;
+        }
+        self.txs_loading.store(true, Ordering::Relaxed);
+        self.txs_limit.fetch_add(TXS_PAGE_SIZE, Ordering::Relaxed);
+        {
+            let mut w_min_id = self.txs_min_id.write();
+            *w_min_id = None;
+        }
+        // Wake up sync thread to load transactions.
+        let r_thread = self.sync_thread.read();
+        if let Some(thread) = r_thread.as_ref() {
+            thread.unpark();
+        }
+    }
+
+    /// Reset loaded transactions window to the first page.
+    fn reset_txs_window(&self) {
+        self.txs_limit.store(TXS_PAGE_SIZE, Ordering::Relaxed);
+        self.txs_more.store(false, Ordering::Relaxed);
+        let mut w_min_id = self.txs_min_id.write();
+        *w_min_id = None;
+    }
+
+    /// Check if wallet data was saved at previous launch and is not synced yet.
+    pub fn is_data_stale(&self) -> bool {
+        self.data_stale.load(Ordering::Relaxed)
+    }
+
+    /// Get replayed wallet data fixture.
+    pub fn fixture_data(&self) -> Option<WalletData> {
+        self.fixture.read().clone()
+    }
+
+    /// Record anonymized fixture of current wallet data.
+    pub fn record_fixture(&self) -> Option<Vec<u8>> {
+        WalletFixture::record(&self.get_data()?)
+    }
+
+    /// Replay recorded fixture to show it in read-only mode without changes of wallet data.
+    pub fn replay_fixture(&self, fixture: &[u8]) -> bool {
+        if let Some(data) = WalletFixture::load(fixture) {
+            let mut w_fixture = self.fixture.write();
+            *w_fixture = Some(data);
+            return true;
+        }
+        false
+    }
+
+    /// Stop showing replayed wallet data fixture.
+    pub fn close_fixture(&self) {
+        let mut w_fixture = self.fixture.write();
+        *w_fixture = None;
+    }
+
+    /// Load encrypted wallet data saved at previous launch for current account.
+    fn load_data_snapshot(&self) {
+        if self.get_data().is_some() {
+            return;
+        }
+        let r_secret = self.data_secret.read();
+        if let Some(secret) = r_secret.as_ref() {
+            let config = self.get_config();
+            let store = WalletDataStore::new(config.get_extra_db_path());
+            let snapshot = store.read_snapshot()
+                .and_then(|data| WalletUtils::decrypt_with_secret(secret, &data))
+                .and_then(|data| serde_json::from_slice::<WalletDataSnapshot>(&data).ok());
+            if let Some(snapshot) = snapshot {
+                if snapshot.account == config.account {
+                    self.update_balance(Some(&snapshot.data));
+                    let mut w_data = self.data.write();
+                    *w_data = Some(snapshot.data);
+                    self.data_stale.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Save encrypted wallet data to show at next launch.
+    fn save_data_snapshot(&self) {
+        let r_secret = self.data_secret.read();
+        if let (Some(secret), Some(data)) = (r_secret.as_ref(), self.get_data()) {
+            let config = self.get_config();
+            let snapshot = WalletDataSnapshot {
+                account: config.account.clone(),
+                data,
+            };
+            if let Ok(json) = serde_json::to_vec(&snapshot) {
+                if let Some(encrypted) = WalletUtils::encrypt_with_secret(secret, &json) {
+                    WalletDataStore::new(config.get_extra_db_path()).write_snapshot(&encrypted);
+                }
+            }
+        }
+    }
+
     /// Sync wallet data from node at sync thread or locally synchronously.
     pub fn sync(&self) {
+        self.sync_requested.store(true, Ordering::Relaxed);
         let thread_r = self.sync_thread.read();
         if let Some(thread) = thread_r.as_ref() {
             thread.unpark();
@@ -612,6 +1587,31 @@ impl Wallet {
         None
     }
 
+    /// Get running Owner API server port.
+    pub fn owner_api_port(&self) -> Option<u16> {
+        self.owner_api_server.read().as_ref().map(|api| api.1)
+    }
+
+    /// Get log of requests received by Foreign API, latest first.
+    pub fn foreign_api_log(&self) -> Vec<ForeignApiRequest> {
+        self.foreign_api_log.read().iter().rev().cloned().collect()
+    }
+
+    /// Save request received by Foreign API to the log.
+    pub fn log_foreign_api_request(&self, request: ForeignApiRequest) {
+        let mut w_log = self.foreign_api_log.write();
+        if w_log.len() >= FOREIGN_API_LOG_SIZE {
+            w_log.pop_front();
+        }
+        w_log.push_back(request);
+    }
+
+    /// Clear log of requests received by Foreign API.
+    pub fn clear_foreign_api_log(&self) {
+        let mut w_log = self.foreign_api_log.write();
+        w_log.clear();
+    }
+
     /// Parse Slatepack message into [`Slate`].
     pub fn parse_slatepack(&self, text: &String) -> Result<Slate, grin_wallet_controller::Error> {
         let r_inst = self.instance.as_ref().read();
@@ -623,6 +1623,16 @@ impl Wallet {
         }
     }
 
+    /// Check if Slatepack message can be handled by opened wallet,
+    /// returning `true` if message belongs to existing wallet transaction.
+    pub fn slatepack_match(&self, text: &String) -> Option<bool> {
+        if !self.is_open() || self.get_data().and_then(|d| d.txs).is_none() {
+            return None;
+        }
+        let slate = self.parse_slatepack(text).ok()?;
+        Some(self.tx_by_slate(&slate).is_some())
+    }
+
     /// Create Slatepack message from provided slate.
     fn create_slatepack_message(&self, slate: &Slate) -> Result<String, Error> {
         let mut message = "".to_string();
@@ -651,6 +1661,39 @@ impl Wallet {
         }
     }
 
+    /// Get names of all stored Slatepack message files for transaction.
+    pub fn slatepack_files(&self, tx: &WalletTransaction) -> Vec<String> {
+        let slate_id = match tx.data.tx_slate_id {
+            Some(id) => id.to_string(),
+            None => return vec![]
+        };
+        let prefix = format!("{}.", slate_id);
+        let mut files = fs::read_dir(self.get_config().get_slatepacks_dir())
+            .map(|dir| {
+                dir.filter_map(|e| e.ok())
+                    .filter_map(|e| e.file_name().to_str().map(|n| n.to_string()))
+                    .filter(|n| n.starts_with(&prefix) && n.ends_with(".slatepack"))
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+        files.sort();
+        files
+    }
+
+    /// Read Slatepack message from stored file with provided name.
+    pub fn read_slatepack_file(&self, name: &String) -> Option<String> {
+        let mut path = self.get_config().get_slatepacks_dir();
+        path.push(name);
+        fs::read_to_string(path).ok()
+    }
+
+    /// Delete stored Slatepack message file with provided name.
+    pub fn delete_slatepack_file(&self, name: &String) {
+        let mut path = self.get_config().get_slatepacks_dir();
+        path.push(name);
+        let _ = fs::remove_file(path);
+    }
+
     /// Get last stored [`Slate`] for transaction.
     pub fn read_slate_by_tx(&self, tx: &WalletTransaction) -> Option<(Slate, String)> {
         let mut slate = None;
@@ -706,7 +1749,11 @@ impl Wallet {
     }
 
     /// Initialize a transaction to send amount, return request for funds receiver.
-    pub fn send(&self, amount: u64, receiver: Option<SlatepackAddress>) -> Result<WalletTransaction, Error> {
+    pub fn send(&self,
+                amount: u64,
+                receiver: Option<SlatepackAddress>,
+                fee_factor: u64) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         let config = self.get_config();
         let args = InitTxArgs {
             payment_proof_recipient_address: receiver,
@@ -720,13 +1767,16 @@ impl Wallet {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let api = Owner::new(instance, None);
-        let slate = api.init_send_tx(None, args)?;
+        let slate = with_fee_factor(fee_factor, || api.init_send_tx(None, args))?;
 
         // Lock outputs to for this transaction.
         api.tx_lock_outputs(None, &slate)?;
 
-        // Create Slatepack message response.
-        let _ = self.create_slatepack_message(&slate)?;
+        // Create Slatepack message response, unlock outputs on failure.
+        if let Err(e) = self.create_slatepack_message(&slate) {
+            let _ = api.cancel_tx(None, None, Some(slate.id));
+            return Err(e);
+        }
 
         // Refresh wallet info.
         sync_wallet_data(&self, false);
@@ -735,12 +1785,56 @@ impl Wallet {
         Ok(tx)
     }
 
+    /// Estimate fee to send amount with provided fee base factor without locking of outputs.
+    pub fn estimate_fee(&self, amount: u64, fee_factor: u64) -> Result<u64, Error> {
+        let config = self.get_config();
+        let args = InitTxArgs {
+            src_acct_name: Some(config.account),
+            amount,
+            minimum_confirmations: config.min_confirmations,
+            num_change_outputs: 1,
+            selection_strategy_is_use_all: false,
+            estimate_only: Some(true),
+            ..Default::default()
+        };
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+        let slate = with_fee_factor(fee_factor, || api.init_send_tx(None, args))?;
+        Ok(slate.fee_fields.fee())
+    }
+
+    /// Get statistics of sending over Tor since wallet opening.
+    pub fn tor_stats(&self) -> TransportStats {
+        self.tor_stats.read().clone()
+    }
+
     /// Send amount to provided address with Tor transport.
     pub async fn send_tor(&mut self,
                           amount: u64,
-                          addr: &SlatepackAddress) -> Result<WalletTransaction, Error> {
+                          addr: &SlatepackAddress,
+                          fee_factor: u64) -> Result<WalletTransaction, Error> {
+        let start = Instant::now();
+        let res = self.send_tor_tx(amount, addr, fee_factor).await;
+        // Update sending statistics.
+        let mut w_stats = self.tor_stats.write();
+        if res.is_ok() {
+            w_stats.sent += 1;
+        } else {
+            w_stats.failed += 1;
+        }
+        w_stats.send_duration += start.elapsed();
+        res
+    }
+
+    /// Send transaction to provided address over Tor, finalize and post it.
+    async fn send_tor_tx(&mut self,
+                         amount: u64,
+                         addr: &SlatepackAddress,
+                         fee_factor: u64) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         // Initialize transaction.
-        let tx = self.send(amount, Some(addr.clone()))?;
+        let tx = self.send(amount, Some(addr.clone()), fee_factor)?;
         let slate_res = self.read_slate_by_tx(&tx);
         if slate_res.is_none() {
             return Err(Error::GenericError("Slate not found".to_string()));
@@ -799,6 +1893,8 @@ impl Wallet {
                         ret_slate = Some(slate.clone());
                         // Save Slatepack message to file.
                         let _ = self.create_slatepack_message(&slate).unwrap_or("".to_string());
+                        self.save_tx_stage(&slate, TxStage::Signed);
+                        self.save_tx_stage(&slate, TxStage::Finalized);
                         // Post transaction to blockchain.
                         let result = self.post(&slate);
                         match result {
@@ -829,6 +1925,7 @@ impl Wallet {
 
     /// Initialize an invoice transaction to receive amount, return request for funds sender.
     pub fn issue_invoice(&self, amount: u64) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         let args = IssueInvoiceTxArgs {
             dest_acct_name: None,
             amount,
@@ -851,6 +1948,7 @@ impl Wallet {
 
     /// Handle message from the invoice issuer to send founds, return response for funds receiver.
     pub fn pay(&self, message: &String) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         if let Ok(slate) = self.parse_slatepack(message) {
             let config = self.get_config();
             let args = InitTxArgs {
@@ -866,11 +1964,15 @@ impl Wallet {
             let slate = api.process_invoice_tx(None, &slate, args)?;
             api.tx_lock_outputs(None, &slate)?;
 
-            // Create Slatepack message response.
-            let _ = self.create_slatepack_message(&slate)?;
+            // Create Slatepack message response, unlock outputs on failure.
+            if let Err(e) = self.create_slatepack_message(&slate) {
+                let _ = api.cancel_tx(None, None, Some(slate.id));
+                return Err(e);
+            }
 
             // Refresh wallet info.
             sync_wallet_data(&self, false);
+            self.save_tx_stage(&slate, TxStage::Signed);
 
             Ok(self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?)
         } else {
@@ -880,6 +1982,7 @@ impl Wallet {
 
     /// Handle message to receive funds, return response to sender.
     pub fn receive(&self, message: &String) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         if let Ok(mut slate) = self.parse_slatepack(message) {
             let r_inst = self.instance.as_ref().read();
             let instance = r_inst.clone().unwrap();
@@ -893,6 +1996,7 @@ impl Wallet {
 
             // Refresh wallet info.
             sync_wallet_data(&self, false);
+            self.save_tx_stage(&slate, TxStage::Signed);
 
             Ok(self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?)
         } else {
@@ -902,6 +2006,7 @@ impl Wallet {
 
     /// Finalize transaction from provided message as sender or invoice issuer with Dandelion.
     pub fn finalize(&self, message: &String) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
         if let Ok(mut slate) = self.parse_slatepack(message) {
             let r_inst = self.instance.as_ref().read();
             let instance = r_inst.clone().unwrap();
@@ -909,9 +2014,19 @@ impl Wallet {
             slate = api.finalize_tx(None, &slate)?;
             // Save Slatepack message to file.
             let _ = self.create_slatepack_message(&slate)?;
+            self.save_tx_stage(&slate, TxStage::Signed);
+            self.save_tx_stage(&slate, TxStage::Finalized);
 
-            // Post transaction to blockchain.
-            let tx = self.post(&slate)?;
+            // Post transaction to blockchain or queue it if node is not available.
+            let tx = match self.post(&slate) {
+                Ok(tx) => tx,
+                Err(e) => {
+                    if self.node_available() {
+                        return Err(e);
+                    }
+                    self.queue_post(&slate)?
+                }
+            };
 
             // Refresh wallet info.
             sync_wallet_data(&self, false);
@@ -928,84 +2043,942 @@ impl Wallet {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let api = Owner::new(instance, None);
-        api.post_tx(None, slate, self.can_use_dandelion())?;
+        api.post_tx(None, slate, self.can_use_dandelion())?;
+
+        // Refresh wallet info.
+        sync_wallet_data(&self, false);
+        self.save_tx_stage(slate, TxStage::Posted);
+
+        Ok(self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?)
+    }
+
+    /// Save current time of transaction stage for provided [`Slate`].
+    fn save_tx_stage(&self, slate: &Slate, stage: TxStage) {
+        let (id, parent_key_id) = match self.tx_by_slate(slate) {
+            Some(tx) => (tx.data.id, tx.data.parent_key_id),
+            None => return
+        };
+        let config = self.get_config();
+        let store = TxStageStore::new(config.get_tx_db_path(&parent_key_id));
+        let stages = store.write(id, stage, chrono::Utc::now().timestamp());
+
+        // Update stage times at wallet data.
+        let mut w_data = self.data.write();
+        if let Some(data) = w_data.as_mut() {
+            if let Some(txs) = data.txs.as_mut() {
+                for tx in txs.iter_mut() {
+                    if tx.data.id == id && tx.data.parent_key_id == parent_key_id {
+                        tx.stages = stages;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check if node used by the wallet is reachable.
+    fn node_available(&self) -> bool {
+        let r_inst = self.instance.as_ref().read();
+        if let Some(instance) = r_inst.clone() {
+            let mut w_lock = instance.lock();
+            if let Ok(lc) = w_lock.lc_provider() {
+                if let Ok(w) = lc.wallet_inst() {
+                    return w.w2n_client().get_chain_tip().is_ok();
+                }
+            }
+        }
+        false
+    }
+
+    /// Save finalized transaction to post it when node will be available.
+    fn queue_post(&self, slate: &Slate) -> Result<WalletTransaction, Error> {
+        sync_wallet_data(&self, false);
+        let tx = self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?;
+        let config = self.get_config();
+        let store = TxPostQueueStore::new(config.get_tx_db_path(&tx.data.parent_key_id));
+        store.queue(tx.data.id, chrono::Utc::now().timestamp());
+        sync_wallet_data(&self, false);
+        Ok(self.tx_by_slate(&slate).unwrap_or(tx))
+    }
+
+    /// Get transactions queued to post when node will be available.
+    pub fn post_queue(&self) -> Vec<WalletTransaction> {
+        if let Some(data) = self.get_data() {
+            if let Some(txs) = data.txs {
+                return txs.into_iter().filter(|tx| tx.post_queued.is_some()).collect();
+            }
+        }
+        vec![]
+    }
+
+    /// Try to post queued transaction, removing it from the queue on success.
+    pub fn post_queued(&self, tx: &WalletTransaction) -> Result<(), Error> {
+        let _task = self.start_task();
+        let slate = self.finalized_slate(tx)?;
+        self.post(&slate)?;
+        self.remove_from_post_queue(&tx.data);
+        Ok(())
+    }
+
+    /// Read finalized transaction [`Slate`] from saved Slatepack message.
+    fn finalized_slate(&self, tx: &WalletTransaction) -> Result<Slate, Error> {
+        let slate_id = tx.data.tx_slate_id.ok_or(Error::GenericError("No slate".to_string()))?;
+        let mut slate = Slate::blank(1, false);
+        slate.id = slate_id;
+        slate.state = match tx.data.tx_type {
+            TxLogEntryType::TxReceived => SlateState::Invoice3,
+            _ => SlateState::Standard3
+        };
+        let message = self.read_slatepack(&slate)
+            .ok_or(Error::GenericError("No Slatepack message found".to_string()))?;
+        self.parse_slatepack(&message)
+    }
+
+    /// Automatically rebroadcast finalized transactions which were not confirmed
+    /// after delay, up to maximal amount of attempts.
+    fn rebroadcast_txs(&self) {
+        let txs = match self.get_data().and_then(|d| d.txs) {
+            Some(txs) => txs,
+            None => return
+        };
+        let config = self.get_config();
+        let now = chrono::Utc::now().timestamp();
+        for tx in txs {
+            if !self.is_open() || self.is_closing() {
+                return;
+            }
+            let store = TxRepostStore::new(config.get_tx_db_path(&tx.data.parent_key_id));
+            let unconfirmed = tx.finalizing && !tx.data.confirmed && !tx.cancelling &&
+                tx.post_queued.is_none() && tx.from_node &&
+                (tx.data.tx_type == TxLogEntryType::TxSent ||
+                    tx.data.tx_type == TxLogEntryType::TxReceived);
+            if !unconfirmed {
+                continue;
+            }
+            // Start counting delay from the first check.
+            let (time, count) = match store.read(tx.data.id) {
+                Some(r) => r,
+                None => {
+                    store.write(tx.data.id, now, 0);
+                    continue;
+                }
+            };
+            if count >= MAX_TX_REPOSTS || now - time < TX_REPOST_DELAY {
+                continue;
+            }
+            store.write(tx.data.id, now, count + 1);
+            let _ = self.finalized_slate(&tx).and_then(|slate| self.post(&slate));
+        }
+    }
+
+    /// Try to post all queued transactions.
+    pub fn post_queued_txs(&self) {
+        for tx in self.post_queue() {
+            if !self.is_open() || self.is_closing() {
+                return;
+            }
+            let _ = self.post_queued(&tx);
+        }
+    }
+
+    /// Remove transaction from the queue to post.
+    pub fn remove_from_post_queue(&self, entry: &TxLogEntry) {
+        let config = self.get_config();
+        let store = TxPostQueueStore::new(config.get_tx_db_path(&entry.parent_key_id));
+        store.remove(entry.id);
+
+        // Update queue status at wallet data.
+        let mut w_data = self.data.write();
+        if let Some(data) = w_data.as_mut() {
+            if let Some(txs) = data.txs.as_mut() {
+                for tx in txs.iter_mut() {
+                    if tx.data.id == entry.id && tx.data.parent_key_id == entry.parent_key_id {
+                        tx.post_queued = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get outstanding invoices issued by the wallet.
+    pub fn invoices(&self) -> Vec<WalletTransaction> {
+        if let Some(data) = self.get_data() {
+            if let Some(txs) = data.txs {
+                return txs.into_iter().filter(|tx| tx.is_invoice()).collect();
+            }
+        }
+        vec![]
+    }
+
+    /// Mark issued invoice as abandoned or restore it.
+    pub fn update_invoice_abandoned(&self, entry: &TxLogEntry, abandoned: bool) {
+        let config = self.get_config();
+        let store = TxAbandonedStore::new(config.get_tx_db_path(&entry.parent_key_id));
+        let time = if abandoned {
+            let now = chrono::Utc::now().timestamp();
+            store.abandon(entry.id, now);
+            Some(now)
+        } else {
+            store.remove(entry.id);
+            None
+        };
+
+        // Update abandoned status at wallet data.
+        let mut w_data = self.data.write();
+        if let Some(data) = w_data.as_mut() {
+            if let Some(txs) = data.txs.as_mut() {
+                for tx in txs.iter_mut() {
+                    if tx.data.id == entry.id && tx.data.parent_key_id == entry.parent_key_id {
+                        tx.abandoned = time;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Cancel transaction.
+    pub fn cancel(&self, id: u32) {
+        // Setup cancelling status.
+        {
+            let mut w_data = self.data.write();
+            let mut data = w_data.clone().unwrap();
+            let txs = data.txs.clone().unwrap().iter_mut().map(|tx| {
+                if tx.data.id == id {
+                    tx.cancelling = true;
+                    tx.can_finalize = false;
+                }
+                tx.clone()
+            }).collect::<Vec<WalletTransaction>>();
+            data.txs = Some(txs);
+            *w_data = Some(data);
+        }
+
+        self.queue_cancel(id);
+    }
+
+    /// Run transaction cancellation or queue it until wallet repair is finished,
+    /// saving it to continue after next launch.
+    fn queue_cancel(&self, id: u32) {
+        if self.is_repairing() {
+            self.update_queued_cancel(id, true);
+        }
+        self.queue_action(move |wallet| {
+            let _task = wallet.start_task();
+            // Wait sync to finish.
+            if wallet.syncing() {
+                thread::sleep(Duration::from_millis(1000));
+            }
+            let instance = {
+                let r_inst = wallet.instance.as_ref().read();
+                r_inst.clone()
+            };
+            if let Some(instance) = instance {
+                if cancel_tx(instance, None, &None, Some(id), None).is_ok() {
+                    wallet.update_queued_cancel(id, false);
+                    sync_wallet_data(wallet, false);
+                }
+            }
+        });
+    }
+
+    /// Save or remove transaction identifier from the list of queued cancellations.
+    fn update_queued_cancel(&self, id: u32, queued: bool) {
+        let mut w_config = self.config.write();
+        let mut ids = w_config.queued_cancels.clone().unwrap_or_default();
+        ids.retain(|i| *i != id);
+        if queued {
+            ids.push(id);
+        }
+        w_config.queued_cancels = if ids.is_empty() { None } else { Some(ids) };
+        w_config.save();
+    }
+
+    /// Check if sent transaction can be replaced: it was finalized but never posted,
+    /// or it was posted long ago and automatic rebroadcast attempts are finished.
+    pub fn can_replace_tx(&self, tx: &WalletTransaction) -> bool {
+        let unconfirmed = tx.data.tx_type == TxLogEntryType::TxSent && !tx.data.confirmed &&
+            tx.finalizing && !tx.cancelling && tx.from_node;
+        if !unconfirmed {
+            return false;
+        }
+        let never_posted = tx.stages.finalized.is_some() && tx.stages.posted.is_none();
+        let now = chrono::Utc::now().timestamp();
+        let expired = tx.reposts >= MAX_TX_REPOSTS && tx.stages.posted
+            .map(|t| now - t >= REPLACE_TX_DELAY)
+            .unwrap_or(false);
+        never_posted || expired
+    }
+
+    /// Cancel stuck sent transaction and create the same payment with increased fee,
+    /// preserving receiver address and memo. Posted transaction is replaced only after
+    /// verification that it is not included into the chain and not waiting at node pool.
+    pub fn replace_tx(&self, tx: &WalletTransaction) -> Result<WalletTransaction, Error> {
+        if !self.can_replace_tx(tx) {
+            return Err(Error::GenericError("Transaction can not be replaced".to_string()));
+        }
+        let _task = self.start_task();
+        if tx.stages.posted.is_some() {
+            self.verify_tx_expired(tx)?;
+        }
+        let fee = tx.data.fee.map(|f| f.fee()).unwrap_or(0);
+        let amount = tx.amount.saturating_sub(fee);
+        let receiver = tx.receiver();
+        let inputs = self.tx_inputs(tx.data.id)?;
+
+        // Cancel stuck transaction to unlock outputs.
+        {
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            cancel_tx(instance, None, &None, Some(tx.data.id), None)?;
+        }
+
+        // Create new transaction spending inputs of stuck one with increased fee.
+        let min_fee = fee + global::get_accept_fee_base();
+        let new_tx = self.send_replacement(amount, receiver, &inputs, min_fee)?;
+
+        // Move memo to the new transaction.
+        if let Some(memo) = &tx.memo {
+            self.update_tx_memo(&new_tx.data, memo.clone());
+        }
+        Ok(new_tx)
+    }
+
+    /// Get outputs locked as inputs by provided sent transaction.
+    fn tx_inputs(&self, tx_id: u32) -> Result<Vec<OutputData>, Error> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let parent_key_id = w_inst.parent_key_id();
+        let inputs = w_inst.iter().filter(|o| {
+            o.root_key_id == parent_key_id && o.tx_log_entry == Some(tx_id) &&
+                o.status == OutputStatus::Locked
+        }).collect::<Vec<OutputData>>();
+        if inputs.is_empty() {
+            return Err(Error::GenericError("No transaction inputs".to_string()));
+        }
+        Ok(inputs)
+    }
+
+    /// Initialize a transaction to replace cancelled one, spending at least one of its inputs
+    /// to conflict with it and paying not less than provided fee.
+    fn send_replacement(&self,
+                        amount: u64,
+                        receiver: Option<SlatepackAddress>,
+                        inputs: &Vec<OutputData>,
+                        min_fee: u64) -> Result<WalletTransaction, Error> {
+        let config = self.get_config();
+        let args = |use_all: bool| InitTxArgs {
+            payment_proof_recipient_address: receiver.clone(),
+            src_acct_name: Some(config.account.clone()),
+            amount,
+            minimum_confirmations: config.min_confirmations,
+            num_change_outputs: 1,
+            selection_strategy_is_use_all: use_all,
+            ..Default::default()
+        };
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+
+        // Select only inputs of cancelled transaction or all outputs when they are not enough.
+        let slate = match self.with_only_inputs(inputs, || {
+            init_tx_with_min_fee(&api, args(false), min_fee)
+        }) {
+            Err(Error::NotEnoughFunds { .. }) => init_tx_with_min_fee(&api, args(true), min_fee),
+            res => res
+        }?;
+
+        // Check that transactions are conflicting to not send the payment twice.
+        let commits = inputs.iter()
+            .filter_map(|o| o.commit.clone())
+            .collect::<Vec<String>>();
+        let conflicting = slate.tx_or_err()?
+            .inputs_committed()
+            .iter()
+            .any(|c| commits.contains(&c.to_hex()));
+        if !conflicting {
+            return Err(Error::GenericError("Inputs of transaction not spent".to_string()));
+        }
+
+        // Lock outputs to for this transaction.
+        api.tx_lock_outputs(None, &slate)?;
+
+        // Create Slatepack message response, unlock outputs on failure.
+        if let Err(e) = self.create_slatepack_message(&slate) {
+            let _ = api.cancel_tx(None, None, Some(slate.id));
+            return Err(e);
+        }
+
+        // Refresh wallet info.
+        sync_wallet_data(&self, false);
+
+        let tx = self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?;
+        Ok(tx)
+    }
+
+    /// Run callback while spendable outputs of current account except provided inputs are
+    /// temporarily marked as locked to exclude them from coin selection.
+    fn with_only_inputs<T>(&self,
+                           inputs: &Vec<OutputData>,
+                           f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let excluded = {
+            let mut w_lock = instance.lock();
+            let lc = w_lock.lc_provider()?;
+            let w_inst = lc.wallet_inst()?;
+            let parent_key_id = w_inst.parent_key_id();
+            let excluded = w_inst.iter().filter(|o| {
+                o.root_key_id == parent_key_id && o.status == OutputStatus::Unspent &&
+                    !inputs.iter().any(|i| i.key_id == o.key_id)
+            }).collect::<Vec<OutputData>>();
+            let mut batch = w_inst.batch(None)?;
+            for o in &excluded {
+                let mut o = o.clone();
+                o.status = OutputStatus::Locked;
+                batch.save(o)?;
+            }
+            batch.commit()?;
+            excluded
+        };
+        let res = f();
+        // Restore status of excluded outputs.
+        {
+            let mut w_lock = instance.lock();
+            let lc = w_lock.lc_provider()?;
+            let w_inst = lc.wallet_inst()?;
+            let mut batch = w_inst.batch(None)?;
+            for o in excluded {
+                if let Ok(mut o) = batch.get(&o.key_id, &o.mmr_index) {
+                    if o.status == OutputStatus::Locked {
+                        o.status = OutputStatus::Unspent;
+                        batch.save(o)?;
+                    }
+                }
+            }
+            batch.commit()?;
+        }
+        res
+    }
+
+    /// Check that posted transaction kernel is not found at the chain and at node pool.
+    fn verify_tx_expired(&self, tx: &WalletTransaction) -> Result<(), Error> {
+        let excess = tx.data.kernel_excess
+            .ok_or(Error::GenericError("No transaction kernel".to_string()))?;
+        let mut client = self.node_client()
+            .ok_or(Error::GenericError("No node connection".to_string()))?;
+        if client.get_kernel(&excess, None, None)?.is_some() {
+            return Err(Error::GenericError("Transaction is confirmed".to_string()));
+        }
+        let conn = ExternalConnection::new(client.node_url().to_string(),
+                                           client.node_api_secret());
+        match conn.pool_has_kernel(&excess.0.to_hex()) {
+            Some(false) => Ok(()),
+            Some(true) => Err(Error::GenericError("Transaction is at pool".to_string())),
+            None => Err(Error::GenericError("Node pool is not available".to_string()))
+        }
+    }
+
+    /// Export transactions of provided account or all accounts, adding optional category
+    /// column from transaction memos and amounts split into debit and credit columns.
+    pub fn export_txs(&self,
+                      account: Option<String>,
+                      format: TxExportFormat,
+                      with_category: bool) -> Result<String, Error> {
+        let accounts = self.accounts();
+        let account_path = match &account {
+            Some(label) => Some(accounts.iter()
+                .find(|a| &a.label == label)
+                .map(|a| a.path.clone())
+                .ok_or(Error::GenericError("Account not found".to_string()))?),
+            None => None
+        };
+        // Read transactions of all accounts from database.
+        let mut txs: Vec<TxLogEntry> = vec![];
+        {
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            let mut w_lock = instance.lock();
+            let lc = w_lock.lc_provider()?;
+            let w_inst = lc.wallet_inst()?;
+            txs.extend(w_inst.tx_log_iter());
+        }
+        txs.retain(|tx| {
+            account_path.as_ref()
+                .map(|p| &tx.parent_key_id.to_bip_32_string() == p)
+                .unwrap_or(true)
+        });
+        txs.sort_by_key(|tx| tx.creation_ts);
+
+        let config = self.get_config();
+        let mut memo_stores: HashMap<Identifier, TxMemoStore> = HashMap::new();
+        let rows: Vec<Value> = txs.iter().map(|tx| {
+            let path = tx.parent_key_id.to_bip_32_string();
+            let account = accounts.iter()
+                .find(|a| a.path == path)
+                .map(|a| a.label.clone())
+                .unwrap_or(path);
+            let (debit, credit) = if tx.amount_debited > tx.amount_credited {
+                (tx.amount_debited - tx.amount_credited, 0)
+            } else {
+                (0, tx.amount_credited - tx.amount_debited)
+            };
+            let mut row = json!({
+                "id": tx.id,
+                "account": account,
+                "type": tx.tx_type.to_string(),
+                "confirmed": tx.confirmed,
+                "created": tx.creation_ts.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "confirmed_at": tx.confirmation_ts
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                "debit": amount_to_hr_string(debit, false),
+                "credit": amount_to_hr_string(credit, false),
+                "fee": amount_to_hr_string(tx.fee.map(|f| f.fee()).unwrap_or(0), false),
+                "slate_id": tx.tx_slate_id.map(|id| id.to_string()).unwrap_or_default(),
+            });
+            if with_category {
+                let memo_store = memo_stores.entry(tx.parent_key_id).or_insert_with(|| {
+                    TxMemoStore::new(config.get_tx_db_path(&tx.parent_key_id))
+                });
+                row["category"] = json!(memo_store.read_tx_memo(tx.id).unwrap_or_default());
+            }
+            row
+        }).collect();
+
+        match format {
+            TxExportFormat::Json => serde_json::to_string_pretty(&rows)
+                .map_err(|e| Error::GenericError(e.to_string())),
+            TxExportFormat::Csv => {
+                let mut columns = vec![
+                    "id", "account", "type", "confirmed", "created", "confirmed_at",
+                    "debit", "credit", "fee", "slate_id"
+                ];
+                if with_category {
+                    columns.push("category");
+                }
+                let mut csv = format!("{}\n", columns.join(","));
+                for row in rows {
+                    let values: Vec<String> = columns.iter().map(|c| {
+                        let value = match &row[*c] {
+                            Value::String(v) => v.clone(),
+                            v => v.to_string()
+                        };
+                        csv_value(value)
+                    }).collect();
+                    csv.push_str(&format!("{}\n", values.join(",")));
+                }
+                Ok(csv)
+            }
+        }
+    }
+
+    /// Save user memo for transaction, empty value removes memo.
+    pub fn update_tx_memo(&self, entry: &TxLogEntry, memo: String) {
+        let config = self.get_config();
+        let memo_store = TxMemoStore::new(config.get_tx_db_path(&entry.parent_key_id));
+        let memo = memo.trim().to_string();
+        memo_store.write_tx_memo(entry.id, &memo);
+
+        // Update memo at wallet data.
+        let mut w_data = self.data.write();
+        if let Some(data) = w_data.as_mut() {
+            if let Some(txs) = data.txs.as_mut() {
+                for tx in txs.iter_mut() {
+                    if tx.data.id == entry.id && tx.data.parent_key_id == entry.parent_key_id {
+                        tx.memo = if memo.is_empty() { None } else { Some(memo.clone()) };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get confirmation heights of transactions from kernels cache, starting lookup of missing
+    /// kernels at node in background, not found kernels are having zero height.
+    fn tx_heights(&self, txs: &[TxLogEntry], db_path: String) -> HashMap<u32, u64> {
+        let store = KernelStore::new(db_path.clone());
+        let now = chrono::Utc::now().timestamp();
+        let scan_start_height = self.get_config().scan_start_height();
+        let conn = self.get_current_connection();
+
+        // Read cached lookup results.
+        let ids = txs.iter()
+            .filter(|tx| tx.kernel_excess.is_some() && tx.confirmed)
+            .map(|tx| tx.id)
+            .collect::<Vec<u32>>();
+        let mut heights = store.read_heights(&ids);
+        let misses = store.read_misses(&ids);
+
+        // Setup transactions to lookup kernels at node.
+        let mut shared: Vec<(u32, u64)> = vec![];
+        let mut lookups: Vec<(u32, Commitment, Option<u64>)> = vec![];
+        for tx in txs {
+            if !tx.confirmed || heights.contains_key(&tx.id) {
+                continue;
+            }
+            let excess = match tx.kernel_excess {
+                Some(excess) => excess,
+                None => continue
+            };
+            // Use kernel height found by another wallet.
+            if let Some(height) = SyncScheduler::kernel_height(&conn, &excess) {
+                shared.push((tx.id, height));
+                continue;
+            }
+            // Skip lookup of not found kernel until retry time.
+            if let Some(miss) = misses.get(&tx.id) {
+                heights.insert(tx.id, 0);
+                if now < miss.retry_time {
+                    continue;
+                }
+            }
+            // Do not look for kernel below wallet creation height, old transactions
+            // without lookup height are checked from wallet creation height.
+            let min_height = tx.kernel_lookup_min_height.unwrap_or(0).max(scan_start_height);
+            lookups.push((tx.id, excess, Some(min_height)));
+        }
+        store.write_heights(&shared);
+        heights.extend(shared);
+
+        if !lookups.is_empty() {
+            self.backfill_kernels(lookups, misses, db_path);
+        }
+        heights
+    }
+
+    /// Get amount of checked and total transactions if lookup of kernels at node is running.
+    pub fn kernels_backfill_progress(&self) -> Option<(usize, usize)> {
+        if !self.kernels_backfill.load(Ordering::Relaxed) {
+            return None;
+        }
+        Some(*self.kernels_progress.read())
+    }
+
+    /// Lookup transaction kernels at node in background by batches of concurrent requests,
+    /// saving results into cache and updating heights of loaded transactions.
+    fn backfill_kernels(&self,
+                        lookups: Vec<(u32, Commitment, Option<u64>)>,
+                        misses: HashMap<u32, KernelMiss>,
+                        db_path: String) {
+        if self.kernels_backfill.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        *self.kernels_progress.write() = (0, lookups.len());
+        let wallet = self.clone();
+        thread::spawn(move || {
+            let store = KernelStore::new(db_path);
+            let conn = wallet.get_current_connection();
+            if let Some(client) = wallet.node_client() {
+                for batch in lookups.chunks(KERNEL_LOOKUP_LIMIT) {
+                    if !wallet.is_open() || wallet.is_closing() {
+                        break;
+                    }
+                    let results = thread::scope(|scope| {
+                        let handles = batch.iter().map(|(id, excess, min_height)| {
+                            let mut client = client.clone();
+                            scope.spawn(move || {
+                                (*id, *excess, client.get_kernel(excess, *min_height, None))
+                            })
+                        }).collect::<Vec<_>>();
+                        handles.into_iter().filter_map(|h| h.join().ok()).collect::<Vec<_>>()
+                    });
+
+                    let now = chrono::Utc::now().timestamp();
+                    let mut found: Vec<(u32, u64)> = vec![];
+                    let mut not_found: Vec<(u32, KernelMiss)> = vec![];
+                    for (id, excess, res) in results {
+                        match res {
+                            Ok(Some((_, h, _))) => {
+                                SyncScheduler::save_kernel_height(&conn, &excess, h);
+                                found.push((id, h));
+                            }
+                            Ok(None) => {
+                                // Save retry time for not found kernel.
+                                let attempts = misses.get(&id).map(|m| m.attempts).unwrap_or(0);
+                                let delay = (KERNEL_RETRY_DELAY << attempts.min(6))
+                                    .min(KERNEL_RETRY_MAX_DELAY);
+                                not_found.push((id, KernelMiss {
+                                    attempts: attempts + 1,
+                                    retry_time: now + delay,
+                                }));
+                            }
+                            Err(_) => {}
+                        }
+                    }
+                    store.write_heights(&found);
+                    store.write_misses(&not_found);
+
+                    // Update heights of loaded transactions.
+                    let mut heights: HashMap<u32, u64> = found.into_iter().collect();
+                    heights.extend(not_found.into_iter().map(|(id, _)| (id, 0)));
+                    {
+                        let mut w_data = wallet.data.write();
+                        if let Some(txs) = w_data.as_mut().and_then(|d| d.txs.as_mut()) {
+                            for tx in txs.iter_mut() {
+                                if let Some(h) = heights.get(&tx.data.id) {
+                                    tx.height = Some(*h);
+                                }
+                            }
+                        }
+                    }
+                    // Update lookup progress.
+                    wallet.kernels_progress.write().0 += batch.len();
+                }
+            }
+            wallet.kernels_backfill.store(false, Ordering::Relaxed);
+        });
+    }
+
+    /// Get additional key derivation rounds of seed file password.
+    pub fn kdf_rounds(&self) -> Option<u32> {
+        SeedKdf::read(&self.get_config().get_seed_path()).map(|kdf| kdf.rounds)
+    }
+
+    /// Check if additional key derivation can be applied to seed file,
+    /// not allowed for wallet imported in place to keep it compatible with CLI wallet.
+    pub fn kdf_allowed(&self) -> bool {
+        self.get_config().data_dir.is_none()
+    }
+
+    /// Change wallet password, migrating seed file to provided key derivation work factor.
+    pub fn change_password(&self,
+                           old: String,
+                           new: String,
+                           kdf_rounds: Option<u32>) -> Result<(), Error> {
+        // Seed file of decoy wallet is opened without key derivation.
+        let duress = self.is_duress();
+        let kdf_rounds = if duress { None } else { kdf_rounds };
+        // Seed file of wallet imported in place should stay readable by CLI wallet.
+        if kdf_rounds.is_some() && !self.kdf_allowed() {
+            return Err(Error::GenericError("Key derivation is not allowed".to_string()));
+        }
+        // Do not allow password which opens another wallet data.
+        let opens_other = if duress {
+            let mut config = self.get_config();
+            config.duress = false;
+            Self::is_seed_password(&mut config, &new)
+        } else {
+            self.check_duress_password(&new)
+        };
+        if opens_other {
+            return Err(Error::GenericError("Wrong password".to_string()));
+        }
+        let seed_path = self.get_config().get_seed_path();
+        let old_kdf = SeedKdf::read(&seed_path);
+        let old_pass = SeedKdf::seed_password(&seed_path, &old);
+        let kdf = kdf_rounds.map(SeedKdf::new);
+        let new_pass = match &kdf {
+            Some(kdf) => kdf.derive(&new),
+            None => ZeroingString::from(new)
+        };
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut wallet_lock = instance.lock();
+        let lc = wallet_lock.lc_provider()?;
+        lc.change_password(None, old_pass.clone(), new_pass.clone())?;
+        if !SeedKdf::write(kdf.as_ref(), &seed_path) {
+            // Restore previous password and header to keep seed file readable.
+            lc.change_password(None, new_pass, old_pass)?;
+            SeedKdf::write(old_kdf.as_ref(), &seed_path);
+            return Err(Error::GenericError("Failed to save seed header".to_string()));
+        }
+        if duress {
+            // Update password check to open decoy wallet, quick unlock data is not affected.
+            let hash = WalletUtils::hash_password(&new_pass);
+            let mut w_config = self.config.write();
+            w_config.pass_check = Some(hash.to_hex());
+            w_config.save();
+        } else {
+            // Reset quick unlock data encrypted with old password.
+            self.update_unlock_data(None, None);
+        }
+        Ok(())
+    }
+
+    /// Check if provided password decrypts seed file of wallet with provided config.
+    fn is_seed_password(config: &mut WalletConfig, password: &str) -> bool {
+        let instance = match Self::create_wallet_instance(config) {
+            Ok(i) => i,
+            Err(_) => return false
+        };
+        let mut wallet_lock = instance.lock();
+        match wallet_lock.lc_provider() {
+            Ok(lc) => {
+                let seed_pass = SeedKdf::seed_password(&config.get_seed_path(), password);
+                lc.get_mnemonic(None, seed_pass).is_ok()
+            }
+            Err(_) => false
+        }
+    }
+
+    /// Get spendable outputs of current account sorted by value.
+    fn spendable_outputs(&self) -> Result<Vec<OutputData>, Error> {
+        let min_confirmations = self.get_config().min_confirmations;
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let parent_key_id = w_inst.parent_key_id();
+        let height = w_inst.last_confirmed_height()?;
+        let mut outputs = w_inst.iter().filter(|o| {
+            o.root_key_id == parent_key_id && o.eligible_to_spend(height, min_confirmations)
+        }).collect::<Vec<OutputData>>();
+        outputs.sort_by_key(|o| o.value);
+        Ok(outputs)
+    }
+
+    /// Get amount of spendable outputs of current account.
+    pub fn spendable_outputs_count(&self) -> Result<usize, Error> {
+        Ok(self.spendable_outputs()?.len())
+    }
+
+    /// Calculate maximum amount to send using all spendable outputs with fee base multiplier.
+    pub fn max_send_amount(&self, fee_factor: u64) -> Result<u64, Error> {
+        let outputs = self.spendable_outputs()?;
+        let total: u64 = outputs.iter().map(|o| o.value).sum();
+        // Fee for receiver and change outputs.
+        let fee = tx_fee(outputs.len(), 2, 1) * fee_factor;
+        Ok(total.saturating_sub(fee))
+    }
+
+    /// Combine smallest spendable outputs into one with self-spend transaction to keep
+    /// configured amount of outputs.
+    pub fn consolidate_outputs(&self) -> Result<WalletTransaction, Error> {
+        let _task = self.start_task();
+        let outputs = self.spendable_outputs()?;
+        let target = self.consolidation_target();
+        if outputs.len() <= target {
+            return Err(Error::GenericError("Nothing to consolidate".to_string()));
+        }
+        // Combine smallest outputs without change.
+        let inputs = (outputs.len() - target + 1).min(MAX_CONSOLIDATION_INPUTS);
+        let total: u64 = outputs.iter().take(inputs).map(|o| o.value).sum();
+        let fee = tx_fee(inputs, 1, 1);
+        if total <= fee {
+            return Err(Error::GenericError("Outputs value is lower than fee".to_string()));
+        }
+
+        let config = self.get_config();
+        let args = InitTxArgs {
+            src_acct_name: Some(config.account.clone()),
+            amount: total - fee,
+            minimum_confirmations: config.min_confirmations,
+            num_change_outputs: 1,
+            selection_strategy_is_use_all: false,
+            ..Default::default()
+        };
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+        let mut slate = api.init_send_tx(None, args)?;
+        api.tx_lock_outputs(None, &slate)?;
 
-        // Refresh wallet info.
-        sync_wallet_data(&self, false);
+        // Receive transaction by the same account.
+        controller::foreign_single_use(api.wallet_inst.clone(), None, |foreign| {
+            slate = foreign.receive_tx(&slate, Some(config.account.as_str()), None)?;
+            Ok(())
+        })?;
+        slate = api.finalize_tx(None, &slate)?;
+        drop(r_inst);
 
-        Ok(self.tx_by_slate(&slate).ok_or(Error::GenericError("No tx found".to_string()))?)
+        // Post transaction to blockchain.
+        self.post(&slate)
     }
 
-    /// Cancel transaction.
-    pub fn cancel(&self, id: u32) {
-        // Setup cancelling status.
-        {
-            let mut w_data = self.data.write();
-            let mut data = w_data.clone().unwrap();
-            let txs = data.txs.clone().unwrap().iter_mut().map(|tx| {
-                if tx.data.id == id {
-                    tx.cancelling = true;
-                    tx.can_finalize = false;
-                }
-                tx.clone()
-            }).collect::<Vec<WalletTransaction>>();
-            data.txs = Some(txs);
-            *w_data = Some(data);
+    /// Create signed proof of ownership for confirmed unspent outputs at last confirmed height,
+    /// outputs locked by unfinished transactions are not included.
+    pub fn reserves_proof(&self, message: String) -> Result<ReservesProof, Error> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let k = w_inst.keychain((&None).as_ref())?;
+        let parent_key_id = w_inst.parent_key_id();
+        let mut proof = ReservesProof {
+            height: w_inst.last_confirmed_height()?,
+            message,
+            total: 0,
+            outputs: vec![],
+        };
+        let outputs = w_inst.iter().filter(|o| {
+            o.root_key_id == parent_key_id && o.status == OutputStatus::Unspent &&
+                o.height <= proof.height
+        }).collect::<Vec<OutputData>>();
+        for o in outputs {
+            let commit = k.commit(o.value, &o.key_id, SwitchCommitmentType::Regular)?;
+            let msg = proof.output_message(commit.as_ref())?;
+            let sig = k.sign(&msg, o.value, &o.key_id, SwitchCommitmentType::Regular)?;
+            proof.total += o.value;
+            proof.outputs.push(ReservesOutput {
+                commit: commit.to_hex(),
+                value: o.value,
+                signature: sig.serialize_compact(k.secp()).to_hex(),
+            });
         }
-
-        let wallet = self.clone();
-        thread::spawn(move || {
-            // Wait sync to finish.
-            if wallet.syncing() {
-                thread::sleep(Duration::from_millis(1000));
-            }
-            let r_inst = wallet.instance.as_ref().read();
-            let instance = r_inst.clone().unwrap();
-            if cancel_tx(instance, None, &None, Some(id), None).is_ok() {
-                sync_wallet_data(&wallet, false);
-            }
-        });
+        Ok(proof)
     }
 
-    /// Get possible transaction confirmation height from db or node.
-    fn tx_height(&self, tx: &TxLogEntry, store: &TxHeightStore) -> Result<Option<u64>, Error> {
-        let mut tx_height = None;
-        if tx.kernel_lookup_min_height.is_some() && tx.kernel_excess.is_some() && tx.confirmed {
-            if let Some(height) = store.read_tx_height(tx.id) {
-                tx_height = Some(height);
-            } else {
-                let r_inst = self.instance.as_ref().read();
-                let instance = r_inst.clone().unwrap();
-                let mut w_lock = instance.lock();
-                let w = w_lock.lc_provider()?.wallet_inst()?;
-                if let Ok(res) = w.w2n_client().get_kernel(
-                    tx.kernel_excess.as_ref().unwrap(),
-                    tx.kernel_lookup_min_height,
-                    None
-                ) {
-                    if let Some((_, h, _)) = res {
-                        tx_height = Some(h);
-                        store.write_tx_height(tx.id, h);
-                    } else {
-                        tx_height = Some(0);
-                    }
+    /// Verify signatures of provided [`ReservesProof`] and check its outputs at the chain,
+    /// returning total amount of proven outputs.
+    pub fn verify_reserves_proof(&self, proof: &ReservesProof) -> Result<u64, Error> {
+        let parse_err = |_| Error::GenericError("Invalid proof data".to_string());
+        let mut commits = vec![];
+        let mut total: u64 = 0;
+        {
+            let secp = static_secp_instance();
+            let secp = secp.lock();
+            for o in &proof.outputs {
+                let commit = Commitment::from_vec(from_hex(&o.commit).map_err(parse_err)?);
+                if commits.contains(&commit) {
+                    return Err(Error::GenericError("Duplicate output".to_string()));
                 }
+                let sig_data = from_hex(&o.signature).map_err(parse_err)?;
+                let sig = Signature::from_compact(&secp, &sig_data)?;
+                // Get public key of blinding factor from commitment and value.
+                let value_commit = secp.commit_value(o.value)?;
+                let pub_key = secp.commit_sum(vec![commit], vec![value_commit])?
+                    .to_pubkey(&secp)?;
+                let msg = proof.output_message(commit.as_ref())?;
+                secp.verify(&msg, &sig, &pub_key)
+                    .map_err(|_| Error::GenericError("Invalid signature".to_string()))?;
+                commits.push(commit);
+                total = total.checked_add(o.value)
+                    .ok_or(Error::GenericError("Invalid total".to_string()))?;
             }
         }
-        Ok(tx_height)
-    }
-
-    /// Change wallet password.
-    pub fn change_password(&self, old: String, new: String) -> Result<(), Error> {
+        if total != proof.total {
+            return Err(Error::GenericError("Invalid total".to_string()));
+        }
+        // Check proof height and if outputs exist at the chain since proof height.
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
-        let mut wallet_lock = instance.lock();
-        let lc = wallet_lock.lc_provider()?;
-        lc.change_password(None, ZeroingString::from(old), ZeroingString::from(new))
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        let w_inst = lc.wallet_inst()?;
+        let client = w_inst.w2n_client();
+        let (tip_height, _) = client.get_chain_tip()?;
+        if proof.height > tip_height {
+            return Err(Error::GenericError("Invalid height".to_string()));
+        }
+        let chain_outputs = client.get_outputs_from_node(commits.clone())?;
+        let at_height = |c: &Commitment| {
+            chain_outputs.get(c).map(|(_, height, _)| *height <= proof.height).unwrap_or(false)
+        };
+        if !commits.iter().all(at_height) {
+            return Err(Error::GenericError("Output not found".to_string()));
+        }
+        Ok(total)
     }
 
     /// Initiate wallet repair by scanning its outputs.
-    pub fn repair(&self) {
+    pub fn repair(&self, reason: RepairReason) {
+        {
+            let mut w_reason = self.repair_reason.write();
+            *w_reason = reason;
+        }
         self.repair_needed.store(true, Ordering::Relaxed);
         self.sync();
     }
@@ -1020,6 +2993,110 @@ impl Wallet {
         self.repair_progress.load(Ordering::Relaxed)
     }
 
+    /// Scan copy of wallet database to find changes which repair would apply
+    /// without modification of wallet data.
+    pub fn check_repair(&self, password: String) -> Result<(), Error> {
+        if self.is_repairing() || self.is_repair_checking() {
+            return Ok(());
+        }
+        // Check password before scanning.
+        self.get_recovery(password.clone())?;
+        self.repair_checking.store(true, Ordering::Relaxed);
+        {
+            let mut w_report = self.repair_report.write();
+            *w_report = None;
+        }
+        let wallet = self.clone();
+        thread::spawn(move || {
+            let report = check_repair_wallet(&wallet, ZeroingString::from(password))
+                .map_err(|e| format!("{}", e));
+            let mut w_report = wallet.repair_report.write();
+            *w_report = Some(report);
+            wallet.repair_checking.store(false, Ordering::Relaxed);
+        });
+        Ok(())
+    }
+
+    /// Check if wallet repair check is running.
+    pub fn is_repair_checking(&self) -> bool {
+        self.repair_checking.load(Ordering::Relaxed)
+    }
+
+    /// Get result of wallet repair check.
+    pub fn repair_report(&self) -> Option<Result<RepairReport, String>> {
+        self.repair_report.read().clone()
+    }
+
+    /// Clear result of wallet repair check.
+    pub fn clear_repair_report(&self) {
+        let mut w_report = self.repair_report.write();
+        *w_report = None;
+    }
+
+    /// Get last completed wallet repair.
+    pub fn completed_repair(&self) -> Option<RepairLogEntry> {
+        self.completed_repair.read().clone()
+    }
+
+    /// Clear last completed wallet repair after showing.
+    pub fn clear_completed_repair(&self) {
+        let mut w_repair = self.completed_repair.write();
+        *w_repair = None;
+    }
+
+    /// Get history of completed wallet repairs from newest to oldest.
+    pub fn repair_history(&self) -> Vec<RepairLogEntry> {
+        RepairLogStore::new(self.get_config().get_extra_db_path()).read_all()
+    }
+
+    /// Run action at separate thread or queue it until wallet repair is finished.
+    pub fn queue_action(&self, action: impl FnOnce(&Wallet) + Send + Sync + 'static) {
+        if self.is_repairing() {
+            let mut w_actions = self.queued_actions.write();
+            w_actions.push(Box::new(action));
+        } else {
+            let wallet = self.clone();
+            thread::spawn(move || {
+                action(&wallet);
+            });
+        }
+    }
+
+    /// Start tracking of task changing wallet state until returned guard is dropped.
+    fn start_task(&self) -> WalletTaskGuard {
+        self.active_tasks.fetch_add(1, Ordering::Relaxed);
+        WalletTaskGuard {
+            active_tasks: self.active_tasks.clone()
+        }
+    }
+
+    /// Check if tasks changing wallet state are running.
+    pub fn has_active_tasks(&self) -> bool {
+        self.active_tasks.load(Ordering::Relaxed) != 0
+    }
+
+    /// Get amount of actions waiting for wallet repair to finish.
+    pub fn queued_actions_count(&self) -> usize {
+        self.queued_actions.read().len()
+    }
+
+    /// Run actions queued while wallet was repairing.
+    fn run_queued_actions(&self) {
+        let actions = {
+            let mut w_actions = self.queued_actions.write();
+            std::mem::take(&mut *w_actions)
+        };
+        if actions.is_empty() {
+            return;
+        }
+        let wallet = self.clone();
+        thread::spawn(move || {
+            for action in actions {
+                action(&wallet);
+            }
+        });
+    }
+
     /// Deleting wallet database files.
     pub fn delete_db(&self, reopen: bool) {
         let wallet_delete = self.clone();
@@ -1047,7 +3124,8 @@ impl Wallet {
         let instance = r_inst.clone().unwrap();
         let mut wallet_lock = instance.lock();
         let lc = wallet_lock.lc_provider().unwrap();
-        lc.get_mnemonic(None, ZeroingString::from(password))
+        let seed_pass = SeedKdf::seed_password(&self.get_config().get_seed_path(), &password);
+        lc.get_mnemonic(None, seed_pass)
     }
 
     /// Close the wallet, delete its files and mark it as deleted.
@@ -1088,6 +3166,58 @@ const ATTEMPT_DELAY: Duration = Duration::from_millis(3 * 1000);
 /// Number of attempts to sync [`WalletData`] before setting an error.
 const SYNC_ATTEMPTS: u8 = 10;
 
+/// Amount of transactions to load from wallet database per page.
+const TXS_PAGE_SIZE: usize = 100;
+
+/// Maximal amount of inputs to combine with one consolidation transaction.
+const MAX_CONSOLIDATION_INPUTS: usize = 100;
+
+/// Delay in seconds after first post to allow replacement of not confirmed transaction.
+const REPLACE_TX_DELAY: i64 = 24 * 60 * 60;
+
+/// Delay in seconds to rebroadcast finalized transaction if it was not confirmed.
+const TX_REPOST_DELAY: i64 = 30 * 60;
+
+/// Maximal amount of automatic rebroadcast attempts for finalized transaction.
+const MAX_TX_REPOSTS: u64 = 3;
+
+/// Format version of quick unlock data, increased on encryption changes.
+const UNLOCK_DATA_VERSION: u8 = 1;
+
+/// Maximal amount of Foreign API requests to keep at log.
+const FOREIGN_API_LOG_SIZE: usize = 100;
+
+/// Maximal amount of concurrent kernel lookups at sync.
+const KERNEL_LOOKUP_LIMIT: usize = 8;
+
+/// Initial delay in seconds to retry lookup of not found kernel, doubling on each attempt.
+const KERNEL_RETRY_DELAY: i64 = 60;
+
+/// Maximal delay in seconds to retry lookup of not found kernel.
+const KERNEL_RETRY_MAX_DELAY: i64 = 60 * 60;
+
+/// Run provided callback with fee base multiplied by provided factor at current thread.
+fn with_fee_factor<T>(factor: u64, f: impl FnOnce() -> T) -> T {
+    let fee_base = global::get_accept_fee_base();
+    global::set_local_accept_fee_base(fee_base * factor.max(1));
+    let res = f();
+    global::set_local_accept_fee_base(fee_base);
+    res
+}
+
+/// Initialize a transaction with fee base multiplied to pay not less than provided fee.
+fn init_tx_with_min_fee(
+    api: &Owner<DefaultLCProvider<HTTPNodeClient, ExtKeychain>, HTTPNodeClient, ExtKeychain>,
+    args: InitTxArgs,
+    min_fee: u64
+) -> Result<Slate, Error> {
+    let mut estimate_args = args.clone();
+    estimate_args.estimate_only = Some(true);
+    let fee = api.init_send_tx(None, estimate_args)?.fee_fields.fee().max(1);
+    let factor = ((min_fee + fee - 1) / fee).max(1);
+    with_fee_factor(factor, || api.init_send_tx(None, args))
+}
+
 /// Launch thread to sync wallet data from node.
 fn start_sync(wallet: Wallet) -> Thread {
     // Reset progress values.
@@ -1103,6 +3233,10 @@ fn start_sync(wallet: Wallet) -> Thread {
         // Clear wallet info.
         let mut w_data = wallet.data.write();
         *w_data = None;
+        wallet.update_balance(None);
+        wallet.data_stale.store(false, Ordering::Relaxed);
+        wallet.close_fixture();
+        wallet.synced_tip.store(0, Ordering::Relaxed);
 
         // Clear syncing status.
         wallet.syncing.store(false, Ordering::Relaxed);
@@ -1132,8 +3266,8 @@ fn start_sync(wallet: Wallet) -> Thread {
             }
             // Set an error when required integrated node is not enabled.
             wallet.set_sync_error(not_enabled);
-            // Skip cycle when node sync is not finished.
-            if !Node::is_running() || Node::get_sync_status() != Some(SyncStatus::NoSync) {
+            // Skip cycle when node sync is not finished or node is not ready for wallets.
+            if !Node::is_ready_for_wallets() {
                 thread::park_timeout(ATTEMPT_DELAY);
                 continue;
             }
@@ -1169,18 +3303,46 @@ fn start_sync(wallet: Wallet) -> Thread {
                 }
             }
 
+            // Start or stop Owner API server.
+            wallet.update_owner_api_server();
+
             // Start Tor service if API server is running and wallet is open.
-            if wallet.auto_start_tor_listener() && wallet.is_open() && !wallet.is_closing() &&
-                api_server_running && !Tor::is_service_running(&wallet.identifier()) {
+            if wallet.auto_start_tor_listener() && wallet.tor_listener_allowed() &&
+                wallet.is_open() && !wallet.is_closing() && api_server_running && !Tor::is_service_running(&wallet.identifier()) {
                 let r_foreign_api = wallet.foreign_api_server.read();
                 let api = r_foreign_api.as_ref().unwrap();
-                if let Ok(sec_key) = wallet.secret_key() {
+                if let Ok(sec_key) = wallet.tor_service_key() {
                     Tor::start_service(api.1, sec_key, &wallet.identifier());
+                    // Update default connections manifest when Tor is used.
+                    NodesManifest::update();
                 }
             }
 
-            // Sync wallet from node.
-            sync_wallet_data(&wallet, true);
+            // Sync wallet from node if requested or chain tip was changed since last sync,
+            // otherwise refresh data from local database.
+            let requested = wallet.sync_requested.swap(false, Ordering::Relaxed);
+            let tip = wallet.node_client().and_then(|client| {
+                SyncScheduler::chain_tip(&wallet.get_current_connection(), &client)
+            });
+            let synced_tip = wallet.synced_tip.load(Ordering::Relaxed);
+            if requested || tip.map(|t| t != synced_tip).unwrap_or(true) {
+                {
+                    let _worker = SyncScheduler::acquire();
+                    sync_wallet_data(&wallet, true);
+                }
+
+                // Post queued transactions and rebroadcast unconfirmed if node is available.
+                if !wallet.sync_error() && wallet.get_sync_attempts() == 0 {
+                    wallet.synced_tip.store(tip.unwrap_or(0), Ordering::Relaxed);
+                    if let Some(height) = tip {
+                        wallet.save_creation_height(height);
+                    }
+                    wallet.post_queued_txs();
+                    wallet.rebroadcast_txs();
+                }
+            } else {
+                sync_wallet_data(&wallet, false);
+            }
         }
 
         // Stop sync if wallet was closed.
@@ -1192,6 +3354,11 @@ fn start_sync(wallet: Wallet) -> Thread {
         // Setup flag to check if sync was failed.
         let failed_sync = wallet.sync_error() || wallet.get_sync_attempts() != 0;
 
+        // Switch to next available external connection on failure.
+        if failed_sync && wallet.failover_connection() {
+            continue;
+        }
+
         // Clear syncing status.
         if !failed_sync {
             wallet.syncing.store(false, Ordering::Relaxed);
@@ -1247,6 +3414,7 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
             // Do not retrieve txs if wallet was closed or its first sync.
             if !wallet.is_open() || wallet.is_closing() ||
                 (!from_node && info.1.last_confirmed_height == 0) {
+                wallet.txs_loading.store(false, Ordering::Relaxed);
                 return;
             }
 
@@ -1263,51 +3431,121 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
                 // Update wallet info.
                 {
                     let mut w_data = wallet.data.write();
-                    let txs = if w_data.is_some() {
-                        w_data.clone().unwrap().txs
-                    } else {
-                        None
+                    let (txs, stats) = match w_data.clone() {
+                        Some(data) => (data.txs, data.stats),
+                        None => (None, WalletTxStats::default())
                     };
-                    *w_data = Some(WalletData { info: info.1.clone(), txs });
+                    *w_data = Some(WalletData { info: info.1.clone(), txs, stats });
+                    wallet.update_balance(w_data.as_ref());
                 }
 
-                // Retrieve txs from local database.
+                // Retrieve txs from local database, fetching only loaded window at periodic sync
+                // or next page with one more transaction to check if more are available.
+                let min_id = {
+                    let r_min_id = wallet.txs_min_id.read();
+                    *r_min_id
+                };
+                let limit = wallet.txs_limit.load(Ordering::Relaxed);
                 let txs_args = RetrieveTxQueryArgs {
                     exclude_cancelled: Some(false),
+                    min_id,
+                    limit: if min_id.is_none() { Some(limit as u32 + 1) } else { None },
+                    sort_field: Some(RetrieveTxQuerySortField::CreationTimestamp),
+                    sort_order: Some(RetrieveTxQuerySortOrder::Desc),
+                    ..Default::default()
+                };
+                // Retrieve unfinished txs outside of loaded window.
+                let outstanding_args = RetrieveTxQueryArgs {
+                    exclude_cancelled: Some(true),
+                    include_outstanding_only: Some(true),
                     sort_field: Some(RetrieveTxQuerySortField::CreationTimestamp),
                     sort_order: Some(RetrieveTxQuerySortOrder::Desc),
                     ..Default::default()
                 };
-                if let Ok(txs) = retrieve_txs(instance.clone(),
-                                              None,
-                                              &None,
-                                              false,
-                                              None,
-                                              None,
-                                              Some(txs_args)) {
+                let page = retrieve_txs(instance.clone(),
+                                        None,
+                                        &None,
+                                        false,
+                                        None,
+                                        None,
+                                        Some(txs_args));
+                let outstanding = retrieve_txs(instance.clone(),
+                                               None,
+                                               &None,
+                                               false,
+                                               None,
+                                               None,
+                                               Some(outstanding_args));
+                if let (Ok(page), Ok(outstanding)) = (page, outstanding) {
                     // Exit if wallet was closed.
                     if !wallet.is_open() {
+                        wallet.txs_loading.store(false, Ordering::Relaxed);
                         return;
                     }
                     // Reset sync attempts.
                     wallet.reset_sync_attempts();
 
-                    // Filter transactions for current account.
-                    let account_txs = txs.1.iter().map(|v| v.clone()).filter(|tx| {
-                        match wallet.get_parent_key_id() {
-                            Ok(key) => {
-                                tx.parent_key_id == key
-                            }
-                            Err(_) => {
-                                true
-                            }
+                    // Setup loaded window and flag to check if more txs are available.
+                    let mut page_txs = page.1;
+                    if min_id.is_none() {
+                        wallet.txs_more.store(page_txs.len() > limit, Ordering::Relaxed);
+                        page_txs.truncate(limit);
+                        let mut w_min_id = wallet.txs_min_id.write();
+                        *w_min_id = page_txs.iter().map(|tx| tx.id).min();
+                    }
+
+                    // Add unfinished transactions and filter them for current account.
+                    let parent_key_id = wallet.get_parent_key_id().ok();
+                    for tx in outstanding.1 {
+                        if !page_txs.iter().any(|t| t.id == tx.id) {
+                            page_txs.push(tx);
                         }
+                    }
+                    let account_txs = page_txs.into_iter().filter(|tx| {
+                        parent_key_id.as_ref().map(|k| tx.parent_key_id == *k).unwrap_or(true)
                     }).collect::<Vec<TxLogEntry>>();
 
-                    // Initialize tx confirmation height storage.
-                    let tx_height_store = TxHeightStore::new(config.get_extra_db_path());
+                    // Transactions data is stored separately for each account.
+                    let tx_db_path = match &parent_key_id {
+                        Some(k) => config.get_tx_db_path(k),
+                        None => config.get_extra_db_path()
+                    };
+
+                    // Update saved aggregated data with loaded transactions,
+                    // aggregating all account transactions only when it is not possible.
+                    let tx_stats_store = TxStatsStore::new(tx_db_path.clone());
+                    let mut stats_state = tx_stats_store.read();
+                    let updated = stats_state.as_mut()
+                        .map(|s| s.update(&account_txs))
+                        .unwrap_or(false);
+                    if !updated {
+                        if let Ok(all_txs) = wallet.retrieve_account_txs() {
+                            stats_state = Some(TxStatsState::from_txs(&all_txs));
+                        }
+                    }
+                    if let Some(state) = &stats_state {
+                        tx_stats_store.write(state);
+                    }
+                    let stats = match &stats_state {
+                        Some(state) => WalletTxStats::from_state(state),
+                        None => wallet.get_data().map(|d| d.stats).unwrap_or_default()
+                    };
+                    // Initialize tx memo storage.
+                    let tx_memo_store = TxMemoStore::new(tx_db_path.clone());
+                    // Initialize tx post queue storage.
+                    let tx_post_queue_store = TxPostQueueStore::new(tx_db_path.clone());
+                    // Initialize tx rebroadcast storage.
+                    let tx_repost_store = TxRepostStore::new(tx_db_path.clone());
+                    // Initialize abandoned invoices storage.
+                    let tx_abandoned_store = TxAbandonedStore::new(tx_db_path.clone());
+                    // Initialize tx stage times storage.
+                    let tx_stage_store = TxStageStore::new(tx_db_path.clone());
+                    // Get confirmation heights of transactions.
+                    let tx_heights = wallet.tx_heights(&account_txs, tx_db_path.clone());
                     let data = wallet.get_data().unwrap();
                     let data_txs = data.txs.unwrap_or(vec![]);
+                    // Get minimal incoming amount to mark dust.
+                    let min_receive = wallet.min_receive_amount();
 
                     let mut new_txs: Vec<WalletTransaction> = vec![];
                     for tx in &account_txs {
@@ -1348,7 +3586,7 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
                         };
 
                         // Setup confirmation height and cancelling status
-                        let mut conf_height = wallet.tx_height(tx, &tx_height_store).unwrap_or(None);
+                        let mut conf_height = tx_heights.get(&tx.id).copied();
                         let mut cancelling = false;
                         for t in &data_txs {
                             if t.data.id == tx.id {
@@ -1364,6 +3602,40 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
                             }
                         }
 
+                        // Setup time when transaction was queued to post,
+                        // removing it from the queue after confirmation or cancellation.
+                        let mut post_queued = tx_post_queue_store.read_queued_time(tx.id);
+                        if post_queued.is_some() && (tx.confirmed || !finalizing ||
+                            tx.tx_type == TxLogEntryType::TxReceivedCancelled ||
+                            tx.tx_type == TxLogEntryType::TxSentCancelled) {
+                            tx_post_queue_store.remove(tx.id);
+                            post_queued = None;
+                        }
+
+                        // Setup amount of rebroadcast attempts,
+                        // removing data after confirmation or cancellation.
+                        let repost = tx_repost_store.read(tx.id);
+                        let mut reposts = repost.map(|r| r.1).unwrap_or(0);
+                        if repost.is_some() && (tx.confirmed ||
+                            tx.tx_type == TxLogEntryType::TxReceivedCancelled ||
+                            tx.tx_type == TxLogEntryType::TxSentCancelled) {
+                            tx_repost_store.remove(tx.id);
+                            reposts = 0;
+                        }
+
+                        // Setup time when invoice was abandoned,
+                        // removing it after confirmation or cancellation.
+                        let mut abandoned = tx_abandoned_store.read_abandoned_time(tx.id);
+                        if abandoned.is_some() && (tx.confirmed ||
+                            tx.tx_type == TxLogEntryType::TxReceivedCancelled) {
+                            tx_abandoned_store.remove(tx.id);
+                            abandoned = None;
+                        }
+
+                        // Mark incoming transaction below minimal amount as dust.
+                        let dust = tx.tx_type == TxLogEntryType::TxReceived &&
+                            min_receive.map(|m| amount < m).unwrap_or(false);
+
                         // Add transaction to the list.
                         new_txs.push(WalletTransaction {
                             data: tx.clone(),
@@ -1372,18 +3644,58 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
                             can_finalize,
                             finalizing,
                             height: conf_height,
-                            from_node: !fresh_sync || from_node
+                            from_node: !fresh_sync || from_node,
+                            memo: tx_memo_store.read_tx_memo(tx.id),
+                            post_queued,
+                            reposts,
+                            abandoned,
+                            dust,
+                            stages: tx_stage_store.read(tx.id)
                         });
                     }
 
+                    // Save received funds to notify about at Android background service,
+                    // comparing with confirmation time of last notified transaction.
+                    if cfg!(target_os = "android") {
+                        let tx_notify_store = TxNotifyStore::new(tx_db_path);
+                        let notified_time = tx_notify_store.read_notified_time();
+                        let mut last_time = notified_time.unwrap_or(0);
+                        for tx in &new_txs {
+                            if tx.data.tx_type != TxLogEntryType::TxReceived || !tx.data.confirmed {
+                                continue;
+                            }
+                            let time = match tx.data.confirmation_ts {
+                                Some(ts) => ts.timestamp(),
+                                None => continue
+                            };
+                            // Skip notifications for transactions confirmed before first sync.
+                            if notified_time.map(|t| time > t).unwrap_or(false) {
+                                let mut w_received = RECEIVED_FUNDS.write();
+                                w_received.push((config.name.clone(), tx.amount));
+                            }
+                            last_time = last_time.max(time);
+                        }
+                        if notified_time != Some(last_time) {
+                            tx_notify_store.write_notified_time(last_time);
+                        }
+                    }
+
                     // Update wallet txs.
-                    let mut w_data = wallet.data.write();
-                    let info = if w_data.is_some() {
-                        w_data.clone().unwrap().info
-                    } else {
-                        info.1
-                    };
-                    *w_data = Some(WalletData { info, txs: Some(new_txs) });
+                    {
+                        let mut w_data = wallet.data.write();
+                        let info = if w_data.is_some() {
+                            w_data.clone().unwrap().info
+                        } else {
+                            info.1
+                        };
+                        *w_data = Some(WalletData { info, txs: Some(new_txs), stats });
+                        wallet.update_balance(w_data.as_ref());
+                    }
+                    wallet.data_stale.store(false, Ordering::Relaxed);
+                    wallet.txs_loading.store(false, Ordering::Relaxed);
+
+                    // Save synced data to show at next launch.
+                    wallet.save_data_snapshot();
                     return;
                 }
             }
@@ -1392,6 +3704,7 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
 
     // Reset progress.
     wallet.info_sync_progress.store(0, Ordering::Relaxed);
+    wallet.txs_loading.store(false, Ordering::Relaxed);
 
     // Exit if wallet was closed.
     if !wallet.is_open() {
@@ -1399,7 +3712,7 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
     }
 
     // Set an error if data was not loaded after opening or increment attempts count.
-    if wallet.get_data().is_none() {
+    if wallet.get_data().is_none() || wallet.is_data_stale() {
         wallet.set_sync_error(true);
     } else {
         wallet.increment_sync_attempts();
@@ -1412,29 +3725,42 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
     }
 }
 
-/// Start Foreign API server to receive txs over transport and mining rewards.
-fn start_api_server(wallet: &Wallet) -> Result<(ApiServer, u16), Error> {
-    let host = "127.0.0.1";
-    let port = wallet.get_config().api_port.unwrap_or(rand::thread_rng().gen_range(10000..30000));
-    let free_port = (port..).find(|port| {
-        return match TcpListener::bind((host, port.to_owned())) {
+/// Host of wallet API servers.
+const API_HOST: &'static str = "127.0.0.1";
+
+/// Find free port for wallet API server starting from provided or random port
+/// at configured range.
+fn free_api_port(port: Option<u16>) -> Option<u16> {
+    let mut port = port.unwrap_or(rand::thread_rng().gen_range(10000..30000));
+    // Limit port to configured range.
+    let range = AppConfig::wallet_api_ports().unwrap_or((port, u16::MAX));
+    if port < range.0 || port > range.1 {
+        port = range.0;
+    }
+    (port..=range.1).chain(range.0..port).find(|port| {
+        return match TcpListener::bind((API_HOST, port.to_owned())) {
             Ok(_) => {
                 let node_p2p_port = NodeConfig::get_p2p_port();
                 let node_api_port = NodeConfig::get_api_ip_port().1;
-                let free = port.to_string() != node_p2p_port && port.to_string() != node_api_port;
-                if free {
-                    let mut config = wallet.config.write();
-                    config.api_port = Some(*port);
-                    config.save();
-                }
-                free
+                port.to_string() != node_p2p_port && port.to_string() != node_api_port
             },
             Err(_) => false
         }
-    }).unwrap();
+    })
+}
+
+/// Start Foreign API server to receive txs over transport and mining rewards.
+fn start_api_server(wallet: &Wallet) -> Result<(ApiServer, u16), Error> {
+    let free_port = free_api_port(wallet.get_config().api_port)
+        .ok_or_else(|| Error::GenericError("No free port for Foreign API".to_string()))?;
+    {
+        let mut config = wallet.config.write();
+        config.api_port = Some(free_port);
+        config.save();
+    }
 
     // Setup API server address.
-    let api_addr = format!("{}:{}", host, free_port);
+    let api_addr = format!("{}:{}", API_HOST, free_port);
 
     // Start Foreign API server thread.
     let r_inst = wallet.instance.as_ref().read();
@@ -1443,9 +3769,10 @@ fn start_api_server(wallet: &Wallet) -> Result<(ApiServer, u16), Error> {
                                                   Arc::new(Mutex::new(None)),
                                                   false,
                                                   Mutex::new(None));
+    let api_guard = ForeignAPIGuard::new(Arc::new(api_handler_v2), wallet.clone());
     let mut router = Router::new();
     router
-        .add_route("/v2/foreign", Arc::new(api_handler_v2))
+        .add_route("/v2/foreign", Arc::new(api_guard))
         .map_err(|_| Error::GenericError("Router failed to add route".to_string()))?;
 
     let api_chan: &'static mut (oneshot::Sender<()>, oneshot::Receiver<()>) =
@@ -1458,6 +3785,34 @@ fn start_api_server(wallet: &Wallet) -> Result<(ApiServer, u16), Error> {
     Ok((apis, free_port))
 }
 
+/// Start local read-only Owner and status API server for external tools.
+fn start_owner_api_server(wallet: &Wallet) -> Result<(ApiServer, u16), Error> {
+    let free_port = free_api_port(wallet.get_config().owner_api_port)
+        .ok_or_else(|| Error::GenericError("No free port for Owner API".to_string()))?;
+    {
+        let mut config = wallet.config.write();
+        config.owner_api_port = Some(free_port);
+        config.save();
+    }
+
+    let mut router = Router::new();
+    router
+        .add_route(OwnerAPIHandler::ROUTE, Arc::new(OwnerAPIHandler::new(wallet.clone())))
+        .map_err(|_| Error::GenericError("Router failed to add route".to_string()))?;
+    router
+        .add_route(StatusAPIHandler::ROUTE, Arc::new(StatusAPIHandler::new(wallet.clone())))
+        .map_err(|_| Error::GenericError("Router failed to add route".to_string()))?;
+
+    let api_chan: &'static mut (oneshot::Sender<()>, oneshot::Receiver<()>) =
+        Box::leak(Box::new(oneshot::channel::<()>()));
+
+    let mut apis = ApiServer::new();
+    let socket_addr: SocketAddr = format!("{}:{}", API_HOST, free_port).parse().unwrap();
+    let _ = apis.start(socket_addr, router, None, api_chan)
+        .map_err(|_| Error::GenericError("API thread failed to start".to_string()))?;
+    Ok((apis, free_port))
+}
+
 /// Update wallet accounts data.
 fn update_accounts(wallet: &Wallet, current_height: u64, current_spendable: Option<u64>) {
     if let Some(spendable) = current_spendable {
@@ -1527,14 +3882,32 @@ fn repair_wallet(wallet: &Wallet) {
     // Start wallet scanning.
     let r_inst = wallet.instance.as_ref().read();
     let instance = r_inst.clone().unwrap();
-    let api = Owner::new(instance, Some(info_tx));
-    match api.scan(None, Some(1), false) {
+    let api = Owner::new(instance.clone(), Some(info_tx));
+    let config = wallet.get_config();
+    let state_before = repair_state(&api, instance.clone(), config.min_confirmations).ok();
+    match api.scan(None, Some(config.scan_start_height()), false) {
         Ok(()) => {
             // Set sync error if scanning was not complete and wallet is open.
             if wallet.is_open() && wallet.repair_progress.load(Ordering::Relaxed) != 100 {
                 wallet.set_sync_error(true);
             } else {
+                // Save changes made by repair.
+                let state_after = repair_state(&api, instance, config.min_confirmations).ok();
+                if let (Some(before), Some(after)) = (state_before, state_after) {
+                    let entry = RepairLogEntry {
+                        time: chrono::Utc::now().timestamp(),
+                        reason: *wallet.repair_reason.read(),
+                        report: repair_changes(&before, &after),
+                    };
+                    RepairLogStore::new(config.get_extra_db_path()).append(entry.clone());
+                    let mut w_repair = wallet.completed_repair.write();
+                    *w_repair = Some(entry);
+                }
                 wallet.repair_needed.store(false, Ordering::Relaxed);
+                // Run actions queued while repairing.
+                if wallet.is_open() {
+                    wallet.run_queued_actions();
+                }
             }
         }
         Err(_) => {
@@ -1549,4 +3922,199 @@ fn repair_wallet(wallet: &Wallet) {
 
     // Reset repair progress.
     wallet.repair_progress.store(0, Ordering::Relaxed);
+}
+
+/// Check if database lock file is held by another process.
+fn db_lock_held(lock_path: &PathBuf) -> bool {
+    let mut options = fs::OpenOptions::new();
+    options.read(true).write(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::io::AsRawFd;
+        let file = match options.open(lock_path) {
+            Ok(f) => f,
+            Err(_) => return true
+        };
+        // Check for conflicting lock without acquiring it.
+        let mut lock: libc::flock = unsafe { std::mem::zeroed() };
+        lock.l_type = libc::F_WRLCK as _;
+        lock.l_whence = libc::SEEK_SET as _;
+        let res = unsafe { libc::fcntl(file.as_raw_fd(), libc::F_GETLK, &mut lock) };
+        res != 0 || lock.l_type != libc::F_UNLCK as _
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+        // Opening without sharing fails while file is opened by another process.
+        options.share_mode(0).open(lock_path).is_err()
+    }
+}
+
+/// Name of directory to scan copy of wallet database for repair check.
+const REPAIR_CHECK_DIR: &'static str = "repair_check";
+
+/// Scan copy of wallet database to report changes which repair would apply.
+fn check_repair_wallet(wallet: &Wallet, password: ZeroingString) -> Result<RepairReport, Error> {
+    let mut config = wallet.get_config();
+    let data_path = PathBuf::from(config.get_data_path());
+    let mut check_path = data_path.clone();
+    check_path.push(REPAIR_CHECK_DIR);
+    let _ = fs::remove_dir_all(&check_path);
+    let mut check_data_path = check_path.clone();
+    check_data_path.push("wallet_data");
+    let mut wallet_data_path = PathBuf::from(config.get_top_level_path());
+    wallet_data_path.push("wallet_data");
+    {
+        // Hold wallet instance lock to avoid database changes while copying.
+        let r_inst = wallet.instance.as_ref().read();
+        let current = r_inst.clone().ok_or(Error::GenericError("No wallet".to_string()))?;
+        let _lock = current.lock();
+        copy_dir(&wallet_data_path, &check_data_path)
+            .map_err(|e| Error::GenericError(e.to_string()))?;
+    }
+
+    // Open wallet from database copy.
+    let node_client = Wallet::create_node_client(&config)?;
+    let instance = Wallet::inst_wallet::<
+        DefaultLCProvider<HTTPNodeClient, ExtKeychain>,
+        HTTPNodeClient,
+        ExtKeychain,
+    >(&mut config, node_client)?;
+    {
+        let mut w_lock = instance.lock();
+        let lc = w_lock.lc_provider()?;
+        lc.set_top_level_directory(check_path.to_str().unwrap())?;
+        let seed_pass = SeedKdf::seed_password(&config.get_seed_path(), &password);
+        lc.open_wallet(None, seed_pass, false, false)?;
+    }
+
+    let result = (|| {
+        // Read current state from wallet database.
+        let r_inst = wallet.instance.as_ref().read();
+        let current = r_inst.clone().unwrap();
+        let api = Owner::new(current.clone(), None);
+        let before = repair_state(&api, current, config.min_confirmations)?;
+
+        // Scan database copy and read changed state.
+        let check_api = Owner::new(instance.clone(), None);
+        check_api.set_active_account(None, config.account.as_str())?;
+        check_api.scan(None, Some(config.scan_start_height()), false)?;
+        let after = repair_state(&check_api, instance.clone(), config.min_confirmations)?;
+        Ok(repair_changes(&before, &after))
+    })();
+
+    // Close wallet and remove database copy.
+    {
+        let mut w_lock = instance.lock();
+        if let Ok(lc) = w_lock.lc_provider() {
+            let _ = lc.close_wallet(None);
+        }
+    }
+    let _ = fs::remove_dir_all(&check_path);
+    result
+}
+
+/// State of wallet database to compare changes made by repair.
+struct RepairState {
+    /// Outputs of active account.
+    outputs: Vec<OutputData>,
+    /// Transactions of active account.
+    txs: Vec<TxLogEntry>,
+    /// Total balance of active account.
+    balance: u64,
+}
+
+/// Read state of wallet database to compare changes made by repair.
+fn repair_state(
+    api: &Owner<DefaultLCProvider<HTTPNodeClient, ExtKeychain>, HTTPNodeClient, ExtKeychain>,
+    instance: WalletInstance,
+    min_confirmations: u64
+) -> Result<RepairState, Error> {
+    let outputs = api.retrieve_outputs(None, true, false, None)?.1
+        .into_iter()
+        .map(|o| o.output)
+        .collect();
+    let txs = retrieve_txs(instance, None, &None, false, None, None, None)?.1;
+    let balance = api.retrieve_summary_info(None, false, min_confirmations)?.1.total;
+    Ok(RepairState { outputs, txs, balance })
+}
+
+/// Find changes made by repair between provided wallet database states.
+fn repair_changes(before: &RepairState, after: &RepairState) -> RepairReport {
+    let mut report = RepairReport::default();
+    for out in &after.outputs {
+        match before.outputs.iter().find(|o| o.key_id == out.key_id) {
+            None => report.restored_outputs.push(out.value),
+            Some(o) => {
+                if o.status != OutputStatus::Spent && out.status == OutputStatus::Spent {
+                    report.spent_outputs.push(out.value);
+                }
+            }
+        }
+    }
+    let is_cancelled = |tx: &TxLogEntry| {
+        tx.tx_type == TxLogEntryType::TxSentCancelled ||
+            tx.tx_type == TxLogEntryType::TxReceivedCancelled
+    };
+    for tx in after.txs.iter().filter(|tx| is_cancelled(tx)) {
+        let cancelled_before = before.txs.iter()
+            .find(|t| t.id == tx.id)
+            .map(|t| is_cancelled(t))
+            .unwrap_or(false);
+        if !cancelled_before {
+            report.cancelled_txs.push(tx.id);
+        }
+    }
+    report.balance_delta = after.balance as i64 - before.balance as i64;
+    report
+}
+
+/// Escape value for CSV format.
+fn csv_value(value: String) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// LMDB database lock file name, recreated on database opening.
+const LMDB_LOCK_FILE: &'static str = "lock.mdb";
+
+/// Copy directory content recursively, skipping LMDB lock files.
+fn copy_dir(from: &PathBuf, to: &PathBuf) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_name() == LMDB_LOCK_FILE {
+            continue;
+        }
+        let mut target = to.clone();
+        target.push(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[allow(dead_code)]
+#[cfg(target_os = "android")]
+#[allow(non_snake_case)]
+#[no_mangle]
+/// Get text for Android notification about received funds in Java string format.
+pub extern "C" fn Java_mw_gri_android_BackgroundService_getReceivedFundsText(
+    _env: jni::JNIEnv,
+    _class: jni::objects::JObject,
+    _activity: jni::objects::JObject,
+) -> jni::sys::jstring {
+    if let Some((name, amount)) = Wallet::take_received_funds() {
+        let amount = grin_core::core::amount_to_hr_string(amount, true);
+        let text = t!("wallets.funds_received", "amount" = amount, "wallet" = name);
+        let j_text = _env.new_string(text);
+        return j_text.unwrap().into_raw();
+    }
+    std::ptr::null_mut()
 }
\ No newline at end of file